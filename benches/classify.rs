@@ -0,0 +1,31 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use hyperpolyglot::detectors::{try_classify_with_layout, ClassifierLayout};
+use std::fs;
+
+// Compares scoring the candidates language by language with scoring them token by token on a long
+// file, against every language like the fallback for files without any candidates does and
+// against a few candidates like an ambiguous extension does
+fn bench_classify_long(c: &mut Criterion) {
+    let content = fs::read_to_string("samples/Rust/hashmap.rs").unwrap();
+    let few_candidates = ["C", "C++", "Rust", "RenderScript"];
+
+    for (name, candidates) in &[
+        ("all languages", &[][..]),
+        ("few candidates", &few_candidates[..]),
+    ] {
+        // Build the token-major probabilities before timing anything
+        try_classify_with_layout(&content, candidates, ClassifierLayout::TokenMajor);
+
+        c.bench_function(&format!("classify long language-major {}", name), |b| {
+            b.iter(|| {
+                try_classify_with_layout(&content, candidates, ClassifierLayout::LanguageMajor)
+            })
+        });
+        c.bench_function(&format!("classify long token-major {}", name), |b| {
+            b.iter(|| try_classify_with_layout(&content, candidates, ClassifierLayout::TokenMajor))
+        });
+    }
+}
+
+criterion_group!(benches, bench_classify_long);
+criterion_main!(benches);
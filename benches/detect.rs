@@ -0,0 +1,53 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use hyperpolyglot::{detect, Detection};
+use std::path::Path;
+
+// Sample files that are each detected by a different strategy, from the cheapest to the most
+// expensive. Only the filename and extension cases are detected without reading the file
+const CASES: &[(&str, &str, Detection)] = &[
+    (
+        "filename",
+        "samples/Makefile/filenames/Makefile",
+        Detection::Filename("Makefile"),
+    ),
+    (
+        "extension",
+        "samples/Go/embedded.go",
+        Detection::Extension("Go"),
+    ),
+    (
+        "shebang",
+        "samples/Erlang/factorial",
+        Detection::Shebang("Erlang"),
+    ),
+    (
+        "heuristics",
+        "samples/Objective-C/Foo.h",
+        Detection::Heuristics("Objective-C"),
+    ),
+    (
+        "classifier",
+        "samples/MATLAB/normalize.m",
+        Detection::Classifier("MATLAB"),
+    ),
+];
+
+fn bench_detect(c: &mut Criterion) {
+    for (strategy, path, expected) in CASES {
+        let path = Path::new(path);
+        // Make sure the case still exercises the strategy it's named after
+        assert_eq!(
+            detect(path).unwrap().as_ref(),
+            Some(expected),
+            "{}",
+            path.display()
+        );
+
+        c.bench_function(&format!("detect {}", strategy), |b| {
+            b.iter(|| detect(path).unwrap())
+        });
+    }
+}
+
+criterion_group!(benches, bench_detect);
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use hyperpolyglot::Detector;
+
+// Short snippets with ambiguous extensions so every item makes it to the classifier
+const SNIPPETS: &[(&str, &str)] = &[
+    ("foo.h", "#include <stdio.h>\nint add(int a, int b);\n"),
+    (
+        "foo.h",
+        "class Foo {\n  public:\n    Foo();\n    ~Foo();\n};\n",
+    ),
+    ("foo.h", "@interface Foo : NSObject\n- (void)bar;\n@end\n"),
+    ("main.pl", "my $name = shift;\nprint \"Hello $name\\n\";\n"),
+    ("main.pl", "parent(tom, bob).\nparent(bob, ann).\n"),
+    ("util.m", "function y = square(x)\n  y = x .^ 2;\nend\n"),
+    (
+        "util.m",
+        "#import <Foundation/Foundation.h>\nint main() { return 0; }\n",
+    ),
+];
+
+fn items(count: usize) -> Vec<(Option<&'static str>, &'static str)> {
+    SNIPPETS
+        .iter()
+        .cycle()
+        .take(count)
+        .map(|(filename, content)| (Some(*filename), *content))
+        .collect()
+}
+
+fn bench_detect_batch(c: &mut Criterion) {
+    let detector = Detector::new();
+    let items = items(1_000);
+
+    c.bench_function("detect_from_str", |b| {
+        b.iter(|| {
+            items
+                .iter()
+                .map(|(filename, content)| detector.detect_from_str(*filename, content))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("detect_batch", |b| {
+        b.iter(|| detector.detect_batch(items.iter().copied()))
+    });
+}
+
+criterion_group!(benches, bench_detect_batch);
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use hyperpolyglot::{detect_with_content, Detector};
+use std::path::{Path, PathBuf};
+
+// A tree of files whose extensions each map to a single language, so every file is detected
+// without looking at its content
+fn paths(count: usize) -> Vec<PathBuf> {
+    ["go", "py", "java", "kt", "swift", "rb"]
+        .iter()
+        .cycle()
+        .take(count)
+        .enumerate()
+        .map(|(i, extension)| Path::new("src").join(format!("file_{}.{}", i, extension)))
+        .collect()
+}
+
+fn bench_detect_extension(c: &mut Criterion) {
+    let detector = Detector::new();
+    let paths = paths(10_000);
+
+    c.bench_function("detect_with_content single language", |b| {
+        b.iter(|| {
+            paths
+                .iter()
+                .map(|path| detect_with_content(path, ""))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("detect_from_str single language", |b| {
+        b.iter(|| {
+            paths
+                .iter()
+                .map(|path| detector.detect_from_str(path.to_str(), ""))
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+criterion_group!(benches, bench_detect_extension);
+criterion_main!(benches);
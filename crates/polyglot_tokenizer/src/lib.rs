@@ -1,4 +1,6 @@
+pub mod owned_tokenizer;
 pub mod tokenizer;
+pub use owned_tokenizer::{OwnedToken, OwnedTokenizer};
 pub use tokenizer::{Token, Tokenizer};
 
 /// Tokenize the content and return only the identifiers and symbols from the langauge
@@ -0,0 +1,382 @@
+use crate::tokenizer::{Token, Tokenizer};
+use std::{
+    collections::VecDeque,
+    io::{self, ErrorKind, Read},
+    str,
+};
+
+const CHUNK_SIZE_BYTES: usize = 8192;
+
+// The most content a block comment or multi-line string without an end is buffered for while
+// waiting for its end to be read. Past this the block is treated as unterminated, so content like
+// `(*fp)(a)` in C, which looks like the start of an OCaml comment, doesn't hold back every token
+// after it until the end of the reader.
+const MAX_UNTERMINATED_BLOCK_BYTES: usize = 4 * CHUNK_SIZE_BYTES;
+
+/// OwnedToken is the owned counterpart of [`Token`] returned from the [`OwnedTokenizer`]. The
+/// variants hold the same parts as their [`Token`] equivalents.
+///
+/// # Examples
+/// ```
+/// use polyglot_tokenizer::{OwnedToken, Token};
+///
+/// let token = OwnedToken::from(Token::LineComment("//", "Line Comment"));
+/// let expected = OwnedToken::LineComment(String::from("//"), String::from("Line Comment"));
+///
+/// assert_eq!(token, expected);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OwnedToken {
+    BlockComment(String, String, String),
+    Ident(String),
+    LineComment(String, String),
+    Number(String),
+    String(String, String, String),
+    Symbol(String),
+}
+
+impl<'a> From<Token<'a>> for OwnedToken {
+    fn from(token: Token<'a>) -> Self {
+        match token {
+            Token::BlockComment(start, content, end) => {
+                OwnedToken::BlockComment(start.into(), content.into(), end.into())
+            }
+            Token::Ident(ident) => OwnedToken::Ident(ident.into()),
+            Token::LineComment(start, content) => {
+                OwnedToken::LineComment(start.into(), content.into())
+            }
+            Token::Number(number) => OwnedToken::Number(number.into()),
+            Token::String(start, content, end) => {
+                OwnedToken::String(start.into(), content.into(), end.into())
+            }
+            Token::Symbol(symbol) => OwnedToken::Symbol(symbol.into()),
+        }
+    }
+}
+
+/// The owned tokenizer turns the content of a reader into an iterator of [`OwnedToken`] without
+/// reading all of the content into memory first. Content is read in chunks and only the
+/// unfinished tail of the content is kept around between chunks. A block comment or multi-line
+/// string without an end is buffered until its end is read, for up to 32 KiB.
+///
+/// The tokens produced are the same as the tokens produced by the [`Tokenizer`] over the full
+/// content, except that a block comment or multi-line string longer than 32 KiB is treated as if
+/// it were never terminated.
+///
+/// # Examples
+/// ```
+/// use polyglot_tokenizer::{OwnedToken, OwnedTokenizer};
+/// use std::io::Cursor;
+///
+/// let content = Cursor::new("let x = 5;");
+/// let tokens: Vec<OwnedToken> = OwnedTokenizer::new(content)
+///     .tokens()
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// let expected = vec![
+///   OwnedToken::Ident(String::from("let")),
+///   OwnedToken::Ident(String::from("x")),
+///   OwnedToken::Symbol(String::from("=")),
+///   OwnedToken::Number(String::from("5")),
+///   OwnedToken::Symbol(String::from(";")),
+/// ];
+/// assert_eq!(tokens, expected);
+/// ```
+pub struct OwnedTokenizer<R> {
+    reader: R,
+}
+
+impl<R: Read> OwnedTokenizer<R> {
+    pub fn new(reader: R) -> Self {
+        OwnedTokenizer { reader }
+    }
+
+    pub fn tokens(self) -> OwnedTokens<R> {
+        OwnedTokens {
+            buffer: String::new(),
+            chunk: vec![0; CHUNK_SIZE_BYTES],
+            incomplete_bytes: Vec::new(),
+            is_eof: false,
+            pending: VecDeque::new(),
+            reader: self.reader,
+            unterminated: None,
+        }
+    }
+}
+
+pub struct OwnedTokens<R> {
+    buffer: String,
+    chunk: Vec<u8>,
+    incomplete_bytes: Vec<u8>,
+    is_eof: bool,
+    pending: VecDeque<OwnedToken>,
+    reader: R,
+    // The end sequence of the unterminated block at the start of the buffer that tokenizing is
+    // waiting on, along with how much of the buffer is known not to contain it
+    unterminated: Option<(String, usize)>,
+}
+
+impl<R: Read> OwnedTokens<R> {
+    // Reads the next chunk from the reader into the buffer. Bytes at the end of the chunk that
+    // don't make up a full utf-8 character are held onto until the next read.
+    fn fill_buffer(&mut self) -> io::Result<()> {
+        let bytes_read = loop {
+            match self.reader.read(&mut self.chunk) {
+                Ok(bytes_read) => break bytes_read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        };
+
+        if bytes_read == 0 {
+            self.is_eof = true;
+            if !self.incomplete_bytes.is_empty() {
+                return Err(invalid_utf8_error());
+            }
+            return Ok(());
+        }
+
+        self.incomplete_bytes
+            .extend_from_slice(&self.chunk[..bytes_read]);
+        let valid_up_to = match str::from_utf8(&self.incomplete_bytes) {
+            Ok(content) => content.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => return Err(invalid_utf8_error()),
+        };
+        let content = str::from_utf8(&self.incomplete_bytes[..valid_up_to]).unwrap();
+        self.buffer.push_str(content);
+        self.incomplete_bytes.drain(..valid_up_to);
+        Ok(())
+    }
+
+    // Tokenizes the part of the buffer that can't be changed by content that hasn't been read
+    // yet. Returns the number of bytes at the start of the buffer that were consumed.
+    fn tokenize_buffer(&mut self) -> usize {
+        let limit = if self.is_eof {
+            self.buffer.len()
+        } else {
+            match self.buffer.rfind('\n') {
+                Some(idx) => idx + 1,
+                None => return 0,
+            }
+        };
+
+        let content = &self.buffer[..];
+        let mut consumed = limit;
+        self.unterminated = None;
+        let mut tokens = Tokenizer::new(content).tokens();
+        while let Some(token) = tokens.next() {
+            let (start, end) = token_bounds(content, &token);
+            // A block without an end in the buffer could still be ended by content that hasn't
+            // been read yet, unless it's already longer than a block is waited on for
+            let unterminated = tokens
+                .unterminated_end_sequence()
+                .filter(|_| !self.is_eof && content.len() - start < MAX_UNTERMINATED_BLOCK_BYTES);
+            if let Some(end_sequence) = unterminated {
+                self.unterminated = Some((end_sequence.to_string(), content.len() - start));
+                consumed = start;
+                break;
+            }
+            if end > limit {
+                consumed = start;
+                break;
+            }
+            self.pending.push_back(token.into());
+        }
+        consumed
+    }
+
+    // Returns true if the content read since the buffer was last tokenized can't change how it's
+    // tokenized, which is when the buffer starts with an unterminated block whose end still hasn't
+    // been read and the block isn't long enough to give up on yet
+    fn is_still_unterminated(&mut self) -> bool {
+        let (end_sequence, searched) = match &mut self.unterminated {
+            Some(unterminated) if !self.is_eof => unterminated,
+            _ => return false,
+        };
+        if self.buffer.len() >= MAX_UNTERMINATED_BLOCK_BYTES {
+            return false;
+        }
+        // The end sequence could straddle the content that was already searched and the new
+        // content
+        let mut search_start = searched.saturating_sub(end_sequence.len() - 1);
+        while !self.buffer.is_char_boundary(search_start) {
+            search_start -= 1;
+        }
+        if self.buffer[search_start..].contains(end_sequence.as_str()) {
+            return false;
+        }
+        *searched = self.buffer.len();
+        true
+    }
+}
+
+impl<R: Read> Iterator for OwnedTokens<R> {
+    type Item = io::Result<OwnedToken>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(token) = self.pending.pop_front() {
+                return Some(Ok(token));
+            }
+
+            if self.is_eof {
+                return None;
+            }
+
+            if let Err(e) = self.fill_buffer() {
+                self.buffer.clear();
+                self.is_eof = true;
+                return Some(Err(e));
+            }
+            if self.is_still_unterminated() {
+                continue;
+            }
+
+            let consumed = self.tokenize_buffer();
+            self.buffer.drain(..consumed);
+        }
+    }
+}
+
+fn invalid_utf8_error() -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+}
+
+// Returns the start and end byte offsets of the token in the content it was tokenized from
+fn token_bounds(content: &str, token: &Token) -> (usize, usize) {
+    let (first, last) = match token {
+        Token::BlockComment(start, _, end) | Token::String(start, _, end) => (start, end),
+        Token::LineComment(start, comment) => (start, comment),
+        Token::Ident(part) | Token::Number(part) | Token::Symbol(part) => (part, part),
+    };
+    let offset = |part: &str| part.as_ptr() as usize - content.as_ptr() as usize;
+    (offset(first), offset(last) + last.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    // Reads one byte at a time to force tokens to be split across reads
+    struct ByteReader<'a>(&'a [u8]);
+
+    impl<'a> Read for ByteReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.split_first() {
+                Some((byte, rest)) if !buf.is_empty() => {
+                    buf[0] = *byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    fn owned_tokens<R: Read>(reader: R) -> Vec<OwnedToken> {
+        OwnedTokenizer::new(reader)
+            .tokens()
+            .collect::<io::Result<_>>()
+            .unwrap()
+    }
+
+    fn expected_tokens(content: &str) -> Vec<OwnedToken> {
+        Tokenizer::new(content).tokens().map(Into::into).collect()
+    }
+
+    #[test]
+    fn matches_tokenizer() {
+        let sample = r#"
+        fn main() {
+            let x_x2 = 京y;
+            // line comment
+            println!("{}", x_x2);
+        }
+        "#;
+
+        assert_eq!(owned_tokens(Cursor::new(sample)), expected_tokens(sample));
+        assert_eq!(
+            owned_tokens(ByteReader(sample.as_bytes())),
+            expected_tokens(sample)
+        );
+    }
+
+    #[test]
+    fn multiline_tokens_across_reads() {
+        let sample = "let x = 1;\n/* a\n block */\n\"\"\" multi\n line \"\"\"\n";
+
+        assert_eq!(
+            owned_tokens(ByteReader(sample.as_bytes())),
+            expected_tokens(sample)
+        );
+    }
+
+    #[test]
+    fn unterminated_block_across_reads() {
+        let sample = "/* let x\n let y\n";
+
+        assert_eq!(
+            owned_tokens(ByteReader(sample.as_bytes())),
+            expected_tokens(sample)
+        );
+    }
+
+    #[test]
+    fn c_function_pointers_across_reads() {
+        // (* starts an OCaml comment that's never terminated
+        let sample = "int x = (*fp)(a);\nint y = (*gp)(b);\n";
+        assert_eq!(
+            owned_tokens(ByteReader(sample.as_bytes())),
+            expected_tokens(sample)
+        );
+
+        // The comment is terminated after more reads
+        let sample = "int x = (*fp)(a);\nint y = b *) + 1;\n";
+        assert_eq!(
+            owned_tokens(ByteReader(sample.as_bytes())),
+            expected_tokens(sample)
+        );
+    }
+
+    #[test]
+    fn unterminated_blocks_dont_hold_back_tokens() {
+        let sample = "int x = (*fp)(a);\n".repeat(20_000);
+
+        let mut reader = ByteReader(sample.as_bytes());
+        let tokens: Vec<OwnedToken> = OwnedTokenizer::new(&mut reader)
+            .tokens()
+            .take(5)
+            .collect::<io::Result<_>>()
+            .unwrap();
+        let bytes_read = sample.len() - reader.0.len();
+
+        assert_eq!(tokens, expected_tokens(&sample)[..5].to_vec());
+        assert!(bytes_read < sample.len() / 4, "{}", bytes_read);
+        assert!(
+            bytes_read <= MAX_UNTERMINATED_BLOCK_BYTES + CHUNK_SIZE_BYTES,
+            "{}",
+            bytes_read
+        );
+        assert_eq!(owned_tokens(Cursor::new(&sample)), expected_tokens(&sample));
+    }
+
+    #[test]
+    fn no_trailing_newline() {
+        let sample = "let x\nlet y";
+
+        assert_eq!(
+            owned_tokens(ByteReader(sample.as_bytes())),
+            expected_tokens(sample)
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut tokens = OwnedTokenizer::new(Cursor::new(b"let x\n\xff\n")).tokens();
+        let error = tokens.find_map(Result::err).unwrap();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        assert!(tokens.next().is_none());
+    }
+}
@@ -1,9 +1,4 @@
-use circular_queue::CircularQueue;
-use std::{
-    collections::VecDeque,
-    iter::{DoubleEndedIterator, Peekable},
-    str::CharIndices,
-};
+use std::{iter::Peekable, str::CharIndices};
 
 /// Token is an enum whose variants represent each type of possible Token returned from the
 /// [`Tokenizer`]. Block Comments and Strings hold both the start and end indicator for the Tokens.
@@ -66,24 +61,42 @@ impl<'a> Tokenizer<'a> {
 
     pub fn tokens(&self) -> Tokens<'a> {
         Tokens {
-            backlog: VecDeque::new(),
             chars: self.content.char_indices().peekable(),
+            chars_start: 0,
             content: self.content,
             current_token_idx: 0,
+            missing_end_sequences: Vec::new(),
+            unterminated_end_sequence: None,
         }
     }
 }
 
 pub struct Tokens<'a> {
-    backlog: VecDeque<(usize, char)>,
+    // The chars of the content starting at chars_start. When a token turns out to be incomplete
+    // the chars are restarted from an earlier position instead of buffering the chars to revisit
     chars: Peekable<CharIndices<'a>>,
+    chars_start: usize,
     content: &'a str,
     current_token_idx: usize,
+    // End sequences that don't appear anywhere after the index they were searched for from. Any
+    // later search for them can fail right away, so a file full of unterminated comments isn't
+    // rescanned to the end for every one of them.
+    missing_end_sequences: Vec<(&'a str, usize)>,
+    // The end sequence that couldn't be found for the last token, which is set when a block
+    // comment or multi-line string isn't terminated and its first char is returned as a Symbol
+    unterminated_end_sequence: Option<&'a str>,
 }
 
 impl<'a> Tokens<'a> {
+    // Returns the end sequence of the block comment or multi-line string the last token started,
+    // if the end sequence wasn't found and the token is the Symbol that was returned instead
+    pub(crate) fn unterminated_end_sequence(&self) -> Option<&'a str> {
+        self.unterminated_end_sequence
+    }
+
     fn advance(&mut self) -> Option<(usize, char)> {
-        self.next_backlog().or_else(|| self.chars.next())
+        let chars_start = self.chars_start;
+        self.chars.next().map(|(idx, ch)| (idx + chars_start, ch))
     }
 
     fn start_new_token(&mut self) -> Option<char> {
@@ -93,24 +106,14 @@ impl<'a> Tokens<'a> {
     }
 
     fn peek(&mut self) -> Option<(usize, char)> {
-        self.peek_backlog().or(self.chars.peek().copied())
+        let chars_start = self.chars_start;
+        self.chars.peek().map(|(idx, ch)| (idx + chars_start, *ch))
     }
 
-    fn next_backlog(&mut self) -> Option<(usize, char)> {
-        self.backlog.pop_front()
-    }
-
-    fn peek_backlog(&mut self) -> Option<(usize, char)> {
-        self.backlog.front().copied()
-    }
-
-    fn push_backlog<I>(&mut self, new_chars: I)
-    where
-        I: Iterator<Item = (usize, char)> + DoubleEndedIterator,
-    {
-        for ch in new_chars.rev() {
-            self.backlog.push_front(ch)
-        }
+    // Continues tokenizing from the index, which has to be on a char boundary
+    fn restart_at(&mut self, idx: usize) {
+        self.chars = self.content[idx..].char_indices().peekable();
+        self.chars_start = idx;
     }
 
     fn token_start(&self) -> usize {
@@ -167,6 +170,14 @@ impl<'a> Tokens<'a> {
         self.slice_from_token_start(end)
     }
 
+    // Whether the current token starts directly after an alphanumeric character
+    fn follows_word(&self) -> bool {
+        match self.content[..self.token_start()].chars().next_back() {
+            Some(ch) => ch.is_alphanumeric() || ch == '_',
+            None => false,
+        }
+    }
+
     fn slice_from_token_start(&self, end: usize) -> &'a str {
         self.slice(self.token_start(), end)
     }
@@ -175,66 +186,59 @@ impl<'a> Tokens<'a> {
         &self.content[start..end]
     }
 
-    fn block_comment(
-        &mut self,
-        start_sequence: &Vec<char>,
-        end_sequence: &Vec<char>,
-    ) -> Option<Token<'a>> {
-        let mut symbol = vec![start_sequence[0]];
-        for expected_symbol in start_sequence[1..].into_iter() {
+    fn block_comment(&mut self, start_sequence: &str, end_sequence: &'a str) -> Option<Token<'a>> {
+        let token_start = self.token_start();
+        for expected_symbol in start_sequence.chars().skip(1) {
             match self.peek() {
-                Some((_, ch)) if ch == *expected_symbol => {
-                    symbol.push(ch);
+                Some((_, ch)) if ch == expected_symbol => {
                     self.advance();
                 }
                 _ => {
-                    let token_start = self.token_start();
-                    let backlog_chars = symbol[1..]
-                        .into_iter()
-                        .enumerate()
-                        .map(|(idx, ch)| (idx + token_start, *ch));
-                    self.push_backlog(backlog_chars);
-
+                    self.restart_at(token_start + 1);
                     return Some(Token::Symbol(self.slice_from_token_start(token_start + 1)));
                 }
             }
         }
-        let symbol = self.slice_from_token_start(self.token_start() + symbol.len());
-        match self.take_block(self.token_start() + symbol.len(), end_sequence) {
+        let symbol = self.slice_from_token_start(token_start + start_sequence.len());
+        match self.take_block(token_start + start_sequence.len(), end_sequence) {
             Ok((content, end_sequence)) => Some(Token::BlockComment(symbol, content, end_sequence)),
             Err(token) => Some(token),
         }
     }
 
+    // Takes the content up to and including the end sequence. When the end sequence is never
+    // found, the first char of the token is returned as a Symbol and tokenizing restarts after it
     fn take_block(
         &mut self,
         content_idx: usize,
-        end_sequence: &Vec<char>,
+        end_sequence: &'a str,
     ) -> Result<(&'a str, &'a str), Token<'a>> {
-        // start with a random char '@' that won't match the closure check
-        let mut prev_chars = CircularQueue::with_capacity(end_sequence.len());
-        let mut take_if = |ch| {
-            let should_take = prev_chars.iter().eq(end_sequence.iter());
-            if should_take {
-                prev_chars.push(ch);
-            }
-            should_take
+        let is_missing = self
+            .missing_end_sequences
+            .iter()
+            .any(|(missing, from)| *missing == end_sequence && *from <= content_idx);
+        let content_len = match is_missing {
+            true => None,
+            false => self.content[content_idx..].find(end_sequence),
         };
-
-        let end = self.take_if(&mut take_if);
-        if prev_chars.iter().eq(end_sequence.iter()) {
-            let end_sequence_start = end - end_sequence.len();
-            let content = self.slice(content_idx, end_sequence_start);
-            let end_sequence = self.slice(end_sequence_start, end);
-            Ok((content, end_sequence))
-        } else {
-            let backlog_start = self.token_start() + 1;
-            let backlog_chars = self
-                .slice(backlog_start, end)
-                .char_indices()
-                .map(|(idx, ch)| (idx + backlog_start, ch));
-            self.push_backlog(backlog_chars);
-            Err(Token::Symbol(self.slice_from_token_start(backlog_start)))
+        match content_len {
+            Some(content_len) => {
+                let end_sequence_start = content_idx + content_len;
+                let end = end_sequence_start + end_sequence.len();
+                self.restart_at(end);
+                let content = self.slice(content_idx, end_sequence_start);
+                let end_sequence = self.slice(end_sequence_start, end);
+                Ok((content, end_sequence))
+            }
+            None => {
+                if !is_missing {
+                    self.missing_end_sequences.push((end_sequence, content_idx));
+                }
+                self.unterminated_end_sequence = Some(end_sequence);
+                let restart = self.token_start() + 1;
+                self.restart_at(restart);
+                Err(Token::Symbol(self.slice_from_token_start(restart)))
+            }
         }
     }
 }
@@ -242,6 +246,7 @@ impl<'a> Tokens<'a> {
 impl<'a> Iterator for Tokens<'a> {
     type Item = Token<'a>;
     fn next(&mut self) -> Option<Self::Item> {
+        self.unterminated_end_sequence = None;
         self.eat_whitespace();
         match self.start_new_token() {
             Some(ch) if ch.is_alphabetic() || ch == '_' => Some(Token::Ident(
@@ -287,6 +292,16 @@ impl<'a> Iterator for Tokens<'a> {
             Some(ch) if ch.is_numeric() => {
                 Some(Token::Number(self.take_if_slice(&mut numeric_closure())))
             }
+            // A leading decimal point starts a number when it's followed by a digit, e.g. `.5`,
+            // unless it's attached to the end of a previous number or identifier, e.g. `1.1.4`
+            Some('.') => match self.peek() {
+                Some((_, ch)) if ch.is_numeric() && !self.follows_word() => Some(Token::Number(
+                    self.take_if_slice(&mut |ch| ch.is_numeric() || ch == '_'),
+                )),
+                _ => Some(Token::Symbol(
+                    self.slice_from_token_start(self.token_start() + 1),
+                )),
+            },
             Some('/') => match self.peek() {
                 Some((_, '/')) => {
                     let symbol = self.take_if_slice(&mut |ch| ch == '/');
@@ -295,31 +310,38 @@ impl<'a> Iterator for Tokens<'a> {
                     let comment = self.slice(comment_start, comment_end);
                     Some(Token::LineComment(symbol, comment))
                 }
-                Some((_, '*')) => self.block_comment(&vec!['/', '*'], &vec!['*', '/']),
+                Some((_, '*')) => self.block_comment("/*", "*/"),
                 _ => Some(Token::Symbol(
                     self.slice_from_token_start(self.token_start() + 1),
                 )),
             },
             Some('{') => match self.peek() {
-                Some((_, '-')) => self.block_comment(&vec!['{', '-'], &vec!['-', '}']),
+                Some((_, '-')) => self.block_comment("{-", "-}"),
                 _ => Some(Token::Symbol(
                     self.slice_from_token_start(self.token_start() + 1),
                 )),
             },
             Some('(') => match self.peek() {
-                Some((_, '*')) => self.block_comment(&vec!['(', '*'], &vec!['*', ')']),
+                Some((_, '*')) => self.block_comment("(*", "*)"),
                 _ => Some(Token::Symbol(
                     self.slice_from_token_start(self.token_start() + 1),
                 )),
             },
-            Some('<') => self.block_comment(&vec!['<', '!', '-', '-'], &vec!['-', '-', '>']),
-            Some('#') => {
-                let symbol = self.take_if_slice(&mut |ch| ch == '#');
-                let comment_start = self.eat_non_newline_whitespace();
-                let comment_end = self.take_if(&mut |ch| ch != '\r' && ch != '\n');
-                let comment = self.slice(comment_start, comment_end);
-                Some(Token::LineComment(symbol, comment))
-            }
+            Some('<') => self.block_comment("<!--", "-->"),
+            // A # only starts a comment when it's followed by whitespace or more #s, so C
+            // preprocessor directives like `#include` are kept as a symbol and an identifier
+            Some('#') => match self.peek() {
+                Some((_, ch)) if ch != '#' && !ch.is_whitespace() => Some(Token::Symbol(
+                    self.slice_from_token_start(self.token_start() + 1),
+                )),
+                _ => {
+                    let symbol = self.take_if_slice(&mut |ch| ch == '#');
+                    let comment_start = self.eat_non_newline_whitespace();
+                    let comment_end = self.take_if(&mut |ch| ch != '\r' && ch != '\n');
+                    let comment = self.slice(comment_start, comment_end);
+                    Some(Token::LineComment(symbol, comment))
+                }
+            },
             Some('%') => {
                 let symbol = self.take_if_slice(&mut |ch| ch == '%');
                 let comment_start = self.eat_non_newline_whitespace();
@@ -351,13 +373,9 @@ impl<'a> Iterator for Tokens<'a> {
                                 ))
                             }
                             _ => {
-                                let backlog_start = self.token_start() + 1;
-                                let chars_to_backlog = string_content
-                                    .char_indices()
-                                    .map(|(idx, ch)| (idx + backlog_start, ch));
-
-                                self.push_backlog(chars_to_backlog);
-                                Some(Token::Symbol(self.slice_from_token_start(backlog_start)))
+                                let restart = self.token_start() + 1;
+                                self.restart_at(restart);
+                                Some(Token::Symbol(self.slice_from_token_start(restart)))
                             }
                         }
                     }
@@ -369,20 +387,14 @@ impl<'a> Iterator for Tokens<'a> {
                     )),
                     // If there were more than two quote identifiers, assume a mutli line string,
                     // that ends with the same number of identifiers
-                    _ => {
-                        let string_indicator = vec![quote_char; symbol.len()];
-                        match self.take_block(
-                            self.token_start() + string_indicator.len(),
-                            &string_indicator,
-                        ) {
-                            Ok((content, end_indicator)) => Some(Token::String(
-                                self.slice_from_token_start(self.token_start() + symbol.len()),
-                                content,
-                                end_indicator,
-                            )),
-                            Err(token) => Some(token),
-                        }
-                    }
+                    _ => match self.take_block(self.token_start() + symbol.len(), symbol) {
+                        Ok((content, end_indicator)) => Some(Token::String(
+                            self.slice_from_token_start(self.token_start() + symbol.len()),
+                            content,
+                            end_indicator,
+                        )),
+                        Err(token) => Some(token),
+                    },
                 }
             }
             Some(ch) if ch.is_ascii_punctuation() => Some(Token::Symbol(
@@ -463,6 +475,7 @@ mod tests {
             -1;
             -1_000;
             1.5;
+            .5;
             .1.5;
             1.1.4;
             0b1010;
@@ -483,8 +496,11 @@ mod tests {
             Symbol(";"),
             Number("1.5"),
             Symbol(";"),
+            Number(".5"),
+            Symbol(";"),
+            Number(".1"),
             Symbol("."),
-            Number("1.5"),
+            Number("5"),
             Symbol(";"),
             Number("1.1"),
             Symbol("."),
@@ -526,6 +542,31 @@ mod tests {
         assert_eq!(tokens, expected)
     }
 
+    #[test]
+    fn hash_comment() {
+        let sample = "#include <stdio.h>\n# a python comment\n#\n#define MAX 10";
+
+        let tokenizer = Tokenizer::new(sample);
+        let tokens: Vec<Token> = tokenizer.tokens().collect();
+        let expected = vec![
+            Symbol("#"),
+            Ident("include"),
+            Symbol("<"),
+            Ident("stdio"),
+            Symbol("."),
+            Ident("h"),
+            Symbol(">"),
+            LineComment("#", "a python comment"),
+            LineComment("#", ""),
+            Symbol("#"),
+            Ident("define"),
+            Ident("MAX"),
+            Number("10"),
+        ];
+
+        assert_eq!(tokens, expected)
+    }
+
     #[test]
     fn string() {
         let sample = r#"
@@ -750,6 +791,32 @@ mod tests {
         assert_eq!(tokens, expected);
     }
 
+    #[test]
+    fn large_unterminated_block_comment() {
+        let sample = format!("/* {}", "let x = 5;\n".repeat(100_000));
+
+        let tokens: Vec<Token> = Tokenizer::new(&sample).tokens().collect();
+
+        assert_eq!(tokens.len(), 2 + 5 * 100_000);
+        assert_eq!(
+            &tokens[..4],
+            &[Symbol("/"), Symbol("*"), Ident("let"), Ident("x")]
+        );
+    }
+
+    #[test]
+    fn many_unterminated_block_comments() {
+        let sample = "/* (* ".repeat(100_000);
+
+        let tokens: Vec<Token> = Tokenizer::new(&sample).tokens().collect();
+
+        assert_eq!(tokens.len(), 4 * 100_000);
+        assert_eq!(
+            &tokens[..4],
+            &[Symbol("/"), Symbol("*"), Symbol("("), Symbol("*")]
+        );
+    }
+
     #[test]
     fn random_chars() {
         let sample = r#"
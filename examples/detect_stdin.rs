@@ -0,0 +1,50 @@
+//! Detects the language of the content piped to stdin and prints the detection as JSON
+//!
+//! ```sh
+//! cargo run --example detect_stdin -- --filename build.h < include/build.h
+//! ```
+
+use clap::{App, Arg};
+use hyperpolyglot::{detect_from_bytes, Language};
+use std::{
+    convert::TryFrom,
+    io::{self, Read},
+};
+
+fn main() {
+    let matches = App::new("detect_stdin")
+        .about("Detects the language of the content read from stdin")
+        .arg(
+            Arg::with_name("filename")
+                .long("filename")
+                .takes_value(true)
+                .help(
+                    "The filename of the content, used for the filename and extension strategies",
+                ),
+        )
+        .get_matches();
+
+    let mut bytes = Vec::new();
+    if let Err(e) = io::stdin().read_to_end(&mut bytes) {
+        eprintln!("Error reading stdin: {}", e);
+        std::process::exit(1);
+    }
+
+    match detect_from_bytes(matches.value_of("filename"), &bytes) {
+        Some((detection, confidence)) => {
+            let language_type = Language::try_from(detection.language())
+                .map(|language| format!("{:?}", language.language_type.to_string()))
+                .unwrap_or_else(|_| String::from("null"));
+            println!(
+                r#"{{"language": {:?}, "type": {}, "strategy": {:?}, "confidence": {}}}"#,
+                detection.language(),
+                language_type,
+                detection.variant(),
+                confidence
+            );
+        }
+        None => {
+            println!(r#"{{"language": null, "type": null, "strategy": null, "confidence": null}}"#)
+        }
+    }
+}
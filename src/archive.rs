@@ -0,0 +1,171 @@
+//! Detection for the files inside of tar and zip archives without extracting them to disk
+//!
+//! The entries in an archive are filtered using the same documentation and vendor overrides as
+//! [`get_language_breakdown`](crate::get_language_breakdown). Directories and symlinks are
+//! skipped.
+
+use crate::{detect_reader, filters, Detection, LanguageBreakdown};
+use ignore::overrides::Override;
+use std::{
+    collections::HashMap,
+    io::{self, Read, Seek},
+    path::PathBuf,
+};
+
+/// Detects the language of every file in a tar archive and tallies them up by language
+///
+/// Returns a map from the programming languages to a Vec of the files that were detected and the
+/// strategy used. The paths are the paths of the entries inside of the archive.
+///
+/// # Errors
+/// Errors if the archive can't be read
+pub fn get_tar_language_breakdown<R: Read>(reader: R) -> Result<LanguageBreakdown, io::Error> {
+    let overrides = archive_overrides();
+    let mut language_breakdown = HashMap::new();
+
+    let mut archive = tar::Archive::new(reader);
+    for entry in archive.entries()? {
+        let entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+
+        let path = entry.path()?.into_owned();
+        if filters::is_ignored(&overrides, &path) {
+            continue;
+        }
+
+        if let Some(detection) = detect_reader(&path, entry)? {
+            add_detection(&mut language_breakdown, detection, path);
+        }
+    }
+
+    Ok(language_breakdown)
+}
+
+/// Detects the language of every file in a zip archive and tallies them up by language
+///
+/// Returns a map from the programming languages to a Vec of the files that were detected and the
+/// strategy used. The paths are the paths of the entries inside of the archive.
+///
+/// # Errors
+/// Errors if the archive can't be read
+pub fn get_zip_language_breakdown<R: Read + Seek>(
+    reader: R,
+) -> Result<LanguageBreakdown, zip::result::ZipError> {
+    let overrides = archive_overrides();
+    let mut language_breakdown = HashMap::new();
+
+    let mut archive = zip::ZipArchive::new(reader)?;
+    for i in 0..archive.len() {
+        let entry = archive.by_index(i)?;
+        if !entry.is_file() || is_symlink(entry.unix_mode()) {
+            continue;
+        }
+
+        // Skip entries with paths that would escape the archive
+        let path = match entry.enclosed_name() {
+            Some(path) => path.to_path_buf(),
+            None => continue,
+        };
+        if filters::is_ignored(&overrides, &path) {
+            continue;
+        }
+
+        if let Some(detection) = detect_reader(&path, entry)? {
+            add_detection(&mut language_breakdown, detection, path);
+        }
+    }
+
+    Ok(language_breakdown)
+}
+
+fn archive_overrides() -> Override {
+    filters::default_overrides("").build().unwrap()
+}
+
+fn is_symlink(unix_mode: Option<u32>) -> bool {
+    const S_IFMT: u32 = 0o170000;
+    const S_IFLNK: u32 = 0o120000;
+    matches!(unix_mode, Some(mode) if mode & S_IFMT == S_IFLNK)
+}
+
+fn add_detection(language_breakdown: &mut LanguageBreakdown, detection: Detection, path: PathBuf) {
+    language_breakdown
+        .entry(detection.language())
+        .or_default()
+        .push((detection, path));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+
+    #[test]
+    fn test_tar_language_breakdown() {
+        let mut builder = tar::Builder::new(Vec::new());
+        let mut append = |path: &str, content: &[u8], entry_type: tar::EntryType| {
+            let mut header = tar::Header::new_gnu();
+            header.set_entry_type(entry_type);
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, content).unwrap();
+        };
+        append("src/main.go", b"package main", tar::EntryType::Regular);
+        append("src/lib.purs", b"", tar::EntryType::Regular);
+        append("node_modules/dep/index.go", b"", tar::EntryType::Regular);
+        append("src/", b"", tar::EntryType::Directory);
+        append("script", b"#!/usr/bin/env python", tar::EntryType::Regular);
+        let archive = builder.into_inner().unwrap();
+
+        let breakdown = get_tar_language_breakdown(Cursor::new(archive)).unwrap();
+
+        assert_eq!(
+            breakdown.get("Go"),
+            Some(&vec![(
+                Detection::Extension("Go"),
+                PathBuf::from("src/main.go")
+            )])
+        );
+        assert_eq!(
+            breakdown.get("PureScript"),
+            Some(&vec![(
+                Detection::Extension("PureScript"),
+                PathBuf::from("src/lib.purs")
+            )])
+        );
+        assert_eq!(
+            breakdown.get("Python"),
+            Some(&vec![(
+                Detection::Shebang("Python"),
+                PathBuf::from("script")
+            )])
+        );
+        assert_eq!(breakdown.len(), 3);
+    }
+
+    #[test]
+    fn test_zip_language_breakdown() {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::FileOptions::default();
+        writer.start_file("src/main.go", options).unwrap();
+        writer.write_all(b"package main").unwrap();
+        writer.add_directory("docs/", options).unwrap();
+        writer.start_file("docs/index.go", options).unwrap();
+        writer.write_all(b"package docs").unwrap();
+        let archive = writer.finish().unwrap();
+
+        let breakdown = get_zip_language_breakdown(archive).unwrap();
+
+        assert_eq!(
+            breakdown.get("Go"),
+            Some(&vec![(
+                Detection::Extension("Go"),
+                PathBuf::from("src/main.go")
+            )])
+        );
+        assert_eq!(breakdown.len(), 1);
+    }
+}
@@ -0,0 +1,173 @@
+//! Comparison of detections against a baseline of the languages Linguist detected for the same
+//! files, for measuring how closely hyperpolyglot matches Linguist
+
+use crate::{Detection, DetectionStrategy};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+};
+
+/// A file that hyperpolyglot and the baseline detected as different languages
+#[derive(Debug, Clone, PartialEq)]
+pub struct Disagreement {
+    /// The path of the file relative to the root of the breakdown
+    pub path: PathBuf,
+    /// What hyperpolyglot detected the file as
+    pub detection: Detection,
+    /// The language the baseline has for the file
+    pub baseline_language: String,
+}
+
+/// The differences between a breakdown and a baseline
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DiffReport {
+    /// The number of files both detected as the same language
+    pub agreements: usize,
+    /// The files that were detected as different languages, sorted by path
+    pub disagreements: Vec<Disagreement>,
+    /// The files that are only in the breakdown, sorted by path
+    pub missing_from_baseline: Vec<(PathBuf, Detection)>,
+    /// The files that are only in the baseline along with their language, sorted by path
+    pub missing_from_breakdown: Vec<(PathBuf, String)>,
+}
+
+impl DiffReport {
+    /// Groups the disagreements by the strategy hyperpolyglot used to detect the file, which
+    /// shows whether the heuristics or the classifier are the ones to improve
+    pub fn disagreements_by_strategy(&self) -> BTreeMap<DetectionStrategy, Vec<&Disagreement>> {
+        let mut by_strategy = BTreeMap::new();
+        for disagreement in self.disagreements.iter() {
+            by_strategy
+                .entry(disagreement.detection.strategy())
+                .or_insert_with(Vec::new)
+                .push(disagreement);
+        }
+        by_strategy
+    }
+}
+
+/// Compares a breakdown against a baseline of the language Linguist detected for every file
+///
+/// The baseline is a JSON object mapping the paths of files relative to the root to their
+/// language, ex/ `{"src/main.rs": "Rust"}`. The paths in the breakdown are made relative to
+/// `root`, which should be the path the breakdown was made for.
+///
+/// # Errors
+/// Errors if the baseline isn't a JSON object of strings
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{baseline::compare_to_baseline, get_language_breakdown};
+///
+/// let breakdown = get_language_breakdown("src/bin");
+/// let baseline = r#"{"main.rs": "Rust", "codegen.rs": "RenderScript"}"#;
+/// let report = compare_to_baseline(&breakdown, "src/bin", baseline).unwrap();
+/// assert_eq!(report.agreements, 1);
+/// assert_eq!(report.disagreements[0].baseline_language, "RenderScript");
+/// ```
+pub fn compare_to_baseline<P: AsRef<Path>>(
+    breakdown: &HashMap<&'static str, Vec<(Detection, PathBuf)>>,
+    root: P,
+    baseline_json: &str,
+) -> Result<DiffReport, serde_yaml::Error> {
+    // JSON is a subset of YAML so the YAML parser the crate already depends on can read it
+    let mut baseline: HashMap<PathBuf, String> = serde_yaml::from_str(baseline_json)?;
+
+    let mut report = DiffReport::default();
+    for (detection, path) in breakdown.values().flatten() {
+        let path = path.strip_prefix(&root).unwrap_or(path).to_path_buf();
+        match baseline.remove(&path) {
+            Some(language) if language == detection.language() => report.agreements += 1,
+            Some(baseline_language) => report.disagreements.push(Disagreement {
+                path,
+                detection: *detection,
+                baseline_language,
+            }),
+            None => report.missing_from_baseline.push((path, *detection)),
+        }
+    }
+    report.missing_from_breakdown = baseline.into_iter().collect();
+
+    report.disagreements.sort_by(|a, b| a.path.cmp(&b.path));
+    report.missing_from_baseline.sort_by(|a, b| a.0.cmp(&b.0));
+    report.missing_from_breakdown.sort();
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_to_baseline() {
+        let mut breakdown = HashMap::new();
+        breakdown.insert(
+            "Rust",
+            vec![
+                (
+                    Detection::Extension("Rust"),
+                    PathBuf::from("repo/src/main.rs"),
+                ),
+                (
+                    Detection::Classifier("Rust"),
+                    PathBuf::from("repo/src/lib.rs"),
+                ),
+            ],
+        );
+        breakdown.insert(
+            "C",
+            vec![(
+                Detection::Heuristics("C"),
+                PathBuf::from("repo/include/a.h"),
+            )],
+        );
+        breakdown.insert(
+            "Python",
+            vec![(Detection::Shebang("Python"), PathBuf::from("repo/script"))],
+        );
+        let baseline = r#"{
+            "src/main.rs": "Rust",
+            "src/lib.rs": "RenderScript",
+            "include/a.h": "C++",
+            "build.zig": "Zig"
+        }"#;
+
+        let report = compare_to_baseline(&breakdown, "repo", baseline).unwrap();
+
+        assert_eq!(report.agreements, 1);
+        assert_eq!(
+            report.disagreements,
+            vec![
+                Disagreement {
+                    path: PathBuf::from("include/a.h"),
+                    detection: Detection::Heuristics("C"),
+                    baseline_language: String::from("C++"),
+                },
+                Disagreement {
+                    path: PathBuf::from("src/lib.rs"),
+                    detection: Detection::Classifier("Rust"),
+                    baseline_language: String::from("RenderScript"),
+                },
+            ]
+        );
+        assert_eq!(
+            report.missing_from_baseline,
+            vec![(PathBuf::from("script"), Detection::Shebang("Python"))]
+        );
+        assert_eq!(
+            report.missing_from_breakdown,
+            vec![(PathBuf::from("build.zig"), String::from("Zig"))]
+        );
+
+        let by_strategy = report.disagreements_by_strategy();
+        assert_eq!(by_strategy[&DetectionStrategy::Heuristics].len(), 1);
+        assert_eq!(by_strategy[&DetectionStrategy::Classifier].len(), 1);
+        assert!(!by_strategy.contains_key(&DetectionStrategy::Extension));
+    }
+
+    #[test]
+    fn test_compare_to_baseline_invalid_json() {
+        let breakdown = HashMap::new();
+        assert!(compare_to_baseline(&breakdown, "repo", r#"["src/main.rs"]"#).is_err());
+    }
+}
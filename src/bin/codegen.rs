@@ -2,7 +2,7 @@ use pcre2::bytes::Regex as PCRERegex;
 use phf_codegen::Map as PhfMap;
 use serde::Deserialize;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fs::{self, File},
     io::{BufWriter, Write},
     iter,
@@ -20,6 +20,7 @@ struct LanguageDTO {
     language_type: LanguageType,
     color: Option<String>,
     group: Option<String>,
+    language_id: u32,
 }
 
 impl LanguageDTO {
@@ -64,16 +65,6 @@ struct Disambiguation {
     rules: Vec<RuleDTO>,
 }
 
-impl Disambiguation {
-    fn to_domain_object_code(&self, named_patterns: &NamedPatterns) -> String {
-        let mut rules = String::new();
-        for rule in self.rules.iter() {
-            rules.push_str(format!("{},", rule.to_domain_object_code(named_patterns)).as_str());
-        }
-        format!("&[{}]", rules)
-    }
-}
-
 #[derive(Deserialize)]
 struct RuleDTO {
     language: MaybeMany<String>,
@@ -174,6 +165,7 @@ enum MaybeMany<T> {
 
 const DISAMBIGUATION_HEURISTICS_FILE: &str = "src/codegen/disambiguation-heuristics-map.rs";
 const EXTENSION_MAP_FILE: &str = "src/codegen/extension-language-map.rs";
+const UNAMBIGUOUS_EXTENSION_MAP_FILE: &str = "src/codegen/unambiguous-extension-map.rs";
 const FILENAME_MAP_FILE: &str = "src/codegen/filename-language-map.rs";
 const INTERPRETER_MAP_FILE: &str = "src/codegen/interpreter-language-map.rs";
 const LANGUAGE_INFO_FILE: &str = "src/codegen/language-info-map.rs";
@@ -183,11 +175,28 @@ const TOKEN_LOG_PROBABILITY_FILE: &str = "src/codegen/token-log-probabilities.rs
 const HEURISTICS_SOURCE_FILE: &str = "heuristics.yml";
 const LANGUAGE_SOURCE_FILE: &str = "languages.yml";
 
+// languages.yml is vendored from Linguist, so the extensions and filenames we detect that upstream
+// doesn't are merged in from here rather than edited into it. Linguist leaves automake's .am files
+// out of Makefile and only knows configure.ac out of the autoconf files M4Sugar covers
+const EXTENSION_OVERRIDES: &[(&str, &str)] = &[(".am", "Makefile")];
+const FILENAME_OVERRIDES: &[(&str, &str)] = &[
+    ("acinclude.m4", "M4Sugar"),
+    ("aclocal.m4", "M4Sugar"),
+    ("configure.in", "M4Sugar"),
+];
+
+// Matches hyperpolyglot::MAX_TOKEN_BYTES, which the classifier filters tokens with
 const MAX_TOKEN_BYTES: usize = 32;
+// The pseudo count added to every token in the vocabulary when estimating token probabilities.
+// This smooths the probabilities so tokens a language's samples never used get a probability
+// based on how many tokens the language was trained on instead of a fixed value. Larger values
+// made the classifier less accurate on the samples, which are mostly short files
+const TOKEN_SMOOTHING: f64 = 0.00001;
 
 fn main() {
-    let languages: LanguageMap =
+    let mut languages: LanguageMap =
         serde_yaml::from_reader(File::open(LANGUAGE_SOURCE_FILE).unwrap()).unwrap();
+    apply_overrides(&mut languages);
 
     write_language_list(&languages);
     write_language_info(&languages);
@@ -202,6 +211,23 @@ fn main() {
     train_classifier();
 }
 
+fn apply_overrides(languages: &mut LanguageMap) {
+    for (extension, language_name) in EXTENSION_OVERRIDES.iter() {
+        let language = languages.get_mut(*language_name).unwrap();
+        language
+            .extensions
+            .get_or_insert_with(Vec::new)
+            .push(extension.to_string());
+    }
+    for (filename, language_name) in FILENAME_OVERRIDES.iter() {
+        let language = languages.get_mut(*language_name).unwrap();
+        language
+            .filenames
+            .get_or_insert_with(Vec::new)
+            .push(filename.to_string());
+    }
+}
+
 fn write_language_list(languages: &LanguageMap) {
     let mut file = BufWriter::new(File::create(LANGUAGE_LIST_FILE).unwrap());
 
@@ -219,11 +245,14 @@ fn write_language_info(languages: &LanguageMap) {
     let mut file = BufWriter::new(File::create(LANGUAGE_INFO_FILE).unwrap());
 
     let mut language_info_map = PhfMap::new();
+    // The ids are kept out of Language so adding them didn't add a field to the public struct
+    let mut language_id_map = PhfMap::new();
     for (language_name, language) in languages.iter() {
         language_info_map.entry(
             &language_name[..],
             &language.to_domain_object_code(&language_name[..])[..],
         );
+        language_id_map.entry(&language_name[..], &language.language_id.to_string()[..]);
     }
 
     writeln!(
@@ -232,6 +261,12 @@ fn write_language_info(languages: &LanguageMap) {
         language_info_map.build()
     )
     .unwrap();
+    writeln!(
+        &mut file,
+        "static LANGUAGE_IDS: phf::Map<&'static str, u32> =\n{};\n",
+        language_id_map.build()
+    )
+    .unwrap();
 }
 
 fn create_filename_map(languages: &LanguageMap) {
@@ -318,31 +353,59 @@ fn create_extension_map(languages: &LanguageMap) {
         extension_to_language_map.build()
     )
     .unwrap();
+
+    // The extensions that map to a single language get their own map so the common case can be
+    // looked up without going through the candidates
+    let mut file = BufWriter::new(File::create(UNAMBIGUOUS_EXTENSION_MAP_FILE).unwrap());
+    let mut unambiguous_extension_map = PhfMap::new();
+    for (extension, languages) in temp_map.iter() {
+        if let [language] = languages[..] {
+            unambiguous_extension_map.entry(&extension[..], &format!("{:?}", language)[..]);
+        }
+    }
+
+    writeln!(
+        &mut file,
+        "static UNAMBIGUOUS_EXTENSIONS: phf::Map<&'static str, &'static str> =\n{};\n",
+        unambiguous_extension_map.build()
+    )
+    .unwrap();
 }
 
 fn create_disambiguation_heuristics_map(heuristics: Heuristics) {
     let mut file = BufWriter::new(File::create(DISAMBIGUATION_HEURISTICS_FILE).unwrap());
 
-    let mut temp_map: HashMap<String, String> = HashMap::new();
-    for mut dis in heuristics.disambiguations.into_iter() {
+    // The first rule that matches wins so the rules for each extension are kept in the order they
+    // appear in the heuristics file, even when the extension is in more than one disambiguation
+    let mut temp_map: HashMap<String, Vec<String>> = HashMap::new();
+    for dis in heuristics.disambiguations.iter() {
         for ext in dis.extensions.iter() {
-            // Adding a rule to default to C for .h if the Objective C and C++ patterns don't match
-            // The classifer was unreliable for distinguishing between C and C++ for .h
-            if ext == ".h" {
-                dis.rules.push(RuleDTO {
-                    language: MaybeMany::One(String::from("C")),
-                    pattern: None,
-                });
+            let rules = temp_map.entry(ext.to_ascii_lowercase()).or_default();
+            for rule in dis.rules.iter() {
+                rules.push(rule.to_domain_object_code(&heuristics.named_patterns));
             }
-            let extension = ext.clone().to_ascii_lowercase();
-            let key = extension;
-            let value = dis.to_domain_object_code(&heuristics.named_patterns);
-            temp_map.insert(key, value);
         }
     }
 
+    // Adding a rule to default to C for .h if the Objective C and C++ patterns don't match
+    // The classifer was unreliable for distinguishing between C and C++ for .h
+    if let Some(rules) = temp_map.get_mut(".h") {
+        let c_fallback = RuleDTO {
+            language: MaybeMany::One(String::from("C")),
+            pattern: None,
+        };
+        rules.push(c_fallback.to_domain_object_code(&heuristics.named_patterns));
+    }
+
     let mut disambiguation_heuristic_map = PhfMap::new();
-    for (key, value) in temp_map.iter() {
+    let values: Vec<(&String, String)> = temp_map
+        .iter()
+        .map(|(key, rules)| {
+            let rules: String = rules.iter().map(|rule| format!("{},", rule)).collect();
+            (key, format!("&[{}]", rules))
+        })
+        .collect();
+    for (key, value) in values.iter() {
         disambiguation_heuristic_map.entry(&key[..], &value[..]);
     }
 
@@ -404,19 +467,30 @@ fn train_classifier() {
             }
         });
 
-    // Write token log probabilities
+    // Write token log probabilities using Lidstone smoothing over the vocabulary of every language
+    let vocabulary_size = temp_token_count
+        .values()
+        .flat_map(|token_count_map| token_count_map.keys())
+        .collect::<HashSet<_>>()
+        .len() as f64;
     let mut file = BufWriter::new(File::create(TOKEN_LOG_PROBABILITY_FILE).unwrap());
     let mut language_token_log_probabilities = PhfMap::new();
+    let mut unseen_token_log_probabilities = PhfMap::new();
     for (language, token_count_map) in temp_token_count.iter() {
         let total_tokens = *temp_total_tokens_count.get(language).unwrap() as f64;
+        let smoothed_total = total_tokens + TOKEN_SMOOTHING * vocabulary_size;
         let mut token_log_probabilities = PhfMap::new();
         for (token, token_count) in token_count_map.iter() {
-            let probability = (*token_count as f64) / (total_tokens);
+            let probability = (*token_count as f64 + TOKEN_SMOOTHING) / smoothed_total;
             let log_probability = probability.ln();
             token_log_probabilities.entry(&token[..], &format!("{}f64", log_probability)[..]);
         }
         let codegen_log_prob_map = format!("{}", token_log_probabilities.build());
         language_token_log_probabilities.entry(&language[..], &codegen_log_prob_map[..]);
+
+        let unseen_log_probability = (TOKEN_SMOOTHING / smoothed_total).ln();
+        unseen_token_log_probabilities
+            .entry(&language[..], &format!("{}f64", unseen_log_probability)[..]);
     }
 
     writeln!(
@@ -425,4 +499,10 @@ fn train_classifier() {
         language_token_log_probabilities.build()
     )
     .unwrap();
+    writeln!(
+        &mut file,
+        "static UNSEEN_TOKEN_LOG_PROBABILITIES: phf::Map<&'static str, f64> =\n{};\n",
+        unseen_token_log_probabilities.build()
+    )
+    .unwrap();
 }
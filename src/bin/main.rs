@@ -1,21 +1,35 @@
-use clap::{App, Arg};
+use clap::{App, Arg, ArgMatches};
+use ignore::overrides::OverrideBuilder;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::{
     cmp::Reverse,
-    collections::{BinaryHeap, HashMap},
+    collections::{BTreeMap, BinaryHeap, HashMap},
     convert::TryFrom,
-    io::{self, Write},
-    path::PathBuf,
+    fs::{self, File},
+    io::{self, BufWriter, Read},
+    path::{Path, PathBuf},
 };
-use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+use termcolor::{Color, ColorChoice, ColorSpec, NoColor, StandardStream, WriteColor};
 
-use hyperpolyglot::{get_language_breakdown, Detection, Language, LanguageType};
+use hyperpolyglot::{
+    detect_paths, expand_glob, filters::DEFAULT_TEST_GLOBS,
+    get_language_breakdown_multi_with_stats, is_detectable_by_name, is_glob, list_files,
+    strategy_counts, BreakdownConfig, Detection, DetectionStrategy, ExcludedFiles, Language,
+    LanguageType, ScanStats, Symlinks, DOCUMENTATION_BUCKET, GENERATED_BUCKET, SYMLINKS_BUCKET,
+    TESTS_BUCKET, VENDORED_BUCKET,
+};
+
+// The line languages below the minimum percentage are collapsed into in the language split
+const OTHER_LANGUAGES: &str = "Other";
 
 struct CLIOptions {
     color: bool,
     condensed_output: bool,
     filters: Option<Vec<Regex>>,
+    min_percentage: f64,
+    relative_to: PathBuf,
+    show_both: bool,
 }
 
 impl CLIOptions {
@@ -27,6 +41,37 @@ impl CLIOptions {
         }
     }
 
+    // Returns the filters that don't match any of the headers
+    fn unmatched_filters<'a>(&'a self, headers: &[&str]) -> Vec<&'a Regex> {
+        match &self.filters {
+            Some(filters) => filters
+                .iter()
+                .filter(|filter| !headers.iter().any(|header| filter.is_match(header)))
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    fn display_path(&self, path: &Path) -> PathBuf {
+        if let Ok(relative) = path.strip_prefix(&self.relative_to) {
+            if relative.components().next().is_some() {
+                return relative.to_path_buf();
+            }
+        }
+
+        // The paths may differ in whether they're absolute or relative so compare them canonically
+        let canonical_relative = fs::canonicalize(path).and_then(|canonical_path| {
+            let canonical_base = fs::canonicalize(&self.relative_to)?;
+            Ok(canonical_path
+                .strip_prefix(canonical_base)
+                .map(|relative| relative.to_path_buf()))
+        });
+        match canonical_relative {
+            Ok(Ok(relative)) if relative.components().next().is_some() => relative,
+            _ => strip_relative_parts(path).to_path_buf(),
+        }
+    }
+
     fn color_option(&self) -> ColorChoice {
         if self.color {
             ColorChoice::Auto
@@ -36,25 +81,86 @@ impl CLIOptions {
     }
 }
 
+// Returns where the output is written to, which is the output file without any color when one is
+// given and stdout otherwise
+fn open_output(
+    output_file: Option<&str>,
+    options: &CLIOptions,
+) -> Result<Box<dyn WriteColor>, io::Error> {
+    match output_file {
+        Some(path) => Ok(Box::new(NoColor::new(BufWriter::new(File::create(path)?)))),
+        None => Ok(Box::new(StandardStream::stdout(options.color_option()))),
+    }
+}
+
 fn main() {
     let matches = get_cli().get_matches();
-    let path = matches.value_of("PATH").unwrap();
-    let breakdown = get_language_breakdown(path);
-
-    let mut language_count: Vec<(&'static str, Vec<(Detection, PathBuf)>)> = breakdown
-        .into_iter()
-        .filter(|(language_name, _)| {
-            match Language::try_from(*language_name).map(|l| l.language_type) {
-                Ok(LanguageType::Markup) | Ok(LanguageType::Programming) => true,
-                _ => false,
-            }
-        })
-        .collect();
-    language_count.sort_by(|(_, a), (_, b)| b.len().cmp(&a.len()));
-    if let Err(_) = print_language_split(&language_count) {
-        std::process::exit(1);
+    let path_args: Vec<&str> = matches.values_of("PATH").unwrap().collect();
+    let include_globs: Vec<String> = matches
+        .values_of("include-glob")
+        .map(|globs| globs.map(String::from).collect())
+        .unwrap_or_default();
+    for glob in include_globs.iter() {
+        if let Err(e) = OverrideBuilder::new(path_args[0]).add(glob) {
+            eprintln!("Invalid include glob: {}", e);
+            std::process::exit(1);
+        }
     }
+    let config = BreakdownConfig {
+        include_hidden: matches.is_present("hidden"),
+        disable_classifier: matches.is_present("no-classifier"),
+        include_globs,
+        excluded_files: if matches.is_present("bucket-excluded") {
+            ExcludedFiles::Bucket
+        } else {
+            ExcludedFiles::Exclude
+        },
+        test_globs: if matches.is_present("bucket-tests") {
+            DEFAULT_TEST_GLOBS
+                .iter()
+                .map(|glob| glob.to_string())
+                .collect()
+        } else {
+            vec![]
+        },
+        symlinks: if matches.is_present("follow-symlinks") {
+            Symlinks::Follow
+        } else if matches.is_present("bucket-symlinks") {
+            Symlinks::Bucket
+        } else {
+            Symlinks::Detect
+        },
+        ..BreakdownConfig::default()
+    };
 
+    let files_from = matches.value_of("files-from");
+    let mut paths: Vec<PathBuf> = vec![];
+    if let Some(file_list) = files_from {
+        match read_file_list(file_list) {
+            Ok(files) => paths = files,
+            Err(e) => {
+                eprintln!("Error reading the file list: {}", e);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        // Globs that the shell didn't expand, like quoted ones, are expanded into the files they
+        // match
+        for path in path_args.iter() {
+            if is_glob(path) {
+                let files = expand_glob(path, &config);
+                if files.is_empty() {
+                    eprintln!("No files matched {}", path);
+                }
+                paths.extend(files);
+            } else {
+                paths.push(PathBuf::from(path));
+            }
+        }
+        if paths.is_empty() {
+            std::process::exit(1);
+        }
+    }
     let cli_options = CLIOptions {
         color: !matches.is_present("no-color"),
         condensed_output: matches.is_present("condensed"),
@@ -64,28 +170,205 @@ fn main() {
                 filters.map(|f| Regex::new(f).expect(&format!("Invalid filter: {}", f)[..]))
             })
             .map(|filters| filters.collect()),
+        min_percentage: matches
+            .value_of("min-percentage")
+            .and_then(|percentage| percentage.parse().ok())
+            .unwrap_or(0.0),
+        relative_to: matches
+            .value_of("relative-to")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                // Paths under multiple roots are shown relative to the working directory
+                if paths.len() != 1 {
+                    return PathBuf::from(".");
+                }
+                let root = &paths[0];
+                match root.parent() {
+                    Some(parent) if root.is_file() => parent.to_path_buf(),
+                    _ => root.to_path_buf(),
+                }
+            }),
+        show_both: matches.is_present("show-both"),
     };
 
-    if matches.is_present("file-breakdown") {
-        writeln!(io::stdout(), "").unwrap_or_else(|_| std::process::exit(1));
-        if let Err(_) = print_file_breakdown(&language_count, &cli_options) {
+    let mut output = match open_output(matches.value_of("output-file"), &cli_options) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Error opening the output file: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if matches.is_present("list-files") {
+        if print_file_list(&mut output, &paths, &config, &cli_options).is_err() {
             std::process::exit(1);
         }
+        return;
+    }
+
+    let (mut breakdown, stats) = match files_from {
+        Some(_) => detect_paths(&paths, &config),
+        None => get_language_breakdown_multi_with_stats(&paths, &config),
+    };
+    breakdown.retain(|language_name, _| {
+        if [
+            VENDORED_BUCKET,
+            DOCUMENTATION_BUCKET,
+            GENERATED_BUCKET,
+            TESTS_BUCKET,
+            SYMLINKS_BUCKET,
+        ]
+        .contains(language_name)
+        {
+            return true;
+        }
+        matches!(
+            Language::try_from(*language_name).map(|l| l.language_type),
+            Ok(LanguageType::Markup | LanguageType::Programming)
+        )
+    });
+    let strategy_counts = strategy_counts(&breakdown);
+
+    let mut language_count: Vec<(&'static str, Vec<(Detection, PathBuf)>)> =
+        breakdown.into_iter().collect();
+    // The walk is parallel so the order files are detected in changes between runs. Sorting keeps
+    // the output stable so it can be diffed
+    language_count.sort_by(|(a_language, a), (b_language, b)| {
+        b.len().cmp(&a.len()).then(a_language.cmp(b_language))
+    });
+    for (_, files) in language_count.iter_mut() {
+        files.sort_by(|(_, a), (_, b)| a.cmp(b));
     }
 
+    if language_count.is_empty() && matches.is_present("fail-if-empty") {
+        let scanned = match files_from {
+            Some(_) => String::from("the listed files"),
+            None => path_args.join(", "),
+        };
+        eprintln!(
+            "No programming or markup files were detected in {}",
+            scanned
+        );
+        std::process::exit(1);
+    }
+
+    if print_output(
+        &mut output,
+        &matches,
+        &language_count,
+        &strategy_counts,
+        &stats,
+        &cli_options,
+    )
+    .is_err()
+    {
+        std::process::exit(1);
+    }
+
+    // The filters only apply to the headers of the file and strategy breakdowns, so a filter that
+    // doesn't match any of them leaves those breakdowns empty, which is usually a typo
+    let mut headers: Vec<&str> = vec![];
+    if matches.is_present("file-breakdown") {
+        headers.extend(language_count.iter().map(|(language, _)| *language));
+    }
     if matches.is_present("strategy-breakdown") {
-        writeln!(io::stdout(), "").unwrap_or_else(|_| std::process::exit(1));
-        if let Err(_) = print_strategy_breakdown(&language_count, &cli_options) {
-            std::process::exit(1);
+        headers.extend(
+            language_count
+                .iter()
+                .flat_map(|(_, files)| files.iter().map(|(detection, _)| detection.variant())),
+        );
+    }
+    if matches.is_present("file-breakdown") || matches.is_present("strategy-breakdown") {
+        for filter in cli_options.unmatched_filters(&headers) {
+            eprintln!("filter '{}' matched no sections", filter.as_str());
         }
     }
 }
 
+// Returns the paths in the newline-delimited list, read from stdin when the list is -. Blank lines
+// are ignored and the paths that don't exist are skipped with a warning
+fn read_file_list(file_list: &str) -> Result<Vec<PathBuf>, io::Error> {
+    let content = if file_list == "-" {
+        let mut content = String::new();
+        io::stdin().read_to_string(&mut content)?;
+        content
+    } else {
+        fs::read_to_string(file_list)?
+    };
+
+    let mut paths = vec![];
+    for line in content.lines() {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            continue;
+        }
+        let path = PathBuf::from(line);
+        if path.exists() {
+            paths.push(path);
+        } else {
+            eprintln!("Skipping {} which doesn't exist", line);
+        }
+    }
+    Ok(paths)
+}
+
+fn print_output(
+    output: &mut dyn WriteColor,
+    matches: &ArgMatches,
+    language_count: &Vec<(&'static str, Vec<(Detection, PathBuf)>)>,
+    strategy_counts: &BTreeMap<DetectionStrategy, usize>,
+    stats: &ScanStats,
+    cli_options: &CLIOptions,
+) -> Result<(), io::Error> {
+    print_language_split(output, language_count, cli_options)?;
+
+    if matches.is_present("file-breakdown") {
+        writeln!(output)?;
+        print_file_breakdown(output, language_count, cli_options)?;
+    }
+
+    if matches.is_present("strategy-breakdown") {
+        writeln!(output)?;
+        print_strategy_breakdown(output, language_count, cli_options)?;
+    }
+
+    if matches.is_present("strategy-summary") {
+        let summary: Vec<String> = strategy_counts
+            .iter()
+            .map(|(strategy, count)| format!("{}: {}", strategy, count))
+            .collect();
+        writeln!(output, "\n{}", summary.join(", "))?;
+    }
+
+    if matches.is_present("verbose") {
+        writeln!(output)?;
+        print_verbose_breakdown(output, language_count, cli_options)?;
+    }
+
+    if matches.is_present("stats") {
+        writeln!(output, "\n{}", stats)?;
+    }
+    output.flush()
+}
+
 fn get_cli<'a, 'b>() -> App<'a, 'b> {
     App::new("Hyperpolyglot")
         .version("0.1.7")
         .about("Hyperpolyglot is a programming language detector. It supports detecting the programming language of a file or the programming language makeup of a directory.")
-        .arg(Arg::with_name("PATH").index(1).default_value("."))
+        .arg(
+            Arg::with_name("PATH")
+                .index(1)
+                .multiple(true)
+                .default_value(".")
+                .help("The paths to detect the languages of. The languages of multiple paths are combined into one breakdown. Quoted globs like 'src/**/*.rs' detect the files they match"),
+        )
+        .arg(
+            Arg::with_name("files-from")
+                .long("files-from")
+                .value_name("FILE")
+                .help("Detects the files listed in FILE, one path per line, instead of walking PATH. Use - to read the list from stdin. ex/ git ls-files | hyply --files-from -")
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("file-breakdown")
                 .short("b")
@@ -97,9 +380,25 @@ fn get_cli<'a, 'b>() -> App<'a, 'b> {
                 .short("s")
                 .long("strategies")
                 .help(
-                    "Prints each strategy used and what files were detected using that strategy",
+                    "Prints each strategy used and what files were detected using that strategy. The strategies are Filename, Extension, Shebang, Heuristics, and Classifier",
                 ),
         )
+        .arg(
+            Arg::with_name("strategy-summary")
+                .long("strategy-summary")
+                .help("Prints the number of files detected by each strategy on one line"),
+        )
+        .arg(
+            Arg::with_name("verbose")
+                .short("v")
+                .long("verbose")
+                .help("Prints every file visited along with the language and strategy it was detected with"),
+        )
+        .arg(
+            Arg::with_name("stats")
+                .long("stats")
+                .help("Prints the number of files visited, detected, and skipped and the number of bytes read"),
+        )
         .arg(
             Arg::with_name("condensed")
                 .short("c")
@@ -111,6 +410,83 @@ fn get_cli<'a, 'b>() -> App<'a, 'b> {
                 "A regex that is used to filter by header which sections get printed for the file and strategy breakdown.",
             ).takes_value(true).multiple(true),
         )
+        .arg(
+            Arg::with_name("relative-to")
+                .long("relative-to")
+                .help("The directory that the paths printed in the breakdowns are relative to. Defaults to PATH, or the current directory when there are multiple paths.")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("show-both")
+                .long("show-both")
+                .help("Prints the percentage of bytes alongside the percentage of files for each language"),
+        )
+        .arg(
+            Arg::with_name("min-percentage")
+                .long("min-percentage")
+                .help("Collapses the languages that make up less than this percentage of the files into a single Other line. ex/ 1.0")
+                .takes_value(true)
+                .validator(|percentage| match percentage.parse::<f64>() {
+                    Ok(percentage) if percentage >= 0.0 => Ok(()),
+                    _ => Err(String::from("The minimum percentage must be a non-negative number")),
+                }),
+        )
+        .arg(
+            Arg::with_name("include-glob")
+                .long("include-glob")
+                .help("A glob for paths to include even if they would be skipped as documentation or vendored code. ex/ vendor/mine/**")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("bucket-excluded")
+                .long("bucket-excluded")
+                .help("Counts documentation, vendored, and generated files under Documentation, Vendored, and Generated instead of skipping them"),
+        )
+        .arg(
+            Arg::with_name("bucket-tests")
+                .long("bucket-tests")
+                .help("Counts test code, like files in tests/ directories or named *_test.*, under Tests instead of its language"),
+        )
+        .arg(
+            Arg::with_name("follow-symlinks")
+                .long("follow-symlinks")
+                .help("Walks the directories that symbolic links point to as well as detecting the files they point to")
+                .conflicts_with("bucket-symlinks"),
+        )
+        .arg(
+            Arg::with_name("bucket-symlinks")
+                .long("bucket-symlinks")
+                .help("Counts symbolic links to files under Symlinks instead of the language of the file they point to"),
+        )
+        .arg(
+            Arg::with_name("hidden")
+                .long("hidden")
+                .help("Includes hidden files and directories like .editorconfig in the breakdown"),
+        )
+        .arg(
+            Arg::with_name("no-classifier")
+                .long("no-classifier")
+                .help("Leaves files that the filename, extension, shebang, and heuristics can't narrow down to one language undetected instead of guessing with the classifier"),
+        )
+        .arg(
+            Arg::with_name("fail-if-empty")
+                .long("fail-if-empty")
+                .help("Exits with a non-zero exit code if no programming or markup files are detected"),
+        )
+        .arg(
+            Arg::with_name("list-files")
+                .long("list-files")
+                .help("Lists the files that would be detected by their name without reading any files, along with how many files would be read to detect them by their content"),
+        )
+        .arg(
+            Arg::with_name("output-file")
+                .short("o")
+                .long("output-file")
+                .help("Writes the output to this file instead of stdout. The file isn't color coded")
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("no-color").short("n").long("no-color").help(
                 "Don't color code the output of the breakdowns. This is useful when piping/redirecting the output of hyperpolyglot.",
@@ -118,38 +494,129 @@ fn get_cli<'a, 'b>() -> App<'a, 'b> {
         )
 }
 
+fn print_file_list(
+    output: &mut dyn WriteColor,
+    paths: &[PathBuf],
+    config: &BreakdownConfig,
+    options: &CLIOptions,
+) -> Result<(), io::Error> {
+    let mut files: Vec<PathBuf> = paths
+        .iter()
+        .flat_map(|path| list_files(path, config))
+        .collect();
+    files.sort();
+    files.dedup();
+
+    let (by_name, by_content): (Vec<&PathBuf>, Vec<&PathBuf>) = files.iter().partition(|file| {
+        file.file_name()
+            .and_then(|filename| filename.to_str())
+            .is_some_and(is_detectable_by_name)
+    });
+    for file in by_name.iter() {
+        writeln!(output, "{}", options.display_path(file).display())?;
+    }
+    writeln!(
+        output,
+        "\n{} files would be detected by name, {} more would be read to detect them by their content",
+        by_name.len(),
+        by_content.len()
+    )?;
+    output.flush()
+}
+
 fn print_language_split(
+    output: &mut dyn WriteColor,
     language_counts: &Vec<(&'static str, Vec<(Detection, PathBuf)>)>,
+    options: &CLIOptions,
 ) -> Result<(), io::Error> {
     let total = language_counts
         .iter()
         .fold(0, |acc, (_, files)| acc + files.len()) as f64;
+
+    // Languages below the minimum percentage are collapsed into a single line of their totals
+    let mut other_files = 0;
+    let mut other_bytes = 0;
+
+    if options.show_both {
+        let language_bytes: Vec<u64> = language_counts
+            .iter()
+            .map(|(_, files)| files.iter().map(|(_, file)| file_size(file)).sum())
+            .collect();
+        let total_bytes = language_bytes.iter().sum::<u64>() as f64;
+        let byte_percentage = |bytes: u64| {
+            if total_bytes > 0.0 {
+                ((bytes * 100) as f64) / total_bytes
+            } else {
+                0.0
+            }
+        };
+        for ((language, files), bytes) in language_counts.iter().zip(language_bytes) {
+            let percentage = ((files.len() * 100) as f64) / total;
+            if percentage < options.min_percentage {
+                other_files += files.len();
+                other_bytes += bytes;
+                continue;
+            }
+            writeln!(
+                output,
+                "{:.2}% (bytes) / {:.2}% (files) {}",
+                byte_percentage(bytes),
+                percentage,
+                language
+            )?;
+        }
+        if other_files > 0 {
+            writeln!(
+                output,
+                "{:.2}% (bytes) / {:.2}% (files) {}",
+                byte_percentage(other_bytes),
+                ((other_files * 100) as f64) / total,
+                OTHER_LANGUAGES
+            )?;
+        }
+        return Ok(());
+    }
+
     for (language, files) in language_counts.iter() {
         let percentage = ((files.len() * 100) as f64) / total;
-        writeln!(io::stdout(), "{:.2}% {}", percentage, language)?;
+        if percentage < options.min_percentage {
+            other_files += files.len();
+            continue;
+        }
+        writeln!(output, "{:.2}% {}", percentage, language)?;
+    }
+    if other_files > 0 {
+        let percentage = ((other_files * 100) as f64) / total;
+        writeln!(output, "{:.2}% {}", percentage, OTHER_LANGUAGES)?;
     }
 
     Ok(())
 }
 
+fn file_size(path: &Path) -> u64 {
+    fs::metadata(path)
+        .map(|metadata| metadata.len())
+        .unwrap_or(0)
+}
+
 fn print_file_breakdown(
+    output: &mut dyn WriteColor,
     language_counts: &Vec<(&'static str, Vec<(Detection, PathBuf)>)>,
     options: &CLIOptions,
 ) -> Result<(), io::Error> {
-    let mut stdout = StandardStream::stdout(options.color_option());
     for (language, breakdowns) in language_counts.iter() {
         if options.matches_filter(language) {
-            stdout.set_color(&TITLE_COLOR)?;
-            write!(stdout, "{}", language)?;
+            output.set_color(&TITLE_COLOR)?;
+            write!(output, "{}", language)?;
 
-            stdout.set_color(&DEFAULT_COLOR)?;
-            writeln!(stdout, " ({})", breakdowns.len())?;
+            output.set_color(&DEFAULT_COLOR)?;
+            writeln!(output, " ({})", breakdowns.len())?;
             if !options.condensed_output {
                 for (_, file) in breakdowns.iter() {
-                    let path = strip_relative_parts(file);
-                    writeln!(stdout, "{}", path.display())?;
+                    let path = options.display_path(file);
+                    writeln!(output, "{}", path.display())?;
                 }
-                writeln!(stdout, "")?;
+                writeln!(output)?;
             }
         }
     }
@@ -157,6 +624,7 @@ fn print_file_breakdown(
 }
 
 fn print_strategy_breakdown(
+    output: &mut dyn WriteColor,
     language_counts: &Vec<(&'static str, Vec<(Detection, PathBuf)>)>,
     options: &CLIOptions,
 ) -> Result<(), io::Error> {
@@ -174,35 +642,62 @@ fn print_strategy_breakdown(
         strategy_breakdown.into_iter().collect();
     strategy_breakdowns.sort_by(|(_, a), (_, b)| b.len().cmp(&a.len()));
 
-    let mut stdout = StandardStream::stdout(options.color_option());
     for (strategy, mut breakdowns) in strategy_breakdowns.into_iter() {
         if options.matches_filter(&strategy[..]) {
-            stdout.set_color(&TITLE_COLOR)?;
-            write!(stdout, "{}", strategy)?;
+            output.set_color(&TITLE_COLOR)?;
+            write!(output, "{}", strategy)?;
 
-            stdout.set_color(&DEFAULT_COLOR)?;
-            writeln!(stdout, " ({})", breakdowns.len())?;
+            output.set_color(&DEFAULT_COLOR)?;
+            writeln!(output, " ({})", breakdowns.len())?;
             if !options.condensed_output {
                 while let Some(Reverse((language, file))) = breakdowns.pop() {
-                    stdout.set_color(&DEFAULT_COLOR)?;
-                    let path = strip_relative_parts(file);
-                    write!(stdout, "{}", path.display())?;
+                    output.set_color(&DEFAULT_COLOR)?;
+                    let path = options.display_path(file);
+                    write!(output, "{}", path.display())?;
 
-                    stdout.set_color(&LANGUAGE_COLOR)?;
-                    writeln!(stdout, " ({})", language)?;
+                    output.set_color(&LANGUAGE_COLOR)?;
+                    writeln!(output, " ({})", language)?;
                 }
-                writeln!(stdout, "")?;
+                writeln!(output)?;
             }
         }
     }
     Ok(())
 }
 
-fn strip_relative_parts<'a>(path: &'a PathBuf) -> &'a std::path::Path {
+fn print_verbose_breakdown(
+    output: &mut dyn WriteColor,
+    language_counts: &Vec<(&'static str, Vec<(Detection, PathBuf)>)>,
+    options: &CLIOptions,
+) -> Result<(), io::Error> {
+    let mut files: Vec<(PathBuf, &str, &Detection)> = language_counts
+        .iter()
+        .flat_map(|(language, files)| {
+            files
+                .iter()
+                .map(move |(detection, file)| (options.display_path(file), *language, detection))
+        })
+        .collect();
+    files.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+
+    for (path, language, detection) in files.into_iter() {
+        output.set_color(&DEFAULT_COLOR)?;
+        write!(output, "{} — ", path.display())?;
+
+        output.set_color(&LANGUAGE_COLOR)?;
+        write!(output, "{}", language)?;
+
+        output.set_color(&DEFAULT_COLOR)?;
+        writeln!(output, " ({})", detection.variant())?;
+    }
+    Ok(())
+}
+
+fn strip_relative_parts(path: &Path) -> &Path {
     if path.starts_with("./") {
         path.strip_prefix("./").unwrap()
     } else {
-        path.as_path()
+        path
     }
 }
 
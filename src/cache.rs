@@ -0,0 +1,221 @@
+use crate::{Detection, LANGUAGE_INFO};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// A cache of detections that lets repeated walks of the same path skip files that haven't
+/// changed since they were last detected
+///
+/// Files are considered unchanged when their modified time and size both match the values
+/// stored when the file was detected. Any mismatch means the file is detected again. The cache
+/// implements serde's `Serialize` and `Deserialize` so it can be persisted between runs.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{get_language_breakdown_with_cache, DetectionCache};
+///
+/// let mut cache = DetectionCache::new();
+/// let breakdown = get_language_breakdown_with_cache("src/", &mut cache);
+/// // The second walk only detects the files that changed since the first
+/// let breakdown = get_language_breakdown_with_cache("src/", &mut cache);
+/// assert!(breakdown.contains_key("Rust"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(into = "Vec<CacheRecord>", try_from = "Vec<CacheRecord>")]
+pub struct DetectionCache {
+    entries: HashMap<PathBuf, CacheEntry>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct CacheEntry {
+    stamp: FileStamp,
+    detection: Detection,
+    confidence: f64,
+}
+
+// The parts of a file's metadata used to tell if it changed since it was detected
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct FileStamp {
+    modified: SystemTime,
+    size: u64,
+}
+
+impl FileStamp {
+    pub(crate) fn from_path(path: &Path) -> Option<FileStamp> {
+        let metadata = fs::metadata(path).ok()?;
+        Some(FileStamp {
+            modified: metadata.modified().ok()?,
+            size: metadata.len(),
+        })
+    }
+}
+
+impl DetectionCache {
+    pub fn new() -> Self {
+        DetectionCache::default()
+    }
+
+    /// Returns the number of files in the cache
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Returns the cached detection for the path if the file hasn't changed since it was detected
+    pub(crate) fn get(&self, path: &Path, stamp: FileStamp) -> Option<(Detection, f64)> {
+        match self.entries.get(path) {
+            Some(entry) if entry.stamp == stamp => Some((entry.detection, entry.confidence)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn insert(
+        &mut self,
+        path: PathBuf,
+        stamp: FileStamp,
+        detection: Detection,
+        confidence: f64,
+    ) {
+        let entry = CacheEntry {
+            stamp,
+            detection,
+            confidence,
+        };
+        self.entries.insert(path, entry);
+    }
+}
+
+// The serialized form of a single cache entry. Detections hold &'static str so languages are
+// stored by name and mapped back to the known language names when deserializing.
+#[derive(Serialize, Deserialize)]
+struct CacheRecord {
+    path: PathBuf,
+    modified: SystemTime,
+    size: u64,
+    strategy: String,
+    language: String,
+    confidence: f64,
+}
+
+impl From<DetectionCache> for Vec<CacheRecord> {
+    fn from(cache: DetectionCache) -> Self {
+        cache
+            .entries
+            .into_iter()
+            .map(|(path, entry)| CacheRecord {
+                path,
+                modified: entry.stamp.modified,
+                size: entry.stamp.size,
+                strategy: entry.detection.variant().to_string(),
+                language: entry.detection.language().to_string(),
+                confidence: entry.confidence,
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<Vec<CacheRecord>> for DetectionCache {
+    type Error = String;
+    fn try_from(records: Vec<CacheRecord>) -> Result<Self, Self::Error> {
+        let mut cache = DetectionCache::new();
+        for record in records.into_iter() {
+            let language = LANGUAGE_INFO
+                .get_key(&record.language[..])
+                .copied()
+                .ok_or_else(|| format!("Unknown language: {}", record.language))?;
+            let detection = match &record.strategy[..] {
+                "Filename" => Detection::Filename(language),
+                "Extension" => Detection::Extension(language),
+                "Shebang" => Detection::Shebang(language),
+                "Heuristics" => Detection::Heuristics(language),
+                "Classifier" => Detection::Classifier(language),
+                strategy => return Err(format!("Unknown strategy: {}", strategy)),
+            };
+            let stamp = FileStamp {
+                modified: record.modified,
+                size: record.size,
+            };
+            cache.insert(record.path, stamp, detection, record.confidence);
+        }
+        Ok(cache)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_stale_entries() {
+        let mut cache = DetectionCache::new();
+        let path = PathBuf::from("a.rs");
+        let stamp = FileStamp {
+            modified: SystemTime::UNIX_EPOCH,
+            size: 10,
+        };
+        cache.insert(path.clone(), stamp, Detection::Heuristics("Rust"), 1.0);
+
+        assert_eq!(
+            cache.get(&path, stamp),
+            Some((Detection::Heuristics("Rust"), 1.0))
+        );
+
+        let resized = FileStamp { size: 11, ..stamp };
+        assert_eq!(cache.get(&path, resized), None);
+
+        let modified = FileStamp {
+            modified: SystemTime::UNIX_EPOCH + Duration::from_secs(1),
+            ..stamp
+        };
+        assert_eq!(cache.get(&path, modified), None);
+    }
+
+    #[test]
+    fn test_serialize_round_trip() {
+        let mut cache = DetectionCache::new();
+        let stamp = FileStamp {
+            modified: SystemTime::UNIX_EPOCH + Duration::from_secs(1_585_000_000),
+            size: 42,
+        };
+        cache.insert(
+            PathBuf::from("src/a.h"),
+            stamp,
+            Detection::Classifier("C++"),
+            0.75,
+        );
+        cache.insert(
+            PathBuf::from("Makefile"),
+            stamp,
+            Detection::Filename("Makefile"),
+            1.0,
+        );
+
+        let serialized = serde_yaml::to_string(&cache).unwrap();
+        let deserialized: DetectionCache = serde_yaml::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, cache);
+    }
+
+    #[test]
+    fn test_deserialize_unknown_language() {
+        let serialized = r#"
+- path: a.xyz
+  modified:
+    secs_since_epoch: 0
+    nanos_since_epoch: 0
+  size: 1
+  strategy: Extension
+  language: Not A Language
+  confidence: 1.0
+"#;
+        assert!(serde_yaml::from_str::<DetectionCache>(serialized).is_err());
+    }
+}
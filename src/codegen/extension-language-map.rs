@@ -2,1394 +2,1398 @@ static EXTENSIONS: phf::Map<&'static str, &[&str]> =
 ::phf::Map {
     key: 3213172566270843353,
     disps: ::phf::Slice::Static(&[
+        (0, 54),
+        (0, 2),
+        (0, 65),
+        (0, 133),
+        (0, 12),
+        (0, 43),
+        (0, 159),
+        (0, 51),
+        (0, 153),
+        (0, 51),
+        (0, 20),
+        (0, 14),
+        (0, 8),
+        (0, 328),
+        (0, 87),
+        (0, 48),
+        (0, 13),
         (0, 0),
-        (0, 391),
-        (0, 57),
-        (0, 0),
-        (0, 427),
         (0, 0),
-        (0, 193),
-        (0, 100),
-        (0, 46),
-        (0, 552),
-        (0, 46),
-        (0, 2),
+        (0, 95),
+        (0, 11),
         (0, 1),
         (0, 4),
-        (0, 170),
-        (0, 29),
-        (0, 0),
-        (0, 14),
-        (0, 625),
-        (1, 581),
-        (0, 14),
-        (0, 796),
-        (0, 113),
-        (0, 67),
         (0, 1),
-        (0, 2),
-        (0, 391),
-        (0, 2),
-        (0, 0),
-        (0, 56),
-        (0, 13),
-        (0, 681),
-        (0, 3),
-        (0, 149),
-        (0, 41),
-        (0, 8),
-        (0, 105),
-        (0, 20),
-        (0, 18),
-        (1, 276),
+        (0, 156),
+        (0, 37),
+        (0, 58),
+        (0, 38),
         (0, 5),
-        (0, 3),
-        (0, 36),
-        (0, 9),
-        (3, 28),
-        (0, 19),
-        (0, 359),
-        (0, 2),
+        (0, 625),
+        (0, 41),
+        (0, 88),
+        (0, 122),
         (0, 161),
-        (0, 1),
-        (0, 40),
-        (0, 179),
-        (0, 240),
-        (0, 307),
-        (0, 0),
-        (0, 14),
-        (0, 189),
+        (1, 171),
+        (0, 102),
+        (0, 39),
         (0, 0),
+        (0, 123),
+        (2, 201),
+        (0, 2),
         (0, 0),
+        (0, 135),
+        (0, 442),
         (0, 8),
-        (0, 4),
-        (0, 3),
-        (0, 0),
-        (0, 574),
-        (0, 57),
-        (1, 1),
-        (0, 25),
-        (0, 12),
-        (0, 30),
-        (4, 976),
-        (0, 1),
+        (0, 131),
+        (0, 6),
         (0, 0),
-        (0, 12),
-        (1, 4),
-        (0, 2),
-        (0, 24),
-        (0, 4),
-        (0, 120),
-        (4, 21),
-        (0, 292),
-        (0, 5),
-        (0, 29),
-        (0, 4),
-        (0, 71),
+        (2, 677),
+        (0, 253),
+        (0, 552),
+        (0, 26),
+        (0, 22),
         (0, 0),
-        (0, 6),
-        (0, 28),
-        (0, 234),
-        (0, 2),
+        (0, 163),
+        (0, 239),
+        (0, 9),
+        (0, 345),
         (0, 8),
-        (0, 1053),
-        (1, 315),
-        (0, 220),
-        (0, 889),
-        (0, 515),
+        (0, 0),
+        (0, 19),
+        (0, 5),
+        (0, 247),
+        (0, 40),
+        (0, 11),
+        (0, 45),
+        (0, 320),
+        (0, 11),
         (0, 10),
-        (0, 606),
-        (0, 877),
-        (0, 46),
-        (0, 26),
-        (0, 285),
-        (0, 190),
-        (0, 270),
-        (1, 38),
-        (1, 794),
+        (0, 839),
+        (0, 137),
+        (0, 18),
+        (0, 210),
+        (1, 120),
+        (0, 140),
+        (0, 16),
+        (0, 230),
         (0, 10),
-        (0, 6),
-        (0, 204),
-        (0, 4),
-        (0, 6),
+        (0, 680),
+        (0, 320),
+        (0, 387),
+        (1, 881),
+        (0, 285),
+        (0, 41),
+        (0, 22),
         (0, 0),
-        (0, 2),
-        (0, 631),
-        (0, 69),
-        (4, 103),
+        (0, 7),
+        (0, 594),
+        (0, 426),
         (0, 28),
-        (0, 228),
-        (0, 465),
-        (0, 23),
-        (0, 0),
+        (0, 24),
+        (1, 342),
+        (0, 33),
+        (0, 961),
+        (0, 55),
         (0, 1),
-        (0, 529),
-        (0, 438),
-        (0, 98),
-        (0, 135),
-        (0, 231),
+        (1, 325),
+        (0, 110),
+        (0, 641),
+        (0, 462),
+        (0, 122),
+        (0, 3),
+        (1, 360),
+        (0, 878),
+        (2, 183),
+        (0, 139),
+        (0, 1066),
+        (0, 188),
         (0, 2),
-        (0, 18),
-        (2, 0),
-        (0, 619),
-        (0, 176),
-        (0, 11),
-        (0, 129),
+        (0, 52),
+        (0, 114),
+        (0, 637),
+        (0, 96),
         (0, 0),
-        (0, 148),
+        (0, 100),
+        (0, 114),
+        (0, 164),
+        (2, 324),
+        (0, 105),
         (0, 2),
-        (3, 733),
-        (0, 827),
-        (0, 44),
-        (0, 224),
-        (0, 72),
-        (0, 7),
-        (0, 357),
-        (0, 667),
-        (0, 627),
-        (0, 352),
         (0, 2),
-        (0, 416),
-        (0, 597),
-        (0, 63),
+        (0, 3),
+        (0, 411),
+        (0, 0),
         (0, 0),
-        (5, 617),
+        (2, 805),
+        (0, 697),
+        (0, 79),
+        (0, 103),
+        (2, 879),
+        (2, 189),
+        (1, 681),
         (0, 50),
-        (8, 209),
-        (0, 553),
-        (0, 147),
-        (0, 11),
-        (2, 733),
-        (0, 400),
-        (0, 819),
-        (2, 419),
-        (0, 16),
-        (0, 2),
+        (0, 12),
+        (1, 599),
+        (0, 164),
+        (0, 1),
+        (4, 814),
+        (14, 474),
+        (0, 138),
+        (0, 195),
+        (3, 133),
+        (0, 97),
+        (0, 18),
+        (2, 589),
+        (0, 213),
+        (0, 3),
+        (3, 400),
+        (0, 46),
+        (0, 552),
+        (2, 95),
+        (0, 1),
+        (0, 104),
+        (1, 517),
+        (2, 459),
+        (0, 1),
+        (0, 104),
+        (0, 466),
+        (2, 1089),
+        (0, 3),
+        (2, 406),
+        (20, 492),
+        (0, 110),
+        (11, 953),
+        (0, 0),
+        (0, 839),
+        (2, 72),
+        (0, 41),
+        (0, 303),
+        (0, 0),
+        (3, 103),
+        (1, 390),
+        (0, 0),
+        (0, 679),
+        (0, 1),
+        (0, 206),
+        (0, 0),
+        (2, 245),
+        (0, 13),
+        (0, 283),
+        (3, 767),
+        (0, 3),
         (0, 9),
-        (0, 504),
+        (0, 15),
+        (0, 17),
+        (4, 1026),
+        (1, 492),
         (0, 11),
-        (0, 263),
-        (3, 482),
-        (0, 59),
-        (0, 757),
-        (1, 107),
-        (0, 2),
-        (0, 330),
-        (1, 767),
-        (0, 30),
-        (0, 1),
-        (0, 71),
-        (0, 22),
-        (0, 19),
-        (0, 113),
-        (0, 33),
-        (2, 91),
-        (2, 512),
-        (1, 4),
-        (2, 909),
-        (0, 26),
-        (0, 745),
         (0, 0),
+        (2, 218),
+        (0, 10),
+        (0, 20),
+        (0, 25),
         (0, 88),
-        (4, 640),
-        (0, 31),
-        (0, 1026),
-        (1, 876),
-        (0, 862),
-        (0, 47),
-        (0, 22),
-        (0, 254),
-        (0, 1080),
-        (0, 4),
-        (0, 1124),
-        (0, 14),
-        (7, 100),
-        (0, 19),
-        (0, 513),
-        (0, 369),
+        (0, 30),
+        (0, 3),
+        (0, 0),
+        (2, 243),
+        (4, 904),
+        (0, 526),
+        (2, 706),
+        (1, 389),
+        (0, 227),
+        (0, 1140),
+        (0, 9),
+        (0, 2),
+        (3, 973),
+        (0, 1148),
+        (1, 779),
+        (0, 23),
+        (0, 15),
+        (0, 32),
+        (0, 1),
         (0, 11),
-        (5, 391),
-        (0, 269),
-        (10, 284),
-        (10, 917),
-        (0, 93),
+        (0, 169),
+        (3, 108),
+        (1, 838),
+        (0, 3),
+        (0, 87),
+        (3, 58),
+        (0, 30),
+        (21, 210),
+        (0, 175),
+        (0, 3),
         (0, 0),
-        (0, 72),
-        (1, 1065),
-        (4, 351),
-        (35, 104),
-        (0, 108),
-        (3, 210),
-        (0, 106),
-        (33, 1042),
-        (6, 879),
-        (1, 557),
+        (14, 649),
+        (0, 44),
         (0, 9),
-        (0, 583),
-        (1, 47),
-        (0, 5),
-        (27, 575),
-        (9, 227),
-        (32, 432),
-        (0, 6),
-        (0, 268),
+        (0, 73),
+        (37, 168),
+        (7, 342),
+        (1, 353),
     ]),
     entries: ::phf::Slice::Static(&[
-        (".udo", &["Csound"]),
+        ("._js", &["JavaScript"]),
+        (".icl", &["Clean"]),
+        (".nit", &["Nit"]),
+        (".ny", &["Common Lisp"]),
+        (".ps1xml", &["XML"]),
+        (".irclog", &["IRC log"]),
+        (".sublime-commands", &["JSON with Comments"]),
+        (".mkdn", &["Markdown"]),
+        (".monkey", &["Monkey"]),
+        (".clixml", &["XML"]),
+        (".sp", &["SourcePawn"]),
+        (".gts", &["Gerber Image"]),
+        (".mkii", &["TeX"]),
+        (".erb", &["HTML+ERB"]),
+        (".maxhelp", &["Max"]),
+        (".dyalog", &["APL"]),
+        (".tex", &["TeX"]),
+        (".prc", &["PLSQL", "SQL"]),
+        (".workbook", &["Markdown"]),
+        (".obj", &["Wavefront Object"]),
+        (".js", &["JavaScript"]),
+        (".aj", &["AspectJ"]),
+        (".yap", &["Prolog"]),
+        (".m", &["M", "Mathematica", "Objective-C", "Limbo", "MATLAB", "MUF", "Mercury"]),
+        (".yml", &["YAML"]),
+        (".emacs.desktop", &["Emacs Lisp"]),
+        (".sig", &["Standard ML"]),
+        (".wsgi", &["Python"]),
+        (".coffee", &["CoffeeScript"]),
+        (".cshtml", &["HTML+Razor"]),
+        (".rbbas", &["REALbasic"]),
+        (".mcmeta", &["JSON"]),
+        (".ebuild", &["Gentoo Ebuild"]),
+        (".cscfg", &["XML"]),
+        (".tese", &["GLSL"]),
+        (".opa", &["Opa"]),
+        (".raml", &["RAML"]),
+        (".tl", &["Type Language"]),
+        (".mak", &["Makefile"]),
+        (".cmake.in", &["CMake"]),
+        (".mir", &["YAML"]),
+        (".rpy", &["Python", "Ren\'Py"]),
+        (".prefab", &["Unity3D Asset"]),
+        (".grt", &["Groovy"]),
+        (".c++", &["C++"]),
+        (".srt", &["SubRip Text", "SRecode Template"]),
+        (".lvproj", &["LabVIEW"]),
+        (".gdbinit", &["GDB"]),
+        (".nu", &["Nu"]),
+        (".cgi", &["Python", "Perl", "Shell"]),
+        (".5", &["Roff", "Roff Manpage"]),
+        (".pyw", &["Python"]),
+        (".zig", &["Zig"]),
+        (".haml.deface", &["Haml"]),
+        (".gi", &["GAP"]),
+        (".i", &["Assembly", "SWIG", "Motorola 68K Assembly"]),
+        (".xojo_toolbar", &["Xojo"]),
+        (".rktd", &["Racket"]),
+        (".mkd", &["Markdown"]),
+        (".xproc", &["XProc"]),
+        (".builds", &["XML"]),
+        (".vhi", &["VHDL"]),
+        (".vxml", &["XML"]),
+        (".vhw", &["VHDL"]),
+        (".nim.cfg", &["Nim"]),
+        (".bro", &["Zeek"]),
+        (".nearley", &["Nearley"]),
+        (".sagews", &["Sage"]),
+        (".yrl", &["Erlang"]),
+        (".psgi", &["Perl"]),
+        (".vstemplate", &["XML"]),
+        (".io", &["Io"]),
+        (".py3", &["Python"]),
+        (".ru", &["Ruby"]),
+        (".parrot", &["Parrot"]),
+        (".mod", &["Modula-2", "AMPL", "XML", "Linux Kernel Module"]),
         (".cs", &["Smalltalk", "C#"]),
-        (".cfm", &["ColdFusion"]),
-        (".cproject", &["XML"]),
-        (".dockerfile", &["Dockerfile"]),
-        (".builder", &["Ruby"]),
-        (".cats", &["C"]),
-        (".gitconfig", &["Git Config"]),
-        (".jsonc", &["JSON with Comments"]),
-        (".cr", &["Crystal"]),
-        (".rq", &["SPARQL"]),
-        (".fcgi", &["Python", "Perl", "Lua", "Ruby", "Shell", "PHP"]),
-        (".ipf", &["IGOR Pro"]),
-        (".gn", &["GN"]),
+        (".sparql", &["SPARQL"]),
+        (".cp", &["Component Pascal", "C++"]),
+        (".model.lkml", &["LookML"]),
+        (".hack", &["Hack"]),
+        (".roff", &["Roff"]),
+        (".rebol", &["Rebol"]),
+        (".ss", &["Scheme"]),
+        (".ceylon", &["Ceylon"]),
+        (".ktm", &["Kotlin"]),
+        (".mtml", &["MTML"]),
+        (".admx", &["XML"]),
+        (".udo", &["Csound"]),
+        (".handlebars", &["Handlebars"]),
+        (".srw", &["PowerBuilder"]),
         (".vh", &["SystemVerilog"]),
-        (".xojo_toolbar", &["Xojo"]),
-        (".ivy", &["XML"]),
-        (".el", &["Emacs Lisp"]),
-        (".phtml", &["HTML+PHP"]),
-        (".afm", &["Adobe Font Metrics"]),
-        (".ex", &["Elixir"]),
-        (".pot", &["Gettext Catalog"]),
-        (".xproj", &["XML"]),
-        (".rdoc", &["RDoc"]),
-        (".ecl", &["ECL", "ECLiPSe"]),
-        (".yaml.sed", &["YAML"]),
-        (".lbx", &["TeX"]),
-        (".myt", &["Myghty"]),
-        (".jflex", &["JFlex"]),
-        (".pd_lua", &["Lua"]),
-        (".chpl", &["Chapel"]),
-        (".w", &["OpenEdge ABL", "CWeb"]),
-        (".g4", &["ANTLR"]),
-        (".tf", &["HCL"]),
-        (".hy", &["Hy"]),
-        (".gradle", &["Gradle"]),
-        (".sexp", &["Common Lisp"]),
-        (".tu", &["Turing"]),
-        (".ur", &["UrWeb"]),
-        (".natvis", &["XML"]),
-        (".bsl", &["1C Enterprise"]),
+        (".pig", &["PigLatin"]),
+        (".nqp", &["Raku"]),
+        (".apl", &["APL"]),
+        (".nl", &["NewLisp", "NL"]),
+        (".tmcommand", &["XML Property List"]),
+        (".cjsx", &["CoffeeScript"]),
+        (".sublime-keymap", &["JSON with Comments"]),
+        (".html.hl", &["HTML"]),
+        (".em", &["EmberScript"]),
+        (".tst", &["GAP", "Scilab"]),
+        (".axs.erb", &["NetLinx+ERB"]),
+        (".tpl", &["Smarty"]),
+        (".swift", &["Swift"]),
+        (".applescript", &["AppleScript"]),
+        (".xojo_code", &["Xojo"]),
+        (".fr", &["Text", "Forth", "Frege"]),
+        (".bats", &["Shell"]),
+        (".ndproj", &["XML"]),
+        (".command", &["Shell"]),
+        (".bas", &["VBA"]),
+        (".gbp", &["Gerber Image"]),
+        (".cocci", &["SmPL"]),
+        (".inc", &["Assembly", "NASL", "POV-Ray SDL", "Motorola 68K Assembly", "HTML", "Pascal", "SourcePawn", "C++", "Pawn", "SQL", "PHP"]),
         (".mjml", &["XML"]),
-        (".scd", &["SuperCollider"]),
-        (".prg", &["xBase"]),
-        (".perl", &["Perl"]),
-        (".sh-session", &["ShellSession"]),
-        (".iss", &["Inno Setup"]),
-        (".eh", &["eC"]),
-        (".xsjslib", &["JavaScript"]),
-        (".json-tmlanguage", &["JSON"]),
-        (".hs", &["Haskell"]),
-        (".nsi", &["NSIS"]),
-        (".wiki", &["MediaWiki"]),
-        (".ph", &["Perl"]),
-        (".eclass", &["Gentoo Eclass"]),
-        (".es", &["Erlang", "JavaScript"]),
-        (".xpl", &["XProc"]),
-        (".fp", &["GLSL"]),
-        (".yacc", &["Yacc"]),
-        (".ipynb", &["Jupyter Notebook"]),
-        (".rbtbar", &["REALbasic"]),
-        (".reb", &["Rebol"]),
-        (".plist", &["OpenStep Property List", "XML Property List"]),
-        (".txl", &["TXL"]),
-        (".tla", &["TLA"]),
-        (".pod", &["Pod 6", "Pod"]),
-        (".cp", &["Component Pascal", "C++"]),
-        (".irclog", &["IRC log"]),
-        (".as", &["AngelScript", "ActionScript"]),
-        (".zimpl", &["Zimpl"]),
-        (".gbr", &["Gerber Image"]),
-        (".ccp", &["COBOL"]),
-        (".ec", &["eC"]),
-        (".zep", &["Zephir"]),
-        (".clj", &["Clojure"]),
-        (".pasm", &["Parrot Assembly"]),
-        (".parrot", &["Parrot"]),
-        (".2", &["Roff", "Roff Manpage"]),
         (".cljc", &["Clojure"]),
-        (".json5", &["JSON5"]),
-        (".txt", &["Text"]),
-        (".gbs", &["Gerber Image"]),
-        (".upc", &["Unified Parallel C"]),
-        (".mq5", &["MQL5"]),
-        (".vcl", &["VCL"]),
-        (".las", &["Lasso"]),
-        (".oz", &["Oz"]),
-        (".for", &["Fortran", "Forth", "Formatted"]),
-        (".php5", &["PHP"]),
-        (".jake", &["JavaScript"]),
-        (".c++", &["C++"]),
-        (".meta", &["Unity3D Asset"]),
-        (".gitignore", &["Ignore List"]),
-        (".6", &["Roff", "Roff Manpage"]),
-        (".pub", &["Public Key"]),
-        (".gsp", &["Groovy Server Pages"]),
-        (".jsonnet", &["Jsonnet"]),
-        (".bib", &["BibTeX"]),
-        (".mirah", &["Mirah"]),
-        (".ini", &["INI"]),
+        (".pls", &["PLSQL"]),
+        (".php4", &["PHP"]),
+        (".dlm", &["IDL"]),
         (".ice", &["Slice", "JSON"]),
-        (".mustache", &["HTML+Django"]),
-        (".wast", &["WebAssembly"]),
-        (".bsv", &["Bluespec"]),
-        (".asn", &["ASN.1"]),
-        (".sublime-keymap", &["JSON with Comments"]),
-        (".thy", &["Isabelle"]),
-        (".properties", &["Java Properties", "INI"]),
-        (".xacro", &["XML"]),
-        (".wlua", &["Lua"]),
-        (".launch", &["XML"]),
-        (".nproj", &["XML"]),
-        (".cmake", &["CMake"]),
-        (".qll", &["CodeQL"]),
+        (".sexp", &["Common Lisp"]),
+        (".awk", &["Awk"]),
+        (".rsh", &["RenderScript"]),
+        (".rbuild", &["Ruby"]),
+        (".es", &["Erlang", "JavaScript"]),
+        (".pkb", &["PLSQL"]),
+        (".cpp-objdump", &["Cpp-ObjDump"]),
+        (".prefs", &["INI"]),
+        (".ino", &["C++"]),
+        (".yy", &["Yacc", "JSON"]),
+        (".bibtex", &["BibTeX"]),
+        (".bdf", &["Glyph Bitmap Distribution Format"]),
+        (".jl", &["Julia"]),
+        (".urs", &["UrWeb"]),
+        (".lsl", &["LSL"]),
+        (".unity", &["Unity3D Asset"]),
+        (".gnu", &["Gnuplot"]),
+        (".ins", &["TeX"]),
+        (".osm", &["XML"]),
+        (".lookml", &["LookML"]),
+        (".xojo_script", &["Xojo"]),
+        (".mysql", &["SQL"]),
+        (".tpb", &["PLSQL"]),
+        (".mcfunction", &["mcfunction"]),
+        (".ur", &["UrWeb"]),
+        (".cxx", &["C++"]),
+        (".lsp", &["Common Lisp", "NewLisp"]),
+        (".postcss", &["PostCSS"]),
+        (".prw", &["xBase"]),
+        (".sci", &["Scilab"]),
+        (".desktop", &["desktop"]),
+        (".cc", &["C++"]),
+        (".matah", &["Stata"]),
+        (".x68", &["Motorola 68K Assembly"]),
         (".pogo", &["PogoScript"]),
-        (".bf", &["Brainfuck", "HyPhy"]),
-        (".numpy", &["NumPy"]),
-        (".mu", &["mupad"]),
+        (".graphql", &["GraphQL"]),
+        (".rest", &["reStructuredText"]),
+        (".cfg", &["HAProxy", "INI"]),
+        (".axi", &["NetLinx"]),
+        (".cwl", &["Common Workflow Language"]),
+        (".eb", &["Easybuild"]),
+        (".cabal", &["Cabal Config"]),
         (".boot", &["Clojure"]),
-        (".groovy", &["Groovy"]),
-        (".raml", &["RAML"]),
-        (".opa", &["Opa"]),
-        (".unity", &["Unity3D Asset"]),
-        (".mediawiki", &["MediaWiki"]),
-        (".j", &["Objective-J", "Jasmin"]),
-        (".gd", &["GAP", "GDScript"]),
-        (".rbxs", &["Lua"]),
-        (".erb.deface", &["HTML+ERB"]),
-        (".regexp", &["Regular Expression"]),
-        (".sublime-mousemap", &["JSON with Comments"]),
-        (".sagews", &["Sage"]),
-        (".prefs", &["INI"]),
+        (".ch", &["Charity", "xBase"]),
+        (".rego", &["Open Policy Agent"]),
+        (".gco", &["G-code"]),
+        (".sql", &["SQLPL", "PLSQL", "PLpgSQL", "SQL", "TSQL"]),
+        (".csproj", &["XML"]),
         (".maxpat", &["Max"]),
+        (".ads", &["Ada"]),
+        (".wxs", &["XML"]),
+        (".axd", &["ASP"]),
+        (".8xk.txt", &["TI Program"]),
+        (".muse", &["Muse"]),
+        (".pyx", &["Cython"]),
+        (".cpy", &["COBOL"]),
+        (".pov", &["POV-Ray SDL"]),
+        (".ct", &["XML"]),
+        (".xzap", &["ZAP"]),
+        (".rake", &["Ruby"]),
+        (".wast", &["WebAssembly"]),
+        (".edc", &["Edje Data Collection"]),
+        (".svh", &["SystemVerilog"]),
+        (".sats", &["ATS"]),
+        (".rbw", &["Ruby"]),
+        (".lpr", &["Pascal"]),
+        (".yml.mysql", &["YAML"]),
+        (".lid", &["Dylan"]),
+        (".libsonnet", &["Jsonnet"]),
+        (".pluginspec", &["Ruby", "XML"]),
+        (".tea", &["Tea"]),
+        (".oxh", &["Ox"]),
+        (".ronn", &["Markdown"]),
+        (".ring", &["Ring"]),
+        (".hxml", &["HXML"]),
+        (".mli", &["OCaml"]),
+        (".cmd", &["Batchfile"]),
+        (".csv", &["CSV"]),
+        (".glsl", &["GLSL"]),
+        (".ruby", &["Ruby"]),
+        (".angelscript", &["AngelScript"]),
+        (".epj", &["Ecere Projects"]),
+        (".ol", &["Jolie"]),
+        (".nsi", &["NSIS"]),
+        (".dircolors", &["dircolors"]),
         (".a51", &["Assembly"]),
-        (".wsgi", &["Python"]),
-        (".ipp", &["C++"]),
-        (".shproj", &["XML"]),
-        (".scxml", &["XML"]),
-        (".hpp", &["C++"]),
-        (".frx", &["VBA"]),
-        (".tmtheme", &["XML Property List"]),
-        (".bdy", &["PLSQL"]),
-        (".nlogo", &["NetLogo"]),
-        (".st", &["HTML", "Smalltalk"]),
-        (".1in", &["Roff", "Roff Manpage"]),
-        (".cfg", &["HAProxy", "INI"]),
-        (".conll", &["CoNLL-U"]),
-        (".bmx", &["BlitzMax"]),
-        (".lhs", &["Literate Haskell"]),
-        (".chs", &["C2hs Haskell"]),
-        (".py3", &["Python"]),
-        (".bdf", &["Glyph Bitmap Distribution Format"]),
-        (".ado", &["Stata"]),
-        (".psc1", &["XML"]),
-        (".tpl", &["Smarty"]),
-        (".tool", &["Shell"]),
-        (".mdpolicy", &["XML"]),
-        (".js.erb", &["JavaScript+ERB"]),
+        (".kicad_mod", &["KiCad Layout"]),
+        (".cfm", &["ColdFusion"]),
+        (".yaml-tmlanguage", &["YAML"]),
+        (".8xp.txt", &["TI Program"]),
+        (".xqy", &["XQuery"]),
+        (".properties", &["Java Properties", "INI"]),
+        (".mu", &["mupad"]),
+        (".agda", &["Agda"]),
+        (".f08", &["Fortran"]),
         (".fea", &["OpenType Feature File"]),
-        (".zs", &["ZenScript"]),
-        (".4", &["Roff", "Roff Manpage"]),
-        (".csh", &["Tcsh"]),
-        (".nu", &["Nu"]),
+        (".c++objdump", &["Cpp-ObjDump"]),
+        (".bf", &["Brainfuck", "HyPhy"]),
+        (".ltx", &["TeX"]),
+        (".eliomi", &["OCaml"]),
+        (".snip", &["Vim Snippet"]),
         (".db2", &["SQLPL"]),
-        (".flf", &["FIGlet Font"]),
-        (".dsp", &["Faust", "Microsoft Developer Studio Project"]),
-        (".hc", &["HolyC"]),
-        (".rest", &["reStructuredText"]),
-        (".kojo", &["Scala"]),
-        (".cxx-objdump", &["Cpp-ObjDump"]),
-        (".xc", &["XC"]),
-        (".spec", &["Python", "Ruby", "RPM Spec"]),
-        (".gmx", &["XML"]),
-        (".self", &["Self"]),
-        (".slim", &["Slim"]),
-        (".apl", &["APL"]),
-        (".hh", &["Hack", "C++"]),
-        (".gtpl", &["Groovy"]),
-        (".lsl", &["LSL"]),
-        (".blade", &["Blade"]),
-        (".regex", &["Regular Expression"]),
-        (".fan", &["Fantom"]),
-        (".cginc", &["HLSL"]),
-        (".8xp.txt", &["TI Program"]),
-        (".frg", &["GLSL"]),
-        (".sublime-snippet", &["XML"]),
-        (".fsi", &["F#"]),
-        (".tfvars", &["HCL"]),
-        (".1m", &["Roff", "Roff Manpage"]),
-        (".csv", &["CSV"]),
-        (".gs", &["JavaScript", "Genie", "GLSL", "Gosu"]),
+        (".gpb", &["Gerber Image"]),
+        (".omgrofl", &["Omgrofl"]),
+        (".h++", &["C++"]),
+        (".gradle.kts", &["Gradle"]),
+        (".fsproj", &["XML"]),
+        (".ts", &["XML", "TypeScript"]),
         (".vba", &["Vim script", "VBA"]),
-        (".escript", &["Erlang"]),
-        (".owl", &["Web Ontology Language"]),
-        (".plt", &["Gnuplot"]),
-        (".c-objdump", &["C-ObjDump"]),
-        (".vsixmanifest", &["XML"]),
-        (".xib", &["XML"]),
-        (".brd", &["KiCad Legacy Layout", "Eagle"]),
-        (".ll", &["LLVM"]),
-        (".8xp", &["TI Program"]),
-        (".fx", &["FLUX", "HLSL"]),
+        (".cpp", &["C++"]),
+        (".clj", &["Clojure"]),
+        (".ctp", &["PHP"]),
+        (".js.erb", &["JavaScript+ERB"]),
+        (".vbhtml", &["Visual Basic .NET"]),
+        (".hxsl", &["Haxe"]),
+        (".dot", &["Graphviz (DOT)"]),
+        (".zimpl", &["Zimpl"]),
+        (".d", &["Makefile", "DTrace", "D"]),
+        (".eex", &["HTML+EEX"]),
+        (".gml", &["Graph Modeling Language", "Game Maker Language", "XML", "Gerber Image"]),
+        (".snippet", &["Vim Snippet"]),
+        (".cfml", &["ColdFusion"]),
+        (".myt", &["Myghty"]),
+        (".gpt", &["Gerber Image"]),
+        (".gms", &["GAMS"]),
+        (".pl", &["Perl", "Raku", "Prolog"]),
+        (".7", &["Roff", "Roff Manpage"]),
+        (".clp", &["CLIPS"]),
+        (".frag", &["JavaScript", "GLSL"]),
+        (".exs", &["Elixir"]),
+        (".gap", &["GAP"]),
+        (".pro", &["Proguard", "QMake", "Prolog", "INI", "IDL"]),
+        (".flf", &["FIGlet Font"]),
+        (".cljs", &["Clojure"]),
+        (".ahk", &["AutoHotkey"]),
+        (".chs", &["C2hs Haskell"]),
+        (".ash", &["AGS Script"]),
+        (".c++-objdump", &["Cpp-ObjDump"]),
+        (".fun", &["Standard ML"]),
+        (".pmod", &["Pike"]),
+        (".gnuplot", &["Gnuplot"]),
+        (".lmi", &["Python"]),
         (".3", &["Roff", "Roff Manpage"]),
-        (".tmpreferences", &["XML Property List"]),
-        (".minid", &["MiniD"]),
-        (".jinja", &["HTML+Django"]),
-        (".xojo_script", &["Xojo"]),
-        (".fxh", &["HLSL"]),
+        (".uno", &["Uno"]),
+        (".glslv", &["GLSL"]),
+        (".jsx", &["JSX"]),
+        (".druby", &["Mirah"]),
+        (".jq", &["JSONiq"]),
+        (".meta", &["Unity3D Asset"]),
+        (".yasnippet", &["YASnippet"]),
+        (".pprx", &["REXX"]),
+        (".ipynb", &["Jupyter Notebook"]),
+        (".jelly", &["XML"]),
+        (".po", &["Gettext Catalog"]),
+        (".mako", &["Mako"]),
+        (".fs", &["Filterscript", "Forth", "F#", "GLSL"]),
+        (".rq", &["SPARQL"]),
+        (".gshader", &["GLSL"]),
+        (".rkt", &["Racket"]),
+        (".v", &["Verilog", "Coq", "V"]),
+        (".mud", &["ZIL"]),
         (".gypi", &["Python"]),
-        (".pm", &["X PixMap", "Perl", "Raku"]),
-        (".ml4", &["OCaml"]),
-        (".cake", &["CoffeeScript", "C#"]),
-        (".pod6", &["Pod 6"]),
-        (".rd", &["R"]),
-        (".4dm", &["4D"]),
-        (".nim.cfg", &["Nim"]),
-        (".reds", &["Red"]),
-        (".mxt", &["Max"]),
-        (".pp", &["Puppet", "Pascal"]),
-        (".pyp", &["Python"]),
-        (".mysql", &["SQL"]),
-        (".cmd", &["Batchfile"]),
-        (".command", &["Shell"]),
-        (".jsfl", &["JavaScript"]),
-        (".gv", &["Graphviz (DOT)"]),
-        (".gbl", &["Gerber Image"]),
-        (".webidl", &["WebIDL"]),
-        (".handlebars", &["Handlebars"]),
-        (".jsonl", &["JSON"]),
-        (".nasl", &["NASL"]),
-        (".latte", &["Latte"]),
-        (".metal", &["Metal"]),
-        (".frag", &["JavaScript", "GLSL"]),
-        (".ch", &["Charity", "xBase"]),
-        (".cjs", &["JavaScript"]),
-        (".glade", &["XML"]),
-        (".io", &["Io"]),
-        (".plot", &["Gnuplot"]),
+        (".xsjslib", &["JavaScript"]),
         (".fth", &["Forth"]),
-        (".raw", &["Raw token data"]),
-        (".tea", &["Tea"]),
-        (".man", &["Roff", "Roff Manpage"]),
-        (".grt", &["Groovy"]),
-        (".tmac", &["Roff"]),
-        (".pyw", &["Python"]),
-        (".1x", &["Roff", "Roff Manpage"]),
-        (".ditaval", &["XML"]),
-        (".pbt", &["PowerBuilder"]),
-        (".sjs", &["JavaScript"]),
-        (".rl", &["Ragel"]),
-        (".au3", &["AutoIt"]),
-        (".pov", &["POV-Ray SDL"]),
-        (".vtt", &["WebVTT"]),
+        (".wat", &["WebAssembly"]),
+        (".sed", &["sed"]),
+        (".maxproj", &["Max"]),
+        (".pcbdoc", &["Altium Designer"]),
+        (".ms", &["Roff", "MAXScript", "Unix Assembly"]),
+        (".kt", &["Kotlin"]),
+        (".tcsh", &["Tcsh"]),
+        (".gawk", &["Awk"]),
+        (".adp", &["Tcl"]),
+        (".p", &["Gnuplot", "OpenEdge ABL"]),
+        (".tmlanguage", &["XML Property List"]),
+        (".rg", &["Rouge"]),
+        (".rdf", &["XML"]),
+        (".mkvi", &["TeX"]),
+        (".wsdl", &["XML"]),
+        (".go", &["Go"]),
+        (".flex", &["JFlex"]),
+        (".xslt", &["XSLT"]),
+        (".tpp", &["C++"]),
+        (".ftl", &["FreeMarker"]),
+        (".sld", &["Scheme"]),
+        (".smt2", &["SMT"]),
+        (".gcode", &["G-code"]),
+        (".shen", &["Shen"]),
+        (".scrbl", &["Racket"]),
+        (".pkgproj", &["XML"]),
+        (".pbi", &["PureBasic"]),
+        (".zcml", &["XML"]),
+        (".ddl", &["PLSQL", "SQL"]),
+        (".objdump", &["ObjDump"]),
+        (".vbs", &["VBScript"]),
         (".rbres", &["REALbasic"]),
-        (".ma", &["Mathematica"]),
-        (".rhtml", &["RHTML"]),
-        (".red", &["Red"]),
-        (".xpy", &["Python"]),
-        (".toc", &["TeX", "World of Warcraft Addon Data"]),
-        (".sps", &["Scheme"]),
-        (".ndproj", &["XML"]),
-        (".cbx", &["TeX"]),
-        (".xul", &["XML"]),
-        (".pyi", &["Python"]),
-        (".scpt", &["AppleScript"]),
+        (".xsp-config", &["XPages"]),
+        (".txt", &["Text"]),
+        (".erb.deface", &["HTML+ERB"]),
+        (".sra", &["PowerBuilder"]),
+        (".xhtml", &["HTML"]),
+        (".mk", &["Makefile"]),
+        (".sage", &["Sage"]),
+        (".dockerfile", &["Dockerfile"]),
+        (".3p", &["Roff", "Roff Manpage"]),
+        (".mrc", &["mIRC Script"]),
+        (".wl", &["Mathematica"]),
+        (".mirah", &["Mirah"]),
+        (".json", &["JSON"]),
+        (".dtx", &["TeX"]),
+        (".vcl", &["VCL"]),
+        (".csdef", &["XML"]),
+        (".cfc", &["ColdFusion CFC"]),
+        (".pri", &["QMake"]),
+        (".1", &["Roff", "Roff Manpage"]),
+        (".gv", &["Graphviz (DOT)"]),
+        (".kojo", &["Scala"]),
+        (".gvy", &["Groovy"]),
         (".hic", &["Clojure"]),
-        (".lid", &["Dylan"]),
-        (".ebnf", &["EBNF"]),
-        (".webmanifest", &["JSON"]),
-        (".lol", &["LOLCODE"]),
-        (".asy", &["LTspice Symbol", "Asymptote"]),
-        (".jinja2", &["HTML+Django"]),
-        (".do", &["Stata"]),
+        (".aspx", &["ASP"]),
+        (".make", &["Makefile"]),
+        (".asciidoc", &["AsciiDoc"]),
+        (".gitignore", &["Ignore List"]),
+        (".hbs", &["Handlebars"]),
+        (".gsp", &["Groovy Server Pages"]),
+        (".sthlp", &["Stata"]),
+        (".razor", &["HTML+Razor"]),
+        (".uc", &["UnrealScript"]),
+        (".scm", &["Scheme"]),
+        (".phps", &["PHP"]),
+        (".sqf", &["SQF"]),
         (".axml", &["XML"]),
-        (".mkdn", &["Markdown"]),
-        (".vim", &["Vim script"]),
-        (".html", &["HTML"]),
-        (".go", &["Go"]),
-        (".cpp-objdump", &["Cpp-ObjDump"]),
-        (".xht", &["HTML"]),
-        (".re", &["Reason", "C++"]),
-        (".riot", &["Riot"]),
-        (".zeek", &["Zeek"]),
-        (".props", &["XML"]),
-        (".bas", &["VBA"]),
-        (".cpp", &["C++"]),
-        (".druby", &["Mirah"]),
-        (".mqh", &["MQL4", "MQL5"]),
-        (".mkvi", &["TeX"]),
-        (".qasm", &["OpenQASM"]),
-        (".sparql", &["SPARQL"]),
-        (".rei", &["Reason"]),
-        (".liquid", &["Liquid"]),
-        (".hb", &["Harbour"]),
-        (".aw", &["PHP"]),
-        (".shader", &["ShaderLab", "GLSL"]),
-        (".decls", &["BlitzBasic"]),
-        (".dtx", &["TeX"]),
-        (".cps", &["Component Pascal"]),
-        (".xojo_code", &["Xojo"]),
-        (".asp", &["ASP"]),
-        (".p6m", &["Raku"]),
-        (".sublime-settings", &["JSON with Comments"]),
-        (".lpr", &["Pascal"]),
-        (".pl6", &["Raku"]),
-        (".3qt", &["Roff", "Roff Manpage"]),
-        (".robot", &["RobotFramework"]),
-        (".yaml", &["YAML"]),
-        (".nawk", &["Awk"]),
-        (".iuml", &["PlantUML"]),
-        (".mumps", &["M"]),
         (".x10", &["X10"]),
-        (".cy", &["Cycript"]),
-        (".py", &["Python"]),
-        (".mkiv", &["TeX"]),
-        (".ino", &["C++"]),
-        (".os", &["1C Enterprise"]),
-        (".lfe", &["LFE"]),
-        (".xrl", &["Erlang"]),
-        (".schdoc", &["Altium Designer"]),
-        (".rkt", &["Racket"]),
-        (".tcc", &["C++"]),
-        (".yap", &["Prolog"]),
-        (".kicad_mod", &["KiCad Layout"]),
-        (".mt", &["Mathematica"]),
-        (".rbfrm", &["REALbasic"]),
-        (".eb", &["Easybuild"]),
-        (".vala", &["Vala"]),
-        (".svh", &["SystemVerilog"]),
-        (".rno", &["Roff", "RUNOFF"]),
-        (".rego", &["Open Policy Agent"]),
-        (".eclxml", &["ECL"]),
+        (".b", &["Brainfuck", "Limbo"]),
+        (".tmtheme", &["XML Property List"]),
+        (".plx", &["Perl"]),
+        (".x3d", &["XML"]),
+        (".pd", &["Pure Data"]),
+        (".cirru", &["Cirru"]),
+        (".g4", &["ANTLR"]),
+        (".iuml", &["PlantUML"]),
+        (".p6l", &["Raku"]),
+        (".thy", &["Isabelle"]),
+        (".vbproj", &["XML"]),
+        (".mspec", &["Ruby"]),
+        (".geojson", &["JSON"]),
+        (".iss", &["Inno Setup"]),
+        (".wxi", &["XML"]),
+        (".idc", &["C"]),
+        (".wlua", &["Lua"]),
+        (".sls", &["SaltStack", "Scheme"]),
+        (".slim", &["Slim"]),
+        (".nasm", &["Assembly"]),
+        (".pde", &["Processing"]),
+        (".r2", &["Rebol"]),
+        (".i3", &["Modula-3"]),
+        (".gn", &["GN"]),
+        (".view.lkml", &["LookML"]),
+        (".zone", &["DNS Zone"]),
+        (".adoc", &["AsciiDoc"]),
+        (".gbl", &["Gerber Image"]),
         (".grxml", &["XML"]),
-        (".zil", &["ZIL"]),
-        (".xqm", &["XQuery"]),
-        (".eam.fs", &["Formatted"]),
-        (".factor", &["Factor"]),
-        (".cfc", &["ColdFusion CFC"]),
-        (".ctp", &["PHP"]),
-        (".cmake.in", &["CMake"]),
-        (".aux", &["TeX"]),
-        (".rmd", &["RMarkdown"]),
+        (".mdown", &["Markdown"]),
+        (".fx", &["FLUX", "HLSL"]),
+        (".do", &["Stata"]),
+        (".m4", &["M4Sugar", "M4"]),
+        (".cu", &["Cuda"]),
+        (".gdb", &["GDB"]),
+        (".sublime-macro", &["JSON with Comments"]),
+        (".muf", &["MUF"]),
         (".ashx", &["ASP"]),
-        (".wxl", &["XML"]),
-        (".gemspec", &["Ruby"]),
-        (".bison", &["Bison"]),
-        (".tac", &["Python"]),
-        (".http", &["HTTP"]),
-        (".jsx", &["JSX"]),
-        (".xproc", &["XProc"]),
-        (".cwl", &["Common Workflow Language"]),
-        (".mkd", &["Markdown"]),
-        (".hql", &["HiveQL"]),
-        (".cppobjdump", &["Cpp-ObjDump"]),
-        (".monkey2", &["Monkey"]),
-        (".7", &["Roff", "Roff Manpage"]),
-        (".xtend", &["Xtend"]),
-        (".tcl", &["Tcl"]),
-        (".ssjs", &["JavaScript"]),
-        (".textile", &["Textile"]),
-        (".com", &["DIGITAL Command Language"]),
-        (".c++-objdump", &["Cpp-ObjDump"]),
-        (".ml", &["Standard ML", "OCaml"]),
-        (".syntax", &["YAML"]),
-        (".pan", &["Pan"]),
-        (".oxh", &["Ox"]),
-        (".me", &["Roff"]),
-        (".s", &["Unix Assembly", "Motorola 68K Assembly"]),
-        (".tst", &["GAP", "Scilab"]),
-        (".gbo", &["Gerber Image"]),
-        (".rviz", &["YAML"]),
-        (".gko", &["Gerber Image"]),
-        (".bbx", &["TeX"]),
-        (".ijs", &["J"]),
+        (".ampl", &["AMPL"]),
+        (".sublime-project", &["JSON with Comments"]),
+        (".rbi", &["Ruby"]),
+        (".lagda", &["Literate Agda"]),
+        (".odd", &["XML"]),
+        (".cginc", &["HLSL"]),
+        (".pytb", &["Python traceback"]),
+        (".numpy", &["NumPy"]),
+        (".sh-session", &["ShellSession"]),
+        (".xacro", &["XML"]),
+        (".trg", &["PLSQL"]),
+        (".mq4", &["MQL4"]),
+        (".vht", &["VHDL"]),
+        (".asmx", &["ASP"]),
+        (".ant", &["XML"]),
         (".jisonlex", &["Jison Lex"]),
-        (".l", &["Roff", "Lex", "Common Lisp", "PicoLisp"]),
-        (".rbbas", &["REALbasic"]),
-        (".pkgproj", &["XML"]),
-        (".scss", &["SCSS"]),
-        (".auk", &["Awk"]),
-        (".gtp", &["Gerber Image"]),
-        (".plantuml", &["PlantUML"]),
-        (".mcfunction", &["mcfunction"]),
-        (".vhi", &["VHDL"]),
-        (".1", &["Roff", "Roff Manpage"]),
-        (".befunge", &["Befunge"]),
-        (".fr", &["Text", "Forth", "Frege"]),
-        (".ccproj", &["XML"]),
-        (".zone", &["DNS Zone"]),
-        (".jsb", &["JavaScript"]),
-        (".gawk", &["Awk"]),
-        (".matah", &["Stata"]),
-        (".sss", &["SugarSS"]),
-        (".click", &["Click"]),
-        (".mm", &["Objective-C++", "XML"]),
-        (".depproj", &["XML"]),
-        (".pks", &["PLSQL"]),
+        (".y", &["Yacc"]),
+        (".1in", &["Roff", "Roff Manpage"]),
+        (".eq", &["EQ"]),
+        (".cljs.hl", &["Clojure"]),
+        (".puml", &["PlantUML"]),
+        (".asp", &["ASP"]),
+        (".ld", &["Linker Script"]),
+        (".pot", &["Gettext Catalog"]),
+        (".ncl", &["Text", "NCL", "XML"]),
+        (".p8", &["Lua"]),
+        (".pike", &["Pike"]),
+        (".plb", &["PLSQL"]),
+        (".phtml", &["HTML+PHP"]),
+        (".ttl", &["Turtle"]),
         (".es6", &["JavaScript"]),
-        (".conllu", &["CoNLL-U"]),
-        (".outjob", &["Altium Designer"]),
-        (".anim", &["Unity3D Asset"]),
+        (".ps", &["PostScript"]),
+        (".jinja", &["HTML+Django"]),
+        (".glade", &["XML"]),
+        (".elm", &["Elm"]),
+        (".reek", &["YAML"]),
+        (".fpp", &["Fortran"]),
+        (".sh", &["Shell"]),
+        (".vssettings", &["XML"]),
         (".styl", &["Stylus"]),
-        (".hqf", &["SQF"]),
-        (".ne", &["Nearley"]),
-        (".sublime_metrics", &["JSON with Comments"]),
-        (".5", &["Roff", "Roff Manpage"]),
-        (".pic", &["Pic"]),
-        (".pony", &["Pony"]),
-        (".bones", &["JavaScript"]),
-        (".ct", &["XML"]),
-        (".pls", &["PLSQL"]),
-        (".sass", &["Sass"]),
-        (".9", &["Roff", "Roff Manpage"]),
-        (".csx", &["C#"]),
-        (".zmpl", &["Zimpl"]),
-        (".rdf", &["XML"]),
-        (".vb", &["Visual Basic .NET"]),
+        (".mo", &["Modelica"]),
+        (".outjob", &["Altium Designer"]),
+        (".rmd", &["RMarkdown"]),
+        (".pod6", &["Pod 6"]),
+        (".pb", &["PureBasic"]),
+        (".gitconfig", &["Git Config"]),
         (".yang", &["YANG"]),
-        (".eye", &["Ruby"]),
-        (".pwn", &["Pawn"]),
-        (".numpyw", &["NumPy"]),
-        (".mrc", &["mIRC Script"]),
-        (".ik", &["Ioke"]),
-        (".gyp", &["Python"]),
-        (".rest.txt", &["reStructuredText"]),
-        (".sas", &["SAS"]),
-        (".axi.erb", &["NetLinx+ERB"]),
-        (".jbuilder", &["Ruby"]),
-        (".fpp", &["Fortran"]),
-        (".builds", &["XML"]),
-        (".hhi", &["Hack"]),
-        (".asn1", &["ASN.1"]),
-        (".b", &["Brainfuck", "Limbo"]),
-        (".plb", &["PLSQL"]),
-        (".prefab", &["Unity3D Asset"]),
-        (".8xk", &["TI Program"]),
-        (".xql", &["XQuery"]),
-        (".cgi", &["Python", "Perl", "Shell"]),
-        (".odin", &["Object Data Instance Notation", "Odin"]),
-        (".lean", &["Lean"]),
-        (".osm", &["XML"]),
-        (".xsp-config", &["XPages"]),
-        (".dart", &["Dart"]),
-        (".i3", &["Modula-3"]),
-        (".gnuplot", &["Gnuplot"]),
-        (".rss", &["XML"]),
-        (".moon", &["MoonScript"]),
-        (".inl", &["C++"]),
-        (".fy", &["Fancy"]),
-        (".mcr", &["MAXScript"]),
-        (".pm6", &["Raku"]),
-        (".ox", &["Ox"]),
-        (".xspec", &["XML"]),
-        (".sublime-macro", &["JSON with Comments"]),
-        (".ins", &["TeX"]),
-        (".moo", &["Moocode", "Mercury"]),
-        (".tfstate", &["JSON"]),
-        (".mdown", &["Markdown"]),
-        (".gst", &["Gosu"]),
-        (".jsm", &["JavaScript"]),
-        (".angelscript", &["AngelScript"]),
-        (".cls", &["Apex", "OpenEdge ABL", "VBA", "ObjectScript", "TeX"]),
-        (".workbook", &["Markdown"]),
-        (".csproj", &["XML"]),
-        (".tcsh", &["Tcsh"]),
-        (".sage", &["Sage"]),
-        (".storyboard", &["XML"]),
-        (".cocci", &["SmPL"]),
-        (".wlt", &["Mathematica"]),
-        (".axs", &["NetLinx"]),
-        (".csdef", &["XML"]),
-        (".njs", &["JavaScript"]),
-        (".podsl", &["Common Lisp"]),
-        (".workflow", &["HCL", "XML"]),
+        (".wsf", &["XML"]),
+        (".astro", &["Astro"]),
+        (".nlogo", &["NetLogo"]),
+        (".xq", &["XQuery"]),
+        (".nimble", &["Nim"]),
+        (".wisp", &["wisp"]),
+        (".c", &["C"]),
+        (".jsonnet", &["Jsonnet"]),
+        (".scxml", &["XML"]),
+        (".ada", &["Ada"]),
+        (".ne", &["Nearley"]),
         (".sty", &["TeX"]),
-        (".f95", &["Fortran"]),
-        (".flux", &["FLUX"]),
-        (".vhf", &["VHDL"]),
-        (".nuspec", &["XML"]),
-        (".rst", &["reStructuredText"]),
-        (".m4", &["M4Sugar", "M4"]),
-        (".mkdown", &["Markdown"]),
-        (".mo", &["Modelica"]),
-        (".nr", &["Roff"]),
-        (".texi", &["Texinfo"]),
-        (".vue", &["Vue"]),
-        (".nim", &["Nim"]),
-        (".proto", &["Protocol Buffer"]),
-        (".cfml", &["ColdFusion"]),
-        (".icl", &["Clean"]),
-        (".rbmnu", &["REALbasic"]),
-        (".mms", &["Module Management System"]),
-        (".lektorproject", &["INI"]),
-        (".hbs", &["Handlebars"]),
-        (".pprx", &["REXX"]),
-        (".pri", &["QMake"]),
-        (".rsc", &["Rascal"]),
-        (".xojo_report", &["Xojo"]),
-        (".p8", &["Lua"]),
-        (".rbx", &["Ruby"]),
-        (".mako", &["Mako"]),
-        (".pascal", &["Pascal"]),
-        (".xojo_menu", &["Xojo"]),
-        (".asm", &["Assembly", "Motorola 68K Assembly"]),
-        (".zap", &["ZAP"]),
-        (".fsh", &["GLSL"]),
-        (".yasnippet", &["YASnippet"]),
-        (".ck", &["ChucK"]),
-        (".hlsli", &["HLSL"]),
-        (".wxs", &["XML"]),
-        (".adml", &["XML"]),
-        (".yy", &["Yacc", "JSON"]),
-        (".gi", &["GAP"]),
-        (".bats", &["Shell"]),
-        (".org", &["Org"]),
-        (".libsonnet", &["Jsonnet"]),
-        (".mat", &["Unity3D Asset"]),
-        (".sp", &["SourcePawn"]),
-        (".vho", &["VHDL"]),
-        (".cxx", &["C++"]),
-        ("._ls", &["LiveScript"]),
-        (".php3", &["PHP"]),
-        (".clp", &["CLIPS"]),
-        (".p6", &["Raku"]),
-        (".xml.dist", &["XML"]),
-        (".aug", &["Augeas"]),
-        (".cc", &["C++"]),
-        (".dot", &["Graphviz (DOT)"]),
-        (".lgt", &["Logtalk"]),
-        (".ily", &["LilyPond"]),
-        (".rsx", &["R"]),
-        (".clixml", &["XML"]),
-        (".pike", &["Pike"]),
-        (".fsx", &["F#"]),
-        (".pep", &["Pep8"]),
-        (".axi", &["NetLinx"]),
-        (".rst.txt", &["reStructuredText"]),
-        (".rsh", &["RenderScript"]),
-        (".xmi", &["XML"]),
-        (".sv", &["SystemVerilog"]),
-        (".tesc", &["GLSL"]),
-        (".lagda", &["Literate Agda"]),
-        (".bash", &["Shell"]),
-        (".tfstate.backup", &["JSON"]),
-        (".t", &["Terra", "Perl", "Turing", "Raku"]),
-        (".tpb", &["PLSQL"]),
-        (".ampl", &["AMPL"]),
-        (".wdl", &["wdl"]),
-        (".nf", &["Nextflow"]),
-        (".frm", &["VBA"]),
-        (".vark", &["Gosu"]),
-        (".resx", &["XML"]),
-        (".md", &["Markdown", "GCC Machine Description"]),
-        (".arc", &["Arc"]),
-        (".roff", &["Roff"]),
-        (".rebol", &["Rebol"]),
-        (".sru", &["PowerBuilder"]),
-        (".xml", &["XML"]),
-        (".xm", &["Logos"]),
-        (".opencl", &["OpenCL"]),
-        (".lasso9", &["Lasso"]),
-        (".ooc", &["ooc"]),
-        (".xquery", &["XQuery"]),
-        (".gaml", &["GAML"]),
-        (".smk", &["Python"]),
-        (".wat", &["WebAssembly"]),
-        (".yara", &["YARA"]),
-        (".glslv", &["GLSL"]),
-        (".xlf", &["XML"]),
-        (".ecr", &["HTML+ECR"]),
-        (".pd", &["Pure Data"]),
-        (".sj", &["Objective-J"]),
-        (".sublime-completions", &["JSON with Comments"]),
-        (".gltf", &["JSON"]),
-        (".lasso8", &["Lasso"]),
-        (".fst", &["F*"]),
-        (".sfd", &["Spline Font Database"]),
-        (".emacs", &["Emacs Lisp"]),
-        (".json", &["JSON"]),
-        (".dircolors", &["dircolors"]),
-        (".tl", &["Type Language"]),
-        (".nimrod", &["Nim"]),
-        (".rs.in", &["Rust"]),
-        (".puml", &["PlantUML"]),
-        (".mq4", &["MQL4"]),
-        (".php", &["Hack", "PHP"]),
-        (".ahkl", &["AutoHotkey"]),
-        (".ms", &["Roff", "MAXScript", "Unix Assembly"]),
-        (".epsi", &["PostScript"]),
-        (".htm", &["HTML"]),
-        (".nsh", &["NSIS"]),
+        (".asy", &["LTspice Symbol", "Asymptote"]),
+        (".eh", &["eC"]),
+        (".mg", &["Modula-3"]),
         (".bal", &["Ballerina"]),
-        (".fun", &["Standard ML"]),
-        (".mkfile", &["Makefile"]),
-        (".kicad_pcb", &["KiCad Layout"]),
-        (".marko", &["Marko"]),
-        (".cirru", &["Cirru"]),
-        (".ps1xml", &["XML"]),
-        (".pb", &["PureBasic"]),
-        (".di", &["D"]),
-        (".dll.config", &["XML"]),
-        (".yyp", &["JSON"]),
-        (".srw", &["PowerBuilder"]),
-        (".scrbl", &["Racket"]),
-        (".nbp", &["Mathematica"]),
         (".emberscript", &["EmberScript"]),
-        (".psd1", &["PowerShell"]),
-        (".erb", &["HTML+ERB"]),
-        (".ltx", &["TeX"]),
-        (".qml", &["QML"]),
-        (".vhdl", &["VHDL"]),
-        (".ol", &["Jolie"]),
-        (".gni", &["GN"]),
-        (".maxproj", &["Max"]),
-        (".tm", &["Tcl"]),
-        (".vssettings", &["XML"]),
-        (".monkey", &["Monkey"]),
-        (".omgrofl", &["Omgrofl"]),
-        (".cdf", &["Mathematica"]),
-        (".diff", &["Diff"]),
-        (".nasm", &["Assembly"]),
-        (".app.src", &["Erlang"]),
-        (".srt", &["SubRip Text", "SRecode Template"]),
-        (".nims", &["Nim"]),
-        (".bzl", &["Starlark"]),
-        (".ejs", &["EJS"]),
-        (".snippet", &["Vim Snippet"]),
-        (".dita", &["XML"]),
-        (".nimble", &["Nim"]),
+        (".nc", &["nesC"]),
+        (".pks", &["PLSQL"]),
+        (".monkey2", &["Monkey"]),
+        (".epsi", &["PostScript"]),
+        (".cproject", &["XML"]),
+        (".aw", &["PHP"]),
+        (".xib", &["XML"]),
+        (".vapi", &["Vala"]),
+        (".vmb", &["Vim script"]),
+        (".chem", &["Pic"]),
+        (".cy", &["Cycript"]),
+        (".cnc", &["G-code"]),
+        (".txi", &["Texinfo"]),
+        (".inl", &["C++"]),
+        (".phpt", &["PHP"]),
+        (".4", &["Roff", "Roff Manpage"]),
         (".tmsnippet", &["XML Property List"]),
+        (".perl", &["Perl"]),
+        (".jsonl", &["JSON"]),
+        (".eliom", &["OCaml"]),
+        (".urdf", &["XML"]),
+        (".lex", &["Lex"]),
+        (".sublime-settings", &["JSON with Comments"]),
+        (".webidl", &["WebIDL"]),
+        (".hql", &["HiveQL"]),
+        (".graphqls", &["GraphQL"]),
+        (".scpt", &["AppleScript"]),
+        (".ston", &["STON"]),
+        (".app.src", &["Erlang"]),
+        (".pl6", &["Raku"]),
+        (".xml.dist", &["XML"]),
+        (".ccp", &["COBOL"]),
+        (".pan", &["Pan"]),
+        (".scala", &["Scala"]),
+        (".cdf", &["Mathematica"]),
+        (".asset", &["Unity3D Asset"]),
+        (".9", &["Roff", "Roff Manpage"]),
+        (".mao", &["Mako"]),
+        (".f03", &["Fortran"]),
+        (".hxx", &["C++"]),
+        (".8", &["Roff", "Roff Manpage"]),
+        (".dae", &["COLLADA"]),
+        (".tu", &["Turing"]),
+        (".nasl", &["NASL"]),
+        (".xsl", &["XSLT"]),
+        (".nbp", &["Mathematica"]),
+        (".yaml.sed", &["YAML"]),
         (".fish", &["fish"]),
-        (".pas", &["Pascal"]),
+        (".xpy", &["Python"]),
+        (".twig", &["Twig"]),
+        (".thor", &["Ruby"]),
+        (".bones", &["JavaScript"]),
+        (".workflow", &["HCL", "XML"]),
         (".gsx", &["Gosu"]),
-        (".gts", &["Gerber Image"]),
-        (".hats", &["ATS"]),
-        (".mir", &["YAML"]),
         (".darcspatch", &["Darcs Patch"]),
-        (".kid", &["Genshi"]),
-        (".tex", &["TeX"]),
-        (".erl", &["Erlang"]),
-        (".glf", &["Glyph"]),
-        (".pro", &["Proguard", "QMake", "Prolog", "INI", "IDL"]),
-        (".mk", &["Makefile"]),
-        (".mli", &["OCaml"]),
-        (".cobol", &["COBOL"]),
-        (".mata", &["Stata"]),
-        (".glslf", &["GLSL"]),
-        (".xsjs", &["JavaScript"]),
-        (".xhtml", &["HTML"]),
+        (".bash", &["Shell"]),
         (".zsh", &["Shell"]),
-        (".sed", &["sed"]),
-        (".desktop", &["desktop"]),
-        (".html.hl", &["HTML"]),
-        (".rs", &["Rust", "RenderScript"]),
-        (".scm", &["Scheme"]),
-        (".pcbdoc", &["Altium Designer"]),
-        (".spc", &["PLSQL"]),
-        (".eex", &["HTML+EEX"]),
-        (".php4", &["PHP"]),
-        (".spin", &["Propeller Spin"]),
-        (".f", &["Fortran", "Forth", "Filebench WML"]),
-        (".cnc", &["G-code"]),
-        (".mcmeta", &["JSON"]),
-        (".smt", &["SMT"]),
-        (".ninja", &["Ninja"]),
-        (".matlab", &["MATLAB"]),
-        (".hxx", &["C++"]),
-        (".odd", &["XML"]),
-        (".maxhelp", &["Max"]),
-        (".dyl", &["Dylan"]),
-        (".eps", &["PostScript"]),
-        (".nit", &["Nit"]),
-        (".pyt", &["Python"]),
-        (".rg", &["Rouge"]),
-        (".filters", &["XML"]),
-        (".cpy", &["COBOL"]),
-        (".lisp", &["Common Lisp", "NewLisp"]),
-        (".rake", &["Ruby"]),
-        (".pyde", &["Python"]),
-        (".dfm", &["Pascal"]),
-        (".cl", &["Common Lisp", "Cool", "OpenCL"]),
-        (".texinfo", &["Texinfo"]),
-        (".eliom", &["OCaml"]),
-        (".js", &["JavaScript"]),
-        (".hxsl", &["Haxe"]),
-        (".no", &["Text"]),
-        (".ncl", &["Text", "NCL", "XML"]),
-        (".hack", &["Hack"]),
-        (".lua", &["Lua"]),
-        (".reg", &["Windows Registry Entries"]),
-        (".topojson", &["JSON"]),
-        (".i7x", &["Inform 7"]),
-        (".dcl", &["Clean"]),
-        (".applescript", &["AppleScript"]),
-        (".d", &["Makefile", "DTrace", "D"]),
-        (".admx", &["XML"]),
-        (".webapp", &["JSON"]),
-        (".em", &["EmberScript"]),
-        (".gco", &["G-code"]),
-        (".jsproj", &["XML"]),
-        (".3in", &["Roff", "Roff Manpage"]),
-        (".psgi", &["Perl"]),
-        (".yml", &["YAML"]),
-        (".jl", &["Julia"]),
-        (".gf", &["Grammatical Framework"]),
-        (".gms", &["GAMS"]),
-        (".frt", &["Forth"]),
-        (".6pm", &["Raku"]),
-        (".cbl", &["COBOL"]),
-        (".tpp", &["C++"]),
-        (".kts", &["Kotlin"]),
-        (".r2", &["Rebol"]),
-        (".view.lkml", &["LookML"]),
-        (".sats", &["ATS"]),
-        (".sld", &["Scheme"]),
-        (".f03", &["Fortran"]),
+        (".toc", &["TeX", "World of Warcraft Addon Data"]),
+        (".jbuilder", &["Ruby"]),
+        (".sco", &["Csound Score"]),
+        (".c-objdump", &["C-ObjDump"]),
+        (".nawk", &["Awk"]),
+        (".3m", &["Roff", "Roff Manpage"]),
+        (".asax", &["ASP"]),
+        (".cob", &["COBOL"]),
+        (".fy", &["Fancy"]),
+        (".axs", &["NetLinx"]),
+        (".cjs", &["JavaScript"]),
+        (".eam.fs", &["Formatted"]),
+        (".rdoc", &["RDoc"]),
+        (".oxygene", &["Oxygene"]),
+        (".tcl", &["Tcl"]),
+        (".pd_lua", &["Lua"]),
         (".avsc", &["JSON"]),
-        (".trg", &["PLSQL"]),
-        (".xsd", &["XML"]),
-        (".muse", &["Muse"]),
-        (".m3", &["Modula-3"]),
-        (".udf", &["SQL"]),
-        (".logtalk", &["Logtalk"]),
-        (".xi", &["Logos"]),
-        (".mud", &["ZIL"]),
-        (".mdwn", &["Markdown"]),
-        (".kicad_wks", &["KiCad Layout"]),
-        (".jelly", &["XML"]),
-        (".prc", &["PLSQL", "SQL"]),
-        (".pde", &["Processing"]),
-        (".vhd", &["VHDL"]),
-        (".pgsql", &["PLpgSQL"]),
-        (".jade", &["Pug"]),
-        (".n", &["Roff", "Nemerle"]),
-        (".apib", &["API Blueprint"]),
-        (".nl", &["NewLisp", "NL"]),
-        (".hcl", &["HCL"]),
-        (".adb", &["Ada"]),
-        (".dpr", &["Pascal"]),
-        (".oxo", &["Ox"]),
-        (".urdf", &["XML"]),
-        (".dlm", &["IDL"]),
-        (".xaml", &["XML"]),
-        (".prjpcb", &["Altium Designer"]),
-        (".sml", &["Standard ML"]),
-        (".cabal", &["Cabal Config"]),
-        (".hxml", &["HXML"]),
-        (".aj", &["AspectJ"]),
-        (".ascx", &["ASP"]),
-        (".hsc", &["Haskell"]),
-        (".p6l", &["Raku"]),
-        (".po", &["Gettext Catalog"]),
-        (".gql", &["GraphQL"]),
-        (".razor", &["HTML+Razor"]),
-        (".asset", &["Unity3D Asset"]),
-        (".hs-boot", &["Haskell"]),
-        (".gpb", &["Gerber Image"]),
-        (".mod", &["Modula-2", "AMPL", "XML", "Linux Kernel Module"]),
-        (".mao", &["Mako"]),
-        (".rex", &["REXX"]),
+        (".svelte", &["Svelte"]),
+        (".eye", &["Ruby"]),
+        (".befunge", &["Befunge"]),
+        (".sch", &["XML", "KiCad Schematic", "Scheme", "Eagle"]),
+        (".eml", &["EML"]),
+        (".pyi", &["Python"]),
+        (".escript", &["Erlang"]),
+        (".groovy", &["Groovy"]),
+        (".moo", &["Moocode", "Mercury"]),
+        (".pxi", &["Cython"]),
+        (".resx", &["XML"]),
+        (".scaml", &["Scaml"]),
+        (".pug", &["Pug"]),
+        (".man", &["Roff", "Roff Manpage"]),
         (".jsp", &["Java Server Pages"]),
-        (".orc", &["Csound"]),
-        (".ktm", &["Kotlin"]),
-        (".mlir", &["MLIR"]),
-        (".xliff", &["XML"]),
-        (".model.lkml", &["LookML"]),
-        (".feature", &["Gherkin"]),
-        (".vbhtml", &["Visual Basic .NET"]),
-        (".ccxml", &["XML"]),
-        (".plsql", &["PLSQL"]),
-        (".geo", &["GLSL"]),
-        (".h", &["Objective-C", "C", "C++"]),
-        (".gvy", &["Groovy"]),
-        (".proj", &["XML"]),
-        (".ceylon", &["Ceylon"]),
-        (".ant", &["XML"]),
-        (".cscfg", &["XML"]),
-        (".dhall", &["Dhall"]),
+        (".rnh", &["RUNOFF"]),
+        (".csl", &["XML"]),
+        (".html", &["HTML"]),
+        (".jsfl", &["JavaScript"]),
+        (".ly", &["LilyPond"]),
+        (".tac", &["Python"]),
+        (".dof", &["INI"]),
+        (".textile", &["Textile"]),
+        (".mediawiki", &["MediaWiki"]),
+        (".nsh", &["NSIS"]),
+        (".f", &["Fortran", "Forth", "Filebench WML"]),
+        (".cats", &["C"]),
+        (".spec", &["Python", "Ruby", "RPM Spec"]),
+        (".jsb", &["JavaScript"]),
+        (".wlt", &["Mathematica"]),
+        (".ck", &["ChucK"]),
+        (".frm", &["VBA"]),
+        (".launch", &["XML"]),
+        (".aug", &["Augeas"]),
+        (".asc", &["Public Key", "AGS Script", "AsciiDoc"]),
+        (".wixproj", &["XML"]),
+        (".fancypack", &["Fancy"]),
+        (".conll", &["CoNLL-U"]),
+        (".ec", &["eC"]),
+        (".lasso", &["Lasso"]),
+        (".f90", &["Fortran"]),
+        (".gf", &["Grammatical Framework"]),
+        (".plot", &["Gnuplot"]),
+        (".zep", &["Zephir"]),
+        (".cls", &["Apex", "OpenEdge ABL", "VBA", "ObjectScript", "TeX"]),
+        (".geom", &["GLSL"]),
+        (".hcl", &["HCL"]),
+        (".anim", &["Unity3D Asset"]),
+        (".hy", &["Hy"]),
+        (".ooc", &["ooc"]),
+        (".tab", &["SQL"]),
+        (".matlab", &["MATLAB"]),
+        (".xquery", &["XQuery"]),
+        (".xtend", &["Xtend"]),
+        (".agc", &["Apollo Guidance Computer"]),
+        (".moon", &["MoonScript"]),
+        (".props", &["XML"]),
+        (".pm6", &["Raku"]),
+        (".fxh", &["HLSL"]),
+        (".apacheconf", &["ApacheConf"]),
+        (".xm", &["Logos"]),
+        (".plantuml", &["PlantUML"]),
+        (".rbxs", &["Lua"]),
+        (".sublime-snippet", &["XML"]),
         (".cw", &["Redcode"]),
-        (".nqp", &["Raku"]),
-        (".elm", &["Elm"]),
-        (".c++objdump", &["Cpp-ObjDump"]),
-        (".bat", &["Batchfile"]),
-        (".grace", &["Grace"]),
-        (".vcxproj", &["XML"]),
-        (".eml", &["EML"]),
-        (".mawk", &["Awk"]),
-        (".djs", &["Dogescript"]),
-        (".ui", &["XML"]),
-        (".toml", &["TOML"]),
-        (".agda", &["Agda"]),
-        (".f08", &["Fortran"]),
-        (".rktl", &["Racket"]),
-        (".3pm", &["Roff", "Roff Manpage"]),
-        (".cl2", &["Clojure"]),
-        (".shen", &["Shen"]),
-        (".har", &["JSON"]),
-        (".tps", &["PLSQL"]),
-        (".emacs.desktop", &["Emacs Lisp"]),
-        (".xq", &["XQuery"]),
-        (".vxml", &["XML"]),
-        (".ksh", &["Shell"]),
-        (".sfproj", &["XML"]),
-        (".iced", &["CoffeeScript"]),
-        (".ps1", &["PowerShell"]),
-        (".ux", &["XML"]),
-        (".nginxconf", &["Nginx"]),
-        (".qbs", &["QML"]),
-        (".lvproj", &["LabVIEW"]),
-        (".snip", &["Vim Snippet"]),
         (".f77", &["Fortran"]),
-        (".sthlp", &["Stata"]),
-        (".fs", &["Filterscript", "Forth", "F#", "GLSL"]),
-        (".dm", &["DM"]),
-        (".rb", &["Ruby"]),
-        (".duby", &["Mirah"]),
-        (".rockspec", &["Lua"]),
-        (".xqy", &["XQuery"]),
-        (".ni", &["Inform 7"]),
-        (".clw", &["Clarion"]),
-        (".sublime-build", &["JSON with Comments"]),
-        (".lex", &["Lex"]),
-        (".ruby", &["Ruby"]),
-        (".intr", &["Dylan"]),
-        (".svelte", &["Svelte"]),
-        (".awk", &["Awk"]),
-        (".vmb", &["Vim script"]),
-        (".xpm", &["X PixMap"]),
-        (".fnc", &["PLSQL"]),
-        (".svg", &["SVG"]),
-        (".god", &["Ruby"]),
-        (".lmi", &["Python"]),
-        (".sublime-theme", &["JSON with Comments"]),
-        (".aspx", &["ASP"]),
-        (".idr", &["Idris"]),
-        (".pir", &["Parrot Internal Representation"]),
-        (".axd", &["ASP"]),
-        (".cob", &["COBOL"]),
-        (".p4", &["P4"]),
-        (".mtl", &["Wavefront Material"]),
-        (".podspec", &["Ruby"]),
-        (".4th", &["Forth"]),
+        (".veo", &["Verilog"]),
+        (".ecr", &["HTML+ECR"]),
+        (".arpa", &["DNS Zone"]),
+        (".cr", &["Crystal"]),
+        (".fp", &["GLSL"]),
+        (".natvis", &["XML"]),
+        (".yaml", &["YAML"]),
+        (".pascal", &["Pascal"]),
+        (".jake", &["JavaScript"]),
+        (".ini", &["INI"]),
+        (".sps", &["Scheme"]),
+        (".dats", &["ATS"]),
+        (".filters", &["XML"]),
+        (".mdpolicy", &["XML"]),
+        (".mcr", &["MAXScript"]),
+        (".glslf", &["GLSL"]),
+        (".jsonld", &["JSONLD"]),
+        (".dita", &["XML"]),
+        (".asd", &["Common Lisp"]),
+        (".cljscm", &["Clojure"]),
+        (".purs", &["PureScript"]),
+        (".tfstate.backup", &["JSON"]),
         (".hx", &["Haxe"]),
-        (".8", &["Roff", "Roff Manpage"]),
-        (".sls", &["SaltStack", "Scheme"]),
-        (".sh.in", &["Shell"]),
-        (".watchr", &["Ruby"]),
-        (".edn", &["edn"]),
-        (".8xk.txt", &["TI Program"]),
-        (".mspec", &["Ruby"]),
-        (".pbi", &["PureBasic"]),
-        (".fsproj", &["XML"]),
-        (".xsl", &["XSLT"]),
-        (".vshader", &["GLSL"]),
-        (".dylan", &["Dylan"]),
-        (".lasso", &["Lasso"]),
-        (".3m", &["Roff", "Roff Manpage"]),
-        (".nse", &["Lua"]),
-        (".fancypack", &["Fancy"]),
-        (".kml", &["XML"]),
-        (".pytb", &["Python traceback"]),
-        (".ihlp", &["Stata"]),
-        (".3x", &["Roff", "Roff Manpage"]),
-        (".dotsettings", &["XML"]),
-        (".xslt", &["XSLT"]),
-        (".cuh", &["Cuda"]),
-        (".markdown", &["Markdown"]),
-        (".prolog", &["Prolog"]),
+        (".mms", &["Module Management System"]),
+        (".dfm", &["Pascal"]),
+        (".fsx", &["F#"]),
+        (".8xp", &["TI Program"]),
+        (".r", &["Rebol", "R"]),
+        (".l", &["Roff", "Lex", "Common Lisp", "PicoLisp"]),
+        (".tool", &["Shell"]),
+        (".zap", &["ZAP"]),
+        (".eclxml", &["ECL"]),
+        (".xi", &["Logos"]),
+        (".las", &["Lasso"]),
+        (".ex", &["Elixir"]),
+        (".mkiv", &["TeX"]),
+        (".dhall", &["Dhall"]),
+        (".jsonc", &["JSON with Comments"]),
+        (".less", &["Less"]),
+        (".kid", &["Genshi"]),
+        (".sublime-mousemap", &["JSON with Comments"]),
+        (".toml", &["TOML"]),
+        (".vhdl", &["VHDL"]),
+        (".json5", &["JSON5"]),
         (".g", &["GAP", "G-code"]),
-        (".ring", &["Ring"]),
-        (".r", &["Rebol", "R"]),
-        (".rnh", &["RUNOFF"]),
-        ("._coffee", &["CoffeeScript"]),
-        (".vhs", &["VHDL"]),
-        (".fxml", &["XML"]),
-        (".wixproj", &["XML"]),
-        (".dyalog", &["APL"]),
-        (".uc", &["UnrealScript"]),
-        (".hrl", &["Erlang"]),
-        (".gap", &["GAP"]),
-        (".dof", &["INI"]),
-        (".postcss", &["PostCSS"]),
+        (".krl", &["KRL"]),
+        (".scss", &["SCSS"]),
+        (".auk", &["Awk"]),
+        (".gltf", &["JSON"]),
+        (".wlk", &["Wollok"]),
+        (".pcss", &["PostCSS"]),
+        (".mm", &["Objective-C++", "XML"]),
+        (".mat", &["Unity3D Asset"]),
+        (".smk", &["Python"]),
+        (".kml", &["XML"]),
+        (".rest.txt", &["reStructuredText"]),
+        (".gtl", &["Gerber Image"]),
+        (".zmpl", &["Zimpl"]),
+        (".dm", &["DM"]),
+        (".tsx", &["TSX", "XML"]),
+        (".sublime_session", &["JSON with Comments"]),
+        (".zil", &["ZIL"]),
+        (".am", &["Makefile"]),
+        (".glf", &["Glyph"]),
+        (".gko", &["Gerber Image"]),
         (".als", &["Alloy"]),
-        (".hlean", &["Lean"]),
-        (".cljs", &["Clojure"]),
-        (".mbox", &["EML"]),
-        (".purs", &["PureScript"]),
-        (".ddl", &["PLSQL", "SQL"]),
-        (".nut", &["Squirrel"]),
-        (".csl", &["XML"]),
-        (".less", &["Less"]),
-        (".graphqls", &["GraphQL"]),
-        (".sqf", &["SQF"]),
-        (".v", &["Verilog", "Coq", "V"]),
-        (".mask", &["Unity3D Asset", "Mask"]),
+        (".udf", &["SQL"]),
+        (".djs", &["Dogescript"]),
+        (".mdwn", &["Markdown"]),
+        (".thrift", &["Thrift"]),
+        (".pfa", &["PostScript"]),
         (".rexx", &["REXX"]),
-        (".apacheconf", &["ApacheConf"]),
-        (".kit", &["Kit"]),
-        (".blade.php", &["Blade"]),
-        (".doh", &["Stata"]),
-        (".ps", &["PostScript"]),
-        (".sql", &["SQLPL", "PLSQL", "PLpgSQL", "SQL", "TSQL"]),
-        (".txi", &["Texinfo"]),
+        (".frx", &["VBA"]),
+        (".tcc", &["C++"]),
+        (".sj", &["Objective-J"]),
+        (".lds", &["Linker Script"]),
+        (".minid", &["MiniD"]),
+        (".ninja", &["Ninja"]),
+        (".gni", &["GN"]),
+        (".rsx", &["R"]),
+        (".fxml", &["XML"]),
+        (".pgsql", &["PLpgSQL"]),
+        (".cbx", &["TeX"]),
+        (".smt", &["SMT"]),
+        (".chpl", &["Chapel"]),
+        (".xht", &["HTML"]),
+        (".rsc", &["Rascal"]),
+        (".f95", &["Fortran"]),
+        (".jison", &["Jison"]),
+        (".ivy", &["XML"]),
+        (".asm", &["Assembly", "Motorola 68K Assembly"]),
+        (".asn1", &["ASN.1"]),
+        (".tfvars", &["HCL"]),
+        (".nut", &["Squirrel"]),
+        (".sublime-build", &["JSON with Comments"]),
+        (".csx", &["C#"]),
+        (".flux", &["FLUX"]),
+        (".hc", &["HolyC"]),
         (".sma", &["Pawn"]),
-        (".wisp", &["wisp"]),
-        (".pkb", &["PLSQL"]),
-        (".mmk", &["Module Management System"]),
-        (".stan", &["Stan"]),
-        (".psc", &["Papyrus"]),
-        (".ada", &["Ada"]),
+        (".syntax", &["YAML"]),
+        (".oxo", &["Ox"]),
+        (".conllu", &["CoNLL-U"]),
+        (".hs", &["Haskell"]),
+        (".vhs", &["VHDL"]),
         (".viw", &["SQL"]),
-        (".ts", &["XML", "TypeScript"]),
-        (".objdump", &["ObjDump"]),
-        (".mjs", &["JavaScript"]),
-        (".vhw", &["VHDL"]),
-        (".flex", &["JFlex"]),
-        (".jsonld", &["JSONLD"]),
-        (".capnp", &["Cap\'n Proto"]),
+        (".diff", &["Diff"]),
+        (".me", &["Roff"]),
+        (".csh", &["Tcsh"]),
+        (".stan", &["Stan"]),
+        (".xojo_window", &["Xojo"]),
+        (".pyp", &["Python"]),
+        (".php3", &["PHP"]),
+        (".cmake", &["CMake"]),
+        (".prolog", &["Prolog"]),
+        (".jflex", &["JFlex"]),
+        (".click", &["Click"]),
+        (".t", &["Terra", "Perl", "Turing", "Raku"]),
+        (".mss", &["CartoCSS"]),
+        (".sublime_metrics", &["JSON with Comments"]),
+        (".txl", &["TXL"]),
+        (".zs", &["ZenScript"]),
+        (".nix", &["Nix"]),
+        (".rktl", &["Racket"]),
+        (".vshader", &["GLSL"]),
+        (".dcl", &["Clean"]),
+        (".sublime-menu", &["JSON with Comments"]),
+        (".qasm", &["OpenQASM"]),
+        (".lidr", &["Idris"]),
+        (".6", &["Roff", "Roff Manpage"]),
+        (".vho", &["VHDL"]),
+        (".cbl", &["COBOL"]),
+        (".plt", &["Gnuplot"]),
+        (".mmk", &["Module Management System"]),
+        (".xc", &["XC"]),
+        (".upc", &["Unified Parallel C"]),
+        (".xliff", &["XML"]),
+        (".mly", &["OCaml"]),
+        (".6pm", &["Raku"]),
+        (".ig", &["Modula-3"]),
+        (".hlsl", &["HLSL"]),
+        (".psd1", &["PowerShell"]),
+        (".iml", &["XML"]),
+        (".mq5", &["MQL5"]),
+        (".3in", &["Roff", "Roff Manpage"]),
+        (".plsql", &["PLSQL"]),
+        (".mt", &["Mathematica"]),
         (".tml", &["XML"]),
-        (".forth", &["Forth"]),
-        (".lsp", &["Common Lisp", "NewLisp"]),
-        (".boo", &["Boo"]),
-        (".sco", &["Csound Score"]),
-        (".inc", &["Assembly", "NASL", "POV-Ray SDL", "Motorola 68K Assembly", "HTML", "Pascal", "SourcePawn", "C++", "Pawn", "SQL", "PHP"]),
-        (".volt", &["Volt"]),
-        (".yrl", &["Erlang"]),
-        (".asc", &["Public Key", "AGS Script", "AsciiDoc"]),
-        (".targets", &["XML"]),
+        (".gto", &["Gerber Image"]),
         (".vw", &["PLSQL"]),
-        (".geom", &["GLSL"]),
-        (".m", &["M", "Mathematica", "Objective-C", "Limbo", "MATLAB", "MUF", "Mercury"]),
-        (".yaml-tmlanguage", &["YAML"]),
-        (".ahk", &["AutoHotkey"]),
-        (".lslp", &["LSL"]),
-        (".make", &["Makefile"]),
-        (".x68", &["Motorola 68K Assembly"]),
-        (".weechatlog", &["IRC log"]),
-        (".sublime-project", &["JSON with Comments"]),
-        (".gnu", &["Gnuplot"]),
-        (".tab", &["SQL"]),
-        (".y", &["Yacc"]),
-        (".opal", &["Opal"]),
-        (".gml", &["Graph Modeling Language", "Game Maker Language", "XML", "Gerber Image"]),
-        (".njk", &["HTML+Django"]),
-        (".yar", &["YARA"]),
-        (".6pl", &["Raku"]),
-        (".dwl", &["DataWeave"]),
-        (".idc", &["C"]),
-        (".gdb", &["GDB"]),
-        (".vrx", &["GLSL"]),
-        (".coffee", &["CoffeeScript"]),
-        (".ditamap", &["XML"]),
-        (".bro", &["Zeek"]),
-        (".edc", &["Edje Data Collection"]),
-        (".ston", &["STON"]),
-        (".adp", &["Tcl"]),
-        (".gp", &["Gnuplot"]),
-        (".c", &["C"]),
-        (".hlsl", &["HLSL"]),
+        (".blade.php", &["Blade"]),
+        (".hpp", &["C++"]),
+        (".texi", &["Texinfo"]),
+        (".pp", &["Puppet", "Pascal"]),
+        (".jinja2", &["HTML+Django"]),
+        (".latte", &["Latte"]),
+        (".marko", &["Marko"]),
+        (".ihlp", &["Stata"]),
+        (".shproj", &["XML"]),
+        (".psm1", &["PowerShell"]),
+        (".srdf", &["XML"]),
+        (".numpyw", &["NumPy"]),
+        (".pasm", &["Parrot Assembly"]),
+        (".rl", &["Ragel"]),
+        (".sublime-workspace", &["JSON with Comments"]),
+        (".au3", &["AutoIt"]),
+        (".cljx", &["Clojure"]),
+        (".clw", &["Clarion"]),
+        (".mxml", &["XML"]),
+        (".nse", &["Lua"]),
+        (".abnf", &["ABNF"]),
+        (".gyp", &["Python"]),
+        (".sfproj", &["XML"]),
+        (".lasso8", &["Lasso"]),
+        (".cuh", &["Cuda"]),
+        (".svg", &["SVG"]),
+        (".vhd", &["VHDL"]),
+        (".ecl", &["ECL", "ECLiPSe"]),
+        (".ahkl", &["AutoHotkey"]),
+        (".eclass", &["Gentoo Eclass"]),
+        (".tmux", &["Shell"]),
+        (".opencl", &["OpenCL"]),
+        (".4th", &["Forth"]),
+        (".psc1", &["XML"]),
+        (".e", &["Eiffel", "E"]),
+        (".geo", &["GLSL"]),
+        (".xpl", &["XProc"]),
+        (".gtpl", &["Groovy"]),
+        (".org", &["Org"]),
+        (".har", &["JSON"]),
+        (".pic", &["Pic"]),
+        (".ksh", &["Shell"]),
+        (".mustache", &["HTML+Django"]),
+        (".yyp", &["JSON"]),
+        (".fsi", &["F#"]),
+        (".gbs", &["Gerber Image"]),
+        (".pat", &["Max"]),
+        (".vala", &["Vala"]),
+        (".bbx", &["TeX"]),
+        (".abap", &["ABAP"]),
+        (".xmi", &["XML"]),
+        (".sfd", &["Spline Font Database"]),
+        (".pyt", &["Python"]),
+        (".sublime-completions", &["JSON with Comments"]),
+        (".patch", &["Diff"]),
+        (".bat", &["Batchfile"]),
+        (".mata", &["Stata"]),
+        (".sml", &["Standard ML"]),
+        (".ui", &["XML"]),
+        (".nproj", &["XML"]),
+        (".tmac", &["Roff"]),
+        (".lean", &["Lean"]),
+        (".capnp", &["Cap\'n Proto"]),
+        (".hhi", &["Hack"]),
+        (".1x", &["Roff", "Roff Manpage"]),
+        (".boo", &["Boo"]),
+        (".re", &["Reason", "C++"]),
+        (".coq", &["Coq"]),
+        (".bison", &["Bison"]),
+        (".rhtml", &["RHTML"]),
+        (".dpr", &["Pascal"]),
+        (".mlir", &["MLIR"]),
+        (".ma", &["Mathematica"]),
+        (".pkl", &["Pickle"]),
+        (".numsc", &["NumPy"]),
+        (".3qt", &["Roff", "Roff Manpage"]),
         (".sc", &["SuperCollider", "Scala"]),
-        (".wxi", &["XML"]),
-        (".lookml", &["LookML"]),
+        (".ditaval", &["XML"]),
+        (".rs.in", &["Rust"]),
+        (".kts", &["Kotlin"]),
+        (".rbx", &["Ruby"]),
+        (".ipp", &["C++"]),
+        (".jsm", &["JavaScript"]),
+        (".as", &["AngelScript", "ActionScript"]),
+        (".r3", &["Rebol"]),
+        (".gd", &["GAP", "GDScript"]),
+        (".plist", &["OpenStep Property List", "XML Property List"]),
         (".al", &["Perl"]),
-        (".wsf", &["XML"]),
-        (".rbi", &["Ruby"]),
-        (".coq", &["Coq"]),
-        (".sce", &["Scilab"]),
-        (".nc", &["nesC"]),
-        (".pac", &["JavaScript"]),
-        (".desktop.in", &["desktop"]),
-        (".pluginspec", &["Ruby", "XML"]),
-        (".dae", &["COLLADA"]),
+        (".vrx", &["GLSL"]),
+        (".d-objdump", &["D-ObjDump"]),
+        (".reb", &["Rebol"]),
+        (".1m", &["Roff", "Roff Manpage"]),
+        (".opal", &["Opal"]),
+        (".ps1", &["PowerShell"]),
+        (".brd", &["KiCad Legacy Layout", "Eagle"]),
+        (".mbox", &["EML"]),
+        (".lfe", &["LFE"]),
+        (".reg", &["Windows Registry Entries"]),
+        (".gradle", &["Gradle"]),
+        (".di", &["D"]),
+        (".ll", &["LLVM"]),
+        (".gql", &["GraphQL"]),
+        (".http", &["HTTP"]),
+        (".factor", &["Factor"]),
+        (".depproj", &["XML"]),
+        (".zeek", &["Zeek"]),
+        (".emacs", &["Emacs Lisp"]),
         (".vsh", &["GLSL"]),
-        (".phps", &["PHP"]),
-        (".vbs", &["VBScript"]),
-        (".lidr", &["Idris"]),
-        (".thor", &["Ruby"]),
-        (".asmx", &["ASP"]),
-        (".cljscm", &["Clojure"]),
+        (".mxt", &["Max"]),
+        (".h", &["Objective-C", "C", "C++"]),
+        (".rss", &["XML"]),
+        (".red", &["Red"]),
+        (".topojson", &["JSON"]),
+        (".rs", &["Rust", "RenderScript"]),
+        (".erl", &["Erlang"]),
+        (".robot", &["RobotFramework"]),
+        (".grace", &["Grace"]),
+        (".doh", &["Stata"]),
+        (".golo", &["Golo"]),
+        (".afm", &["Adobe Font Metrics"]),
+        (".tps", &["PLSQL"]),
+        (".csd", &["Csound Document"]),
+        (".xqm", &["XQuery"]),
+        (".mtl", &["Wavefront Material"]),
         (".sl", &["Slash"]),
-        (".pyx", &["Cython"]),
-        (".vbproj", &["XML"]),
-        (".muf", &["MUF"]),
-        (".ss", &["Scheme"]),
-        (".sublime-menu", &["JSON with Comments"]),
-        (".cljx", &["Clojure"]),
-        (".zpl", &["Zimpl"]),
-        (".ash", &["AGS Script"]),
-        (".iml", &["XML"]),
-        (".mdoc", &["Roff", "Roff Manpage"]),
-        (".swift", &["Swift"]),
-        (".pat", &["Max"]),
-        (".mg", &["Modula-3"]),
+        (".2", &["Roff", "Roff Manpage"]),
+        (".gst", &["Gosu"]),
+        (".webapp", &["JSON"]),
+        (".py", &["Python"]),
+        (".xpm", &["X PixMap"]),
+        (".qbs", &["QML"]),
+        (".os", &["1C Enterprise"]),
+        (".bmx", &["BlitzMax"]),
+        (".builder", &["Ruby"]),
+        (".lektorproject", &["INI"]),
+        ("._ls", &["LiveScript"]),
+        (".vhf", &["VHDL"]),
+        (".riot", &["Riot"]),
+        (".ni", &["Inform 7"]),
+        (".reds", &["Red"]),
+        (".nimrod", &["Nim"]),
+        (".xojo_menu", &["Xojo"]),
+        (".bsv", &["Bluespec"]),
+        (".no", &["Text"]),
+        (".mathematica", &["Mathematica"]),
+        (".wxl", &["XML"]),
+        (".pt", &["XML"]),
+        (".cps", &["Component Pascal"]),
+        (".kit", &["Kit"]),
+        (".prisma", &["Prisma"]),
+        (".dylan", &["Dylan"]),
+        (".gp", &["Gnuplot"]),
+        (".vb", &["Visual Basic .NET"]),
+        (".gaml", &["GAML"]),
+        (".xproj", &["XML"]),
+        (".spc", &["PLSQL"]),
+        (".ph", &["Perl"]),
+        (".texinfo", &["Texinfo"]),
+        (".ccproj", &["XML"]),
+        (".xbm", &["X BitMap"]),
+        (".creole", &["Creole"]),
+        (".ado", &["Stata"]),
+        (".frt", &["Forth"]),
+        (".6pl", &["Raku"]),
+        (".bib", &["BibTeX"]),
+        (".spin", &["Propeller Spin"]),
+        (".ebnf", &["EBNF"]),
+        (".pas", &["Pascal"]),
+        (".rd", &["R"]),
+        (".proto", &["Protocol Buffer"]),
+        (".pir", &["Parrot Internal Representation"]),
+        (".webmanifest", &["JSON"]),
+        (".lslp", &["LSL"]),
+        (".wiki", &["MediaWiki"]),
+        (".litcoffee", &["Literate CoffeeScript"]),
+        (".shader", &["ShaderLab", "GLSL"]),
+        (".nginxconf", &["Nginx"]),
+        (".hh", &["Hack", "C++"]),
+        (".blade", &["Blade"]),
+        (".adml", &["XML"]),
+        (".rockspec", &["Lua"]),
+        (".yara", &["YARA"]),
+        (".i7x", &["Inform 7"]),
+        (".hqf", &["SQF"]),
+        (".rbmnu", &["REALbasic"]),
+        (".mll", &["OCaml"]),
+        (".4dm", &["4D"]),
+        (".ccxml", &["XML"]),
+        (".xml", &["XML"]),
+        (".regex", &["Regular Expression"]),
+        (".ipf", &["IGOR Pro"]),
+        (".qml", &["QML"]),
+        (".xsd", &["XML"]),
+        (".vsixmanifest", &["XML"]),
+        (".sv", &["SystemVerilog"]),
+        (".dsp", &["Faust", "Microsoft Developer Studio Project"]),
+        (".tesc", &["GLSL"]),
+        (".fan", &["Fantom"]),
         (".jscad", &["JavaScript"]),
-        (".gshader", &["GLSL"]),
-        (".phpt", &["PHP"]),
-        (".brs", &["Brightscript"]),
-        (".bb", &["BlitzBasic", "BitBake"]),
-        (".nix", &["Nix"]),
-        (".sublime-workspace", &["JSON with Comments"]),
-        (".3p", &["Roff", "Roff Manpage"]),
-        (".ads", &["Ada"]),
-        (".golo", &["Golo"]),
-        (".cql", &["SQL"]),
-        (".cu", &["Cuda"]),
-        (".java", &["Java"]),
+        (".cobol", &["COBOL"]),
+        (".scd", &["SuperCollider"]),
+        (".mkdown", &["Markdown"]),
+        (".lhs", &["Literate Haskell"]),
+        (".forth", &["Forth"]),
+        (".tm", &["Tcl"]),
+        (".gmx", &["XML"]),
+        ("._coffee", &["CoffeeScript"]),
+        (".dll.config", &["XML"]),
+        (".decls", &["BlitzBasic"]),
+        (".nr", &["Roff"]),
+        (".sass", &["Sass"]),
+        (".gbr", &["Gerber Image"]),
+        (".sjs", &["JavaScript"]),
+        (".jss", &["JavaScript"]),
+        (".xul", &["XML"]),
+        (".logtalk", &["Logtalk"]),
+        (".hrl", &["Erlang"]),
+        (".weechatlog", &["IRC log"]),
+        (".xsjs", &["JavaScript"]),
+        (".rbuistate", &["REALbasic"]),
+        (".lua", &["Lua"]),
+        (".kicad_pcb", &["KiCad Layout"]),
+        (".iol", &["Jolie"]),
+        (".tfstate", &["JSON"]),
+        (".pac", &["JavaScript"]),
+        (".nim", &["Nim"]),
+        (".mdoc", &["Roff", "Roff Manpage"]),
+        (".self", &["Self"]),
+        (".pwn", &["Pawn"]),
+        (".ily", &["LilyPond"]),
+        (".st", &["HTML", "Smalltalk"]),
+        (".desktop.in", &["desktop"]),
+        (".dwl", &["DataWeave"]),
+        (".el", &["Emacs Lisp"]),
+        (".bdy", &["PLSQL"]),
         (".pck", &["PLSQL"]),
-        (".fshader", &["GLSL"]),
-        (".css", &["CSS"]),
-        (".gcode", &["G-code"]),
-        (".rbuild", &["Ruby"]),
-        (".scala", &["Scala"]),
-        (".axs.erb", &["NetLinx+ERB"]),
-        (".sublime_session", &["JSON with Comments"]),
-        (".gdbinit", &["GDB"]),
-        (".p", &["Gnuplot", "OpenEdge ABL"]),
-        (".chem", &["Pic"]),
-        (".mll", &["OCaml"]),
-        (".q", &["q", "HiveQL"]),
-        (".x", &["DirectX 3D File", "RPC", "Linker Script", "Logos"]),
-        (".cshtml", &["HTML+Razor"]),
-        (".pt", &["XML"]),
-        (".dats", &["ATS"]),
-        (".numsc", &["NumPy"]),
-        (".ny", &["Common Lisp"]),
-        (".asd", &["Common Lisp"]),
-        (".nearley", &["Nearley"]),
-        (".sublime-commands", &["JSON with Comments"]),
-        (".haml.deface", &["Haml"]),
-        (".f90", &["Fortran"]),
+        (".odin", &["Object Data Instance Notation", "Odin"]),
+        (".wdl", &["wdl"]),
+        (".sce", &["Scilab"]),
+        (".fnc", &["PLSQL"]),
+        (".sss", &["SugarSS"]),
+        (".mumps", &["M"]),
+        (".nuspec", &["XML"]),
+        (".prjpcb", &["Altium Designer"]),
+        (".smali", &["Smali"]),
+        (".owl", &["Web Ontology Language"]),
+        (".sru", &["PowerBuilder"]),
+        (".dyl", &["Dylan"]),
+        (".htm", &["HTML"]),
+        (".fsh", &["GLSL"]),
+        (".axi.erb", &["NetLinx+ERB"]),
+        (".dpatch", &["Darcs Patch"]),
+        (".psc", &["Papyrus"]),
+        (".hlean", &["Lean"]),
+        (".ox", &["Ox"]),
+        (".fst", &["F*"]),
+        (".xojo_report", &["Xojo"]),
+        (".duby", &["Mirah"]),
+        (".idr", &["Idris"]),
+        (".dart", &["Dart"]),
+        (".storyboard", &["XML"]),
+        (".xrl", &["Erlang"]),
+        (".snippets", &["Vim Snippet"]),
+        (".sh.in", &["Shell"]),
+        (".p4", &["P4"]),
+        (".nf", &["Nextflow"]),
+        (".prg", &["xBase"]),
+        (".zpl", &["Zimpl"]),
+        (".yacc", &["Yacc"]),
+        (".rst.txt", &["reStructuredText"]),
+        (".jade", &["Pug"]),
+        (".bb", &["BlitzBasic", "BitBake"]),
+        (".mqh", &["MQL4", "MQL5"]),
+        (".vtt", &["WebVTT"]),
+        (".ml4", &["OCaml"]),
+        (".hlsli", &["HLSL"]),
+        (".fcgi", &["Python", "Perl", "Lua", "Ruby", "Shell", "PHP"]),
+        (".njs", &["JavaScript"]),
+        (".mkfile", &["Makefile"]),
+        (".soy", &["Closure Templates"]),
+        (".com", &["DIGITAL Command Language"]),
         (".scad", &["OpenSCAD"]),
-        (".xojo_window", &["Xojo"]),
-        (".graphql", &["GraphQL"]),
-        (".x3d", &["XML"]),
-        (".rpy", &["Python", "Ren\'Py"]),
-        (".r3", &["Rebol"]),
-        (".jq", &["JSONiq"]),
-        (".tsx", &["TSX", "XML"]),
-        (".vstemplate", &["XML"]),
-        (".plx", &["Perl"]),
-        (".zcml", &["XML"]),
-        (".i", &["Assembly", "SWIG", "Motorola 68K Assembly"]),
-        (".gtl", &["Gerber Image"]),
-        (".mly", &["OCaml"]),
-        (".ronn", &["Markdown"]),
-        (".jss", &["JavaScript"]),
-        (".veo", &["Verilog"]),
-        (".cljs.hl", &["Clojure"]),
-        (".agc", &["Apollo Guidance Computer"]),
-        (".obj", &["Wavefront Object"]),
-        (".ig", &["Modula-3"]),
-        (".xbm", &["X BitMap"]),
-        (".smt2", &["SMT"]),
+        (".proj", &["XML"]),
+        (".ql", &["CodeQL"]),
+        (".ditamap", &["XML"]),
+        (".sttheme", &["XML Property List"]),
+        (".pbt", &["PowerBuilder"]),
+        (".cql", &["SQL"]),
+        (".mjs", &["JavaScript"]),
+        (".json-tmlanguage", &["JSON"]),
+        (".w", &["OpenEdge ABL", "CWeb"]),
+        (".3pm", &["Roff", "Roff Manpage"]),
+        (".hs-boot", &["Haskell"]),
+        (".ijs", &["J"]),
+        (".oz", &["Oz"]),
+        (".gtp", &["Gerber Image"]),
+        (".m3", &["Modula-3"]),
+        (".qll", &["CodeQL"]),
+        (".s", &["Unix Assembly", "Motorola 68K Assembly"]),
+        (".rex", &["REXX"]),
+        (".hats", &["ATS"]),
         (".haml", &["Haml"]),
-        (".ly", &["LilyPond"]),
-        (".abnf", &["ABNF"]),
-        (".ls", &["LiveScript", "LoomScript"]),
-        (".snippets", &["Vim Snippet"]),
-        (".sbt", &["Scala"]),
-        (".h++", &["C++"]),
-        (".krl", &["KRL"]),
-        (".litcoffee", &["Literate CoffeeScript"]),
-        (".exs", &["Elixir"]),
-        (".vhost", &["Nginx", "ApacheConf"]),
-        (".adoc", &["AsciiDoc"]),
-        (".tmlanguage", &["XML Property List"]),
+        (".ascx", &["ASP"]),
+        (".lgt", &["Logtalk"]),
+        (".vue", &["Vue"]),
         (".xsp.metadata", &["XPages"]),
-        (".asax", &["ASP"]),
-        (".mtml", &["MTML"]),
-        (".pug", &["Pug"]),
-        (".pfa", &["PostScript"]),
-        (".scaml", &["Scaml"]),
-        (".ftl", &["FreeMarker"]),
-        (".pxd", &["Cython"]),
-        (".rbuistate", &["REALbasic"]),
-        (".wsdl", &["XML"]),
-        (".psm1", &["PowerShell"]),
-        (".sig", &["Standard ML"]),
-        (".sci", &["Scilab"]),
-        (".asciidoc", &["AsciiDoc"]),
-        (".wl", &["Mathematica"]),
-        (".iol", &["Jolie"]),
-        (".xzap", &["ZAP"]),
-        (".cson", &["CSON"]),
-        (".twig", &["Twig"]),
-        (".csd", &["Csound Document"]),
-        (".reek", &["YAML"]),
-        (".dpatch", &["Darcs Patch"]),
-        (".nb", &["Text", "Mathematica"]),
-        (".pxi", &["Cython"]),
-        (".patch", &["Diff"]),
-        (".urs", &["UrWeb"]),
-        (".mathematica", &["Mathematica"]),
-        (".ttl", &["Turtle"]),
-        (".pkl", &["Pickle"]),
-        (".rtf", &["Rich Text Format"]),
-        (".pig", &["PigLatin"]),
-        (".gbp", &["Gerber Image"]),
-        (".epj", &["Ecere Projects"]),
-        (".d-objdump", &["D-ObjDump"]),
-        (".uno", &["Uno"]),
-        (".oxygene", &["Oxygene"]),
-        (".ru", &["Ruby"]),
-        (".mxml", &["XML"]),
-        (".tmcommand", &["XML Property List"]),
-        (".rbw", &["Ruby"]),
-        (".e", &["Eiffel", "E"]),
-        (".glsl", &["GLSL"]),
-        (".ebuild", &["Gentoo Ebuild"]),
-        (".abap", &["ABAP"]),
-        (".pl", &["Perl", "Raku", "Prolog"]),
-        (".arpa", &["DNS Zone"]),
-        (".pmod", &["Pike"]),
-        (".prw", &["xBase"]),
-        (".ld", &["Linker Script"]),
-        ("._js", &["JavaScript"]),
-        (".cjsx", &["CoffeeScript"]),
-        (".gto", &["Gerber Image"]),
-        (".gpt", &["Gerber Image"]),
-        (".srdf", &["XML"]),
-        (".yml.mysql", &["YAML"]),
+        (".vim", &["Vim script"]),
+        (".lasso9", &["Lasso"]),
         (".nanorc", &["nanorc"]),
-        (".wlk", &["Wollok"]),
-        (".mak", &["Makefile"]),
-        (".ql", &["CodeQL"]),
-        (".thrift", &["Thrift"]),
-        (".vapi", &["Vala"]),
-        (".sublime-syntax", &["YAML"]),
-        (".eliomi", &["OCaml"]),
-        (".jison", &["Jison"]),
-        (".geojson", &["JSON"]),
+        (".apib", &["API Blueprint"]),
+        (".pm", &["X PixMap", "Perl", "Raku"]),
+        (".ls", &["LiveScript", "LoomScript"]),
+        (".podsl", &["Common Lisp"]),
         (".mdx", &["Markdown"]),
-        (".creole", &["Creole"]),
-        (".bibtex", &["BibTeX"]),
-        (".rabl", &["Ruby"]),
-        (".tmux", &["Shell"]),
-        (".tese", &["GLSL"]),
-        (".mkii", &["TeX"]),
-        (".lds", &["Linker Script"]),
+        (".lol", &["LOLCODE"]),
+        (".jsproj", &["XML"]),
+        (".intr", &["Dylan"]),
+        (".iced", &["CoffeeScript"]),
         (".vert", &["GLSL"]),
-        (".sttheme", &["XML Property List"]),
-        (".eq", &["EQ"]),
-        (".smali", &["Smali"]),
-        (".vht", &["VHDL"]),
-        (".sch", &["XML", "KiCad Schematic", "Scheme", "Eagle"]),
-        (".sh", &["Shell"]),
-        (".kt", &["Kotlin"]),
-        (".prisma", &["Prisma"]),
-        (".rktd", &["Racket"]),
-        (".mss", &["CartoCSS"]),
+        (".cppobjdump", &["Cpp-ObjDump"]),
+        (".schdoc", &["Altium Designer"]),
+        (".kicad_wks", &["KiCad Layout"]),
+        (".regexp", &["Regular Expression"]),
+        (".orc", &["Csound"]),
+        (".pod", &["Pod 6", "Pod"]),
+        (".q", &["q", "HiveQL"]),
+        (".bsl", &["1C Enterprise"]),
         (".xs", &["XS"]),
-        (".soy", &["Closure Templates"]),
-        (".pcss", &["PostCSS"]),
-        (".zig", &["Zig"]),
-        (".sra", &["PowerBuilder"]),
+        (".mask", &["Unity3D Asset", "Mask"]),
+        (".cson", &["CSON"]),
+        (".cl2", &["Clojure"]),
+        (".podspec", &["Ruby"]),
+        (".tf", &["HCL"]),
+        (".xspec", &["XML"]),
+        (".sbt", &["Scala"]),
+        (".gbo", &["Gerber Image"]),
+        (".metal", &["Metal"]),
+        (".vcxproj", &["XML"]),
+        (".feature", &["Gherkin"]),
+        (".java", &["Java"]),
+        (".p6", &["Raku"]),
+        (".pep", &["Pep8"]),
+        (".ik", &["Ioke"]),
+        (".watchr", &["Ruby"]),
+        (".ux", &["XML"]),
+        (".rei", &["Reason"]),
+        (".n", &["Roff", "Nemerle"]),
+        (".targets", &["XML"]),
+        (".edn", &["edn"]),
+        (".cl", &["Common Lisp", "Cool", "OpenCL"]),
+        (".3x", &["Roff", "Roff Manpage"]),
+        (".yar", &["YARA"]),
+        (".fshader", &["GLSL"]),
+        (".rbfrm", &["REALbasic"]),
+        (".raw", &["Raw token data"]),
+        (".rb", &["Ruby"]),
+        (".adb", &["Ada"]),
+        (".lbx", &["TeX"]),
+        (".nb", &["Text", "Mathematica"]),
+        (".ml", &["Standard ML", "OCaml"]),
+        (".sublime-syntax", &["YAML"]),
+        (".xlf", &["XML"]),
+        (".nims", &["Nim"]),
+        (".vhost", &["Nginx", "ApacheConf"]),
+        (".tmpreferences", &["XML Property List"]),
+        (".xql", &["XQuery"]),
+        (".volt", &["Volt"]),
+        (".cxx-objdump", &["Cpp-ObjDump"]),
+        (".gs", &["JavaScript", "Genie", "GLSL", "Gosu"]),
+        (".sublime-theme", &["JSON with Comments"]),
+        (".rst", &["reStructuredText"]),
+        (".arc", &["Arc"]),
+        (".ejs", &["EJS"]),
+        (".8xk", &["TI Program"]),
+        (".mawk", &["Awk"]),
+        (".rno", &["Roff", "RUNOFF"]),
+        (".rbtbar", &["REALbasic"]),
+        (".xaml", &["XML"]),
+        (".x", &["DirectX 3D File", "RPC", "Linker Script", "Logos"]),
+        (".tla", &["TLA"]),
+        (".brs", &["Brightscript"]),
+        (".css", &["CSS"]),
+        (".cake", &["CoffeeScript", "C#"]),
+        (".php", &["Hack", "PHP"]),
+        (".rtf", &["Rich Text Format"]),
+        (".god", &["Ruby"]),
+        (".pyde", &["Python"]),
+        (".asn", &["ASN.1"]),
+        (".pub", &["Public Key"]),
+        (".frg", &["GLSL"]),
+        (".aux", &["TeX"]),
+        (".for", &["Fortran", "Forth", "Formatted"]),
+        (".rviz", &["YAML"]),
+        (".pxd", &["Cython"]),
+        (".rabl", &["Ruby"]),
+        (".bzl", &["Starlark"]),
+        (".hsc", &["Haskell"]),
+        (".ssjs", &["JavaScript"]),
+        (".p6m", &["Raku"]),
+        (".sas", &["SAS"]),
+        (".php5", &["PHP"]),
+        (".eps", &["PostScript"]),
+        (".liquid", &["Liquid"]),
+        (".pony", &["Pony"]),
+        (".hb", &["Harbour"]),
+        (".md", &["Markdown", "GCC Machine Description"]),
+        (".markdown", &["Markdown"]),
+        (".njk", &["HTML+Django"]),
+        (".j", &["Objective-J", "Jasmin"]),
+        (".vark", &["Gosu"]),
+        (".lisp", &["Common Lisp", "NewLisp"]),
+        (".dotsettings", &["XML"]),
+        (".gemspec", &["Ruby"]),
     ]),
 };
 
@@ -1,318 +1,321 @@
 static FILENAMES: phf::Map<&'static str, &'static str> =
 ::phf::Map {
-    key: 3213172566270843353,
+    key: 732231254413039614,
     disps: ::phf::Slice::Static(&[
-        (0, 119),
-        (7, 60),
-        (0, 3),
-        (0, 94),
-        (6, 89),
-        (1, 1),
-        (0, 4),
-        (1, 225),
-        (5, 238),
-        (0, 198),
-        (0, 28),
-        (0, 73),
-        (4, 103),
-        (6, 247),
-        (0, 7),
-        (45, 104),
-        (0, 101),
-        (2, 48),
-        (8, 57),
-        (0, 143),
-        (0, 245),
-        (0, 3),
-        (0, 14),
-        (10, 27),
-        (1, 243),
-        (7, 112),
-        (2, 195),
-        (0, 4),
-        (3, 21),
+        (13, 215),
+        (0, 12),
+        (0, 1),
+        (0, 1),
+        (0, 57),
+        (0, 1),
+        (0, 5),
         (0, 0),
-        (0, 14),
+        (0, 110),
+        (1, 68),
+        (0, 8),
+        (8, 156),
+        (0, 5),
         (0, 0),
+        (0, 23),
+        (0, 89),
+        (0, 42),
+        (0, 141),
+        (0, 72),
+        (0, 41),
+        (0, 99),
+        (0, 17),
+        (0, 7),
+        (0, 143),
+        (0, 95),
+        (0, 202),
         (0, 0),
+        (4, 80),
+        (0, 49),
         (0, 0),
-        (0, 9),
-        (0, 52),
-        (0, 1),
-        (0, 150),
-        (5, 208),
-        (0, 18),
-        (5, 200),
-        (37, 118),
-        (0, 87),
-        (0, 5),
-        (0, 47),
+        (7, 92),
         (0, 4),
+        (2, 211),
+        (4, 188),
+        (1, 21),
+        (0, 15),
+        (26, 90),
+        (0, 5),
+        (20, 70),
+        (6, 39),
+        (0, 55),
+        (162, 205),
+        (0, 103),
+        (88, 57),
+        (190, 54),
+        (0, 3),
+        (3, 51),
         (0, 28),
-        (22, 90),
-        (0, 42),
-        (34, 212),
-        (4, 209),
-        (7, 143),
+        (17, 47),
+        (0, 81),
+        (0, 52),
+        (1, 84),
     ]),
     entries: ::phf::Slice::Static(&[
-        ("package.use.stable.mask", "Text"),
-        ("Cargo.lock", "TOML"),
-        ("BUCK", "Starlark"),
-        (".php_cs", "PHP"),
-        ("Kbuild", "Makefile"),
-        ("Jarfile", "Ruby"),
-        (".XCompose", "XCompose"),
-        ("man", "Shell"),
-        (".jslintrc", "JSON with Comments"),
-        ("COPYING", "Text"),
-        ("_emacs", "Emacs Lisp"),
-        (".jscsrc", "JSON with Comments"),
-        ("mkfile", "Makefile"),
-        (".project", "XML"),
-        ("DEPS", "Python"),
-        ("9fs", "Shell"),
-        (".stylelintignore", "Ignore List"),
-        ("fonts.alias", "X Font Directory Index"),
-        ("zprofile", "Shell"),
-        ("sshd_config", "SSH Config"),
-        ("dir_colors", "dircolors"),
+        ("Rexfile", "Perl"),
+        ("App.config", "XML"),
+        ("makefile.sco", "Makefile"),
+        ("gitignore_global", "Ignore List"),
+        ("encodings.dir", "X Font Directory Index"),
+        (".eslintrc.json", "JSON with Comments"),
+        ("SConstruct", "Python"),
+        ("ld.script", "Linker Script"),
         (".abbrev_defs", "Emacs Lisp"),
-        (".tern-config", "JSON"),
-        ("Slakefile", "LiveScript"),
-        ("composer.lock", "JSON"),
-        ("Guardfile", "Ruby"),
-        ("mmn", "Roff"),
-        ("Makefile.frag", "Makefile"),
-        ("bash_aliases", "Shell"),
+        ("delete.me", "Text"),
+        (".viper", "Emacs Lisp"),
+        ("profile", "Shell"),
+        ("ROOT", "Isabelle ROOT"),
+        ("Berksfile", "Ruby"),
+        ("tsconfig.json", "JSON with Comments"),
+        ("Thorfile", "Ruby"),
+        ("m3overrides", "Quake"),
+        ("ssh_config", "SSH Config"),
+        ("cabal.config", "Cabal Config"),
+        (".zshrc", "Shell"),
+        ("sshd-config", "SSH Config"),
+        ("zlogin", "Shell"),
+        (".luacheckrc", "Lua"),
+        (".watchmanconfig", "JSON"),
         (".factor-rc", "Factor"),
-        (".cproject", "XML"),
-        ("zlogout", "Shell"),
+        ("zprofile", "Shell"),
+        ("INSTALL.mysql", "Text"),
         ("README.mysql", "Text"),
-        ("m3makefile", "Quake"),
-        ("click.me", "Text"),
-        ("troffrc-end", "Roff"),
-        ("tsconfig.json", "JSON with Comments"),
-        (".spacemacs", "Emacs Lisp"),
-        (".dir_colors", "dircolors"),
-        ("wscript", "Python"),
-        ("LICENSE", "Text"),
+        (".bash_aliases", "Shell"),
+        ("Gopkg.lock", "TOML"),
+        (".eslintignore", "Ignore List"),
+        ("Gemfile", "Ruby"),
+        (".emacs", "Emacs Lisp"),
+        ("use.stable.mask", "Text"),
+        ("Project.ede", "Emacs Lisp"),
         ("BUILD.bazel", "Starlark"),
-        ("Makefile.in", "Makefile"),
-        ("eqnrc", "Roff"),
-        ("SConscript", "Python"),
-        ("buildfile", "Ruby"),
-        (".gitignore", "Ignore List"),
+        (".jscsrc", "JSON with Comments"),
+        (".factor-boot-rc", "Factor"),
+        ("cpanfile", "Perl"),
+        ("apache2.conf", "ApacheConf"),
+        ("COPYING.regex", "Text"),
+        ("jsconfig.json", "JSON with Comments"),
+        ("starfield", "Tcl"),
+        ("gitignore-global", "Ignore List"),
+        ("DEPS", "Python"),
+        ("acinclude.m4", "M4Sugar"),
+        ("ant.xml", "Ant Build System"),
+        ("_emacs", "Emacs Lisp"),
+        ("PKGBUILD", "Shell"),
+        (".cvsignore", "Ignore List"),
+        (".zlogin", "Shell"),
+        ("BUCK", "Starlark"),
+        (".curlrc", "cURL Config"),
+        (".tern-project", "JSON"),
+        ("Vagrantfile", "Ruby"),
+        ("BSDmakefile", "Makefile"),
+        ("firestore.rules", "Cloud Firestore Security Rules"),
+        ("Cargo.lock", "TOML"),
         (".dockerignore", "Ignore List"),
+        (".project", "XML"),
+        ("Kbuild", "Makefile"),
+        ("makefile", "Makefile"),
+        (".zlogout", "Shell"),
+        (".php_cs", "PHP"),
+        ("Fastfile", "Ruby"),
+        ("aclocal.m4", "M4Sugar"),
+        ("CMakeLists.txt", "CMake"),
+        ("sshd_config", "SSH Config"),
+        (".tern-config", "JSON"),
+        ("Makefile.am", "Makefile"),
+        (".wgetrc", "Wget Config"),
+        ("Emakefile", "Erlang"),
+        ("Appraisals", "Ruby"),
+        ("ack", "Perl"),
+        ("nim.cfg", "Nim"),
+        ("nanorc", "nanorc"),
+        (".login", "Shell"),
+        ("Rakefile", "Ruby"),
+        (".Rprofile", "R"),
+        ("eqnrc", "Roff"),
+        ("Nukefile", "Nu"),
+        ("descrip.mmk", "Module Management System"),
+        ("package.use.stable.mask", "Text"),
+        ("Cakefile", "CoffeeScript"),
+        ("haproxy.cfg", "HAProxy"),
+        (".gclient", "Python"),
+        ("LICENSE", "Text"),
+        ("_dir_colors", "dircolors"),
+        (".classpath", "XML"),
+        ("Jenkinsfile", "Groovy"),
+        ("language-configuration.json", "JSON with Comments"),
+        ("glide.lock", "YAML"),
+        (".bash_history", "Shell"),
+        (".gitmodules", "Git Config"),
+        ("DIR_COLORS", "dircolors"),
+        ("APKBUILD", "Alpine Abuild"),
+        ("_curlrc", "cURL Config"),
+        ("Podfile", "Ruby"),
+        ("rebar.config.lock", "Erlang"),
+        ("owh", "Tcl"),
+        ("sshconfig.snip", "SSH Config"),
+        (".gitconfig", "Git Config"),
         (".gitattributes", "Git Attributes"),
-        ("cabal.config", "Cabal Config"),
-        ("fonts.scale", "X Font Directory Index"),
-        ("starfield", "Tcl"),
-        ("keep.me", "Text"),
-        (".htmlhintrc", "JSON"),
-        ("SConstruct", "Python"),
-        (".php", "PHP"),
-        (".vscodeignore", "Ignore List"),
+        ("mmt", "Roff"),
         (".arcconfig", "JSON"),
-        ("_dir_colors", "dircolors"),
-        ("meson.build", "Meson"),
-        (".luacheckrc", "Lua"),
+        ("vimrc", "Vim script"),
+        ("buildozer.spec", "INI"),
+        (".babelrc", "JSON with Comments"),
+        ("read.me", "Text"),
+        ("keep.me", "Text"),
+        ("Phakefile", "PHP"),
+        (".dir_colors", "dircolors"),
+        (".vimrc", "Vim script"),
+        ("nvimrc", "Vim script"),
+        (".zshenv", "Shell"),
         ("zshrc", "Shell"),
-        ("makefile", "Makefile"),
-        ("_dircolors", "dircolors"),
-        ("cshrc", "Shell"),
+        (".emacs.desktop", "Emacs Lisp"),
+        ("click.me", "Text"),
+        ("ssh-config", "SSH Config"),
+        ("Mavenfile", "Ruby"),
+        ("m3makefile", "Quake"),
+        ("COPYRIGHT.regex", "Text"),
+        (".XCompose", "XCompose"),
+        ("Deliverfile", "Ruby"),
+        ("Web.Release.config", "XML"),
+        (".prettierignore", "Ignore List"),
+        ("bashrc", "Shell"),
         ("package.use.mask", "Text"),
-        ("firestore.rules", "Cloud Firestore Security Rules"),
+        ("sshconfig", "SSH Config"),
+        (".atomignore", "Ignore List"),
+        ("Makefile", "Makefile"),
+        ("login", "Shell"),
         ("mcmod.info", "JSON"),
-        ("Fakefile", "Fancy"),
-        ("_curlrc", "cURL Config"),
-        ("Snakefile", "Python"),
-        ("INSTALL", "Text"),
-        ("apache2.conf", "ApacheConf"),
-        ("bash_logout", "Shell"),
-        ("NEWS", "Text"),
-        (".bash_aliases", "Shell"),
-        ("GNUmakefile", "Makefile"),
-        ("build.xml", "Ant Build System"),
-        (".zshrc", "Shell"),
+        ("configure.ac", "M4Sugar"),
+        (".clang-format", "YAML"),
+        ("readme.1st", "Text"),
         ("README.1ST", "Text"),
-        (".tern-project", "JSON"),
-        ("Cask", "Emacs Lisp"),
-        ("Notebook", "Jupyter Notebook"),
-        ("FONTLOG", "Text"),
-        (".zlogin", "Shell"),
-        ("abbrev_defs", "Emacs Lisp"),
+        ("yarn.lock", "YAML"),
+        ("buildfile", "Ruby"),
+        ("Slakefile", "LiveScript"),
+        ("Makefile.PL", "Perl"),
+        ("XCompose", "XCompose"),
+        (".inputrc", "Readline Config"),
+        ("descrip.mms", "Module Management System"),
+        ("nginx.conf", "Nginx"),
+        ("NEWS", "Text"),
+        (".jshintrc", "JSON with Comments"),
+        ("cshrc", "Shell"),
+        ("Modulefile", "Puppet"),
+        ("mmn", "Roff"),
+        ("xcompose", "XCompose"),
+        ("meson.build", "Meson"),
+        ("Guardfile", "Ruby"),
+        ("SConscript", "Python"),
+        ("Jakefile", "JavaScript"),
+        ("Makefile.frag", "Makefile"),
+        ("mkfile", "Makefile"),
+        (".bzrignore", "Ignore List"),
+        ("Jarfile", "Ruby"),
+        (".htmlhintrc", "JSON"),
+        ("use.mask", "Text"),
+        (".php_cs.dist", "PHP"),
+        ("fonts.scale", "X Font Directory Index"),
+        ("_vimrc", "Vim script"),
+        ("fonts.dir", "X Font Directory Index"),
+        ("Buildfile", "Ruby"),
+        ("LICENSE.mysql", "Text"),
+        (".php", "PHP"),
+        ("troffrc-end", "Roff"),
+        ("Makefile.wat", "Makefile"),
+        ("rebar.config", "Erlang"),
         ("meson_options.txt", "Meson"),
-        (".atomignore", "Ignore List"),
-        ("README.me", "Text"),
-        ("go.mod", "Text"),
-        (".clang-format", "YAML"),
-        (".emacs.desktop", "Emacs Lisp"),
-        ("NuGet.config", "XML"),
-        (".factor-boot-rc", "Factor"),
-        (".dircolors", "dircolors"),
-        ("gitignore_global", "Ignore List"),
-        ("fp-lib-table", "KiCad Layout"),
-        ("Gemfile.lock", "Ruby"),
-        ("ant.xml", "Ant Build System"),
-        ("App.config", "XML"),
-        (".gitconfig", "Git Config"),
-        ("encodings.dir", "X Font Directory Index"),
-        ("CMakeLists.txt", "CMake"),
-        (".bash_profile", "Shell"),
-        ("packages.config", "XML"),
-        ("Makefile.am", "Makefile"),
-        (".classpath", "XML"),
+        ("Web.config", "XML"),
+        ("rebar.lock", "Erlang"),
         ("gradlew", "Shell"),
-        ("DIR_COLORS", "dircolors"),
-        ("gvimrc", "Vim script"),
-        ("Makefile.boot", "Makefile"),
-        ("descrip.mms", "Module Management System"),
+        (".cproject", "XML"),
+        (".clang-tidy", "YAML"),
+        ("WORKSPACE", "Starlark"),
+        ("_dircolors", "dircolors"),
+        ("test.me", "Text"),
+        (".bashrc", "Shell"),
+        ("Cask", "Emacs Lisp"),
         (".gnus", "Emacs Lisp"),
-        ("configure.ac", "M4Sugar"),
-        (".npmrc", "NPM Config"),
-        ("haproxy.cfg", "HAProxy"),
+        ("Snakefile", "Python"),
+        ("bash_logout", "Shell"),
+        (".htaccess", "ApacheConf"),
+        ("composer.lock", "JSON"),
+        ("fonts.alias", "X Font Directory Index"),
+        (".nodemonignore", "Ignore List"),
         ("expr-dist", "R"),
+        (".nanorc", "nanorc"),
+        ("Gemfile.lock", "Ruby"),
+        (".editorconfig", "EditorConfig"),
+        ("wscript", "Python"),
+        ("httpd.conf", "ApacheConf"),
+        ("Web.Debug.config", "XML"),
+        (".gitignore", "Ignore List"),
+        (".gemrc", "YAML"),
         ("inputrc", "Readline Config"),
-        (".nvimrc", "Vim script"),
-        ("Project.ede", "Emacs Lisp"),
-        (".babelignore", "Ignore List"),
-        ("bash_profile", "Shell"),
-        (".login", "Shell"),
-        ("package.mask", "Text"),
+        ("Puppetfile", "Ruby"),
+        ("contents.lr", "Markdown"),
+        (".stylelintignore", "Ignore List"),
+        ("COPYING", "Text"),
+        ("zshenv", "Shell"),
+        ("Dangerfile", "Ruby"),
+        ("fp-lib-table", "KiCad Layout"),
+        ("go.mod", "Go Module"),
         ("nextflow.config", "Nextflow"),
-        ("ssh-config", "SSH Config"),
-        ("mmt", "Roff"),
-        (".cshrc", "Shell"),
-        (".bash_history", "Shell"),
+        ("gvimrc", "Vim script"),
+        ("Settings.StyleCop", "XML"),
+        (".npmrc", "NPM Config"),
+        ("bash_profile", "Shell"),
+        ("Notebook", "Jupyter Notebook"),
+        ("BUILD", "Starlark"),
+        (".spacemacs", "Emacs Lisp"),
+        ("GNUmakefile", "Makefile"),
+        ("packages.config", "XML"),
+        (".babelignore", "Ignore List"),
+        (".pryrc", "Ruby"),
+        ("Dockerfile", "Dockerfile"),
+        (".dircolors", "dircolors"),
+        ("go.sum", "Go Checksums"),
+        ("INSTALL", "Text"),
+        ("FONTLOG", "Text"),
         (".profile", "Shell"),
-        ("cabal.project", "Cabal Config"),
-        ("Makefile.wat", "Makefile"),
-        ("Jakefile", "JavaScript"),
+        ("dir_colors", "dircolors"),
+        (".bash_profile", "Shell"),
+        ("configure.in", "M4Sugar"),
+        ("build.xml", "Ant Build System"),
+        ("9fs", "Shell"),
+        ("Brewfile", "Ruby"),
         (".gvimrc", "Vim script"),
-        ("cpanfile", "Perl"),
-        ("nanorc", "nanorc"),
-        ("bashrc", "Shell"),
-        ("ack", "Perl"),
-        ("sshconfig", "SSH Config"),
-        (".gclient", "Python"),
-        ("Rexfile", "Perl"),
-        ("xcompose", "XCompose"),
-        (".bashrc", "Shell"),
-        (".zlogout", "Shell"),
-        (".vimrc", "Vim script"),
-        ("Cakefile", "CoffeeScript"),
-        (".eslintrc.json", "JSON with Comments"),
-        ("sshconfig.snip", "SSH Config"),
+        ("bash_aliases", "Shell"),
+        ("NuGet.config", "XML"),
+        ("Makefile.in", "Makefile"),
+        (".irbrc", "Ruby"),
+        ("Capfile", "Ruby"),
+        (".nvimrc", "Vim script"),
         ("Makefile.inc", "Makefile"),
-        (".zprofile", "Shell"),
-        (".nanorc", "nanorc"),
-        ("readme.1st", "Text"),
-        ("Gopkg.lock", "TOML"),
-        ("BUILD", "Starlark"),
-        ("Web.Debug.config", "XML"),
-        ("Phakefile", "PHP"),
-        ("APKBUILD", "Alpine Abuild"),
-        ("COPYRIGHT.regex", "Text"),
-        ("nim.cfg", "Nim"),
+        (".gn", "GN"),
         ("troffrc", "Roff"),
-        (".htaccess", "ApacheConf"),
-        ("Buildfile", "Ruby"),
+        (".cshrc", "Shell"),
+        (".vscodeignore", "Ignore List"),
+        ("riemann.config", "Clojure"),
+        (".npmignore", "Ignore List"),
+        ("cabal.project", "Cabal Config"),
+        ("README.me", "Text"),
+        ("abbrev_defs", "Emacs Lisp"),
         ("mix.lock", "Elixir"),
-        (".irbrc", "Ruby"),
-        ("rebar.lock", "Erlang"),
-        (".inputrc", "Readline Config"),
-        ("Gemfile", "Ruby"),
-        ("Dangerfile", "Ruby"),
-        ("vimrc", "Vim script"),
-        (".pryrc", "Ruby"),
-        (".zshenv", "Shell"),
         (".coffeelintignore", "Ignore List"),
-        ("profile", "Shell"),
-        ("rebar.config.lock", "Erlang"),
-        ("PKGBUILD", "Shell"),
-        (".wgetrc", "Wget Config"),
-        ("Appraisals", "Ruby"),
-        ("COPYING.regex", "Text"),
-        ("ROOT", "Isabelle ROOT"),
-        (".Rprofile", "R"),
-        ("WORKSPACE", "Starlark"),
-        ("owh", "Tcl"),
-        ("makefile.sco", "Makefile"),
-        ("fonts.dir", "X Font Directory Index"),
-        ("Web.config", "XML"),
-        ("nvimrc", "Vim script"),
-        ("BSDmakefile", "Makefile"),
-        ("use.stable.mask", "Text"),
-        (".gemrc", "YAML"),
-        ("use.mask", "Text"),
-        ("Jenkinsfile", "Groovy"),
-        ("Makefile.PL", "Perl"),
-        (".curlrc", "cURL Config"),
-        ("zlogin", "Shell"),
-        ("Modulefile", "Puppet"),
-        ("gitignore-global", "Ignore List"),
-        ("ssh_config", "SSH Config"),
-        ("jsconfig.json", "JSON with Comments"),
-        (".eslintignore", "Ignore List"),
-        ("INSTALL.mysql", "Text"),
-        ("Brewfile", "Ruby"),
+        ("Fakefile", "Fancy"),
+        (".jslintrc", "JSON with Comments"),
+        ("Makefile.boot", "Makefile"),
         (".bash_logout", "Shell"),
-        (".watchmanconfig", "JSON"),
-        (".npmignore", "Ignore List"),
+        (".zprofile", "Shell"),
+        ("package.mask", "Text"),
         ("Snapfile", "Ruby"),
-        ("Thorfile", "Ruby"),
-        ("Rakefile", "Ruby"),
-        (".php_cs.dist", "PHP"),
-        ("Podfile", "Ruby"),
-        ("Settings.StyleCop", "XML"),
-        ("sshd-config", "SSH Config"),
-        (".prettierignore", "Ignore List"),
-        ("ld.script", "Linker Script"),
-        ("rebar.config", "Erlang"),
-        (".gitmodules", "Git Config"),
-        ("delete.me", "Text"),
-        ("XCompose", "XCompose"),
-        ("zshenv", "Shell"),
-        ("Berksfile", "Ruby"),
-        ("Puppetfile", "Ruby"),
-        (".viper", "Emacs Lisp"),
-        ("glide.lock", "YAML"),
-        ("login", "Shell"),
-        ("Dockerfile", "Dockerfile"),
-        ("Capfile", "Ruby"),
-        ("Deliverfile", "Ruby"),
-        (".emacs", "Emacs Lisp"),
-        ("read.me", "Text"),
         ("pom.xml", "Maven POM"),
-        ("Emakefile", "Erlang"),
-        (".editorconfig", "EditorConfig"),
-        ("contents.lr", "Markdown"),
-        (".jshintrc", "JSON with Comments"),
-        ("Mavenfile", "Ruby"),
-        (".cvsignore", "Ignore List"),
-        ("yarn.lock", "YAML"),
-        ("Fastfile", "Ruby"),
-        ("nginx.conf", "Nginx"),
-        ("language-configuration.json", "JSON with Comments"),
-        (".bzrignore", "Ignore List"),
-        ("Nukefile", "Nu"),
-        ("riemann.config", "Clojure"),
-        ("buildozer.spec", "INI"),
-        ("descrip.mmk", "Module Management System"),
-        ("m3overrides", "Quake"),
-        ("go.sum", "Text"),
-        (".babelrc", "JSON with Comments"),
-        ("Vagrantfile", "Ruby"),
-        ("Web.Release.config", "XML"),
-        (".gn", "GN"),
-        ("LICENSE.mysql", "Text"),
-        (".nodemonignore", "Ignore List"),
-        ("_vimrc", "Vim script"),
-        (".clang-tidy", "YAML"),
-        ("Makefile", "Makefile"),
-        ("httpd.conf", "ApacheConf"),
-        ("test.me", "Text"),
+        ("man", "Shell"),
+        ("zlogout", "Shell"),
     ]),
 };
 
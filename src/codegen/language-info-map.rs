@@ -2,672 +2,1353 @@ static LANGUAGE_INFO: phf::Map<&'static str, Language> =
 ::phf::Map {
     key: 3213172566270843353,
     disps: ::phf::Slice::Static(&[
-        (0, 296),
+        (0, 137),
+        (0, 200),
+        (0, 0),
         (0, 5),
-        (0, 213),
-        (0, 231),
+        (0, 481),
+        (0, 46),
+        (0, 0),
         (0, 0),
-        (1, 3),
-        (0, 4),
-        (0, 287),
-        (0, 39),
-        (1, 173),
-        (0, 292),
-        (0, 133),
         (0, 15),
-        (0, 150),
-        (0, 2),
-        (0, 128),
-        (0, 1),
-        (1, 24),
+        (0, 475),
+        (0, 244),
+        (0, 86),
+        (0, 35),
+        (0, 4),
+        (0, 157),
+        (0, 144),
+        (0, 7),
+        (2, 281),
+        (2, 325),
+        (1, 321),
+        (0, 140),
+        (0, 5),
+        (0, 58),
+        (0, 10),
+        (0, 506),
+        (0, 5),
+        (1, 108),
+        (0, 41),
+        (0, 516),
+        (1, 398),
+        (0, 69),
+        (0, 4),
+        (0, 154),
+        (0, 0),
         (0, 0),
-        (0, 38),
-        (0, 259),
-        (0, 2),
-        (0, 128),
-        (0, 110),
-        (0, 2),
-        (0, 48),
         (0, 5),
-        (0, 24),
-        (0, 166),
-        (4, 164),
-        (0, 8),
-        (0, 2),
-        (0, 3),
-        (6, 260),
+        (0, 0),
+        (0, 18),
+        (0, 4),
         (0, 61),
-        (0, 539),
-        (0, 39),
+        (0, 5),
+        (0, 3),
+        (0, 14),
+        (0, 17),
+        (0, 410),
+        (3, 410),
+        (0, 310),
         (0, 0),
-        (0, 45),
-        (0, 219),
-        (0, 157),
-        (0, 54),
-        (0, 288),
-        (0, 393),
-        (0, 179),
-        (3, 362),
-        (0, 210),
-        (0, 146),
-        (0, 112),
-        (0, 69),
+        (0, 8),
+        (0, 17),
+        (0, 127),
+        (0, 6),
+        (0, 73),
+        (1, 257),
+        (0, 0),
+        (0, 302),
+        (1, 0),
+        (0, 50),
+        (0, 2),
+        (7, 256),
+        (0, 26),
+        (0, 16),
+        (0, 244),
+        (0, 15),
+        (0, 37),
+        (0, 118),
+        (0, 166),
+        (8, 52),
+        (0, 46),
+        (0, 24),
+        (1, 85),
+        (0, 407),
+        (0, 41),
         (0, 1),
-        (0, 165),
-        (0, 318),
-        (3, 108),
+        (2, 235),
+        (0, 31),
         (0, 1),
-        (0, 12),
-        (1, 101),
-        (2, 129),
+        (0, 18),
+        (1, 382),
+        (0, 176),
+        (0, 128),
+        (4, 141),
+        (5, 337),
+        (0, 79),
         (0, 0),
-        (0, 487),
-        (1, 131),
-        (1, 10),
-        (0, 1),
-        (0, 82),
-        (0, 3),
-        (0, 39),
-        (2, 538),
-        (0, 158),
-        (0, 33),
-        (10, 217),
+        (0, 13),
+        (0, 131),
+        (0, 526),
+        (0, 224),
+        (0, 128),
+        (5, 41),
+        (0, 546),
+        (0, 2),
+        (4, 443),
+        (0, 386),
+        (0, 228),
+        (15, 149),
+        (4, 96),
+        (3, 104),
+        (3, 458),
+        (30, 154),
+        (1, 270),
+        (5, 350),
+        (0, 18),
+        (0, 6),
         (0, 3),
-        (1, 289),
-        (0, 19),
-        (1, 125),
+        (6, 444),
+        (24, 494),
+        (2, 263),
         (0, 0),
-        (0, 227),
-        (0, 10),
-        (0, 385),
-        (0, 368),
-        (0, 206),
-        (0, 534),
-        (0, 461),
-        (0, 112),
-        (10, 157),
-        (5, 403),
-        (0, 118),
-        (1, 5),
-        (0, 2),
-        (1, 335),
-        (0, 229),
-        (0, 2),
+        (0, 64),
         (0, 0),
-        (0, 268),
-        (1, 211),
-        (0, 3),
-        (0, 1),
-        (0, 33),
-        (5, 260),
-        (1, 4),
-        (3, 67),
-        (0, 95),
-        (3, 331),
-        (7, 110),
-        (0, 208),
-        (0, 240),
-        (0, 93),
-        (8, 211),
-        (1, 136),
-        (6, 438),
-        (8, 523),
-        (1, 243),
     ]),
     entries: ::phf::Slice::Static(&[
-        ("R", Language { name: "R", language_type: LanguageType::Programming, color: Some("#198CE7"), group: None }),
-        ("Ant Build System", Language { name: "Ant Build System", language_type: LanguageType::Data, color: None, group: None }),
-        ("Smali", Language { name: "Smali", language_type: LanguageType::Programming, color: None, group: None }),
-        ("C++", Language { name: "C++", language_type: LanguageType::Programming, color: Some("#f34b7d"), group: None }),
-        ("JFlex", Language { name: "JFlex", language_type: LanguageType::Programming, color: None, group: Some("Lex") }),
+        ("Slim", Language { name: "Slim", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("Pic", Language { name: "Pic", language_type: LanguageType::Markup, color: None, group: Some("Roff") }),
+        ("Thrift", Language { name: "Thrift", language_type: LanguageType::Programming, color: None, group: None }),
+        ("GAP", Language { name: "GAP", language_type: LanguageType::Programming, color: None, group: None }),
+        ("BlitzBasic", Language { name: "BlitzBasic", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Papyrus", Language { name: "Papyrus", language_type: LanguageType::Programming, color: Some("#6600cc"), group: None }),
+        ("GDScript", Language { name: "GDScript", language_type: LanguageType::Programming, color: Some("#355570"), group: None }),
+        ("Mirah", Language { name: "Mirah", language_type: LanguageType::Programming, color: Some("#c7a938"), group: None }),
+        ("Rascal", Language { name: "Rascal", language_type: LanguageType::Programming, color: Some("#fffaa0"), group: None }),
+        ("KiCad Layout", Language { name: "KiCad Layout", language_type: LanguageType::Data, color: None, group: None }),
+        ("Nit", Language { name: "Nit", language_type: LanguageType::Programming, color: Some("#009917"), group: None }),
+        ("Charity", Language { name: "Charity", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Jupyter Notebook", Language { name: "Jupyter Notebook", language_type: LanguageType::Markup, color: Some("#DA5B0B"), group: None }),
+        ("Objective-C++", Language { name: "Objective-C++", language_type: LanguageType::Programming, color: Some("#6866fb"), group: None }),
+        ("LSL", Language { name: "LSL", language_type: LanguageType::Programming, color: Some("#3d9970"), group: None }),
+        ("Coq", Language { name: "Coq", language_type: LanguageType::Programming, color: None, group: None }),
+        ("TeX", Language { name: "TeX", language_type: LanguageType::Markup, color: Some("#3D6117"), group: None }),
+        ("Haskell", Language { name: "Haskell", language_type: LanguageType::Programming, color: Some("#5e5086"), group: None }),
+        ("Emacs Lisp", Language { name: "Emacs Lisp", language_type: LanguageType::Programming, color: Some("#c065db"), group: None }),
+        ("Vim script", Language { name: "Vim script", language_type: LanguageType::Programming, color: Some("#199f4b"), group: None }),
+        ("RAML", Language { name: "RAML", language_type: LanguageType::Markup, color: Some("#77d9fb"), group: None }),
+        ("VBScript", Language { name: "VBScript", language_type: LanguageType::Programming, color: Some("#15dcdc"), group: None }),
+        ("Ioke", Language { name: "Ioke", language_type: LanguageType::Programming, color: Some("#078193"), group: None }),
+        ("World of Warcraft Addon Data", Language { name: "World of Warcraft Addon Data", language_type: LanguageType::Data, color: None, group: None }),
+        ("Alloy", Language { name: "Alloy", language_type: LanguageType::Programming, color: Some("#64C800"), group: None }),
+        ("SRecode Template", Language { name: "SRecode Template", language_type: LanguageType::Markup, color: Some("#348a34"), group: None }),
+        ("1C Enterprise", Language { name: "1C Enterprise", language_type: LanguageType::Programming, color: Some("#814CCC"), group: None }),
+        ("Omgrofl", Language { name: "Omgrofl", language_type: LanguageType::Programming, color: Some("#cabbff"), group: None }),
+        ("mupad", Language { name: "mupad", language_type: LanguageType::Programming, color: None, group: None }),
+        ("nesC", Language { name: "nesC", language_type: LanguageType::Programming, color: Some("#94B0C7"), group: None }),
+        ("PicoLisp", Language { name: "PicoLisp", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Microsoft Developer Studio Project", Language { name: "Microsoft Developer Studio Project", language_type: LanguageType::Data, color: None, group: None }),
+        ("Pickle", Language { name: "Pickle", language_type: LanguageType::Data, color: None, group: None }),
+        ("SystemVerilog", Language { name: "SystemVerilog", language_type: LanguageType::Programming, color: Some("#DAE1C2"), group: None }),
+        ("RUNOFF", Language { name: "RUNOFF", language_type: LanguageType::Markup, color: Some("#665a4e"), group: None }),
+        ("TSX", Language { name: "TSX", language_type: LanguageType::Programming, color: None, group: Some("TypeScript") }),
+        ("Prolog", Language { name: "Prolog", language_type: LanguageType::Programming, color: Some("#74283c"), group: None }),
         ("HAProxy", Language { name: "HAProxy", language_type: LanguageType::Data, color: None, group: None }),
-        ("JSON", Language { name: "JSON", language_type: LanguageType::Data, color: None, group: None }),
-        ("Ruby", Language { name: "Ruby", language_type: LanguageType::Programming, color: Some("#701516"), group: None }),
+        ("Haml", Language { name: "Haml", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("Kotlin", Language { name: "Kotlin", language_type: LanguageType::Programming, color: Some("#F18E33"), group: None }),
+        ("Zimpl", Language { name: "Zimpl", language_type: LanguageType::Programming, color: None, group: None }),
+        ("ECL", Language { name: "ECL", language_type: LanguageType::Programming, color: Some("#8a1267"), group: None }),
+        ("ASP", Language { name: "ASP", language_type: LanguageType::Programming, color: Some("#6a40fd"), group: None }),
+        ("Scala", Language { name: "Scala", language_type: LanguageType::Programming, color: Some("#c22d40"), group: None }),
+        ("Blade", Language { name: "Blade", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("Linker Script", Language { name: "Linker Script", language_type: LanguageType::Data, color: None, group: None }),
+        ("C2hs Haskell", Language { name: "C2hs Haskell", language_type: LanguageType::Programming, color: None, group: Some("Haskell") }),
+        ("Filterscript", Language { name: "Filterscript", language_type: LanguageType::Programming, color: None, group: Some("RenderScript") }),
+        ("Logos", Language { name: "Logos", language_type: LanguageType::Programming, color: None, group: None }),
+        ("CSON", Language { name: "CSON", language_type: LanguageType::Data, color: None, group: None }),
         ("Myghty", Language { name: "Myghty", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Proguard", Language { name: "Proguard", language_type: LanguageType::Data, color: None, group: None }),
-        ("Elm", Language { name: "Elm", language_type: LanguageType::Programming, color: Some("#60B5CC"), group: None }),
-        ("Xojo", Language { name: "Xojo", language_type: LanguageType::Programming, color: None, group: None }),
-        ("CSV", Language { name: "CSV", language_type: LanguageType::Data, color: None, group: None }),
-        ("RDoc", Language { name: "RDoc", language_type: LanguageType::Prose, color: None, group: None }),
-        ("Inno Setup", Language { name: "Inno Setup", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Cirru", Language { name: "Cirru", language_type: LanguageType::Programming, color: Some("#ccccff"), group: None }),
-        ("Pan", Language { name: "Pan", language_type: LanguageType::Programming, color: Some("#cc0000"), group: None }),
+        ("Io", Language { name: "Io", language_type: LanguageType::Programming, color: Some("#a9188d"), group: None }),
+        ("Alpine Abuild", Language { name: "Alpine Abuild", language_type: LanguageType::Programming, color: None, group: Some("Shell") }),
+        ("PlantUML", Language { name: "PlantUML", language_type: LanguageType::Data, color: None, group: None }),
+        ("Erlang", Language { name: "Erlang", language_type: LanguageType::Programming, color: Some("#B83998"), group: None }),
+        ("Squirrel", Language { name: "Squirrel", language_type: LanguageType::Programming, color: Some("#800000"), group: None }),
+        ("NASL", Language { name: "NASL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Rust", Language { name: "Rust", language_type: LanguageType::Programming, color: Some("#dea584"), group: None }),
+        ("Filebench WML", Language { name: "Filebench WML", language_type: LanguageType::Programming, color: None, group: None }),
+        ("MTML", Language { name: "MTML", language_type: LanguageType::Markup, color: Some("#b7e1f4"), group: None }),
+        ("Pawn", Language { name: "Pawn", language_type: LanguageType::Programming, color: Some("#dbb284"), group: None }),
+        ("Maven POM", Language { name: "Maven POM", language_type: LanguageType::Data, color: None, group: None }),
         ("Tcsh", Language { name: "Tcsh", language_type: LanguageType::Programming, color: None, group: Some("Shell") }),
-        ("Dylan", Language { name: "Dylan", language_type: LanguageType::Programming, color: Some("#6c616e"), group: None }),
-        ("Pic", Language { name: "Pic", language_type: LanguageType::Markup, color: None, group: Some("Roff") }),
-        ("Jsonnet", Language { name: "Jsonnet", language_type: LanguageType::Programming, color: Some("#0064bd"), group: None }),
-        ("Objective-J", Language { name: "Objective-J", language_type: LanguageType::Programming, color: Some("#ff0c5a"), group: None }),
+        ("Bluespec", Language { name: "Bluespec", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Component Pascal", Language { name: "Component Pascal", language_type: LanguageType::Programming, color: Some("#B0CE4E"), group: None }),
+        ("Glyph", Language { name: "Glyph", language_type: LanguageType::Programming, color: Some("#c1ac7f"), group: None }),
+        ("Wavefront Object", Language { name: "Wavefront Object", language_type: LanguageType::Data, color: None, group: None }),
+        ("Scaml", Language { name: "Scaml", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("YAML", Language { name: "YAML", language_type: LanguageType::Data, color: None, group: None }),
+        ("Jasmin", Language { name: "Jasmin", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Python console", Language { name: "Python console", language_type: LanguageType::Programming, color: None, group: Some("Python") }),
+        ("XQuery", Language { name: "XQuery", language_type: LanguageType::Programming, color: Some("#5232e7"), group: None }),
+        ("wdl", Language { name: "wdl", language_type: LanguageType::Programming, color: Some("#42f1f4"), group: None }),
+        ("Isabelle ROOT", Language { name: "Isabelle ROOT", language_type: LanguageType::Programming, color: None, group: Some("Isabelle") }),
+        ("ShaderLab", Language { name: "ShaderLab", language_type: LanguageType::Programming, color: None, group: None }),
+        ("mIRC Script", Language { name: "mIRC Script", language_type: LanguageType::Programming, color: Some("#926059"), group: None }),
+        ("Graph Modeling Language", Language { name: "Graph Modeling Language", language_type: LanguageType::Data, color: None, group: None }),
+        ("Visual Basic .NET", Language { name: "Visual Basic .NET", language_type: LanguageType::Programming, color: Some("#945db7"), group: None }),
+        ("AMPL", Language { name: "AMPL", language_type: LanguageType::Programming, color: Some("#E6EFBB"), group: None }),
+        ("OCaml", Language { name: "OCaml", language_type: LanguageType::Programming, color: Some("#3be133"), group: None }),
+        ("HTML+PHP", Language { name: "HTML+PHP", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("HiveQL", Language { name: "HiveQL", language_type: LanguageType::Programming, color: Some("#dce200"), group: None }),
+        ("RPM Spec", Language { name: "RPM Spec", language_type: LanguageType::Data, color: None, group: None }),
         ("COBOL", Language { name: "COBOL", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Ignore List", Language { name: "Ignore List", language_type: LanguageType::Data, color: None, group: Some("INI") }),
-        ("Clarion", Language { name: "Clarion", language_type: LanguageType::Programming, color: Some("#db901e"), group: None }),
-        ("PureBasic", Language { name: "PureBasic", language_type: LanguageType::Programming, color: Some("#5a6986"), group: None }),
-        ("Csound Score", Language { name: "Csound Score", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Jison Lex", Language { name: "Jison Lex", language_type: LanguageType::Programming, color: None, group: Some("Lex") }),
-        ("Crystal", Language { name: "Crystal", language_type: LanguageType::Programming, color: Some("#000100"), group: None }),
-        ("SystemVerilog", Language { name: "SystemVerilog", language_type: LanguageType::Programming, color: Some("#DAE1C2"), group: None }),
-        ("Linux Kernel Module", Language { name: "Linux Kernel Module", language_type: LanguageType::Data, color: None, group: None }),
+        ("Closure Templates", Language { name: "Closure Templates", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("PLpgSQL", Language { name: "PLpgSQL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Windows Registry Entries", Language { name: "Windows Registry Entries", language_type: LanguageType::Data, color: None, group: None }),
+        ("eC", Language { name: "eC", language_type: LanguageType::Programming, color: Some("#913960"), group: None }),
+        ("EJS", Language { name: "EJS", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("Eagle", Language { name: "Eagle", language_type: LanguageType::Data, color: None, group: None }),
+        ("Liquid", Language { name: "Liquid", language_type: LanguageType::Markup, color: None, group: None }),
+        ("REXX", Language { name: "REXX", language_type: LanguageType::Programming, color: None, group: None }),
+        ("V", Language { name: "V", language_type: LanguageType::Programming, color: Some("#5d87bd"), group: None }),
+        ("SPARQL", Language { name: "SPARQL", language_type: LanguageType::Data, color: None, group: None }),
+        ("JSX", Language { name: "JSX", language_type: LanguageType::Programming, color: None, group: Some("JavaScript") }),
+        ("RobotFramework", Language { name: "RobotFramework", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Shell", Language { name: "Shell", language_type: LanguageType::Programming, color: Some("#89e051"), group: None }),
+        ("Object Data Instance Notation", Language { name: "Object Data Instance Notation", language_type: LanguageType::Data, color: None, group: None }),
+        ("Augeas", Language { name: "Augeas", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Svelte", Language { name: "Svelte", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
         ("ObjDump", Language { name: "ObjDump", language_type: LanguageType::Data, color: None, group: None }),
+        ("PureScript", Language { name: "PureScript", language_type: LanguageType::Programming, color: Some("#1D222D"), group: None }),
+        ("Cap'n Proto", Language { name: "Cap'n Proto", language_type: LanguageType::Programming, color: None, group: None }),
+        ("VBA", Language { name: "VBA", language_type: LanguageType::Programming, color: Some("#867db1"), group: None }),
+        ("Asymptote", Language { name: "Asymptote", language_type: LanguageType::Programming, color: Some("#4a0c0c"), group: None }),
+        ("TSQL", Language { name: "TSQL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("dircolors", Language { name: "dircolors", language_type: LanguageType::Data, color: None, group: None }),
+        ("Common Lisp", Language { name: "Common Lisp", language_type: LanguageType::Programming, color: Some("#3fb68b"), group: None }),
+        ("Tcl", Language { name: "Tcl", language_type: LanguageType::Programming, color: Some("#e4cc98"), group: None }),
         ("PHP", Language { name: "PHP", language_type: LanguageType::Programming, color: Some("#4F5D95"), group: None }),
-        ("SCSS", Language { name: "SCSS", language_type: LanguageType::Markup, color: None, group: Some("CSS") }),
-        ("YAML", Language { name: "YAML", language_type: LanguageType::Data, color: None, group: None }),
-        ("IRC log", Language { name: "IRC log", language_type: LanguageType::Data, color: None, group: None }),
-        ("RobotFramework", Language { name: "RobotFramework", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Unix Assembly", Language { name: "Unix Assembly", language_type: LanguageType::Programming, color: None, group: Some("Assembly") }),
-        ("Grace", Language { name: "Grace", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Nemerle", Language { name: "Nemerle", language_type: LanguageType::Programming, color: Some("#3d3c6e"), group: None }),
-        ("RMarkdown", Language { name: "RMarkdown", language_type: LanguageType::Prose, color: None, group: None }),
-        ("Altium Designer", Language { name: "Altium Designer", language_type: LanguageType::Data, color: None, group: None }),
-        ("Boo", Language { name: "Boo", language_type: LanguageType::Programming, color: Some("#d4bec1"), group: None }),
-        ("Ox", Language { name: "Ox", language_type: LanguageType::Programming, color: None, group: None }),
-        ("P4", Language { name: "P4", language_type: LanguageType::Programming, color: Some("#7055b5"), group: None }),
-        ("XML Property List", Language { name: "XML Property List", language_type: LanguageType::Data, color: None, group: Some("XML") }),
-        ("ApacheConf", Language { name: "ApacheConf", language_type: LanguageType::Data, color: None, group: None }),
-        ("Windows Registry Entries", Language { name: "Windows Registry Entries", language_type: LanguageType::Data, color: None, group: None }),
-        ("Arc", Language { name: "Arc", language_type: LanguageType::Programming, color: Some("#aa2afe"), group: None }),
-        ("GraphQL", Language { name: "GraphQL", language_type: LanguageType::Data, color: None, group: None }),
-        ("Text", Language { name: "Text", language_type: LanguageType::Prose, color: None, group: None }),
-        ("Papyrus", Language { name: "Papyrus", language_type: LanguageType::Programming, color: Some("#6600cc"), group: None }),
-        ("Wget Config", Language { name: "Wget Config", language_type: LanguageType::Data, color: None, group: Some("INI") }),
-        ("VBScript", Language { name: "VBScript", language_type: LanguageType::Programming, color: Some("#15dcdc"), group: None }),
-        ("Vim Snippet", Language { name: "Vim Snippet", language_type: LanguageType::Markup, color: None, group: None }),
-        ("LookML", Language { name: "LookML", language_type: LanguageType::Programming, color: Some("#652B81"), group: None }),
-        ("SRecode Template", Language { name: "SRecode Template", language_type: LanguageType::Markup, color: Some("#348a34"), group: None }),
+        ("Eiffel", Language { name: "Eiffel", language_type: LanguageType::Programming, color: Some("#946d57"), group: None }),
+        ("Awk", Language { name: "Awk", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Zephir", Language { name: "Zephir", language_type: LanguageType::Programming, color: Some("#118f9e"), group: None }),
+        ("JavaScript+ERB", Language { name: "JavaScript+ERB", language_type: LanguageType::Programming, color: None, group: Some("JavaScript") }),
+        ("CoNLL-U", Language { name: "CoNLL-U", language_type: LanguageType::Data, color: None, group: None }),
+        ("xBase", Language { name: "xBase", language_type: LanguageType::Programming, color: Some("#403a40"), group: None }),
+        ("Game Maker Language", Language { name: "Game Maker Language", language_type: LanguageType::Programming, color: Some("#71b417"), group: None }),
+        ("X10", Language { name: "X10", language_type: LanguageType::Programming, color: Some("#4B6BEF"), group: None }),
+        ("JSON with Comments", Language { name: "JSON with Comments", language_type: LanguageType::Data, color: None, group: Some("JSON") }),
+        ("Lua", Language { name: "Lua", language_type: LanguageType::Programming, color: Some("#000080"), group: None }),
+        ("Max", Language { name: "Max", language_type: LanguageType::Programming, color: Some("#c4a79c"), group: None }),
+        ("Roff", Language { name: "Roff", language_type: LanguageType::Markup, color: Some("#ecdebe"), group: None }),
         ("CMake", Language { name: "CMake", language_type: LanguageType::Programming, color: None, group: None }),
+        ("QMake", Language { name: "QMake", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Standard ML", Language { name: "Standard ML", language_type: LanguageType::Programming, color: Some("#dc566d"), group: None }),
+        ("M", Language { name: "M", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Pod 6", Language { name: "Pod 6", language_type: LanguageType::Prose, color: None, group: None }),
+        ("Terra", Language { name: "Terra", language_type: LanguageType::Programming, color: Some("#00004c"), group: None }),
+        ("Harbour", Language { name: "Harbour", language_type: LanguageType::Programming, color: Some("#0e60e3"), group: None }),
+        ("HCL", Language { name: "HCL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("fish", Language { name: "fish", language_type: LanguageType::Programming, color: None, group: Some("Shell") }),
+        ("HTML+Django", Language { name: "HTML+Django", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("DataWeave", Language { name: "DataWeave", language_type: LanguageType::Programming, color: Some("#003a52"), group: None }),
+        ("Modula-3", Language { name: "Modula-3", language_type: LanguageType::Programming, color: Some("#223388"), group: None }),
+        ("HLSL", Language { name: "HLSL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Pan", Language { name: "Pan", language_type: LanguageType::Programming, color: Some("#cc0000"), group: None }),
+        ("JFlex", Language { name: "JFlex", language_type: LanguageType::Programming, color: None, group: Some("Lex") }),
         ("SubRip Text", Language { name: "SubRip Text", language_type: LanguageType::Data, color: None, group: None }),
-        ("ASN.1", Language { name: "ASN.1", language_type: LanguageType::Data, color: None, group: None }),
-        ("Nginx", Language { name: "Nginx", language_type: LanguageType::Data, color: None, group: None }),
-        ("Regular Expression", Language { name: "Regular Expression", language_type: LanguageType::Data, color: None, group: None }),
-        ("Literate Agda", Language { name: "Literate Agda", language_type: LanguageType::Programming, color: None, group: Some("Agda") }),
-        ("Cap\'n Proto", Language { name: "Cap'n Proto", language_type: LanguageType::Programming, color: None, group: None }),
-        ("STON", Language { name: "STON", language_type: LanguageType::Data, color: None, group: Some("Smalltalk") }),
-        ("Object Data Instance Notation", Language { name: "Object Data Instance Notation", language_type: LanguageType::Data, color: None, group: None }),
-        ("Moocode", Language { name: "Moocode", language_type: LanguageType::Programming, color: None, group: None }),
-        ("REALbasic", Language { name: "REALbasic", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Grammatical Framework", Language { name: "Grammatical Framework", language_type: LanguageType::Programming, color: Some("#79aa7a"), group: None }),
-        ("XSLT", Language { name: "XSLT", language_type: LanguageType::Programming, color: Some("#EB8CEB"), group: None }),
-        ("Gherkin", Language { name: "Gherkin", language_type: LanguageType::Programming, color: Some("#5B2063"), group: None }),
-        ("Nit", Language { name: "Nit", language_type: LanguageType::Programming, color: Some("#009917"), group: None }),
+        ("EBNF", Language { name: "EBNF", language_type: LanguageType::Data, color: None, group: None }),
+        ("PigLatin", Language { name: "PigLatin", language_type: LanguageType::Programming, color: Some("#fcd7de"), group: None }),
+        ("Pascal", Language { name: "Pascal", language_type: LanguageType::Programming, color: Some("#E3F171"), group: None }),
+        ("TXL", Language { name: "TXL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("MQL5", Language { name: "MQL5", language_type: LanguageType::Programming, color: Some("#4A76B8"), group: None }),
+        ("wisp", Language { name: "wisp", language_type: LanguageType::Programming, color: Some("#7582D1"), group: None }),
+        ("Haxe", Language { name: "Haxe", language_type: LanguageType::Programming, color: Some("#df7900"), group: None }),
+        ("Elixir", Language { name: "Elixir", language_type: LanguageType::Programming, color: Some("#6e4a7e"), group: None }),
+        ("Pony", Language { name: "Pony", language_type: LanguageType::Programming, color: None, group: None }),
+        ("NPM Config", Language { name: "NPM Config", language_type: LanguageType::Data, color: None, group: Some("INI") }),
         ("Mathematica", Language { name: "Mathematica", language_type: LanguageType::Programming, color: None, group: None }),
-        ("TOML", Language { name: "TOML", language_type: LanguageType::Data, color: None, group: None }),
-        ("Scilab", Language { name: "Scilab", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Perl", Language { name: "Perl", language_type: LanguageType::Programming, color: Some("#0298c3"), group: None }),
-        ("C-ObjDump", Language { name: "C-ObjDump", language_type: LanguageType::Data, color: None, group: None }),
-        ("fish", Language { name: "fish", language_type: LanguageType::Programming, color: None, group: Some("Shell") }),
-        ("Java Properties", Language { name: "Java Properties", language_type: LanguageType::Data, color: None, group: None }),
-        ("Brightscript", Language { name: "Brightscript", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Objective-J", Language { name: "Objective-J", language_type: LanguageType::Programming, color: Some("#ff0c5a"), group: None }),
         ("Cycript", Language { name: "Cycript", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Public Key", Language { name: "Public Key", language_type: LanguageType::Data, color: None, group: None }),
-        ("Go", Language { name: "Go", language_type: LanguageType::Programming, color: Some("#00ADD8"), group: None }),
-        ("Makefile", Language { name: "Makefile", language_type: LanguageType::Programming, color: Some("#427819"), group: None }),
-        ("G-code", Language { name: "G-code", language_type: LanguageType::Programming, color: Some("#D08CF2"), group: None }),
-        ("LLVM", Language { name: "LLVM", language_type: LanguageType::Programming, color: Some("#185619"), group: None }),
-        ("reStructuredText", Language { name: "reStructuredText", language_type: LanguageType::Prose, color: None, group: None }),
-        ("cURL Config", Language { name: "cURL Config", language_type: LanguageType::Data, color: None, group: Some("INI") }),
-        ("Yacc", Language { name: "Yacc", language_type: LanguageType::Programming, color: Some("#4B6C4B"), group: None }),
-        ("Pug", Language { name: "Pug", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("Metal", Language { name: "Metal", language_type: LanguageType::Programming, color: Some("#8f14e9"), group: None }),
-        ("NASL", Language { name: "NASL", language_type: LanguageType::Programming, color: None, group: None }),
-        ("MQL5", Language { name: "MQL5", language_type: LanguageType::Programming, color: Some("#4A76B8"), group: None }),
-        ("Alpine Abuild", Language { name: "Alpine Abuild", language_type: LanguageType::Programming, color: None, group: Some("Shell") }),
-        ("ATS", Language { name: "ATS", language_type: LanguageType::Programming, color: Some("#1ac620"), group: None }),
-        ("Slim", Language { name: "Slim", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("LabVIEW", Language { name: "LabVIEW", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Scheme", Language { name: "Scheme", language_type: LanguageType::Programming, color: Some("#1e4aec"), group: None }),
-        ("V", Language { name: "V", language_type: LanguageType::Programming, color: Some("#5d87bd"), group: None }),
-        ("Tcl", Language { name: "Tcl", language_type: LanguageType::Programming, color: Some("#e4cc98"), group: None }),
-        ("Zeek", Language { name: "Zeek", language_type: LanguageType::Programming, color: None, group: None }),
-        ("EML", Language { name: "EML", language_type: LanguageType::Data, color: None, group: None }),
-        ("GDScript", Language { name: "GDScript", language_type: LanguageType::Programming, color: Some("#355570"), group: None }),
-        ("Markdown", Language { name: "Markdown", language_type: LanguageType::Prose, color: None, group: None }),
-        ("MQL4", Language { name: "MQL4", language_type: LanguageType::Programming, color: Some("#62A8D6"), group: None }),
-        ("Vue", Language { name: "Vue", language_type: LanguageType::Markup, color: Some("#2c3e50"), group: None }),
-        ("Gradle", Language { name: "Gradle", language_type: LanguageType::Data, color: None, group: None }),
-        ("Logtalk", Language { name: "Logtalk", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Self", Language { name: "Self", language_type: LanguageType::Programming, color: Some("#0579aa"), group: None }),
-        ("GAP", Language { name: "GAP", language_type: LanguageType::Programming, color: None, group: None }),
-        ("SQF", Language { name: "SQF", language_type: LanguageType::Programming, color: Some("#3F3F3F"), group: None }),
-        ("Groovy Server Pages", Language { name: "Groovy Server Pages", language_type: LanguageType::Programming, color: None, group: Some("Groovy") }),
-        ("Marko", Language { name: "Marko", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("TSX", Language { name: "TSX", language_type: LanguageType::Programming, color: None, group: Some("TypeScript") }),
+        ("Modula-2", Language { name: "Modula-2", language_type: LanguageType::Programming, color: None, group: None }),
         ("Cloud Firestore Security Rules", Language { name: "Cloud Firestore Security Rules", language_type: LanguageType::Data, color: None, group: None }),
-        ("PureScript", Language { name: "PureScript", language_type: LanguageType::Programming, color: Some("#1D222D"), group: None }),
-        ("Open Policy Agent", Language { name: "Open Policy Agent", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Graphviz (DOT)", Language { name: "Graphviz (DOT)", language_type: LanguageType::Data, color: None, group: None }),
+        ("XML", Language { name: "XML", language_type: LanguageType::Data, color: None, group: None }),
+        ("Csound Document", Language { name: "Csound Document", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Nearley", Language { name: "Nearley", language_type: LanguageType::Programming, color: Some("#990000"), group: None }),
+        ("VCL", Language { name: "VCL", language_type: LanguageType::Programming, color: Some("#148AA8"), group: None }),
         ("OpenSCAD", Language { name: "OpenSCAD", language_type: LanguageType::Programming, color: None, group: None }),
-        ("DIGITAL Command Language", Language { name: "DIGITAL Command Language", language_type: LanguageType::Programming, color: None, group: None }),
-        ("MiniD", Language { name: "MiniD", language_type: LanguageType::Programming, color: None, group: None }),
-        ("C2hs Haskell", Language { name: "C2hs Haskell", language_type: LanguageType::Programming, color: None, group: Some("Haskell") }),
-        ("Monkey", Language { name: "Monkey", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Xtend", Language { name: "Xtend", language_type: LanguageType::Programming, color: None, group: None }),
+        ("ASN.1", Language { name: "ASN.1", language_type: LanguageType::Data, color: None, group: None }),
+        ("desktop", Language { name: "desktop", language_type: LanguageType::Data, color: None, group: None }),
+        ("Stylus", Language { name: "Stylus", language_type: LanguageType::Markup, color: None, group: Some("CSS") }),
+        ("Grace", Language { name: "Grace", language_type: LanguageType::Programming, color: None, group: None }),
+        ("ColdFusion CFC", Language { name: "ColdFusion CFC", language_type: LanguageType::Programming, color: None, group: Some("ColdFusion") }),
+        ("F#", Language { name: "F#", language_type: LanguageType::Programming, color: Some("#b845fc"), group: None }),
+        ("Uno", Language { name: "Uno", language_type: LanguageType::Programming, color: None, group: None }),
+        ("AsciiDoc", Language { name: "AsciiDoc", language_type: LanguageType::Prose, color: None, group: None }),
+        ("Click", Language { name: "Click", language_type: LanguageType::Programming, color: Some("#E4E6F3"), group: None }),
+        ("Apex", Language { name: "Apex", language_type: LanguageType::Programming, color: None, group: None }),
+        ("BibTeX", Language { name: "BibTeX", language_type: LanguageType::Markup, color: None, group: Some("TeX") }),
+        ("Python traceback", Language { name: "Python traceback", language_type: LanguageType::Data, color: None, group: Some("Python") }),
+        ("NumPy", Language { name: "NumPy", language_type: LanguageType::Programming, color: None, group: Some("Python") }),
+        ("Julia", Language { name: "Julia", language_type: LanguageType::Programming, color: Some("#a270ba"), group: None }),
+        ("ECLiPSe", Language { name: "ECLiPSe", language_type: LanguageType::Programming, color: None, group: Some("prolog") }),
+        ("Dockerfile", Language { name: "Dockerfile", language_type: LanguageType::Programming, color: Some("#384d54"), group: None }),
+        ("reStructuredText", Language { name: "reStructuredText", language_type: LanguageType::Prose, color: None, group: None }),
+        ("Motorola 68K Assembly", Language { name: "Motorola 68K Assembly", language_type: LanguageType::Programming, color: None, group: Some("Assembly") }),
+        ("Inform 7", Language { name: "Inform 7", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Groovy Server Pages", Language { name: "Groovy Server Pages", language_type: LanguageType::Programming, color: None, group: Some("Groovy") }),
+        ("Rich Text Format", Language { name: "Rich Text Format", language_type: LanguageType::Markup, color: None, group: None }),
+        ("Propeller Spin", Language { name: "Propeller Spin", language_type: LanguageType::Programming, color: Some("#7fa2a7"), group: None }),
+        ("Ninja", Language { name: "Ninja", language_type: LanguageType::Data, color: None, group: None }),
+        ("Bison", Language { name: "Bison", language_type: LanguageType::Programming, color: None, group: Some("Yacc") }),
+        ("CodeQL", Language { name: "CodeQL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("q", Language { name: "q", language_type: LanguageType::Programming, color: Some("#0040cd"), group: None }),
+        ("Factor", Language { name: "Factor", language_type: LanguageType::Programming, color: Some("#636746"), group: None }),
+        ("AutoHotkey", Language { name: "AutoHotkey", language_type: LanguageType::Programming, color: Some("#6594b9"), group: None }),
+        ("Roff Manpage", Language { name: "Roff Manpage", language_type: LanguageType::Markup, color: None, group: Some("Roff") }),
+        ("Starlark", Language { name: "Starlark", language_type: LanguageType::Programming, color: Some("#76d275"), group: None }),
+        ("XC", Language { name: "XC", language_type: LanguageType::Programming, color: Some("#99DA07"), group: None }),
+        ("Dart", Language { name: "Dart", language_type: LanguageType::Programming, color: Some("#00B4AB"), group: None }),
+        ("Muse", Language { name: "Muse", language_type: LanguageType::Prose, color: None, group: None }),
+        ("Metal", Language { name: "Metal", language_type: LanguageType::Programming, color: Some("#8f14e9"), group: None }),
+        ("Stata", Language { name: "Stata", language_type: LanguageType::Programming, color: None, group: None }),
+        ("QML", Language { name: "QML", language_type: LanguageType::Programming, color: Some("#44a51c"), group: None }),
+        ("HXML", Language { name: "HXML", language_type: LanguageType::Data, color: None, group: None }),
+        ("Slice", Language { name: "Slice", language_type: LanguageType::Programming, color: Some("#003fa2"), group: None }),
+        ("APL", Language { name: "APL", language_type: LanguageType::Programming, color: Some("#5A8164"), group: None }),
+        ("ColdFusion", Language { name: "ColdFusion", language_type: LanguageType::Programming, color: Some("#ed2cd6"), group: None }),
+        ("Diff", Language { name: "Diff", language_type: LanguageType::Data, color: None, group: None }),
+        ("IDL", Language { name: "IDL", language_type: LanguageType::Programming, color: Some("#a3522f"), group: None }),
+        ("Literate CoffeeScript", Language { name: "Literate CoffeeScript", language_type: LanguageType::Programming, color: None, group: Some("CoffeeScript") }),
         ("F*", Language { name: "F*", language_type: LanguageType::Programming, color: Some("#572e30"), group: None }),
-        ("Unity3D Asset", Language { name: "Unity3D Asset", language_type: LanguageType::Data, color: None, group: None }),
-        ("GCC Machine Description", Language { name: "GCC Machine Description", language_type: LanguageType::Programming, color: None, group: None }),
-        ("PogoScript", Language { name: "PogoScript", language_type: LanguageType::Programming, color: Some("#d80074"), group: None }),
-        ("CSS", Language { name: "CSS", language_type: LanguageType::Markup, color: Some("#563d7c"), group: None }),
-        ("1C Enterprise", Language { name: "1C Enterprise", language_type: LanguageType::Programming, color: Some("#814CCC"), group: None }),
-        ("ooc", Language { name: "ooc", language_type: LanguageType::Programming, color: Some("#b0b77e"), group: None }),
-        ("ChucK", Language { name: "ChucK", language_type: LanguageType::Programming, color: None, group: None }),
-        ("SmPL", Language { name: "SmPL", language_type: LanguageType::Programming, color: Some("#c94949"), group: None }),
+        ("Git Config", Language { name: "Git Config", language_type: LanguageType::Data, color: None, group: Some("INI") }),
+        ("Mako", Language { name: "Mako", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Raku", Language { name: "Raku", language_type: LanguageType::Programming, color: Some("#0000fb"), group: None }),
+        ("Clojure", Language { name: "Clojure", language_type: LanguageType::Programming, color: Some("#db5855"), group: None }),
+        ("X BitMap", Language { name: "X BitMap", language_type: LanguageType::Data, color: None, group: Some("C") }),
+        ("RMarkdown", Language { name: "RMarkdown", language_type: LanguageType::Prose, color: None, group: None }),
+        ("Genie", Language { name: "Genie", language_type: LanguageType::Programming, color: Some("#fb855d"), group: None }),
+        ("Literate Agda", Language { name: "Literate Agda", language_type: LanguageType::Programming, color: None, group: Some("Agda") }),
         ("Cuda", Language { name: "Cuda", language_type: LanguageType::Programming, color: Some("#3A4E3A"), group: None }),
-        ("Modula-2", Language { name: "Modula-2", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Literate CoffeeScript", Language { name: "Literate CoffeeScript", language_type: LanguageType::Programming, color: None, group: Some("CoffeeScript") }),
-        ("Elixir", Language { name: "Elixir", language_type: LanguageType::Programming, color: Some("#6e4a7e"), group: None }),
-        ("ZIL", Language { name: "ZIL", language_type: LanguageType::Programming, color: Some("#dc75e5"), group: None }),
-        ("Faust", Language { name: "Faust", language_type: LanguageType::Programming, color: Some("#c37240"), group: None }),
+        ("Perl", Language { name: "Perl", language_type: LanguageType::Programming, color: Some("#0298c3"), group: None }),
+        ("JavaScript", Language { name: "JavaScript", language_type: LanguageType::Programming, color: Some("#f1e05a"), group: None }),
+        ("Red", Language { name: "Red", language_type: LanguageType::Programming, color: Some("#f50000"), group: None }),
+        ("Sage", Language { name: "Sage", language_type: LanguageType::Programming, color: None, group: Some("Python") }),
+        ("Adobe Font Metrics", Language { name: "Adobe Font Metrics", language_type: LanguageType::Data, color: None, group: None }),
+        ("DNS Zone", Language { name: "DNS Zone", language_type: LanguageType::Data, color: None, group: None }),
+        ("PureBasic", Language { name: "PureBasic", language_type: LanguageType::Programming, color: Some("#5a6986"), group: None }),
+        ("YARA", Language { name: "YARA", language_type: LanguageType::Programming, color: Some("#220000"), group: None }),
+        ("CWeb", Language { name: "CWeb", language_type: LanguageType::Programming, color: None, group: None }),
+        ("HTTP", Language { name: "HTTP", language_type: LanguageType::Data, color: None, group: None }),
+        ("GAML", Language { name: "GAML", language_type: LanguageType::Programming, color: Some("#FFC766"), group: None }),
+        ("Apollo Guidance Computer", Language { name: "Apollo Guidance Computer", language_type: LanguageType::Programming, color: None, group: Some("Assembly") }),
+        ("DM", Language { name: "DM", language_type: LanguageType::Programming, color: Some("#447265"), group: None }),
+        ("C#", Language { name: "C#", language_type: LanguageType::Programming, color: Some("#178600"), group: None }),
+        ("Volt", Language { name: "Volt", language_type: LanguageType::Programming, color: Some("#1F1F1F"), group: None }),
+        ("API Blueprint", Language { name: "API Blueprint", language_type: LanguageType::Markup, color: Some("#2ACCA8"), group: None }),
+        ("TypeScript", Language { name: "TypeScript", language_type: LanguageType::Programming, color: Some("#2b7489"), group: None }),
+        ("GraphQL", Language { name: "GraphQL", language_type: LanguageType::Data, color: None, group: None }),
+        ("Web Ontology Language", Language { name: "Web Ontology Language", language_type: LanguageType::Data, color: None, group: None }),
+        ("DTrace", Language { name: "DTrace", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Glyph Bitmap Distribution Format", Language { name: "Glyph Bitmap Distribution Format", language_type: LanguageType::Data, color: None, group: None }),
+        ("Wollok", Language { name: "Wollok", language_type: LanguageType::Programming, color: Some("#a23738"), group: None }),
+        ("Cabal Config", Language { name: "Cabal Config", language_type: LanguageType::Data, color: None, group: None }),
+        ("Jison", Language { name: "Jison", language_type: LanguageType::Programming, color: None, group: Some("Yacc") }),
+        ("Ren'Py", Language { name: "Ren'Py", language_type: LanguageType::Programming, color: Some("#ff7f7f"), group: None }),
+        ("Public Key", Language { name: "Public Key", language_type: LanguageType::Data, color: None, group: None }),
+        ("HTML+Razor", Language { name: "HTML+Razor", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("JSONiq", Language { name: "JSONiq", language_type: LanguageType::Programming, color: Some("#40d47e"), group: None }),
+        ("CartoCSS", Language { name: "CartoCSS", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Yacc", Language { name: "Yacc", language_type: LanguageType::Programming, color: Some("#4B6C4B"), group: None }),
+        ("FIGlet Font", Language { name: "FIGlet Font", language_type: LanguageType::Data, color: None, group: None }),
+        ("Lex", Language { name: "Lex", language_type: LanguageType::Programming, color: Some("#DBCA00"), group: None }),
+        ("ZenScript", Language { name: "ZenScript", language_type: LanguageType::Programming, color: Some("#00BCD1"), group: None }),
+        ("SAS", Language { name: "SAS", language_type: LanguageType::Programming, color: Some("#B34936"), group: None }),
+        ("NetLinx", Language { name: "NetLinx", language_type: LanguageType::Programming, color: Some("#0aa0ff"), group: None }),
+        ("HTML+EEX", Language { name: "HTML+EEX", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("Inno Setup", Language { name: "Inno Setup", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Creole", Language { name: "Creole", language_type: LanguageType::Prose, color: None, group: None }),
+        ("Pep8", Language { name: "Pep8", language_type: LanguageType::Programming, color: Some("#C76F5B"), group: None }),
+        ("Twig", Language { name: "Twig", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("RDoc", Language { name: "RDoc", language_type: LanguageType::Prose, color: None, group: None }),
         ("PLSQL", Language { name: "PLSQL", language_type: LanguageType::Programming, color: Some("#dad8d8"), group: None }),
-        ("Squirrel", Language { name: "Squirrel", language_type: LanguageType::Programming, color: Some("#800000"), group: None }),
-        ("Agda", Language { name: "Agda", language_type: LanguageType::Programming, color: Some("#315665"), group: None }),
-        ("Git Config", Language { name: "Git Config", language_type: LanguageType::Data, color: None, group: Some("INI") }),
+        ("AngelScript", Language { name: "AngelScript", language_type: LanguageType::Programming, color: Some("#C7D7DC"), group: None }),
+        ("Nemerle", Language { name: "Nemerle", language_type: LanguageType::Programming, color: Some("#3d3c6e"), group: None }),
+        ("IGOR Pro", Language { name: "IGOR Pro", language_type: LanguageType::Programming, color: Some("#0000cc"), group: None }),
+        ("JSON", Language { name: "JSON", language_type: LanguageType::Data, color: None, group: None }),
+        ("Opal", Language { name: "Opal", language_type: LanguageType::Programming, color: Some("#f7ede0"), group: None }),
+        ("ooc", Language { name: "ooc", language_type: LanguageType::Programming, color: Some("#b0b77e"), group: None }),
+        ("SQF", Language { name: "SQF", language_type: LanguageType::Programming, color: Some("#3F3F3F"), group: None }),
+        ("Brainfuck", Language { name: "Brainfuck", language_type: LanguageType::Programming, color: Some("#2F2530"), group: None }),
+        ("Golo", Language { name: "Golo", language_type: LanguageType::Programming, color: Some("#88562A"), group: None }),
+        ("Nextflow", Language { name: "Nextflow", language_type: LanguageType::Programming, color: Some("#3ac486"), group: None }),
+        ("Java Server Pages", Language { name: "Java Server Pages", language_type: LanguageType::Programming, color: None, group: Some("Java") }),
+        ("Befunge", Language { name: "Befunge", language_type: LanguageType::Programming, color: None, group: None }),
+        ("UrWeb", Language { name: "UrWeb", language_type: LanguageType::Programming, color: None, group: None }),
         ("LilyPond", Language { name: "LilyPond", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Coq", Language { name: "Coq", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Cython", Language { name: "Cython", language_type: LanguageType::Programming, color: None, group: Some("Python") }),
-        ("Pep8", Language { name: "Pep8", language_type: LanguageType::Programming, color: Some("#C76F5B"), group: None }),
-        ("FIGlet Font", Language { name: "FIGlet Font", language_type: LanguageType::Data, color: None, group: None }),
-        ("ZAP", Language { name: "ZAP", language_type: LanguageType::Programming, color: Some("#0d665e"), group: None }),
-        ("Red", Language { name: "Red", language_type: LanguageType::Programming, color: Some("#f50000"), group: None }),
-        ("Parrot", Language { name: "Parrot", language_type: LanguageType::Programming, color: Some("#f3ca0a"), group: None }),
-        ("Pure Data", Language { name: "Pure Data", language_type: LanguageType::Data, color: None, group: None }),
-        ("Type Language", Language { name: "Type Language", language_type: LanguageType::Data, color: None, group: None }),
-        ("Apex", Language { name: "Apex", language_type: LanguageType::Programming, color: None, group: None }),
-        ("J", Language { name: "J", language_type: LanguageType::Programming, color: Some("#9EEDFF"), group: None }),
+        ("XProc", Language { name: "XProc", language_type: LanguageType::Programming, color: None, group: None }),
         ("COLLADA", Language { name: "COLLADA", language_type: LanguageType::Data, color: None, group: None }),
-        ("Jupyter Notebook", Language { name: "Jupyter Notebook", language_type: LanguageType::Markup, color: Some("#DA5B0B"), group: None }),
-        ("CSON", Language { name: "CSON", language_type: LanguageType::Data, color: None, group: None }),
-        ("TI Program", Language { name: "TI Program", language_type: LanguageType::Programming, color: Some("#A0AA87"), group: None }),
-        ("SWIG", Language { name: "SWIG", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Nim", Language { name: "Nim", language_type: LanguageType::Programming, color: Some("#37775b"), group: None }),
-        ("TLA", Language { name: "TLA", language_type: LanguageType::Programming, color: None, group: None }),
-        ("YANG", Language { name: "YANG", language_type: LanguageType::Data, color: None, group: None }),
-        ("X Font Directory Index", Language { name: "X Font Directory Index", language_type: LanguageType::Data, color: None, group: None }),
-        ("Ada", Language { name: "Ada", language_type: LanguageType::Programming, color: Some("#02f88c"), group: None }),
-        ("GAML", Language { name: "GAML", language_type: LanguageType::Programming, color: Some("#FFC766"), group: None }),
-        ("HTML+Django", Language { name: "HTML+Django", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("Cirru", Language { name: "Cirru", language_type: LanguageType::Programming, color: Some("#ccccff"), group: None }),
+        ("Altium Designer", Language { name: "Altium Designer", language_type: LanguageType::Data, color: None, group: None }),
+        ("Pure Data", Language { name: "Pure Data", language_type: LanguageType::Data, color: None, group: None }),
+        ("ShellSession", Language { name: "ShellSession", language_type: LanguageType::Programming, color: None, group: None }),
+        ("RenderScript", Language { name: "RenderScript", language_type: LanguageType::Programming, color: None, group: None }),
         ("Mask", Language { name: "Mask", language_type: LanguageType::Markup, color: Some("#f97732"), group: None }),
-        ("Common Workflow Language", Language { name: "Common Workflow Language", language_type: LanguageType::Programming, color: Some("#B5314C"), group: None }),
-        ("VCL", Language { name: "VCL", language_type: LanguageType::Programming, color: Some("#148AA8"), group: None }),
-        ("Eagle", Language { name: "Eagle", language_type: LanguageType::Data, color: None, group: None }),
-        ("PostCSS", Language { name: "PostCSS", language_type: LanguageType::Markup, color: None, group: Some("CSS") }),
+        ("Hy", Language { name: "Hy", language_type: LanguageType::Programming, color: Some("#7790B2"), group: None }),
+        ("Mercury", Language { name: "Mercury", language_type: LanguageType::Programming, color: Some("#ff2b2b"), group: None }),
+        ("G-code", Language { name: "G-code", language_type: LanguageType::Programming, color: Some("#D08CF2"), group: None }),
+        ("Fantom", Language { name: "Fantom", language_type: LanguageType::Programming, color: Some("#14253c"), group: None }),
+        ("RPC", Language { name: "RPC", language_type: LanguageType::Programming, color: None, group: None }),
+        ("OpenQASM", Language { name: "OpenQASM", language_type: LanguageType::Programming, color: Some("#AA70FF"), group: None }),
+        ("D-ObjDump", Language { name: "D-ObjDump", language_type: LanguageType::Data, color: None, group: None }),
+        ("CLIPS", Language { name: "CLIPS", language_type: LanguageType::Programming, color: None, group: None }),
+        ("SSH Config", Language { name: "SSH Config", language_type: LanguageType::Data, color: None, group: Some("INI") }),
+        ("LabVIEW", Language { name: "LabVIEW", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Unified Parallel C", Language { name: "Unified Parallel C", language_type: LanguageType::Programming, color: None, group: Some("C") }),
+        ("Isabelle", Language { name: "Isabelle", language_type: LanguageType::Programming, color: Some("#FEFE00"), group: None }),
+        ("Vim Snippet", Language { name: "Vim Snippet", language_type: LanguageType::Markup, color: None, group: None }),
+        ("SugarSS", Language { name: "SugarSS", language_type: LanguageType::Markup, color: None, group: Some("CSS") }),
+        ("NCL", Language { name: "NCL", language_type: LanguageType::Programming, color: Some("#28431f"), group: None }),
+        ("DirectX 3D File", Language { name: "DirectX 3D File", language_type: LanguageType::Data, color: None, group: None }),
+        ("LOLCODE", Language { name: "LOLCODE", language_type: LanguageType::Programming, color: Some("#cc9900"), group: None }),
+        ("Fancy", Language { name: "Fancy", language_type: LanguageType::Programming, color: Some("#7b9db4"), group: None }),
+        ("Gettext Catalog", Language { name: "Gettext Catalog", language_type: LanguageType::Prose, color: None, group: None }),
         ("OpenStep Property List", Language { name: "OpenStep Property List", language_type: LanguageType::Data, color: None, group: None }),
-        ("EJS", Language { name: "EJS", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("UrWeb", Language { name: "UrWeb", language_type: LanguageType::Programming, color: None, group: None }),
-        ("ObjectScript", Language { name: "ObjectScript", language_type: LanguageType::Programming, color: Some("#424893"), group: None }),
-        ("LFE", Language { name: "LFE", language_type: LanguageType::Programming, color: Some("#4C3023"), group: None }),
-        ("HTML+ECR", Language { name: "HTML+ECR", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("JavaScript", Language { name: "JavaScript", language_type: LanguageType::Programming, color: Some("#f1e05a"), group: None }),
-        ("Reason", Language { name: "Reason", language_type: LanguageType::Programming, color: Some("#ff5847"), group: None }),
-        ("BibTeX", Language { name: "BibTeX", language_type: LanguageType::Markup, color: None, group: Some("TeX") }),
-        ("Starlark", Language { name: "Starlark", language_type: LanguageType::Programming, color: Some("#76d275"), group: None }),
-        ("Module Management System", Language { name: "Module Management System", language_type: LanguageType::Programming, color: None, group: None }),
-        ("TSQL", Language { name: "TSQL", language_type: LanguageType::Programming, color: None, group: None }),
-        ("RUNOFF", Language { name: "RUNOFF", language_type: LanguageType::Markup, color: Some("#665a4e"), group: None }),
-        ("Cool", Language { name: "Cool", language_type: LanguageType::Programming, color: None, group: None }),
-        ("ECLiPSe", Language { name: "ECLiPSe", language_type: LanguageType::Programming, color: None, group: Some("prolog") }),
-        ("HiveQL", Language { name: "HiveQL", language_type: LanguageType::Programming, color: Some("#dce200"), group: None }),
-        ("XS", Language { name: "XS", language_type: LanguageType::Programming, color: None, group: None }),
-        ("UnrealScript", Language { name: "UnrealScript", language_type: LanguageType::Programming, color: Some("#a54c4d"), group: None }),
-        ("XCompose", Language { name: "XCompose", language_type: LanguageType::Data, color: None, group: None }),
-        ("Lasso", Language { name: "Lasso", language_type: LanguageType::Programming, color: Some("#999999"), group: None }),
-        ("Java", Language { name: "Java", language_type: LanguageType::Programming, color: Some("#b07219"), group: None }),
-        ("MUF", Language { name: "MUF", language_type: LanguageType::Programming, color: None, group: Some("Forth") }),
-        ("Java Server Pages", Language { name: "Java Server Pages", language_type: LanguageType::Programming, color: None, group: Some("Java") }),
-        ("Zig", Language { name: "Zig", language_type: LanguageType::Programming, color: Some("#ec915c"), group: None }),
-        ("HTTP", Language { name: "HTTP", language_type: LanguageType::Data, color: None, group: None }),
-        ("Jolie", Language { name: "Jolie", language_type: LanguageType::Programming, color: Some("#843179"), group: None }),
+        ("Graphviz (DOT)", Language { name: "Graphviz (DOT)", language_type: LanguageType::Data, color: None, group: None }),
+        ("Processing", Language { name: "Processing", language_type: LanguageType::Programming, color: Some("#0096D8"), group: None }),
+        ("Ada", Language { name: "Ada", language_type: LanguageType::Programming, color: Some("#02f88c"), group: None }),
+        ("ATS", Language { name: "ATS", language_type: LanguageType::Programming, color: Some("#1ac620"), group: None }),
+        ("Faust", Language { name: "Faust", language_type: LanguageType::Programming, color: Some("#c37240"), group: None }),
+        ("Go", Language { name: "Go", language_type: LanguageType::Programming, color: Some("#00ADD8"), group: None }),
         ("CoffeeScript", Language { name: "CoffeeScript", language_type: LanguageType::Programming, color: Some("#244776"), group: None }),
-        ("HTML+ERB", Language { name: "HTML+ERB", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("Bison", Language { name: "Bison", language_type: LanguageType::Programming, color: None, group: Some("Yacc") }),
-        ("JSON5", Language { name: "JSON5", language_type: LanguageType::Data, color: None, group: None }),
-        ("BitBake", Language { name: "BitBake", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Python console", Language { name: "Python console", language_type: LanguageType::Programming, color: None, group: Some("Python") }),
-        ("Rust", Language { name: "Rust", language_type: LanguageType::Programming, color: Some("#dea584"), group: None }),
-        ("C", Language { name: "C", language_type: LanguageType::Programming, color: Some("#555555"), group: None }),
-        ("Creole", Language { name: "Creole", language_type: LanguageType::Prose, color: None, group: None }),
-        ("Idris", Language { name: "Idris", language_type: LanguageType::Programming, color: Some("#b30000"), group: None }),
-        ("Web Ontology Language", Language { name: "Web Ontology Language", language_type: LanguageType::Data, color: None, group: None }),
-        ("OpenEdge ABL", Language { name: "OpenEdge ABL", language_type: LanguageType::Programming, color: None, group: None }),
-        ("POV-Ray SDL", Language { name: "POV-Ray SDL", language_type: LanguageType::Programming, color: None, group: None }),
-        ("RPM Spec", Language { name: "RPM Spec", language_type: LanguageType::Data, color: None, group: None }),
+        ("Logtalk", Language { name: "Logtalk", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Dylan", Language { name: "Dylan", language_type: LanguageType::Programming, color: Some("#6c616e"), group: None }),
+        ("mcfunction", Language { name: "mcfunction", language_type: LanguageType::Programming, color: Some("#E22837"), group: None }),
+        ("Common Workflow Language", Language { name: "Common Workflow Language", language_type: LanguageType::Programming, color: Some("#B5314C"), group: None }),
         ("KiCad Schematic", Language { name: "KiCad Schematic", language_type: LanguageType::Data, color: None, group: None }),
-        ("mIRC Script", Language { name: "mIRC Script", language_type: LanguageType::Programming, color: Some("#926059"), group: None }),
-        ("Forth", Language { name: "Forth", language_type: LanguageType::Programming, color: Some("#341708"), group: None }),
-        ("HXML", Language { name: "HXML", language_type: LanguageType::Data, color: None, group: None }),
-        ("Cpp-ObjDump", Language { name: "Cpp-ObjDump", language_type: LanguageType::Data, color: None, group: None }),
-        ("M4", Language { name: "M4", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Rich Text Format", Language { name: "Rich Text Format", language_type: LanguageType::Markup, color: None, group: None }),
-        ("Parrot Internal Representation", Language { name: "Parrot Internal Representation", language_type: LanguageType::Programming, color: None, group: Some("Parrot") }),
-        ("Asymptote", Language { name: "Asymptote", language_type: LanguageType::Programming, color: Some("#4a0c0c"), group: None }),
-        ("PlantUML", Language { name: "PlantUML", language_type: LanguageType::Data, color: None, group: None }),
-        ("GLSL", Language { name: "GLSL", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Assembly", Language { name: "Assembly", language_type: LanguageType::Programming, color: Some("#6E4C13"), group: None }),
-        ("E", Language { name: "E", language_type: LanguageType::Programming, color: Some("#ccce35"), group: None }),
-        ("X PixMap", Language { name: "X PixMap", language_type: LanguageType::Data, color: None, group: Some("C") }),
-        ("Fantom", Language { name: "Fantom", language_type: LanguageType::Programming, color: Some("#14253c"), group: None }),
-        ("MLIR", Language { name: "MLIR", language_type: LanguageType::Programming, color: Some("#5EC8DB"), group: None }),
-        ("Haskell", Language { name: "Haskell", language_type: LanguageType::Programming, color: Some("#5e5086"), group: None }),
-        ("REXX", Language { name: "REXX", language_type: LanguageType::Programming, color: None, group: None }),
-        ("X10", Language { name: "X10", language_type: LanguageType::Programming, color: Some("#4B6BEF"), group: None }),
-        ("Clean", Language { name: "Clean", language_type: LanguageType::Programming, color: Some("#3F85AF"), group: None }),
-        ("Odin", Language { name: "Odin", language_type: LanguageType::Programming, color: Some("#60AFFE"), group: None }),
-        ("q", Language { name: "q", language_type: LanguageType::Programming, color: Some("#0040cd"), group: None }),
-        ("INI", Language { name: "INI", language_type: LanguageType::Data, color: None, group: None }),
-        ("NPM Config", Language { name: "NPM Config", language_type: LanguageType::Data, color: None, group: Some("INI") }),
+        ("GN", Language { name: "GN", language_type: LanguageType::Data, color: None, group: None }),
+        ("EditorConfig", Language { name: "EditorConfig", language_type: LanguageType::Data, color: None, group: Some("INI") }),
+        ("Gradle", Language { name: "Gradle", language_type: LanguageType::Data, color: None, group: None }),
+        ("Sass", Language { name: "Sass", language_type: LanguageType::Markup, color: None, group: Some("CSS") }),
+        ("AspectJ", Language { name: "AspectJ", language_type: LanguageType::Programming, color: Some("#a957b0"), group: None }),
+        ("Wget Config", Language { name: "Wget Config", language_type: LanguageType::Data, color: None, group: Some("INI") }),
+        ("GAMS", Language { name: "GAMS", language_type: LanguageType::Programming, color: None, group: None }),
+        ("M4Sugar", Language { name: "M4Sugar", language_type: LanguageType::Programming, color: None, group: Some("M4") }),
+        ("FreeMarker", Language { name: "FreeMarker", language_type: LanguageType::Programming, color: Some("#0050b2"), group: None }),
+        ("REALbasic", Language { name: "REALbasic", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Agda", Language { name: "Agda", language_type: LanguageType::Programming, color: Some("#315665"), group: None }),
+        ("Formatted", Language { name: "Formatted", language_type: LanguageType::Data, color: None, group: None }),
         ("Turtle", Language { name: "Turtle", language_type: LanguageType::Data, color: None, group: None }),
-        ("AsciiDoc", Language { name: "AsciiDoc", language_type: LanguageType::Prose, color: None, group: None }),
-        ("PigLatin", Language { name: "PigLatin", language_type: LanguageType::Programming, color: Some("#fcd7de"), group: None }),
-        ("SugarSS", Language { name: "SugarSS", language_type: LanguageType::Markup, color: None, group: Some("CSS") }),
-        ("LiveScript", Language { name: "LiveScript", language_type: LanguageType::Programming, color: Some("#499886"), group: None }),
-        ("Pawn", Language { name: "Pawn", language_type: LanguageType::Programming, color: Some("#dbb284"), group: None }),
-        ("Clojure", Language { name: "Clojure", language_type: LanguageType::Programming, color: Some("#db5855"), group: None }),
-        ("Hy", Language { name: "Hy", language_type: LanguageType::Programming, color: Some("#7790B2"), group: None }),
-        ("Csound Document", Language { name: "Csound Document", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Erlang", Language { name: "Erlang", language_type: LanguageType::Programming, color: Some("#B83998"), group: None }),
-        ("JSX", Language { name: "JSX", language_type: LanguageType::Programming, color: None, group: Some("JavaScript") }),
-        ("Roff", Language { name: "Roff", language_type: LanguageType::Markup, color: Some("#ecdebe"), group: None }),
+        ("GDB", Language { name: "GDB", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Gherkin", Language { name: "Gherkin", language_type: LanguageType::Programming, color: Some("#5B2063"), group: None }),
+        ("Slash", Language { name: "Slash", language_type: LanguageType::Programming, color: Some("#007eff"), group: None }),
+        ("AppleScript", Language { name: "AppleScript", language_type: LanguageType::Programming, color: Some("#101F1F"), group: None }),
+        ("Nix", Language { name: "Nix", language_type: LanguageType::Programming, color: Some("#7e7eff"), group: None }),
+        ("MoonScript", Language { name: "MoonScript", language_type: LanguageType::Programming, color: None, group: None }),
+        ("TLA", Language { name: "TLA", language_type: LanguageType::Programming, color: None, group: None }),
         ("edn", Language { name: "edn", language_type: LanguageType::Data, color: None, group: None }),
-        ("Fancy", Language { name: "Fancy", language_type: LanguageType::Programming, color: Some("#7b9db4"), group: None }),
-        ("ECL", Language { name: "ECL", language_type: LanguageType::Programming, color: Some("#8a1267"), group: None }),
-        ("GN", Language { name: "GN", language_type: LanguageType::Data, color: None, group: None }),
-        ("Filterscript", Language { name: "Filterscript", language_type: LanguageType::Programming, color: None, group: Some("RenderScript") }),
-        ("Csound", Language { name: "Csound", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Handlebars", Language { name: "Handlebars", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("Swift", Language { name: "Swift", language_type: LanguageType::Programming, color: Some("#ffac45"), group: None }),
-        ("Emacs Lisp", Language { name: "Emacs Lisp", language_type: LanguageType::Programming, color: Some("#c065db"), group: None }),
-        ("Propeller Spin", Language { name: "Propeller Spin", language_type: LanguageType::Programming, color: Some("#7fa2a7"), group: None }),
-        ("Limbo", Language { name: "Limbo", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Diff", Language { name: "Diff", language_type: LanguageType::Data, color: None, group: None }),
-        ("Redcode", Language { name: "Redcode", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Oxygene", Language { name: "Oxygene", language_type: LanguageType::Programming, color: Some("#cdd0e3"), group: None }),
-        ("Lean", Language { name: "Lean", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Befunge", Language { name: "Befunge", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Nearley", Language { name: "Nearley", language_type: LanguageType::Programming, color: Some("#990000"), group: None }),
-        ("Riot", Language { name: "Riot", language_type: LanguageType::Markup, color: Some("#A71E49"), group: None }),
-        ("RHTML", Language { name: "RHTML", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("RPC", Language { name: "RPC", language_type: LanguageType::Programming, color: None, group: None }),
-        ("EmberScript", Language { name: "EmberScript", language_type: LanguageType::Programming, color: Some("#FFF4F3"), group: None }),
-        ("AGS Script", Language { name: "AGS Script", language_type: LanguageType::Programming, color: Some("#B9D9FF"), group: None }),
+        ("cURL Config", Language { name: "cURL Config", language_type: LanguageType::Data, color: None, group: Some("INI") }),
+        ("Pug", Language { name: "Pug", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("Raw token data", Language { name: "Raw token data", language_type: LanguageType::Data, color: None, group: None }),
+        ("Linux Kernel Module", Language { name: "Linux Kernel Module", language_type: LanguageType::Data, color: None, group: None }),
+        ("INI", Language { name: "INI", language_type: LanguageType::Data, color: None, group: None }),
+        ("GCC Machine Description", Language { name: "GCC Machine Description", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Type Language", Language { name: "Type Language", language_type: LanguageType::Data, color: None, group: None }),
+        ("Cython", Language { name: "Cython", language_type: LanguageType::Programming, color: None, group: Some("Python") }),
+        ("Shen", Language { name: "Shen", language_type: LanguageType::Programming, color: Some("#120F14"), group: None }),
+        ("Cpp-ObjDump", Language { name: "Cpp-ObjDump", language_type: LanguageType::Data, color: None, group: None }),
+        ("SVG", Language { name: "SVG", language_type: LanguageType::Data, color: None, group: None }),
+        ("Unix Assembly", Language { name: "Unix Assembly", language_type: LanguageType::Programming, color: None, group: Some("Assembly") }),
+        ("Smali", Language { name: "Smali", language_type: LanguageType::Programming, color: None, group: None }),
+        ("C++", Language { name: "C++", language_type: LanguageType::Programming, color: Some("#f34b7d"), group: None }),
         ("WebAssembly", Language { name: "WebAssembly", language_type: LanguageType::Programming, color: Some("#04133b"), group: None }),
-        ("Genshi", Language { name: "Genshi", language_type: LanguageType::Programming, color: None, group: None }),
-        ("SQLPL", Language { name: "SQLPL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Clarion", Language { name: "Clarion", language_type: LanguageType::Programming, color: Some("#db901e"), group: None }),
+        ("P4", Language { name: "P4", language_type: LanguageType::Programming, color: Some("#7055b5"), group: None }),
+        ("Readline Config", Language { name: "Readline Config", language_type: LanguageType::Data, color: None, group: Some("INI") }),
+        ("Makefile", Language { name: "Makefile", language_type: LanguageType::Programming, color: Some("#427819"), group: None }),
+        ("MUF", Language { name: "MUF", language_type: LanguageType::Programming, color: None, group: Some("Forth") }),
+        ("LoomScript", Language { name: "LoomScript", language_type: LanguageType::Programming, color: None, group: None }),
+        ("LTspice Symbol", Language { name: "LTspice Symbol", language_type: LanguageType::Data, color: None, group: None }),
+        ("IRC log", Language { name: "IRC log", language_type: LanguageType::Data, color: None, group: None }),
+        ("Org", Language { name: "Org", language_type: LanguageType::Prose, color: None, group: None }),
+        ("Ragel", Language { name: "Ragel", language_type: LanguageType::Programming, color: Some("#9d5200"), group: None }),
+        ("SMT", Language { name: "SMT", language_type: LanguageType::Programming, color: None, group: None }),
+        ("M4", Language { name: "M4", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Module Management System", Language { name: "Module Management System", language_type: LanguageType::Programming, color: None, group: None }),
+        ("LLVM", Language { name: "LLVM", language_type: LanguageType::Programming, color: Some("#185619"), group: None }),
+        ("Nim", Language { name: "Nim", language_type: LanguageType::Programming, color: Some("#37775b"), group: None }),
+        ("Zig", Language { name: "Zig", language_type: LanguageType::Programming, color: Some("#ec915c"), group: None }),
+        ("Go Module", Language { name: "Go Module", language_type: LanguageType::Data, color: Some("#00ADD8"), group: None }),
+        ("WebIDL", Language { name: "WebIDL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("SuperCollider", Language { name: "SuperCollider", language_type: LanguageType::Programming, color: Some("#46390b"), group: None }),
         ("4D", Language { name: "4D", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Protocol Buffer", Language { name: "Protocol Buffer", language_type: LanguageType::Data, color: None, group: None }),
-        ("Microsoft Developer Studio Project", Language { name: "Microsoft Developer Studio Project", language_type: LanguageType::Data, color: None, group: None }),
+        ("Riot", Language { name: "Riot", language_type: LanguageType::Markup, color: Some("#A71E49"), group: None }),
+        ("ActionScript", Language { name: "ActionScript", language_type: LanguageType::Programming, color: Some("#882B0F"), group: None }),
+        ("Lasso", Language { name: "Lasso", language_type: LanguageType::Programming, color: Some("#999999"), group: None }),
+        ("Cool", Language { name: "Cool", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Latte", Language { name: "Latte", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("Vue", Language { name: "Vue", language_type: LanguageType::Markup, color: Some("#2c3e50"), group: None }),
+        ("Ignore List", Language { name: "Ignore List", language_type: LanguageType::Data, color: None, group: Some("INI") }),
+        ("ChucK", Language { name: "ChucK", language_type: LanguageType::Programming, color: None, group: None }),
+        ("STON", Language { name: "STON", language_type: LanguageType::Data, color: None, group: Some("Smalltalk") }),
         ("PostScript", Language { name: "PostScript", language_type: LanguageType::Markup, color: Some("#da291c"), group: None }),
-        ("Gosu", Language { name: "Gosu", language_type: LanguageType::Programming, color: Some("#82937f"), group: None }),
-        ("Pod 6", Language { name: "Pod 6", language_type: LanguageType::Prose, color: None, group: None }),
-        ("SAS", Language { name: "SAS", language_type: LanguageType::Programming, color: Some("#B34936"), group: None }),
-        ("Wollok", Language { name: "Wollok", language_type: LanguageType::Programming, color: Some("#a23738"), group: None }),
-        ("KiCad Layout", Language { name: "KiCad Layout", language_type: LanguageType::Data, color: None, group: None }),
-        ("Gentoo Ebuild", Language { name: "Gentoo Ebuild", language_type: LanguageType::Programming, color: None, group: Some("Shell") }),
-        ("Org", Language { name: "Org", language_type: LanguageType::Prose, color: None, group: None }),
-        ("AutoIt", Language { name: "AutoIt", language_type: LanguageType::Programming, color: Some("#1C3552"), group: None }),
-        ("Quake", Language { name: "Quake", language_type: LanguageType::Programming, color: Some("#882233"), group: None }),
-        ("desktop", Language { name: "desktop", language_type: LanguageType::Data, color: None, group: None }),
-        ("OpenType Feature File", Language { name: "OpenType Feature File", language_type: LanguageType::Data, color: None, group: None }),
-        ("Fortran", Language { name: "Fortran", language_type: LanguageType::Programming, color: Some("#4d41b1"), group: None }),
-        ("Brainfuck", Language { name: "Brainfuck", language_type: LanguageType::Programming, color: Some("#2F2530"), group: None }),
-        ("Ioke", Language { name: "Ioke", language_type: LanguageType::Programming, color: Some("#078193"), group: None }),
+        ("HTML+ERB", Language { name: "HTML+ERB", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("ANTLR", Language { name: "ANTLR", language_type: LanguageType::Programming, color: Some("#9DC3FF"), group: None }),
+        ("C-ObjDump", Language { name: "C-ObjDump", language_type: LanguageType::Data, color: None, group: None }),
         ("Hack", Language { name: "Hack", language_type: LanguageType::Programming, color: Some("#878787"), group: None }),
-        ("PowerBuilder", Language { name: "PowerBuilder", language_type: LanguageType::Programming, color: Some("#8f0f8d"), group: None }),
-        ("ABNF", Language { name: "ABNF", language_type: LanguageType::Data, color: None, group: None }),
-        ("NetLogo", Language { name: "NetLogo", language_type: LanguageType::Programming, color: Some("#ff6375"), group: None }),
-        ("Shen", Language { name: "Shen", language_type: LanguageType::Programming, color: Some("#120F14"), group: None }),
-        ("RAML", Language { name: "RAML", language_type: LanguageType::Markup, color: Some("#77d9fb"), group: None }),
-        ("Readline Config", Language { name: "Readline Config", language_type: LanguageType::Data, color: None, group: Some("INI") }),
-        ("Io", Language { name: "Io", language_type: LanguageType::Programming, color: Some("#a9188d"), group: None }),
-        ("XML", Language { name: "XML", language_type: LanguageType::Data, color: None, group: None }),
-        ("Pickle", Language { name: "Pickle", language_type: LanguageType::Data, color: None, group: None }),
-        ("Muse", Language { name: "Muse", language_type: LanguageType::Prose, color: None, group: None }),
-        ("Solidity", Language { name: "Solidity", language_type: LanguageType::Programming, color: Some("#AA6746"), group: None }),
-        ("Rouge", Language { name: "Rouge", language_type: LanguageType::Programming, color: Some("#cc0088"), group: None }),
-        ("LSL", Language { name: "LSL", language_type: LanguageType::Programming, color: Some("#3d9970"), group: None }),
-        ("SSH Config", Language { name: "SSH Config", language_type: LanguageType::Data, color: None, group: Some("INI") }),
-        ("VHDL", Language { name: "VHDL", language_type: LanguageType::Programming, color: Some("#adb2cb"), group: None }),
-        ("Motorola 68K Assembly", Language { name: "Motorola 68K Assembly", language_type: LanguageType::Programming, color: None, group: Some("Assembly") }),
-        ("Smalltalk", Language { name: "Smalltalk", language_type: LanguageType::Programming, color: Some("#596706"), group: None }),
-        ("Logos", Language { name: "Logos", language_type: LanguageType::Programming, color: None, group: None }),
-        ("EBNF", Language { name: "EBNF", language_type: LanguageType::Data, color: None, group: None }),
-        ("AppleScript", Language { name: "AppleScript", language_type: LanguageType::Programming, color: Some("#101F1F"), group: None }),
-        ("DNS Zone", Language { name: "DNS Zone", language_type: LanguageType::Data, color: None, group: None }),
-        ("World of Warcraft Addon Data", Language { name: "World of Warcraft Addon Data", language_type: LanguageType::Data, color: None, group: None }),
-        ("AutoHotkey", Language { name: "AutoHotkey", language_type: LanguageType::Programming, color: Some("#6594b9"), group: None }),
-        ("HyPhy", Language { name: "HyPhy", language_type: LanguageType::Programming, color: None, group: None }),
-        ("OpenRC runscript", Language { name: "OpenRC runscript", language_type: LanguageType::Programming, color: None, group: Some("Shell") }),
-        ("Vim script", Language { name: "Vim script", language_type: LanguageType::Programming, color: Some("#199f4b"), group: None }),
-        ("Dockerfile", Language { name: "Dockerfile", language_type: LanguageType::Programming, color: Some("#384d54"), group: None }),
-        ("PLpgSQL", Language { name: "PLpgSQL", language_type: LanguageType::Programming, color: None, group: None }),
-        ("EditorConfig", Language { name: "EditorConfig", language_type: LanguageType::Data, color: None, group: Some("INI") }),
-        ("Lex", Language { name: "Lex", language_type: LanguageType::Programming, color: Some("#DBCA00"), group: None }),
-        ("XQuery", Language { name: "XQuery", language_type: LanguageType::Programming, color: Some("#5232e7"), group: None }),
-        ("TypeScript", Language { name: "TypeScript", language_type: LanguageType::Programming, color: Some("#2b7489"), group: None }),
-        ("Ecere Projects", Language { name: "Ecere Projects", language_type: LanguageType::Data, color: None, group: Some("JavaScript") }),
-        ("Genie", Language { name: "Genie", language_type: LanguageType::Programming, color: Some("#fb855d"), group: None }),
-        ("ActionScript", Language { name: "ActionScript", language_type: LanguageType::Programming, color: Some("#882B0F"), group: None }),
-        ("Chapel", Language { name: "Chapel", language_type: LanguageType::Programming, color: Some("#8dc63f"), group: None }),
-        ("Game Maker Language", Language { name: "Game Maker Language", language_type: LanguageType::Programming, color: Some("#71b417"), group: None }),
-        ("RenderScript", Language { name: "RenderScript", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Formatted", Language { name: "Formatted", language_type: LanguageType::Data, color: None, group: None }),
-        ("Raku", Language { name: "Raku", language_type: LanguageType::Programming, color: Some("#0000fb"), group: None }),
-        ("XProc", Language { name: "XProc", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Mako", Language { name: "Mako", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Factor", Language { name: "Factor", language_type: LanguageType::Programming, color: Some("#636746"), group: None }),
-        ("Common Lisp", Language { name: "Common Lisp", language_type: LanguageType::Programming, color: Some("#3fb68b"), group: None }),
-        ("WebIDL", Language { name: "WebIDL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Spline Font Database", Language { name: "Spline Font Database", language_type: LanguageType::Data, color: None, group: None }),
+        ("Python", Language { name: "Python", language_type: LanguageType::Programming, color: Some("#3572A5"), group: None }),
+        ("Rebol", Language { name: "Rebol", language_type: LanguageType::Programming, color: Some("#358a5b"), group: None }),
+        ("Jison Lex", Language { name: "Jison Lex", language_type: LanguageType::Programming, color: None, group: Some("Lex") }),
+        ("ObjectScript", Language { name: "ObjectScript", language_type: LanguageType::Programming, color: Some("#424893"), group: None }),
+        ("AutoIt", Language { name: "AutoIt", language_type: LanguageType::Programming, color: Some("#1C3552"), group: None }),
         ("Textile", Language { name: "Textile", language_type: LanguageType::Prose, color: None, group: None }),
-        ("CoNLL-U", Language { name: "CoNLL-U", language_type: LanguageType::Data, color: None, group: None }),
-        ("Volt", Language { name: "Volt", language_type: LanguageType::Programming, color: Some("#1F1F1F"), group: None }),
-        ("M", Language { name: "M", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Blade", Language { name: "Blade", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("Go Checksums", Language { name: "Go Checksums", language_type: LanguageType::Data, color: Some("#00ADD8"), group: None }),
         ("PowerShell", Language { name: "PowerShell", language_type: LanguageType::Programming, color: Some("#012456"), group: None }),
-        ("Verilog", Language { name: "Verilog", language_type: LanguageType::Programming, color: Some("#b2b7f8"), group: None }),
-        ("Meson", Language { name: "Meson", language_type: LanguageType::Programming, color: Some("#007800"), group: None }),
-        ("NetLinx", Language { name: "NetLinx", language_type: LanguageType::Programming, color: Some("#0aa0ff"), group: None }),
-        ("JSON with Comments", Language { name: "JSON with Comments", language_type: LanguageType::Data, color: None, group: Some("JSON") }),
-        ("LTspice Symbol", Language { name: "LTspice Symbol", language_type: LanguageType::Data, color: None, group: None }),
+        ("sed", Language { name: "sed", language_type: LanguageType::Programming, color: Some("#64b970"), group: None }),
+        ("Xojo", Language { name: "Xojo", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Pod", Language { name: "Pod", language_type: LanguageType::Prose, color: None, group: None }),
+        ("Java", Language { name: "Java", language_type: LanguageType::Programming, color: Some("#b07219"), group: None }),
+        ("Texinfo", Language { name: "Texinfo", language_type: LanguageType::Prose, color: None, group: None }),
+        ("Regular Expression", Language { name: "Regular Expression", language_type: LanguageType::Data, color: None, group: None }),
+        ("NSIS", Language { name: "NSIS", language_type: LanguageType::Programming, color: None, group: None }),
+        ("ApacheConf", Language { name: "ApacheConf", language_type: LanguageType::Data, color: None, group: None }),
+        ("Ballerina", Language { name: "Ballerina", language_type: LanguageType::Programming, color: Some("#FF5000"), group: None }),
+        ("Less", Language { name: "Less", language_type: LanguageType::Markup, color: None, group: Some("CSS") }),
+        ("Opa", Language { name: "Opa", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Dhall", Language { name: "Dhall", language_type: LanguageType::Programming, color: Some("#dfafff"), group: None }),
+        ("Assembly", Language { name: "Assembly", language_type: LanguageType::Programming, color: Some("#6E4C13"), group: None }),
+        ("Reason", Language { name: "Reason", language_type: LanguageType::Programming, color: Some("#ff5847"), group: None }),
+        ("JSONLD", Language { name: "JSONLD", language_type: LanguageType::Data, color: None, group: None }),
+        ("LiveScript", Language { name: "LiveScript", language_type: LanguageType::Programming, color: Some("#499886"), group: None }),
+        ("BlitzMax", Language { name: "BlitzMax", language_type: LanguageType::Programming, color: Some("#cd6400"), group: None }),
+        ("D", Language { name: "D", language_type: LanguageType::Programming, color: Some("#ba595e"), group: None }),
+        ("Idris", Language { name: "Idris", language_type: LanguageType::Programming, color: Some("#b30000"), group: None }),
         ("Ceylon", Language { name: "Ceylon", language_type: LanguageType::Programming, color: Some("#dfa535"), group: None }),
-        ("IGOR Pro", Language { name: "IGOR Pro", language_type: LanguageType::Programming, color: Some("#0000cc"), group: None }),
-        ("Spline Font Database", Language { name: "Spline Font Database", language_type: LanguageType::Data, color: None, group: None }),
-        ("Alloy", Language { name: "Alloy", language_type: LanguageType::Programming, color: Some("#64C800"), group: None }),
-        ("YASnippet", Language { name: "YASnippet", language_type: LanguageType::Markup, color: Some("#32AB90"), group: None }),
-        ("Isabelle", Language { name: "Isabelle", language_type: LanguageType::Programming, color: Some("#FEFE00"), group: None }),
-        ("GDB", Language { name: "GDB", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Adobe Font Metrics", Language { name: "Adobe Font Metrics", language_type: LanguageType::Data, color: None, group: None }),
-        ("Latte", Language { name: "Latte", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("ABNF", Language { name: "ABNF", language_type: LanguageType::Data, color: None, group: None }),
+        ("Protocol Buffer", Language { name: "Protocol Buffer", language_type: LanguageType::Data, color: None, group: None }),
+        ("HTML", Language { name: "HTML", language_type: LanguageType::Markup, color: Some("#e34c26"), group: None }),
+        ("Rouge", Language { name: "Rouge", language_type: LanguageType::Programming, color: Some("#cc0088"), group: None }),
+        ("Arc", Language { name: "Arc", language_type: LanguageType::Programming, color: Some("#aa2afe"), group: None }),
+        ("Zeek", Language { name: "Zeek", language_type: LanguageType::Programming, color: None, group: None }),
+        ("C", Language { name: "C", language_type: LanguageType::Programming, color: Some("#555555"), group: None }),
+        ("VHDL", Language { name: "VHDL", language_type: LanguageType::Programming, color: Some("#adb2cb"), group: None }),
+        ("Edje Data Collection", Language { name: "Edje Data Collection", language_type: LanguageType::Data, color: None, group: None }),
+        ("ABAP", Language { name: "ABAP", language_type: LanguageType::Programming, color: Some("#E8274B"), group: None }),
         ("NL", Language { name: "NL", language_type: LanguageType::Data, color: None, group: None }),
-        ("SQL", Language { name: "SQL", language_type: LanguageType::Data, color: None, group: None }),
-        ("Lua", Language { name: "Lua", language_type: LanguageType::Programming, color: Some("#000080"), group: None }),
-        ("Shell", Language { name: "Shell", language_type: LanguageType::Programming, color: Some("#89e051"), group: None }),
-        ("Gnuplot", Language { name: "Gnuplot", language_type: LanguageType::Programming, color: Some("#f0a9f0"), group: None }),
-        ("SPARQL", Language { name: "SPARQL", language_type: LanguageType::Data, color: None, group: None }),
-        ("Wavefront Material", Language { name: "Wavefront Material", language_type: LanguageType::Data, color: None, group: None }),
-        ("Parrot Assembly", Language { name: "Parrot Assembly", language_type: LanguageType::Programming, color: None, group: Some("Parrot") }),
-        ("OCaml", Language { name: "OCaml", language_type: LanguageType::Programming, color: Some("#3be133"), group: None }),
-        ("Zimpl", Language { name: "Zimpl", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Kit", Language { name: "Kit", language_type: LanguageType::Markup, color: None, group: None }),
-        ("wdl", Language { name: "wdl", language_type: LanguageType::Programming, color: Some("#42f1f4"), group: None }),
-        ("Ninja", Language { name: "Ninja", language_type: LanguageType::Data, color: None, group: None }),
-        ("Ring", Language { name: "Ring", language_type: LanguageType::Programming, color: Some("#2D54CB"), group: None }),
-        ("AspectJ", Language { name: "AspectJ", language_type: LanguageType::Programming, color: Some("#a957b0"), group: None }),
-        ("Cabal Config", Language { name: "Cabal Config", language_type: LanguageType::Data, color: None, group: None }),
-        ("Turing", Language { name: "Turing", language_type: LanguageType::Programming, color: Some("#cf142b"), group: None }),
-        ("TeX", Language { name: "TeX", language_type: LanguageType::Markup, color: Some("#3D6117"), group: None }),
-        ("Awk", Language { name: "Awk", language_type: LanguageType::Programming, color: None, group: None }),
-        ("MediaWiki", Language { name: "MediaWiki", language_type: LanguageType::Prose, color: None, group: None }),
-        ("Glyph", Language { name: "Glyph", language_type: LanguageType::Programming, color: Some("#c1ac7f"), group: None }),
-        ("Haxe", Language { name: "Haxe", language_type: LanguageType::Programming, color: Some("#df7900"), group: None }),
-        ("HLSL", Language { name: "HLSL", language_type: LanguageType::Programming, color: None, group: None }),
-        ("TXL", Language { name: "TXL", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Standard ML", Language { name: "Standard ML", language_type: LanguageType::Programming, color: Some("#dc566d"), group: None }),
-        ("Haml", Language { name: "Haml", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("Python", Language { name: "Python", language_type: LanguageType::Programming, color: Some("#3572A5"), group: None }),
-        ("SuperCollider", Language { name: "SuperCollider", language_type: LanguageType::Programming, color: Some("#46390b"), group: None }),
-        ("C#", Language { name: "C#", language_type: LanguageType::Programming, color: Some("#178600"), group: None }),
-        ("ZenScript", Language { name: "ZenScript", language_type: LanguageType::Programming, color: Some("#00BCD1"), group: None }),
-        ("Nu", Language { name: "Nu", language_type: LanguageType::Programming, color: Some("#c9df40"), group: None }),
-        ("OpenCL", Language { name: "OpenCL", language_type: LanguageType::Programming, color: None, group: Some("C") }),
-        ("Puppet", Language { name: "Puppet", language_type: LanguageType::Programming, color: Some("#302B6D"), group: None }),
-        ("Texinfo", Language { name: "Texinfo", language_type: LanguageType::Prose, color: None, group: None }),
-        ("API Blueprint", Language { name: "API Blueprint", language_type: LanguageType::Markup, color: Some("#2ACCA8"), group: None }),
-        ("Scala", Language { name: "Scala", language_type: LanguageType::Programming, color: Some("#c22d40"), group: None }),
-        ("Linker Script", Language { name: "Linker Script", language_type: LanguageType::Data, color: None, group: None }),
-        ("Closure Templates", Language { name: "Closure Templates", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("Ragel", Language { name: "Ragel", language_type: LanguageType::Programming, color: Some("#9d5200"), group: None }),
-        ("Roff Manpage", Language { name: "Roff Manpage", language_type: LanguageType::Markup, color: None, group: Some("Roff") }),
+        ("PostCSS", Language { name: "PostCSS", language_type: LanguageType::Markup, color: None, group: Some("CSS") }),
+        ("Moocode", Language { name: "Moocode", language_type: LanguageType::Programming, color: None, group: None }),
+        ("AGS Script", Language { name: "AGS Script", language_type: LanguageType::Programming, color: Some("#B9D9FF"), group: None }),
+        ("OpenType Feature File", Language { name: "OpenType Feature File", language_type: LanguageType::Data, color: None, group: None }),
+        ("Csound Score", Language { name: "Csound Score", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Smarty", Language { name: "Smarty", language_type: LanguageType::Programming, color: None, group: None }),
+        ("GLSL", Language { name: "GLSL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Clean", Language { name: "Clean", language_type: LanguageType::Programming, color: Some("#3F85AF"), group: None }),
+        ("Monkey", Language { name: "Monkey", language_type: LanguageType::Programming, color: None, group: None }),
+        ("KRL", Language { name: "KRL", language_type: LanguageType::Programming, color: Some("#28430A"), group: None }),
+        ("Ox", Language { name: "Ox", language_type: LanguageType::Programming, color: None, group: None }),
+        ("CSS", Language { name: "CSS", language_type: LanguageType::Markup, color: Some("#563d7c"), group: None }),
+        ("XSLT", Language { name: "XSLT", language_type: LanguageType::Programming, color: Some("#EB8CEB"), group: None }),
+        ("Text", Language { name: "Text", language_type: LanguageType::Prose, color: None, group: None }),
+        ("Forth", Language { name: "Forth", language_type: LanguageType::Programming, color: Some("#341708"), group: None }),
+        ("SourcePawn", Language { name: "SourcePawn", language_type: LanguageType::Programming, color: Some("#5c7611"), group: None }),
+        ("MiniD", Language { name: "MiniD", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Oz", Language { name: "Oz", language_type: LanguageType::Programming, color: Some("#fab738"), group: None }),
+        ("Scilab", Language { name: "Scilab", language_type: LanguageType::Programming, color: None, group: None }),
+        ("NetLogo", Language { name: "NetLogo", language_type: LanguageType::Programming, color: Some("#ff6375"), group: None }),
+        ("Open Policy Agent", Language { name: "Open Policy Agent", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Astro", Language { name: "Astro", language_type: LanguageType::Markup, color: Some("#ff5a03"), group: None }),
+        ("SmPL", Language { name: "SmPL", language_type: LanguageType::Programming, color: Some("#c94949"), group: None }),
+        ("MQL4", Language { name: "MQL4", language_type: LanguageType::Programming, color: Some("#62A8D6"), group: None }),
+        ("XS", Language { name: "XS", language_type: LanguageType::Programming, color: None, group: None }),
+        ("TOML", Language { name: "TOML", language_type: LanguageType::Data, color: None, group: None }),
+        ("SQLPL", Language { name: "SQLPL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Limbo", Language { name: "Limbo", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Racket", Language { name: "Racket", language_type: LanguageType::Programming, color: Some("#3c5caa"), group: None }),
         ("Literate Haskell", Language { name: "Literate Haskell", language_type: LanguageType::Programming, color: None, group: Some("Haskell") }),
-        ("Thrift", Language { name: "Thrift", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Crystal", Language { name: "Crystal", language_type: LanguageType::Programming, color: Some("#000100"), group: None }),
+        ("Dogescript", Language { name: "Dogescript", language_type: LanguageType::Programming, color: Some("#cca760"), group: None }),
+        ("Tea", Language { name: "Tea", language_type: LanguageType::Markup, color: None, group: None }),
         ("NewLisp", Language { name: "NewLisp", language_type: LanguageType::Programming, color: Some("#87AED7"), group: None }),
-        ("Stata", Language { name: "Stata", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Gerber Image", Language { name: "Gerber Image", language_type: LanguageType::Data, color: None, group: None }),
-        ("Gettext Catalog", Language { name: "Gettext Catalog", language_type: LanguageType::Prose, color: None, group: None }),
-        ("Smarty", Language { name: "Smarty", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Pascal", Language { name: "Pascal", language_type: LanguageType::Programming, color: Some("#E3F171"), group: None }),
-        ("Stylus", Language { name: "Stylus", language_type: LanguageType::Markup, color: None, group: Some("CSS") }),
-        ("YARA", Language { name: "YARA", language_type: LanguageType::Programming, color: Some("#220000"), group: None }),
-        ("Less", Language { name: "Less", language_type: LanguageType::Markup, color: None, group: Some("CSS") }),
-        ("BlitzMax", Language { name: "BlitzMax", language_type: LanguageType::Programming, color: Some("#cd6400"), group: None }),
-        ("Groovy", Language { name: "Groovy", language_type: LanguageType::Programming, color: Some("#e69f56"), group: None }),
-        ("F#", Language { name: "F#", language_type: LanguageType::Programming, color: Some("#b845fc"), group: None }),
-        ("ASP", Language { name: "ASP", language_type: LanguageType::Programming, color: Some("#6a40fd"), group: None }),
-        ("HTML+Razor", Language { name: "HTML+Razor", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("BlitzBasic", Language { name: "BlitzBasic", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Scaml", Language { name: "Scaml", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("RHTML", Language { name: "RHTML", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("MLIR", Language { name: "MLIR", language_type: LanguageType::Programming, color: Some("#5EC8DB"), group: None }),
+        ("Handlebars", Language { name: "Handlebars", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("Objective-C", Language { name: "Objective-C", language_type: LanguageType::Programming, color: Some("#438eff"), group: None }),
+        ("R", Language { name: "R", language_type: LanguageType::Programming, color: Some("#198CE7"), group: None }),
+        ("Java Properties", Language { name: "Java Properties", language_type: LanguageType::Data, color: None, group: None }),
+        ("SCSS", Language { name: "SCSS", language_type: LanguageType::Markup, color: None, group: Some("CSS") }),
+        ("Parrot Internal Representation", Language { name: "Parrot Internal Representation", language_type: LanguageType::Programming, color: None, group: Some("Parrot") }),
+        ("Self", Language { name: "Self", language_type: LanguageType::Programming, color: Some("#0579aa"), group: None }),
+        ("Quake", Language { name: "Quake", language_type: LanguageType::Programming, color: Some("#882233"), group: None }),
+        ("Jolie", Language { name: "Jolie", language_type: LanguageType::Programming, color: Some("#843179"), group: None }),
+        ("Solidity", Language { name: "Solidity", language_type: LanguageType::Programming, color: Some("#AA6746"), group: None }),
+        ("SQL", Language { name: "SQL", language_type: LanguageType::Data, color: None, group: None }),
+        ("Ring", Language { name: "Ring", language_type: LanguageType::Programming, color: Some("#2D54CB"), group: None }),
+        ("TI Program", Language { name: "TI Program", language_type: LanguageType::Programming, color: Some("#A0AA87"), group: None }),
+        ("Gentoo Eclass", Language { name: "Gentoo Eclass", language_type: LanguageType::Programming, color: None, group: Some("Shell") }),
+        ("ZAP", Language { name: "ZAP", language_type: LanguageType::Programming, color: Some("#0d665e"), group: None }),
+        ("Ecere Projects", Language { name: "Ecere Projects", language_type: LanguageType::Data, color: None, group: Some("JavaScript") }),
+        ("Gnuplot", Language { name: "Gnuplot", language_type: LanguageType::Programming, color: Some("#f0a9f0"), group: None }),
+        ("Lean", Language { name: "Lean", language_type: LanguageType::Programming, color: None, group: None }),
+        ("J", Language { name: "J", language_type: LanguageType::Programming, color: Some("#9EEDFF"), group: None }),
+        ("Odin", Language { name: "Odin", language_type: LanguageType::Programming, color: Some("#60AFFE"), group: None }),
+        ("Elm", Language { name: "Elm", language_type: LanguageType::Programming, color: Some("#60B5CC"), group: None }),
+        ("Gosu", Language { name: "Gosu", language_type: LanguageType::Programming, color: Some("#82937f"), group: None }),
+        ("Pike", Language { name: "Pike", language_type: LanguageType::Programming, color: Some("#005390"), group: None }),
+        ("POV-Ray SDL", Language { name: "POV-Ray SDL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Smalltalk", Language { name: "Smalltalk", language_type: LanguageType::Programming, color: Some("#596706"), group: None }),
+        ("Csound", Language { name: "Csound", language_type: LanguageType::Programming, color: None, group: None }),
+        ("NetLinx+ERB", Language { name: "NetLinx+ERB", language_type: LanguageType::Programming, color: Some("#747faa"), group: None }),
+        ("Verilog", Language { name: "Verilog", language_type: LanguageType::Programming, color: Some("#b2b7f8"), group: None }),
+        ("Parrot", Language { name: "Parrot", language_type: LanguageType::Programming, color: Some("#f3ca0a"), group: None }),
+        ("E", Language { name: "E", language_type: LanguageType::Programming, color: Some("#ccce35"), group: None }),
         ("Prisma", Language { name: "Prisma", language_type: LanguageType::Data, color: None, group: None }),
-        ("Modula-3", Language { name: "Modula-3", language_type: LanguageType::Programming, color: Some("#223388"), group: None }),
-        ("Charity", Language { name: "Charity", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Nix", Language { name: "Nix", language_type: LanguageType::Programming, color: Some("#7e7eff"), group: None }),
-        ("LOLCODE", Language { name: "LOLCODE", language_type: LanguageType::Programming, color: Some("#cc9900"), group: None }),
-        ("Rascal", Language { name: "Rascal", language_type: LanguageType::Programming, color: Some("#fffaa0"), group: None }),
-        ("Opal", Language { name: "Opal", language_type: LanguageType::Programming, color: Some("#f7ede0"), group: None }),
-        ("SVG", Language { name: "SVG", language_type: LanguageType::Data, color: None, group: None }),
-        ("DM", Language { name: "DM", language_type: LanguageType::Programming, color: Some("#447265"), group: None }),
-        ("Dogescript", Language { name: "Dogescript", language_type: LanguageType::Programming, color: Some("#cca760"), group: None }),
-        ("Zephir", Language { name: "Zephir", language_type: LanguageType::Programming, color: Some("#118f9e"), group: None }),
-        ("xBase", Language { name: "xBase", language_type: LanguageType::Programming, color: Some("#403a40"), group: None }),
-        ("FreeMarker", Language { name: "FreeMarker", language_type: LanguageType::Programming, color: Some("#0050b2"), group: None }),
+        ("PowerBuilder", Language { name: "PowerBuilder", language_type: LanguageType::Programming, color: Some("#8f0f8d"), group: None }),
+        ("HTML+ECR", Language { name: "HTML+ECR", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("Puppet", Language { name: "Puppet", language_type: LanguageType::Programming, color: Some("#302B6D"), group: None }),
+        ("MediaWiki", Language { name: "MediaWiki", language_type: LanguageType::Prose, color: None, group: None }),
+        ("SaltStack", Language { name: "SaltStack", language_type: LanguageType::Programming, color: Some("#646464"), group: None }),
+        ("Markdown", Language { name: "Markdown", language_type: LanguageType::Prose, color: None, group: None }),
+        ("JSON5", Language { name: "JSON5", language_type: LanguageType::Data, color: None, group: None }),
+        ("Gentoo Ebuild", Language { name: "Gentoo Ebuild", language_type: LanguageType::Programming, color: None, group: Some("Shell") }),
+        ("WebVTT", Language { name: "WebVTT", language_type: LanguageType::Data, color: None, group: None }),
+        ("SWIG", Language { name: "SWIG", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Fortran", Language { name: "Fortran", language_type: LanguageType::Programming, color: Some("#4d41b1"), group: None }),
         ("MAXScript", Language { name: "MAXScript", language_type: LanguageType::Programming, color: Some("#00a6a6"), group: None }),
-        ("Bluespec", Language { name: "Bluespec", language_type: LanguageType::Programming, color: None, group: None }),
-        ("nanorc", Language { name: "nanorc", language_type: LanguageType::Data, color: None, group: Some("INI") }),
-        ("JSONLD", Language { name: "JSONLD", language_type: LanguageType::Data, color: None, group: None }),
-        ("Harbour", Language { name: "Harbour", language_type: LanguageType::Programming, color: Some("#0e60e3"), group: None }),
-        ("Unified Parallel C", Language { name: "Unified Parallel C", language_type: LanguageType::Programming, color: None, group: Some("C") }),
-        ("PicoLisp", Language { name: "PicoLisp", language_type: LanguageType::Programming, color: None, group: None }),
-        ("JSONiq", Language { name: "JSONiq", language_type: LanguageType::Programming, color: Some("#40d47e"), group: None }),
-        ("Batchfile", Language { name: "Batchfile", language_type: LanguageType::Programming, color: Some("#C1F12E"), group: None }),
-        ("Augeas", Language { name: "Augeas", language_type: LanguageType::Programming, color: None, group: None }),
-        ("D", Language { name: "D", language_type: LanguageType::Programming, color: Some("#ba595e"), group: None }),
-        ("Inform 7", Language { name: "Inform 7", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Modelica", Language { name: "Modelica", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Wavefront Material", Language { name: "Wavefront Material", language_type: LanguageType::Data, color: None, group: None }),
+        ("Swift", Language { name: "Swift", language_type: LanguageType::Programming, color: Some("#ffac45"), group: None }),
         ("EQ", Language { name: "EQ", language_type: LanguageType::Programming, color: Some("#a78649"), group: None }),
-        ("mcfunction", Language { name: "mcfunction", language_type: LanguageType::Programming, color: Some("#E22837"), group: None }),
-        ("Apollo Guidance Computer", Language { name: "Apollo Guidance Computer", language_type: LanguageType::Programming, color: None, group: Some("Assembly") }),
-        ("ColdFusion CFC", Language { name: "ColdFusion CFC", language_type: LanguageType::Programming, color: None, group: Some("ColdFusion") }),
-        ("Edje Data Collection", Language { name: "Edje Data Collection", language_type: LanguageType::Data, color: None, group: None }),
+        ("Nu", Language { name: "Nu", language_type: LanguageType::Programming, color: Some("#c9df40"), group: None }),
         ("KiCad Legacy Layout", Language { name: "KiCad Legacy Layout", language_type: LanguageType::Data, color: None, group: None }),
-        ("dircolors", Language { name: "dircolors", language_type: LanguageType::Data, color: None, group: None }),
-        ("Sass", Language { name: "Sass", language_type: LanguageType::Markup, color: None, group: Some("CSS") }),
-        ("ANTLR", Language { name: "ANTLR", language_type: LanguageType::Programming, color: Some("#9DC3FF"), group: None }),
-        ("NSIS", Language { name: "NSIS", language_type: LanguageType::Programming, color: None, group: None }),
-        ("ShaderLab", Language { name: "ShaderLab", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Filebench WML", Language { name: "Filebench WML", language_type: LanguageType::Programming, color: None, group: None }),
-        ("mupad", Language { name: "mupad", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Glyph Bitmap Distribution Format", Language { name: "Glyph Bitmap Distribution Format", language_type: LanguageType::Data, color: None, group: None }),
-        ("X BitMap", Language { name: "X BitMap", language_type: LanguageType::Data, color: None, group: Some("C") }),
-        ("Julia", Language { name: "Julia", language_type: LanguageType::Programming, color: Some("#a270ba"), group: None }),
-        ("MTML", Language { name: "MTML", language_type: LanguageType::Markup, color: Some("#b7e1f4"), group: None }),
-        ("Easybuild", Language { name: "Easybuild", language_type: LanguageType::Data, color: None, group: Some("Python") }),
-        ("ColdFusion", Language { name: "ColdFusion", language_type: LanguageType::Programming, color: Some("#ed2cd6"), group: None }),
-        ("Oz", Language { name: "Oz", language_type: LanguageType::Programming, color: Some("#fab738"), group: None }),
-        ("Dhall", Language { name: "Dhall", language_type: LanguageType::Programming, color: Some("#dfafff"), group: None }),
-        ("nesC", Language { name: "nesC", language_type: LanguageType::Programming, color: Some("#94B0C7"), group: None }),
-        ("Stan", Language { name: "Stan", language_type: LanguageType::Programming, color: Some("#b2011d"), group: None }),
-        ("D-ObjDump", Language { name: "D-ObjDump", language_type: LanguageType::Data, color: None, group: None }),
-        ("HTML", Language { name: "HTML", language_type: LanguageType::Markup, color: Some("#e34c26"), group: None }),
-        ("JavaScript+ERB", Language { name: "JavaScript+ERB", language_type: LanguageType::Programming, color: None, group: Some("JavaScript") }),
-        ("QML", Language { name: "QML", language_type: LanguageType::Programming, color: Some("#44a51c"), group: None }),
-        ("Racket", Language { name: "Racket", language_type: LanguageType::Programming, color: Some("#3c5caa"), group: None }),
-        ("HTML+PHP", Language { name: "HTML+PHP", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("Sage", Language { name: "Sage", language_type: LanguageType::Programming, color: None, group: Some("Python") }),
-        ("Darcs Patch", Language { name: "Darcs Patch", language_type: LanguageType::Data, color: None, group: None }),
-        ("Tea", Language { name: "Tea", language_type: LanguageType::Markup, color: None, group: None }),
-        ("Modelica", Language { name: "Modelica", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Vala", Language { name: "Vala", language_type: LanguageType::Programming, color: Some("#fbe5cd"), group: None }),
-        ("Maven POM", Language { name: "Maven POM", language_type: LanguageType::Data, color: None, group: None }),
-        ("wisp", Language { name: "wisp", language_type: LanguageType::Programming, color: Some("#7582D1"), group: None }),
-        ("Prolog", Language { name: "Prolog", language_type: LanguageType::Programming, color: Some("#74283c"), group: None }),
-        ("Python traceback", Language { name: "Python traceback", language_type: LanguageType::Data, color: None, group: Some("Python") }),
-        ("Dart", Language { name: "Dart", language_type: LanguageType::Programming, color: Some("#00B4AB"), group: None }),
-        ("eC", Language { name: "eC", language_type: LanguageType::Programming, color: Some("#913960"), group: None }),
-        ("sed", Language { name: "sed", language_type: LanguageType::Programming, color: Some("#64b970"), group: None }),
-        ("CartoCSS", Language { name: "CartoCSS", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Kotlin", Language { name: "Kotlin", language_type: LanguageType::Programming, color: Some("#F18E33"), group: None }),
-        ("Graph Modeling Language", Language { name: "Graph Modeling Language", language_type: LanguageType::Data, color: None, group: None }),
-        ("HCL", Language { name: "HCL", language_type: LanguageType::Programming, color: None, group: None }),
-        ("NumPy", Language { name: "NumPy", language_type: LanguageType::Programming, color: None, group: Some("Python") }),
-        ("AngelScript", Language { name: "AngelScript", language_type: LanguageType::Programming, color: Some("#C7D7DC"), group: None }),
-        ("Svelte", Language { name: "Svelte", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("Twig", Language { name: "Twig", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("Slice", Language { name: "Slice", language_type: LanguageType::Programming, color: Some("#003fa2"), group: None }),
-        ("Git Attributes", Language { name: "Git Attributes", language_type: LanguageType::Data, color: None, group: Some("INI") }),
-        ("KRL", Language { name: "KRL", language_type: LanguageType::Programming, color: Some("#28430A"), group: None }),
-        ("Ren\'Py", Language { name: "Ren'Py", language_type: LanguageType::Programming, color: Some("#ff7f7f"), group: None }),
-        ("SMT", Language { name: "SMT", language_type: LanguageType::Programming, color: None, group: None }),
-        ("CodeQL", Language { name: "CodeQL", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Processing", Language { name: "Processing", language_type: LanguageType::Programming, color: Some("#0096D8"), group: None }),
-        ("Golo", Language { name: "Golo", language_type: LanguageType::Programming, color: Some("#88562A"), group: None }),
-        ("XC", Language { name: "XC", language_type: LanguageType::Programming, color: Some("#99DA07"), group: None }),
-        ("Nextflow", Language { name: "Nextflow", language_type: LanguageType::Programming, color: Some("#3ac486"), group: None }),
-        ("Uno", Language { name: "Uno", language_type: LanguageType::Programming, color: None, group: None }),
-        ("HolyC", Language { name: "HolyC", language_type: LanguageType::Programming, color: Some("#ffefaf"), group: None }),
-        ("ABAP", Language { name: "ABAP", language_type: LanguageType::Programming, color: Some("#E8274B"), group: None }),
-        ("Mercury", Language { name: "Mercury", language_type: LanguageType::Programming, color: Some("#ff2b2b"), group: None }),
-        ("Mirah", Language { name: "Mirah", language_type: LanguageType::Programming, color: Some("#c7a938"), group: None }),
-        ("QMake", Language { name: "QMake", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Frege", Language { name: "Frege", language_type: LanguageType::Programming, color: Some("#00cafe"), group: None }),
+        ("X PixMap", Language { name: "X PixMap", language_type: LanguageType::Data, color: None, group: Some("C") }),
+        ("XML Property List", Language { name: "XML Property List", language_type: LanguageType::Data, color: None, group: Some("XML") }),
+        ("LFE", Language { name: "LFE", language_type: LanguageType::Programming, color: Some("#4C3023"), group: None }),
+        ("Batchfile", Language { name: "Batchfile", language_type: LanguageType::Programming, color: Some("#C1F12E"), group: None }),
+        ("EML", Language { name: "EML", language_type: LanguageType::Data, color: None, group: None }),
+        ("Groovy", Language { name: "Groovy", language_type: LanguageType::Programming, color: Some("#e69f56"), group: None }),
+        ("Parrot Assembly", Language { name: "Parrot Assembly", language_type: LanguageType::Programming, color: None, group: Some("Parrot") }),
+        ("Ruby", Language { name: "Ruby", language_type: LanguageType::Programming, color: Some("#701516"), group: None }),
+        ("Redcode", Language { name: "Redcode", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Oxygene", Language { name: "Oxygene", language_type: LanguageType::Programming, color: Some("#cdd0e3"), group: None }),
+        ("OpenEdge ABL", Language { name: "OpenEdge ABL", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Kit", Language { name: "Kit", language_type: LanguageType::Markup, color: None, group: None }),
+        ("Unity3D Asset", Language { name: "Unity3D Asset", language_type: LanguageType::Data, color: None, group: None }),
+        ("X Font Directory Index", Language { name: "X Font Directory Index", language_type: LanguageType::Data, color: None, group: None }),
+        ("Chapel", Language { name: "Chapel", language_type: LanguageType::Programming, color: Some("#8dc63f"), group: None }),
+        ("OpenCL", Language { name: "OpenCL", language_type: LanguageType::Programming, color: None, group: Some("C") }),
+        ("YASnippet", Language { name: "YASnippet", language_type: LanguageType::Markup, color: Some("#32AB90"), group: None }),
+        ("BitBake", Language { name: "BitBake", language_type: LanguageType::Programming, color: None, group: None }),
         ("MATLAB", Language { name: "MATLAB", language_type: LanguageType::Programming, color: Some("#e16737"), group: None }),
-        ("Rebol", Language { name: "Rebol", language_type: LanguageType::Programming, color: Some("#358a5b"), group: None }),
-        ("Omgrofl", Language { name: "Omgrofl", language_type: LanguageType::Programming, color: Some("#cabbff"), group: None }),
-        ("Max", Language { name: "Max", language_type: LanguageType::Programming, color: Some("#c4a79c"), group: None }),
-        ("NetLinx+ERB", Language { name: "NetLinx+ERB", language_type: LanguageType::Programming, color: Some("#747faa"), group: None }),
-        ("Objective-C++", Language { name: "Objective-C++", language_type: LanguageType::Programming, color: Some("#6866fb"), group: None }),
-        ("Raw token data", Language { name: "Raw token data", language_type: LanguageType::Data, color: None, group: None }),
-        ("Visual Basic .NET", Language { name: "Visual Basic .NET", language_type: LanguageType::Programming, color: Some("#945db7"), group: None }),
-        ("Pony", Language { name: "Pony", language_type: LanguageType::Programming, color: None, group: None }),
-        ("GAMS", Language { name: "GAMS", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Objective-C", Language { name: "Objective-C", language_type: LanguageType::Programming, color: Some("#438eff"), group: None }),
+        ("Grammatical Framework", Language { name: "Grammatical Framework", language_type: LanguageType::Programming, color: Some("#79aa7a"), group: None }),
         ("XPages", Language { name: "XPages", language_type: LanguageType::Data, color: None, group: None }),
-        ("Jison", Language { name: "Jison", language_type: LanguageType::Programming, color: None, group: Some("Yacc") }),
-        ("Frege", Language { name: "Frege", language_type: LanguageType::Programming, color: Some("#00cafe"), group: None }),
-        ("Pike", Language { name: "Pike", language_type: LanguageType::Programming, color: Some("#005390"), group: None }),
-        ("CLIPS", Language { name: "CLIPS", language_type: LanguageType::Programming, color: None, group: None }),
-        ("LoomScript", Language { name: "LoomScript", language_type: LanguageType::Programming, color: None, group: None }),
-        ("OpenQASM", Language { name: "OpenQASM", language_type: LanguageType::Programming, color: Some("#AA70FF"), group: None }),
-        ("Xtend", Language { name: "Xtend", language_type: LanguageType::Programming, color: None, group: None }),
-        ("AMPL", Language { name: "AMPL", language_type: LanguageType::Programming, color: Some("#E6EFBB"), group: None }),
-        ("DataWeave", Language { name: "DataWeave", language_type: LanguageType::Programming, color: Some("#003a52"), group: None }),
-        ("CWeb", Language { name: "CWeb", language_type: LanguageType::Programming, color: None, group: None }),
-        ("IDL", Language { name: "IDL", language_type: LanguageType::Programming, color: Some("#a3522f"), group: None }),
-        ("DirectX 3D File", Language { name: "DirectX 3D File", language_type: LanguageType::Data, color: None, group: None }),
-        ("ShellSession", Language { name: "ShellSession", language_type: LanguageType::Programming, color: None, group: None }),
-        ("DTrace", Language { name: "DTrace", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Opa", Language { name: "Opa", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Slash", Language { name: "Slash", language_type: LanguageType::Programming, color: Some("#007eff"), group: None }),
-        ("WebVTT", Language { name: "WebVTT", language_type: LanguageType::Data, color: None, group: None }),
-        ("Ballerina", Language { name: "Ballerina", language_type: LanguageType::Programming, color: Some("#FF5000"), group: None }),
-        ("SourcePawn", Language { name: "SourcePawn", language_type: LanguageType::Programming, color: Some("#5c7611"), group: None }),
-        ("HTML+EEX", Language { name: "HTML+EEX", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
-        ("Pod", Language { name: "Pod", language_type: LanguageType::Prose, color: None, group: None }),
+        ("UnrealScript", Language { name: "UnrealScript", language_type: LanguageType::Programming, color: Some("#a54c4d"), group: None }),
         ("FLUX", Language { name: "FLUX", language_type: LanguageType::Programming, color: Some("#88ccff"), group: None }),
-        ("Eiffel", Language { name: "Eiffel", language_type: LanguageType::Programming, color: Some("#946d57"), group: None }),
-        ("Terra", Language { name: "Terra", language_type: LanguageType::Programming, color: Some("#00004c"), group: None }),
-        ("Wavefront Object", Language { name: "Wavefront Object", language_type: LanguageType::Data, color: None, group: None }),
-        ("Component Pascal", Language { name: "Component Pascal", language_type: LanguageType::Programming, color: Some("#B0CE4E"), group: None }),
-        ("MoonScript", Language { name: "MoonScript", language_type: LanguageType::Programming, color: None, group: None }),
-        ("Gentoo Eclass", Language { name: "Gentoo Eclass", language_type: LanguageType::Programming, color: None, group: Some("Shell") }),
-        ("VBA", Language { name: "VBA", language_type: LanguageType::Programming, color: Some("#867db1"), group: None }),
-        ("M4Sugar", Language { name: "M4Sugar", language_type: LanguageType::Programming, color: None, group: Some("M4") }),
-        ("Liquid", Language { name: "Liquid", language_type: LanguageType::Markup, color: None, group: None }),
-        ("Isabelle ROOT", Language { name: "Isabelle ROOT", language_type: LanguageType::Programming, color: None, group: Some("Isabelle") }),
-        ("NCL", Language { name: "NCL", language_type: LanguageType::Programming, color: Some("#28431f"), group: None }),
-        ("Click", Language { name: "Click", language_type: LanguageType::Programming, color: Some("#E4E6F3"), group: None }),
-        ("APL", Language { name: "APL", language_type: LanguageType::Programming, color: Some("#5A8164"), group: None }),
-        ("SaltStack", Language { name: "SaltStack", language_type: LanguageType::Programming, color: Some("#646464"), group: None }),
-        ("Jasmin", Language { name: "Jasmin", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Darcs Patch", Language { name: "Darcs Patch", language_type: LanguageType::Data, color: None, group: None }),
+        ("HolyC", Language { name: "HolyC", language_type: LanguageType::Programming, color: Some("#ffefaf"), group: None }),
+        ("Brightscript", Language { name: "Brightscript", language_type: LanguageType::Programming, color: None, group: None }),
+        ("XCompose", Language { name: "XCompose", language_type: LanguageType::Data, color: None, group: None }),
+        ("LookML", Language { name: "LookML", language_type: LanguageType::Programming, color: Some("#652B81"), group: None }),
+        ("Scheme", Language { name: "Scheme", language_type: LanguageType::Programming, color: Some("#1e4aec"), group: None }),
+        ("OpenRC runscript", Language { name: "OpenRC runscript", language_type: LanguageType::Programming, color: None, group: Some("Shell") }),
+        ("Turing", Language { name: "Turing", language_type: LanguageType::Programming, color: Some("#cf142b"), group: None }),
+        ("PogoScript", Language { name: "PogoScript", language_type: LanguageType::Programming, color: Some("#d80074"), group: None }),
+        ("Nginx", Language { name: "Nginx", language_type: LanguageType::Data, color: None, group: None }),
+        ("Stan", Language { name: "Stan", language_type: LanguageType::Programming, color: Some("#b2011d"), group: None }),
+        ("Proguard", Language { name: "Proguard", language_type: LanguageType::Data, color: None, group: None }),
+        ("nanorc", Language { name: "nanorc", language_type: LanguageType::Data, color: None, group: Some("INI") }),
+        ("DIGITAL Command Language", Language { name: "DIGITAL Command Language", language_type: LanguageType::Programming, color: None, group: None }),
+        ("ZIL", Language { name: "ZIL", language_type: LanguageType::Programming, color: Some("#dc75e5"), group: None }),
+        ("Vala", Language { name: "Vala", language_type: LanguageType::Programming, color: Some("#fbe5cd"), group: None }),
+        ("Genshi", Language { name: "Genshi", language_type: LanguageType::Programming, color: None, group: None }),
+        ("Easybuild", Language { name: "Easybuild", language_type: LanguageType::Data, color: None, group: Some("Python") }),
+        ("HyPhy", Language { name: "HyPhy", language_type: LanguageType::Programming, color: None, group: None }),
+        ("YANG", Language { name: "YANG", language_type: LanguageType::Data, color: None, group: None }),
+        ("Ant Build System", Language { name: "Ant Build System", language_type: LanguageType::Data, color: None, group: None }),
+        ("Jsonnet", Language { name: "Jsonnet", language_type: LanguageType::Programming, color: Some("#0064bd"), group: None }),
+        ("Meson", Language { name: "Meson", language_type: LanguageType::Programming, color: Some("#007800"), group: None }),
+        ("Marko", Language { name: "Marko", language_type: LanguageType::Markup, color: None, group: Some("HTML") }),
+        ("Git Attributes", Language { name: "Git Attributes", language_type: LanguageType::Data, color: None, group: Some("INI") }),
+        ("CSV", Language { name: "CSV", language_type: LanguageType::Data, color: None, group: None }),
+        ("EmberScript", Language { name: "EmberScript", language_type: LanguageType::Programming, color: Some("#FFF4F3"), group: None }),
+        ("Boo", Language { name: "Boo", language_type: LanguageType::Programming, color: Some("#d4bec1"), group: None }),
+        ("Gerber Image", Language { name: "Gerber Image", language_type: LanguageType::Data, color: None, group: None }),
+    ]),
+};
+
+static LANGUAGE_IDS: phf::Map<&'static str, u32> =
+::phf::Map {
+    key: 3213172566270843353,
+    disps: ::phf::Slice::Static(&[
+        (0, 137),
+        (0, 200),
+        (0, 0),
+        (0, 5),
+        (0, 481),
+        (0, 46),
+        (0, 0),
+        (0, 0),
+        (0, 15),
+        (0, 475),
+        (0, 244),
+        (0, 86),
+        (0, 35),
+        (0, 4),
+        (0, 157),
+        (0, 144),
+        (0, 7),
+        (2, 281),
+        (2, 325),
+        (1, 321),
+        (0, 140),
+        (0, 5),
+        (0, 58),
+        (0, 10),
+        (0, 506),
+        (0, 5),
+        (1, 108),
+        (0, 41),
+        (0, 516),
+        (1, 398),
+        (0, 69),
+        (0, 4),
+        (0, 154),
+        (0, 0),
+        (0, 0),
+        (0, 5),
+        (0, 0),
+        (0, 18),
+        (0, 4),
+        (0, 61),
+        (0, 5),
+        (0, 3),
+        (0, 14),
+        (0, 17),
+        (0, 410),
+        (3, 410),
+        (0, 310),
+        (0, 0),
+        (0, 8),
+        (0, 17),
+        (0, 127),
+        (0, 6),
+        (0, 73),
+        (1, 257),
+        (0, 0),
+        (0, 302),
+        (1, 0),
+        (0, 50),
+        (0, 2),
+        (7, 256),
+        (0, 26),
+        (0, 16),
+        (0, 244),
+        (0, 15),
+        (0, 37),
+        (0, 118),
+        (0, 166),
+        (8, 52),
+        (0, 46),
+        (0, 24),
+        (1, 85),
+        (0, 407),
+        (0, 41),
+        (0, 1),
+        (2, 235),
+        (0, 31),
+        (0, 1),
+        (0, 18),
+        (1, 382),
+        (0, 176),
+        (0, 128),
+        (4, 141),
+        (5, 337),
+        (0, 79),
+        (0, 0),
+        (0, 13),
+        (0, 131),
+        (0, 526),
+        (0, 224),
+        (0, 128),
+        (5, 41),
+        (0, 546),
+        (0, 2),
+        (4, 443),
+        (0, 386),
+        (0, 228),
+        (15, 149),
+        (4, 96),
+        (3, 104),
+        (3, 458),
+        (30, 154),
+        (1, 270),
+        (5, 350),
+        (0, 18),
+        (0, 6),
+        (0, 3),
+        (6, 444),
+        (24, 494),
+        (2, 263),
+        (0, 0),
+        (0, 64),
+        (0, 0),
+    ]),
+    entries: ::phf::Slice::Static(&[
+        ("Slim", 350),
+        ("Pic", 425),
+        ("Thrift", 374),
+        ("GAP", 119),
+        ("BlitzBasic", 34),
+        ("Papyrus", 277),
+        ("GDScript", 123),
+        ("Mirah", 232),
+        ("Rascal", 173616037),
+        ("KiCad Layout", 187),
+        ("Nit", 251),
+        ("Charity", 56),
+        ("Jupyter Notebook", 185),
+        ("Objective-C++", 258),
+        ("LSL", 193),
+        ("Coq", 69),
+        ("TeX", 369),
+        ("Haskell", 157),
+        ("Emacs Lisp", 102),
+        ("Vim script", 388),
+        ("RAML", 308),
+        ("VBScript", 408016005),
+        ("Ioke", 169),
+        ("World of Warcraft Addon Data", 396),
+        ("Alloy", 13),
+        ("SRecode Template", 335),
+        ("1C Enterprise", 0),
+        ("Omgrofl", 260),
+        ("mupad", 416),
+        ("nesC", 417),
+        ("PicoLisp", 285),
+        ("Microsoft Developer Studio Project", 800983837),
+        ("Pickle", 284),
+        ("SystemVerilog", 363),
+        ("RUNOFF", 315),
+        ("TSX", 94901924),
+        ("Prolog", 295),
+        ("HAProxy", 366607477),
+        ("Haml", 154),
+        ("Kotlin", 189),
+        ("Zimpl", 411),
+        ("ECL", 93),
+        ("ASP", 8),
+        ("Scala", 341),
+        ("Blade", 33),
+        ("Linker Script", 202),
+        ("C2hs Haskell", 45),
+        ("Filterscript", 112),
+        ("Logos", 209),
+        ("CSON", 424),
+        ("Myghty", 239),
+        ("Io", 168),
+        ("Alpine Abuild", 14),
+        ("PlantUML", 833504686),
+        ("Erlang", 104),
+        ("Squirrel", 355),
+        ("NASL", 171666519),
+        ("Rust", 327),
+        ("Filebench WML", 111),
+        ("MTML", 218),
+        ("Pawn", 271),
+        ("Maven POM", 226),
+        ("Tcsh", 368),
+        ("Bluespec", 36),
+        ("Component Pascal", 67),
+        ("Glyph", 130),
+        ("Wavefront Object", 393),
+        ("Scaml", 342),
+        ("YAML", 407),
+        ("Jasmin", 180),
+        ("Python console", 428),
+        ("XQuery", 402),
+        ("wdl", 374521672),
+        ("Isabelle ROOT", 171),
+        ("ShaderLab", 664257356),
+        ("mIRC Script", 517654727),
+        ("Graph Modeling Language", 138),
+        ("Visual Basic .NET", 389),
+        ("AMPL", 3),
+        ("OCaml", 255),
+        ("HTML+PHP", 151),
+        ("HiveQL", 931814087),
+        ("RPM Spec", 314),
+        ("COBOL", 48),
+        ("Closure Templates", 357046146),
+        ("PLpgSQL", 274),
+        ("Windows Registry Entries", 969674868),
+        ("eC", 413),
+        ("EJS", 95),
+        ("Eagle", 97),
+        ("Liquid", 204),
+        ("REXX", 311),
+        ("V", 603371597),
+        ("SPARQL", 331),
+        ("JSX", 178),
+        ("RobotFramework", 324),
+        ("Shell", 346),
+        ("Object Data Instance Notation", 985227236),
+        ("Augeas", 25),
+        ("Svelte", 928734530),
+        ("ObjDump", 256),
+        ("PureScript", 302),
+        ("Cap'n Proto", 52),
+        ("VBA", 399230729),
+        ("Asymptote", 591605007),
+        ("TSQL", 918334941),
+        ("dircolors", 691605112),
+        ("Common Lisp", 66),
+        ("Tcl", 367),
+        ("PHP", 272),
+        ("Eiffel", 99),
+        ("Awk", 28),
+        ("Zephir", 410),
+        ("JavaScript+ERB", 914318960),
+        ("CoNLL-U", 421026389),
+        ("xBase", 421),
+        ("Game Maker Language", 125),
+        ("X10", 397),
+        ("JSON with Comments", 423),
+        ("Lua", 213),
+        ("Max", 227),
+        ("Roff", 141),
+        ("CMake", 47),
+        ("QMake", 306),
+        ("Standard ML", 357),
+        ("M", 214),
+        ("Pod 6", 155357471),
+        ("Terra", 371),
+        ("Harbour", 156),
+        ("HCL", 144),
+        ("fish", 415),
+        ("HTML+Django", 147),
+        ("DataWeave", 974514097),
+        ("Modula-3", 564743864),
+        ("HLSL", 145),
+        ("Pan", 276),
+        ("JFlex", 173),
+        ("SubRip Text", 360),
+        ("EBNF", 430),
+        ("PigLatin", 286),
+        ("Pascal", 281),
+        ("TXL", 366),
+        ("MQL5", 427),
+        ("wisp", 420),
+        ("Haxe", 158),
+        ("Elixir", 100),
+        ("Pony", 290),
+        ("NPM Config", 685022663),
+        ("Mathematica", 224),
+        ("Objective-J", 259),
+        ("Cycript", 78),
+        ("Modula-2", 234),
+        ("Cloud Firestore Security Rules", 407996372),
+        ("XML", 399),
+        ("Csound Document", 74),
+        ("Nearley", 521429430),
+        ("VCL", 384),
+        ("OpenSCAD", 266),
+        ("Xtend", 406),
+        ("ASN.1", 7),
+        ("desktop", 412),
+        ("Stylus", 359),
+        ("Grace", 135),
+        ("ColdFusion CFC", 65),
+        ("F#", 105),
+        ("Uno", 381),
+        ("AsciiDoc", 22),
+        ("Click", 61),
+        ("Apex", 17),
+        ("BibTeX", 982188347),
+        ("Python traceback", 304),
+        ("NumPy", 254),
+        ("Julia", 184),
+        ("ECLiPSe", 94),
+        ("Dockerfile", 89),
+        ("reStructuredText", 419),
+        ("Motorola 68K Assembly", 477582706),
+        ("Inform 7", 166),
+        ("Groovy Server Pages", 143),
+        ("Rich Text Format", 51601661),
+        ("Propeller Spin", 296),
+        ("Ninja", 250),
+        ("Bison", 31),
+        ("CodeQL", 424259634),
+        ("q", 970539067),
+        ("Factor", 108),
+        ("AutoHotkey", 26),
+        ("Roff Manpage", 612669833),
+        ("Starlark", 960266174),
+        ("XC", 398),
+        ("Dart", 87),
+        ("Muse", 474864066),
+        ("Metal", 230),
+        ("Stata", 358),
+        ("QML", 305),
+        ("HXML", 786683730),
+        ("Slice", 894641667),
+        ("APL", 6),
+        ("ColdFusion", 64),
+        ("Diff", 88),
+        ("IDL", 161),
+        ("Literate CoffeeScript", 206),
+        ("F*", 336943375),
+        ("Git Config", 807968997),
+        ("Mako", 221),
+        ("Raku", 283),
+        ("Clojure", 62),
+        ("X BitMap", 782911107),
+        ("RMarkdown", 313),
+        ("Genie", 792408528),
+        ("Literate Agda", 205),
+        ("Cuda", 77),
+        ("Perl", 282),
+        ("JavaScript", 183),
+        ("Red", 320),
+        ("Sage", 338),
+        ("Adobe Font Metrics", 147198098),
+        ("DNS Zone", 84),
+        ("PureBasic", 301),
+        ("YARA", 805122868),
+        ("CWeb", 657332628),
+        ("HTTP", 152),
+        ("GAML", 290345951),
+        ("Apollo Guidance Computer", 18),
+        ("DM", 83),
+        ("C#", 42),
+        ("Volt", 390),
+        ("API Blueprint", 5),
+        ("TypeScript", 378),
+        ("GraphQL", 139),
+        ("Web Ontology Language", 394),
+        ("DTrace", 85),
+        ("Glyph Bitmap Distribution Format", 997665271),
+        ("Wollok", 632745969),
+        ("Cabal Config", 677095381),
+        ("Jison", 284531423),
+        ("Ren'Py", 322),
+        ("Public Key", 298),
+        ("HTML+Razor", 479039817),
+        ("JSONiq", 177),
+        ("CartoCSS", 53),
+        ("Yacc", 409),
+        ("FIGlet Font", 686129783),
+        ("Lex", 199),
+        ("ZenScript", 494938890),
+        ("SAS", 328),
+        ("NetLinx", 244),
+        ("HTML+EEX", 149),
+        ("Inno Setup", 167),
+        ("Creole", 71),
+        ("Pep8", 840372442),
+        ("Twig", 377),
+        ("RDoc", 309),
+        ("PLSQL", 273),
+        ("AngelScript", 389477596),
+        ("Nemerle", 243),
+        ("IGOR Pro", 162),
+        ("JSON", 174),
+        ("Opal", 262),
+        ("ooc", 418),
+        ("SQF", 332),
+        ("Brainfuck", 38),
+        ("Golo", 133),
+        ("Nextflow", 506780613),
+        ("Java Server Pages", 182),
+        ("Befunge", 30),
+        ("UrWeb", 383),
+        ("LilyPond", 200),
+        ("XProc", 401),
+        ("COLLADA", 49),
+        ("Cirru", 58),
+        ("Altium Designer", 187772328),
+        ("Pure Data", 300),
+        ("ShellSession", 347),
+        ("RenderScript", 323),
+        ("Mask", 223),
+        ("Hy", 159),
+        ("Mercury", 229),
+        ("G-code", 117),
+        ("Fantom", 110),
+        ("RPC", 1031374237),
+        ("OpenQASM", 153739399),
+        ("D-ObjDump", 81),
+        ("CLIPS", 46),
+        ("SSH Config", 554920715),
+        ("LabVIEW", 194),
+        ("Unified Parallel C", 379),
+        ("Isabelle", 170),
+        ("Vim Snippet", 81265970),
+        ("SugarSS", 826404698),
+        ("NCL", 240),
+        ("DirectX 3D File", 201049282),
+        ("LOLCODE", 192),
+        ("Fancy", 109),
+        ("Gettext Catalog", 129),
+        ("OpenStep Property List", 598917541),
+        ("Graphviz (DOT)", 140),
+        ("Processing", 294),
+        ("Ada", 11),
+        ("ATS", 9),
+        ("Faust", 622529198),
+        ("Go", 132),
+        ("CoffeeScript", 63),
+        ("Logtalk", 210),
+        ("Dylan", 91),
+        ("mcfunction", 462488745),
+        ("Common Workflow Language", 988547172),
+        ("KiCad Schematic", 622447435),
+        ("GN", 302957008),
+        ("EditorConfig", 96139566),
+        ("Gradle", 136),
+        ("Sass", 340),
+        ("AspectJ", 23),
+        ("Wget Config", 668457123),
+        ("GAMS", 118),
+        ("M4Sugar", 216),
+        ("FreeMarker", 115),
+        ("REALbasic", 310),
+        ("Agda", 12),
+        ("Formatted", 113),
+        ("Turtle", 376),
+        ("GDB", 122),
+        ("Gherkin", 76),
+        ("Slash", 349),
+        ("AppleScript", 19),
+        ("Nix", 252),
+        ("MoonScript", 238),
+        ("TLA", 364),
+        ("edn", 414),
+        ("cURL Config", 992375436),
+        ("Pug", 179),
+        ("Raw token data", 318),
+        ("Linux Kernel Module", 203),
+        ("INI", 163),
+        ("GCC Machine Description", 121),
+        ("Type Language", 632765617),
+        ("Cython", 79),
+        ("Shen", 348),
+        ("Cpp-ObjDump", 70),
+        ("SVG", 337),
+        ("Unix Assembly", 120),
+        ("Smali", 351),
+        ("C++", 43),
+        ("WebAssembly", 956556503),
+        ("Clarion", 59),
+        ("P4", 348895984),
+        ("Readline Config", 538732839),
+        ("Makefile", 220),
+        ("MUF", 219),
+        ("LoomScript", 212),
+        ("LTspice Symbol", 1013566805),
+        ("IRC log", 164),
+        ("Org", 267),
+        ("Ragel", 317),
+        ("SMT", 330),
+        ("M4", 215),
+        ("Module Management System", 235),
+        ("LLVM", 191),
+        ("Nim", 249),
+        ("Zig", 646424281),
+        ("Go Module", 947461016),
+        ("WebIDL", 395),
+        ("SuperCollider", 361),
+        ("4D", 577529595),
+        ("Riot", 878396783),
+        ("ActionScript", 10),
+        ("Lasso", 195),
+        ("Cool", 68),
+        ("Latte", 196),
+        ("Vue", 391),
+        ("Ignore List", 74444240),
+        ("ChucK", 57),
+        ("STON", 336),
+        ("PostScript", 291),
+        ("HTML+ERB", 150),
+        ("ANTLR", 4),
+        ("C-ObjDump", 44),
+        ("Hack", 153),
+        ("Spline Font Database", 767169629),
+        ("Python", 303),
+        ("Rebol", 319),
+        ("Jison Lex", 406395330),
+        ("ObjectScript", 202735509),
+        ("AutoIt", 27),
+        ("Textile", 373),
+        ("Go Checksums", 1054391671),
+        ("PowerShell", 293),
+        ("sed", 847830017),
+        ("Xojo", 405),
+        ("Pod", 288),
+        ("Java", 181),
+        ("Texinfo", 988020015),
+        ("Regular Expression", 363378884),
+        ("NSIS", 242),
+        ("ApacheConf", 16),
+        ("Ballerina", 720859680),
+        ("Less", 198),
+        ("Opa", 261),
+        ("Dhall", 793969321),
+        ("Assembly", 24),
+        ("Reason", 869538413),
+        ("JSONLD", 176),
+        ("LiveScript", 208),
+        ("BlitzMax", 35),
+        ("D", 80),
+        ("Idris", 165),
+        ("Ceylon", 54),
+        ("ABNF", 429),
+        ("Protocol Buffer", 297),
+        ("HTML", 146),
+        ("Rouge", 325),
+        ("Arc", 20),
+        ("Zeek", 40),
+        ("C", 41),
+        ("VHDL", 385),
+        ("Edje Data Collection", 342840478),
+        ("ABAP", 1),
+        ("NL", 241),
+        ("PostCSS", 262764437),
+        ("Moocode", 237),
+        ("AGS Script", 2),
+        ("OpenType Feature File", 374317347),
+        ("Csound Score", 75),
+        ("Smarty", 353),
+        ("GLSL", 124),
+        ("Clean", 60),
+        ("Monkey", 236),
+        ("KRL", 186),
+        ("Ox", 268),
+        ("CSS", 50),
+        ("XSLT", 404),
+        ("Text", 372),
+        ("Forth", 114),
+        ("SourcePawn", 354),
+        ("MiniD", 231),
+        ("Oz", 270),
+        ("Scilab", 344),
+        ("NetLogo", 246),
+        ("Open Policy Agent", 840483232),
+        ("Astro", 578209015),
+        ("SmPL", 164123055),
+        ("MQL4", 426),
+        ("XS", 403),
+        ("TOML", 365),
+        ("SQLPL", 334),
+        ("Limbo", 201),
+        ("Racket", 316),
+        ("Literate Haskell", 207),
+        ("Crystal", 72),
+        ("Dogescript", 90),
+        ("Tea", 370),
+        ("NewLisp", 247),
+        ("RHTML", 312),
+        ("MLIR", 448253929),
+        ("Handlebars", 155),
+        ("Objective-C", 257),
+        ("R", 307),
+        ("Java Properties", 519377561),
+        ("SCSS", 329),
+        ("Parrot Internal Representation", 280),
+        ("Self", 345),
+        ("Quake", 375265331),
+        ("Jolie", 998078858),
+        ("Solidity", 237469032),
+        ("SQL", 333),
+        ("Ring", 431),
+        ("TI Program", 422),
+        ("Gentoo Eclass", 128),
+        ("ZAP", 952972794),
+        ("Ecere Projects", 98),
+        ("Gnuplot", 131),
+        ("Lean", 197),
+        ("J", 172),
+        ("Odin", 889244082),
+        ("Elm", 101),
+        ("Gosu", 134),
+        ("Pike", 287),
+        ("POV-Ray SDL", 275),
+        ("Smalltalk", 352),
+        ("Csound", 73),
+        ("NetLinx+ERB", 245),
+        ("Verilog", 387),
+        ("Parrot", 278),
+        ("E", 92),
+        ("Prisma", 499933428),
+        ("PowerBuilder", 292),
+        ("HTML+ECR", 148),
+        ("Puppet", 299),
+        ("MediaWiki", 228),
+        ("SaltStack", 339),
+        ("Markdown", 222),
+        ("JSON5", 175),
+        ("Gentoo Ebuild", 127),
+        ("WebVTT", 658679714),
+        ("SWIG", 1066250075),
+        ("Fortran", 107),
+        ("MAXScript", 217),
+        ("Modelica", 233),
+        ("Wavefront Material", 392),
+        ("Swift", 362),
+        ("EQ", 96),
+        ("Nu", 253),
+        ("KiCad Legacy Layout", 140848857),
+        ("Frege", 116),
+        ("X PixMap", 781846279),
+        ("XML Property List", 75622871),
+        ("LFE", 190),
+        ("Batchfile", 29),
+        ("EML", 529653389),
+        ("Groovy", 142),
+        ("Parrot Assembly", 279),
+        ("Ruby", 326),
+        ("Redcode", 321),
+        ("Oxygene", 269),
+        ("OpenEdge ABL", 264),
+        ("Kit", 188),
+        ("Unity3D Asset", 380),
+        ("X Font Directory Index", 208700028),
+        ("Chapel", 55),
+        ("OpenCL", 263),
+        ("YASnippet", 378760102),
+        ("BitBake", 32),
+        ("MATLAB", 225),
+        ("Grammatical Framework", 137),
+        ("XPages", 400),
+        ("UnrealScript", 382),
+        ("FLUX", 106),
+        ("Darcs Patch", 86),
+        ("HolyC", 928121743),
+        ("Brightscript", 39),
+        ("XCompose", 225167241),
+        ("LookML", 211),
+        ("Scheme", 343),
+        ("OpenRC runscript", 265),
+        ("Turing", 375),
+        ("PogoScript", 289),
+        ("Nginx", 248),
+        ("Stan", 356),
+        ("Proguard", 716513858),
+        ("nanorc", 775996197),
+        ("DIGITAL Command Language", 82),
+        ("ZIL", 973483626),
+        ("Vala", 386),
+        ("Genshi", 126),
+        ("Easybuild", 342840477),
+        ("HyPhy", 160),
+        ("YANG", 408),
+        ("Ant Build System", 15),
+        ("Jsonnet", 664885656),
+        ("Meson", 799141244),
+        ("Marko", 932782397),
+        ("Git Attributes", 956324166),
+        ("CSV", 51),
+        ("EmberScript", 103),
+        ("Boo", 37),
+        ("Gerber Image", 404627610),
     ]),
 };
 
@@ -0,0 +1,1260 @@
+static UNAMBIGUOUS_EXTENSIONS: phf::Map<&'static str, &'static str> =
+::phf::Map {
+    key: 3213172566270843353,
+    disps: ::phf::Slice::Static(&[
+        (0, 326),
+        (0, 2),
+        (0, 0),
+        (0, 76),
+        (0, 183),
+        (0, 33),
+        (0, 151),
+        (0, 0),
+        (0, 204),
+        (0, 33),
+        (0, 100),
+        (0, 2),
+        (0, 908),
+        (0, 76),
+        (0, 4),
+        (0, 35),
+        (1, 8),
+        (0, 84),
+        (0, 72),
+        (0, 0),
+        (0, 284),
+        (0, 239),
+        (0, 565),
+        (0, 128),
+        (0, 47),
+        (0, 217),
+        (0, 111),
+        (0, 80),
+        (0, 39),
+        (0, 16),
+        (0, 125),
+        (0, 49),
+        (0, 5),
+        (0, 0),
+        (0, 15),
+        (0, 8),
+        (0, 194),
+        (0, 181),
+        (0, 484),
+        (0, 180),
+        (0, 75),
+        (0, 1),
+        (0, 429),
+        (0, 7),
+        (0, 4),
+        (0, 24),
+        (0, 29),
+        (0, 2),
+        (0, 88),
+        (0, 7),
+        (1, 108),
+        (0, 383),
+        (0, 24),
+        (0, 28),
+        (0, 61),
+        (0, 3),
+        (0, 196),
+        (0, 200),
+        (0, 0),
+        (0, 623),
+        (0, 141),
+        (0, 86),
+        (0, 54),
+        (0, 22),
+        (0, 526),
+        (0, 169),
+        (0, 83),
+        (0, 529),
+        (4, 974),
+        (0, 19),
+        (0, 1),
+        (1, 1),
+        (0, 212),
+        (0, 79),
+        (0, 55),
+        (0, 15),
+        (0, 0),
+        (0, 1),
+        (1, 208),
+        (0, 3),
+        (0, 2),
+        (0, 205),
+        (0, 496),
+        (0, 27),
+        (0, 11),
+        (0, 44),
+        (0, 0),
+        (0, 454),
+        (0, 885),
+        (0, 16),
+        (2, 603),
+        (0, 5),
+        (0, 0),
+        (0, 43),
+        (0, 513),
+        (0, 34),
+        (0, 560),
+        (0, 5),
+        (0, 381),
+        (0, 631),
+        (0, 81),
+        (0, 16),
+        (1, 291),
+        (0, 30),
+        (0, 129),
+        (2, 545),
+        (5, 347),
+        (0, 4),
+        (0, 4),
+        (0, 25),
+        (0, 447),
+        (0, 23),
+        (0, 474),
+        (0, 10),
+        (0, 103),
+        (1, 260),
+        (0, 47),
+        (0, 17),
+        (0, 206),
+        (0, 24),
+        (0, 710),
+        (0, 1),
+        (0, 1),
+        (0, 536),
+        (0, 507),
+        (1, 182),
+        (0, 140),
+        (0, 397),
+        (0, 21),
+        (0, 382),
+        (0, 560),
+        (0, 242),
+        (0, 34),
+        (0, 565),
+        (0, 6),
+        (0, 60),
+        (4, 11),
+        (4, 805),
+        (0, 321),
+        (0, 0),
+        (0, 871),
+        (0, 1),
+        (0, 767),
+        (0, 839),
+        (3, 236),
+        (0, 16),
+        (0, 790),
+        (0, 90),
+        (0, 70),
+        (0, 0),
+        (1, 247),
+        (0, 117),
+        (0, 2),
+        (1, 171),
+        (0, 233),
+        (0, 52),
+        (0, 100),
+        (0, 98),
+        (0, 686),
+        (1, 543),
+        (0, 1),
+        (0, 36),
+        (0, 76),
+        (0, 0),
+        (0, 2),
+        (2, 33),
+        (0, 586),
+        (0, 40),
+        (0, 4),
+        (0, 20),
+        (1, 720),
+        (0, 102),
+        (0, 3),
+        (0, 6),
+        (1, 401),
+        (4, 717),
+        (1, 353),
+        (1, 381),
+        (0, 162),
+        (0, 543),
+        (26, 164),
+        (0, 35),
+        (11, 164),
+        (0, 6),
+        (14, 36),
+        (2, 30),
+        (0, 23),
+        (7, 42),
+        (0, 62),
+        (0, 247),
+        (31, 597),
+        (3, 983),
+        (1, 76),
+        (0, 317),
+        (0, 21),
+        (12, 398),
+        (0, 68),
+        (0, 128),
+        (0, 271),
+        (1, 0),
+        (2, 591),
+        (0, 6),
+        (1, 306),
+        (0, 0),
+        (0, 272),
+        (0, 4),
+        (0, 58),
+        (0, 155),
+        (0, 524),
+    ]),
+    entries: ::phf::Slice::Static(&[
+        (".json5", "JSON5"),
+        (".pot", "Gettext Catalog"),
+        (".c++objdump", "Cpp-ObjDump"),
+        (".eh", "eC"),
+        (".ksh", "Shell"),
+        (".glslv", "GLSL"),
+        (".jsm", "JavaScript"),
+        (".xbm", "X BitMap"),
+        (".scad", "OpenSCAD"),
+        (".geojson", "JSON"),
+        (".json-tmlanguage", "JSON"),
+        (".pig", "PigLatin"),
+        (".lektorproject", "INI"),
+        (".hic", "Clojure"),
+        (".nim", "Nim"),
+        (".opencl", "OpenCL"),
+        (".sfproj", "XML"),
+        (".xojo_report", "Xojo"),
+        (".gltf", "JSON"),
+        (".astro", "Astro"),
+        (".sty", "TeX"),
+        (".druby", "Mirah"),
+        (".po", "Gettext Catalog"),
+        (".xlf", "XML"),
+        (".graphqls", "GraphQL"),
+        (".cscfg", "XML"),
+        (".hlsl", "HLSL"),
+        (".prolog", "Prolog"),
+        (".cljs", "Clojure"),
+        (".wsf", "XML"),
+        (".ur", "UrWeb"),
+        (".gitignore", "Ignore List"),
+        (".mdpolicy", "XML"),
+        (".ily", "LilyPond"),
+        (".gf", "Grammatical Framework"),
+        (".yang", "YANG"),
+        (".thrift", "Thrift"),
+        (".pov", "POV-Ray SDL"),
+        (".abnf", "ABNF"),
+        (".gto", "Gerber Image"),
+        (".ec", "eC"),
+        (".vala", "Vala"),
+        (".dlm", "IDL"),
+        (".golo", "Golo"),
+        ("._coffee", "CoffeeScript"),
+        (".gts", "Gerber Image"),
+        (".tmtheme", "XML Property List"),
+        (".view.lkml", "LookML"),
+        (".uno", "Uno"),
+        (".xht", "HTML"),
+        (".hb", "Harbour"),
+        (".8xp", "TI Program"),
+        (".cl2", "Clojure"),
+        (".xc", "XC"),
+        (".capnp", "Cap'n Proto"),
+        (".patch", "Diff"),
+        (".agda", "Agda"),
+        (".rdoc", "RDoc"),
+        (".csh", "Tcsh"),
+        (".mkii", "TeX"),
+        (".plot", "Gnuplot"),
+        (".proj", "XML"),
+        (".kicad_wks", "KiCad Layout"),
+        (".xul", "XML"),
+        (".asd", "Common Lisp"),
+        (".snippet", "Vim Snippet"),
+        (".pic", "Pic"),
+        (".cxx-objdump", "Cpp-ObjDump"),
+        (".xs", "XS"),
+        (".el", "Emacs Lisp"),
+        (".gyp", "Python"),
+        (".reb", "Rebol"),
+        (".cw", "Redcode"),
+        (".iced", "CoffeeScript"),
+        (".hc", "HolyC"),
+        (".pod6", "Pod 6"),
+        (".pike", "Pike"),
+        (".vert", "GLSL"),
+        (".sublime-menu", "JSON with Comments"),
+        (".rbuild", "Ruby"),
+        (".lpr", "Pascal"),
+        (".p6m", "Raku"),
+        (".mxml", "XML"),
+        (".jison", "Jison"),
+        (".pac", "JavaScript"),
+        (".f77", "Fortran"),
+        (".exs", "Elixir"),
+        (".p6l", "Raku"),
+        (".sas", "SAS"),
+        (".dita", "XML"),
+        (".gbp", "Gerber Image"),
+        (".lhs", "Literate Haskell"),
+        (".mt", "Mathematica"),
+        (".blade", "Blade"),
+        (".tm", "Tcl"),
+        (".io", "Io"),
+        (".pyde", "Python"),
+        (".hx", "Haxe"),
+        (".tese", "GLSL"),
+        (".wsgi", "Python"),
+        (".grace", "Grace"),
+        (".vue", "Vue"),
+        (".gshader", "GLSL"),
+        (".x10", "X10"),
+        (".r2", "Rebol"),
+        (".toml", "TOML"),
+        (".di", "D"),
+        (".sagews", "Sage"),
+        (".maxproj", "Max"),
+        (".cxx", "C++"),
+        (".nuspec", "XML"),
+        (".lds", "Linker Script"),
+        (".mly", "OCaml"),
+        (".kit", "Kit"),
+        (".csdef", "XML"),
+        (".cob", "COBOL"),
+        (".c-objdump", "C-ObjDump"),
+        (".ini", "INI"),
+        (".ino", "C++"),
+        (".rbx", "Ruby"),
+        (".fshader", "GLSL"),
+        (".mathematica", "Mathematica"),
+        (".pyi", "Python"),
+        (".rexx", "REXX"),
+        (".plantuml", "PlantUML"),
+        (".al", "Perl"),
+        (".mkvi", "TeX"),
+        (".nims", "Nim"),
+        (".lean", "Lean"),
+        (".ltx", "TeX"),
+        (".shproj", "XML"),
+        (".f03", "Fortran"),
+        (".ttl", "Turtle"),
+        (".haml", "Haml"),
+        (".grxml", "XML"),
+        (".hhi", "Hack"),
+        (".factor", "Factor"),
+        (".lbx", "TeX"),
+        (".rkt", "Racket"),
+        (".tmac", "Roff"),
+        (".bat", "Batchfile"),
+        (".yar", "YARA"),
+        (".4dm", "4D"),
+        (".dhall", "Dhall"),
+        (".vshader", "GLSL"),
+        (".hpp", "C++"),
+        (".cwl", "Common Workflow Language"),
+        (".ex", "Elixir"),
+        (".tesc", "GLSL"),
+        (".jsonl", "JSON"),
+        (".viw", "SQL"),
+        (".http", "HTTP"),
+        (".dae", "COLLADA"),
+        (".raml", "RAML"),
+        (".sats", "ATS"),
+        (".svelte", "Svelte"),
+        (".parrot", "Parrot"),
+        (".gko", "Gerber Image"),
+        (".grt", "Groovy"),
+        (".ejs", "EJS"),
+        (".mcfunction", "mcfunction"),
+        (".nr", "Roff"),
+        (".jsx", "JSX"),
+        (".marko", "Marko"),
+        (".jinja2", "HTML+Django"),
+        (".ronn", "Markdown"),
+        (".chem", "Pic"),
+        (".ui", "XML"),
+        (".eclxml", "ECL"),
+        (".g4", "ANTLR"),
+        (".liquid", "Liquid"),
+        (".ps", "PostScript"),
+        (".pat", "Max"),
+        (".kml", "XML"),
+        (".jade", "Pug"),
+        (".jsp", "Java Server Pages"),
+        (".yap", "Prolog"),
+        (".coq", "Coq"),
+        (".xslt", "XSLT"),
+        (".csl", "XML"),
+        (".zpl", "Zimpl"),
+        (".htm", "HTML"),
+        (".cobol", "COBOL"),
+        (".frx", "VBA"),
+        (".bats", "Shell"),
+        (".jisonlex", "Jison Lex"),
+        (".dwl", "DataWeave"),
+        (".plb", "PLSQL"),
+        (".cbl", "COBOL"),
+        (".sublime-theme", "JSON with Comments"),
+        (".sublime-build", "JSON with Comments"),
+        (".plt", "Gnuplot"),
+        (".gnuplot", "Gnuplot"),
+        (".bsv", "Bluespec"),
+        (".pas", "Pascal"),
+        (".ck", "ChucK"),
+        (".bison", "Bison"),
+        (".gradle.kts", "Gradle"),
+        (".mud", "ZIL"),
+        (".monkey", "Monkey"),
+        (".ada", "Ada"),
+        (".numsc", "NumPy"),
+        (".fsx", "F#"),
+        (".upc", "Unified Parallel C"),
+        (".kts", "Kotlin"),
+        (".tml", "XML"),
+        (".ig", "Modula-3"),
+        (".asp", "ASP"),
+        (".xspec", "XML"),
+        (".ceylon", "Ceylon"),
+        (".red", "Red"),
+        (".hxsl", "Haxe"),
+        (".roff", "Roff"),
+        (".svh", "SystemVerilog"),
+        (".podsl", "Common Lisp"),
+        (".pyt", "Python"),
+        (".edn", "edn"),
+        (".watchr", "Ruby"),
+        (".hqf", "SQF"),
+        (".xqm", "XQuery"),
+        (".muse", "Muse"),
+        (".kid", "Genshi"),
+        (".xojo_script", "Xojo"),
+        (".xzap", "ZAP"),
+        (".tac", "Python"),
+        (".rss", "XML"),
+        (".f90", "Fortran"),
+        (".sublime_session", "JSON with Comments"),
+        (".myt", "Myghty"),
+        (".tfvars", "HCL"),
+        (".cproject", "XML"),
+        (".feature", "Gherkin"),
+        (".dyalog", "APL"),
+        (".gpt", "Gerber Image"),
+        (".sublime-settings", "JSON with Comments"),
+        (".creole", "Creole"),
+        (".lid", "Dylan"),
+        (".kt", "Kotlin"),
+        (".fp", "GLSL"),
+        (".matlab", "MATLAB"),
+        (".mkdn", "Markdown"),
+        (".graphql", "GraphQL"),
+        (".mir", "YAML"),
+        (".vssettings", "XML"),
+        (".cy", "Cycript"),
+        (".jsproj", "XML"),
+        (".rbres", "REALbasic"),
+        (".ebuild", "Gentoo Ebuild"),
+        (".mmk", "Module Management System"),
+        (".litcoffee", "Literate CoffeeScript"),
+        (".ps1", "PowerShell"),
+        (".psc1", "XML"),
+        (".webapp", "JSON"),
+        (".gbr", "Gerber Image"),
+        (".nf", "Nextflow"),
+        (".gitconfig", "Git Config"),
+        (".nqp", "Raku"),
+        (".coffee", "CoffeeScript"),
+        (".ik", "Ioke"),
+        (".cjsx", "CoffeeScript"),
+        (".sh-session", "ShellSession"),
+        (".es6", "JavaScript"),
+        (".wxs", "XML"),
+        (".f95", "Fortran"),
+        (".cps", "Component Pascal"),
+        (".dot", "Graphviz (DOT)"),
+        (".lua", "Lua"),
+        (".eam.fs", "Formatted"),
+        (".jelly", "XML"),
+        (".cnc", "G-code"),
+        (".matah", "Stata"),
+        (".veo", "Verilog"),
+        (".nawk", "Awk"),
+        (".mjml", "XML"),
+        (".em", "EmberScript"),
+        (".owl", "Web Ontology Language"),
+        (".rake", "Ruby"),
+        (".textile", "Textile"),
+        (".csx", "C#"),
+        (".xrl", "Erlang"),
+        (".iml", "XML"),
+        (".fsh", "GLSL"),
+        (".tla", "TLA"),
+        (".purs", "PureScript"),
+        (".rego", "Open Policy Agent"),
+        (".psm1", "PowerShell"),
+        (".cbx", "TeX"),
+        (".rex", "REXX"),
+        (".ftl", "FreeMarker"),
+        (".ebnf", "EBNF"),
+        (".ss", "Scheme"),
+        (".gpb", "Gerber Image"),
+        (".swift", "Swift"),
+        (".tcl", "Tcl"),
+        (".vhw", "VHDL"),
+        (".nimrod", "Nim"),
+        (".pxd", "Cython"),
+        (".ld", "Linker Script"),
+        (".sce", "Scilab"),
+        (".ascx", "ASP"),
+        (".geom", "GLSL"),
+        (".zap", "ZAP"),
+        (".njk", "HTML+Django"),
+        (".cljscm", "Clojure"),
+        (".rhtml", "RHTML"),
+        (".psd1", "PowerShell"),
+        (".pgsql", "PLpgSQL"),
+        (".topojson", "JSON"),
+        (".muf", "MUF"),
+        (".html.hl", "HTML"),
+        (".ash", "AGS Script"),
+        (".applescript", "AppleScript"),
+        (".rest", "reStructuredText"),
+        (".sfd", "Spline Font Database"),
+        (".dats", "ATS"),
+        (".logtalk", "Logtalk"),
+        (".flex", "JFlex"),
+        (".riot", "Riot"),
+        (".arpa", "DNS Zone"),
+        (".hxx", "C++"),
+        (".psc", "Papyrus"),
+        (".rb", "Ruby"),
+        (".txt", "Text"),
+        (".vhi", "VHDL"),
+        (".dircolors", "dircolors"),
+        (".pan", "Pan"),
+        (".ivy", "XML"),
+        (".yasnippet", "YASnippet"),
+        (".mbox", "EML"),
+        (".volt", "Volt"),
+        (".nit", "Nit"),
+        (".jsb", "JavaScript"),
+        (".iss", "Inno Setup"),
+        (".rebol", "Rebol"),
+        (".rktd", "Racket"),
+        (".hql", "HiveQL"),
+        (".robot", "RobotFramework"),
+        (".shen", "Shen"),
+        (".rsx", "R"),
+        (".c++", "C++"),
+        (".obj", "Wavefront Object"),
+        (".kicad_pcb", "KiCad Layout"),
+        (".tcsh", "Tcsh"),
+        (".ditaval", "XML"),
+        (".xojo_menu", "Xojo"),
+        (".self", "Self"),
+        (".cljx", "Clojure"),
+        (".m3", "Modula-3"),
+        (".ruby", "Ruby"),
+        (".fun", "Standard ML"),
+        (".oxygene", "Oxygene"),
+        (".sjs", "JavaScript"),
+        (".ston", "STON"),
+        (".pbt", "PowerBuilder"),
+        (".yaml-tmlanguage", "YAML"),
+        (".scaml", "Scaml"),
+        (".texinfo", "Texinfo"),
+        (".lex", "Lex"),
+        (".vtt", "WebVTT"),
+        (".mdx", "Markdown"),
+        (".cginc", "HLSL"),
+        (".jinja", "HTML+Django"),
+        (".pascal", "Pascal"),
+        (".jl", "Julia"),
+        (".xacro", "XML"),
+        (".pasm", "Parrot Assembly"),
+        (".rbfrm", "REALbasic"),
+        (".ampl", "AMPL"),
+        (".thor", "Ruby"),
+        (".ma", "Mathematica"),
+        (".cirru", "Cirru"),
+        (".cpp-objdump", "Cpp-ObjDump"),
+        (".doh", "Stata"),
+        (".adoc", "AsciiDoc"),
+        (".scss", "SCSS"),
+        (".regexp", "Regular Expression"),
+        (".ssjs", "JavaScript"),
+        (".cdf", "Mathematica"),
+        (".sco", "Csound Score"),
+        (".rockspec", "Lua"),
+        (".stan", "Stan"),
+        (".edc", "Edje Data Collection"),
+        (".las", "Lasso"),
+        (".svg", "SVG"),
+        (".metal", "Metal"),
+        (".avsc", "JSON"),
+        (".nu", "Nu"),
+        (".gcode", "G-code"),
+        (".eclass", "Gentoo Eclass"),
+        (".cmd", "Batchfile"),
+        (".ndproj", "XML"),
+        (".bro", "Zeek"),
+        (".au3", "AutoIt"),
+        (".pls", "PLSQL"),
+        (".storyboard", "XML"),
+        (".builder", "Ruby"),
+        (".pogo", "PogoScript"),
+        (".ahkl", "AutoHotkey"),
+        (".sublime-keymap", "JSON with Comments"),
+        (".mata", "Stata"),
+        (".gbs", "Gerber Image"),
+        (".idr", "Idris"),
+        (".sra", "PowerBuilder"),
+        (".wast", "WebAssembly"),
+        (".model.lkml", "LookML"),
+        (".cfm", "ColdFusion"),
+        (".ipp", "C++"),
+        (".numpyw", "NumPy"),
+        (".yacc", "Yacc"),
+        (".eq", "EQ"),
+        (".uc", "UnrealScript"),
+        (".mcmeta", "JSON"),
+        (".mrc", "mIRC Script"),
+        (".qll", "CodeQL"),
+        (".ninja", "Ninja"),
+        (".afm", "Adobe Font Metrics"),
+        (".razor", "HTML+Razor"),
+        (".xsjs", "JavaScript"),
+        (".numpy", "NumPy"),
+        (".nsh", "NSIS"),
+        (".xi", "Logos"),
+        (".admx", "XML"),
+        (".mak", "Makefile"),
+        (".py3", "Python"),
+        (".6pm", "Raku"),
+        (".vbs", "VBScript"),
+        (".hrl", "Erlang"),
+        (".bones", "JavaScript"),
+        (".ol", "Jolie"),
+        (".sublime-completions", "JSON with Comments"),
+        (".tf", "HCL"),
+        (".cu", "Cuda"),
+        (".lslp", "LSL"),
+        (".jss", "JavaScript"),
+        (".pd_lua", "Lua"),
+        (".ahk", "AutoHotkey"),
+        (".8xp.txt", "TI Program"),
+        (".mss", "CartoCSS"),
+        (".outjob", "Altium Designer"),
+        (".y", "Yacc"),
+        (".nlogo", "NetLogo"),
+        (".groovy", "Groovy"),
+        (".reds", "Red"),
+        (".puml", "PlantUML"),
+        (".hlsli", "HLSL"),
+        (".js.erb", "JavaScript+ERB"),
+        (".tcc", "C++"),
+        (".hcl", "HCL"),
+        (".glf", "Glyph"),
+        (".yml.mysql", "YAML"),
+        (".duby", "Mirah"),
+        (".maxpat", "Max"),
+        (".vxml", "XML"),
+        (".phtml", "HTML+PHP"),
+        (".xpy", "Python"),
+        (".mll", "OCaml"),
+        (".xtend", "Xtend"),
+        (".bal", "Ballerina"),
+        (".emacs", "Emacs Lisp"),
+        (".tpp", "C++"),
+        (".icl", "Clean"),
+        (".pb", "PureBasic"),
+        (".axd", "ASP"),
+        (".rsc", "Rascal"),
+        (".zsh", "Shell"),
+        (".pbi", "PureBasic"),
+        (".proto", "Protocol Buffer"),
+        (".ql", "CodeQL"),
+        (".p6", "Raku"),
+        (".sps", "Scheme"),
+        (".scxml", "XML"),
+        (".fan", "Fantom"),
+        (".clj", "Clojure"),
+        (".cpp", "C++"),
+        (".ux", "XML"),
+        (".xojo_code", "Xojo"),
+        (".chs", "C2hs Haskell"),
+        (".sublime-commands", "JSON with Comments"),
+        (".texi", "Texinfo"),
+        (".rbtbar", "REALbasic"),
+        (".yara", "YARA"),
+        (".cppobjdump", "Cpp-ObjDump"),
+        (".trg", "PLSQL"),
+        (".nix", "Nix"),
+        (".gap", "GAP"),
+        (".xquery", "XQuery"),
+        (".bbx", "TeX"),
+        (".tea", "Tea"),
+        (".cmake", "CMake"),
+        (".rdf", "XML"),
+        (".god", "Ruby"),
+        (".nbp", "Mathematica"),
+        (".psgi", "Perl"),
+        (".mu", "mupad"),
+        (".lmi", "Python"),
+        (".har", "JSON"),
+        (".vark", "Gosu"),
+        (".pep", "Pep8"),
+        (".osm", "XML"),
+        (".eps", "PostScript"),
+        (".udo", "Csound"),
+        (".fpp", "Fortran"),
+        (".sublime-snippet", "XML"),
+        (".make", "Makefile"),
+        (".sublime-workspace", "JSON with Comments"),
+        (".eliom", "OCaml"),
+        (".gtpl", "Groovy"),
+        (".rs.in", "Rust"),
+        (".targets", "XML"),
+        (".soy", "Closure Templates"),
+        (".dockerfile", "Dockerfile"),
+        (".ashx", "ASP"),
+        (".xliff", "XML"),
+        (".pytb", "Python traceback"),
+        (".mjs", "JavaScript"),
+        (".intr", "Dylan"),
+        (".ccp", "COBOL"),
+        (".scrbl", "Racket"),
+        (".djs", "Dogescript"),
+        (".rbi", "Ruby"),
+        (".fea", "OpenType Feature File"),
+        (".pub", "Public Key"),
+        (".kicad_mod", "KiCad Layout"),
+        (".cats", "C"),
+        (".bdy", "PLSQL"),
+        (".sublime-macro", "JSON with Comments"),
+        (".app.src", "Erlang"),
+        (".pug", "Pug"),
+        (".gnu", "Gnuplot"),
+        (".dfm", "Pascal"),
+        (".mawk", "Awk"),
+        (".boot", "Clojure"),
+        (".vapi", "Vala"),
+        (".podspec", "Ruby"),
+        (".builds", "XML"),
+        (".gemspec", "Ruby"),
+        (".brs", "Brightscript"),
+        (".prjpcb", "Altium Designer"),
+        (".abap", "ABAP"),
+        (".ny", "Common Lisp"),
+        (".ml4", "OCaml"),
+        (".clw", "Clarion"),
+        (".jsonc", "JSON with Comments"),
+        (".dll.config", "XML"),
+        (".cfc", "ColdFusion CFC"),
+        (".boo", "Boo"),
+        (".sed", "sed"),
+        (".sublime-syntax", "YAML"),
+        (".xojo_toolbar", "Xojo"),
+        (".gst", "Gosu"),
+        (".jscad", "JavaScript"),
+        (".udf", "SQL"),
+        (".fxh", "HLSL"),
+        (".gtl", "Gerber Image"),
+        (".pkl", "Pickle"),
+        (".xpl", "XProc"),
+        (".sqf", "SQF"),
+        (".lsl", "LSL"),
+        (".aux", "TeX"),
+        (".mo", "Modelica"),
+        (".pxi", "Cython"),
+        (".twig", "Twig"),
+        (".launch", "XML"),
+        (".pkgproj", "XML"),
+        (".smali", "Smali"),
+        (".jsfl", "JavaScript"),
+        (".vsixmanifest", "XML"),
+        (".spin", "Propeller Spin"),
+        (".irclog", "IRC log"),
+        (".frg", "GLSL"),
+        (".sl", "Slash"),
+        (".ctp", "PHP"),
+        (".prefab", "Unity3D Asset"),
+        (".desktop.in", "desktop"),
+        (".zs", "ZenScript"),
+        (".ipf", "IGOR Pro"),
+        (".pkb", "PLSQL"),
+        (".command", "Shell"),
+        (".mirah", "Mirah"),
+        (".8xk.txt", "TI Program"),
+        (".lookml", "LookML"),
+        (".qasm", "OpenQASM"),
+        (".cjs", "JavaScript"),
+        (".sml", "Standard ML"),
+        (".py", "Python"),
+        (".moon", "MoonScript"),
+        (".geo", "GLSL"),
+        (".nim.cfg", "Nim"),
+        (".jbuilder", "Ruby"),
+        (".srdf", "XML"),
+        (".jsonld", "JSONLD"),
+        (".click", "Click"),
+        (".pyx", "Cython"),
+        (".reek", "YAML"),
+        (".gypi", "Python"),
+        (".rviz", "YAML"),
+        (".zep", "Zephir"),
+        (".rbxs", "Lua"),
+        (".pyp", "Python"),
+        (".resx", "XML"),
+        (".vho", "VHDL"),
+        (".mtl", "Wavefront Material"),
+        (".nginxconf", "Nginx"),
+        (".cson", "CSON"),
+        (".org", "Org"),
+        (".glade", "XML"),
+        (".mysql", "SQL"),
+        (".sthlp", "Stata"),
+        (".apacheconf", "ApacheConf"),
+        (".mxt", "Max"),
+        (".sci", "Scilab"),
+        (".do", "Stata"),
+        (".bib", "BibTeX"),
+        (".vhs", "VHDL"),
+        (".vh", "SystemVerilog"),
+        (".phps", "PHP"),
+        (".zone", "DNS Zone"),
+        (".asn", "ASN.1"),
+        (".rei", "Reason"),
+        (".agc", "Apollo Guidance Computer"),
+        (".nanorc", "nanorc"),
+        (".plx", "Perl"),
+        (".styl", "Stylus"),
+        (".xq", "XQuery"),
+        (".gdb", "GDB"),
+        (".mcr", "MAXScript"),
+        (".html", "HTML"),
+        (".ditamap", "XML"),
+        (".wixproj", "XML"),
+        (".conllu", "CoNLL-U"),
+        (".cpy", "COBOL"),
+        (".tool", "Shell"),
+        (".pprx", "REXX"),
+        (".postcss", "PostCSS"),
+        (".pri", "QMake"),
+        (".no", "Text"),
+        (".clixml", "XML"),
+        (".fancypack", "Fancy"),
+        (".pck", "PLSQL"),
+        (".am", "Makefile"),
+        (".sma", "Pawn"),
+        (".iol", "Jolie"),
+        (".mustache", "HTML+Django"),
+        (".sld", "Scheme"),
+        (".wxi", "XML"),
+        (".wat", "WebAssembly"),
+        (".dcl", "Clean"),
+        (".perl", "Perl"),
+        (".frt", "Forth"),
+        (".p4", "P4"),
+        (".eml", "EML"),
+        (".apib", "API Blueprint"),
+        (".bas", "VBA"),
+        (".xpm", "X PixMap"),
+        (".php4", "PHP"),
+        (".erl", "Erlang"),
+        (".rd", "R"),
+        (".sexp", "Common Lisp"),
+        (".aspx", "ASP"),
+        (".hlean", "Lean"),
+        (".gmx", "XML"),
+        (".wsdl", "XML"),
+        (".angelscript", "AngelScript"),
+        (".kojo", "Scala"),
+        (".r3", "Rebol"),
+        (".c", "C"),
+        (".gi", "GAP"),
+        (".jake", "JavaScript"),
+        (".ccproj", "XML"),
+        (".xsl", "XSLT"),
+        (".libsonnet", "Jsonnet"),
+        (".tfstate", "JSON"),
+        (".qbs", "QML"),
+        (".ant", "XML"),
+        (".d-objdump", "D-ObjDump"),
+        (".fth", "Forth"),
+        (".pfa", "PostScript"),
+        (".wlt", "Mathematica"),
+        (".smt2", "SMT"),
+        (".snippets", "Vim Snippet"),
+        (".oxo", "Ox"),
+        (".hats", "ATS"),
+        (".mkd", "Markdown"),
+        (".ni", "Inform 7"),
+        (".xml.dist", "XML"),
+        (".ihlp", "Stata"),
+        (".cfml", "ColdFusion"),
+        (".gp", "Gnuplot"),
+        (".tmpreferences", "XML Property List"),
+        (".scpt", "AppleScript"),
+        (".xproj", "XML"),
+        (".zcml", "XML"),
+        (".xproc", "XProc"),
+        (".6pl", "Raku"),
+        (".rst", "reStructuredText"),
+        (".gvy", "Groovy"),
+        (".decls", "BlitzBasic"),
+        (".nut", "Squirrel"),
+        (".mms", "Module Management System"),
+        (".qml", "QML"),
+        (".cljs.hl", "Clojure"),
+        (".flux", "FLUX"),
+        (".als", "Alloy"),
+        (".yml", "YAML"),
+        (".nasm", "Assembly"),
+        (".cshtml", "HTML+Razor"),
+        (".ox", "Ox"),
+        (".wxl", "XML"),
+        (".nasl", "NASL"),
+        (".webidl", "WebIDL"),
+        (".darcspatch", "Darcs Patch"),
+        (".wiki", "MediaWiki"),
+        (".omgrofl", "Omgrofl"),
+        (".go", "Go"),
+        (".regex", "Regular Expression"),
+        (".ado", "Stata"),
+        (".pks", "PLSQL"),
+        (".glsl", "GLSL"),
+        (".vb", "Visual Basic .NET"),
+        (".fnc", "PLSQL"),
+        (".urs", "UrWeb"),
+        (".cabal", "Cabal Config"),
+        (".asax", "ASP"),
+        (".tu", "Turing"),
+        (".lagda", "Literate Agda"),
+        (".eex", "HTML+EEX"),
+        (".tmux", "Shell"),
+        (".com", "DIGITAL Command Language"),
+        (".wdl", "wdl"),
+        (".emberscript", "EmberScript"),
+        (".vhdl", "VHDL"),
+        (".rsh", "RenderScript"),
+        (".arc", "Arc"),
+        (".rabl", "Ruby"),
+        (".vsh", "GLSL"),
+        (".zmpl", "Zimpl"),
+        (".sublime-mousemap", "JSON with Comments"),
+        (".gms", "GAMS"),
+        (".ct", "XML"),
+        (".axs.erb", "NetLinx+ERB"),
+        (".rl", "Ragel"),
+        (".rtf", "Rich Text Format"),
+        (".ll", "LLVM"),
+        (".cuh", "Cuda"),
+        (".pd", "Pure Data"),
+        (".markdown", "Markdown"),
+        (".nc", "nesC"),
+        (".asciidoc", "AsciiDoc"),
+        (".objdump", "ObjDump"),
+        (".txi", "Texinfo"),
+        (".hbs", "Handlebars"),
+        (".snip", "Vim Snippet"),
+        (".latte", "Latte"),
+        (".rktl", "Racket"),
+        (".krl", "KRL"),
+        (".js", "JavaScript"),
+        (".idc", "C"),
+        (".rst.txt", "reStructuredText"),
+        (".mako", "Mako"),
+        (".workbook", "Markdown"),
+        (".sublime_metrics", "JSON with Comments"),
+        (".clp", "CLIPS"),
+        (".pm6", "Raku"),
+        (".mkiv", "TeX"),
+        (".xib", "XML"),
+        (".njs", "JavaScript"),
+        (".phpt", "PHP"),
+        (".rbbas", "REALbasic"),
+        (".prg", "xBase"),
+        (".hxml", "HXML"),
+        (".h++", "C++"),
+        (".txl", "TXL"),
+        (".nearley", "Nearley"),
+        (".xml", "XML"),
+        (".axi", "NetLinx"),
+        (".ru", "Ruby"),
+        (".orc", "Csound"),
+        (".rbuistate", "REALbasic"),
+        (".pony", "Pony"),
+        (".axml", "XML"),
+        (".asmx", "ASP"),
+        (".prisma", "Prisma"),
+        (".smt", "SMT"),
+        (".csproj", "XML"),
+        (".bdf", "Glyph Bitmap Distribution Format"),
+        (".iuml", "PlantUML"),
+        (".xsp-config", "XPages"),
+        (".inl", "C++"),
+        (".pcss", "PostCSS"),
+        (".vht", "VHDL"),
+        (".gni", "GN"),
+        (".gdbinit", "GDB"),
+        (".4th", "Forth"),
+        (".sublime-project", "JSON with Comments"),
+        (".sbt", "Scala"),
+        (".sss", "SugarSS"),
+        (".tmcommand", "XML Property List"),
+        (".wl", "Mathematica"),
+        (".jsonnet", "Jsonnet"),
+        (".8xk", "TI Program"),
+        (".gradle", "Gradle"),
+        (".nsi", "NSIS"),
+        (".plsql", "PLSQL"),
+        (".vmb", "Vim script"),
+        (".opal", "Opal"),
+        (".eye", "Ruby"),
+        (".pl6", "Raku"),
+        (".yyp", "JSON"),
+        (".vhf", "VHDL"),
+        (".aj", "AspectJ"),
+        (".gaml", "GAML"),
+        (".fsi", "F#"),
+        (".odd", "XML"),
+        (".bsl", "1C Enterprise"),
+        (".xsjslib", "JavaScript"),
+        (".lvproj", "LabVIEW"),
+        (".lasso8", "Lasso"),
+        (".mg", "Modula-3"),
+        (".rest.txt", "reStructuredText"),
+        (".sh.in", "Shell"),
+        (".lfe", "LFE"),
+        (".me", "Roff"),
+        (".mdown", "Markdown"),
+        (".p8", "Lua"),
+        (".wisp", "wisp"),
+        (".gsp", "Groovy Server Pages"),
+        (".minid", "MiniD"),
+        (".xm", "Logos"),
+        (".erb.deface", "HTML+ERB"),
+        (".wlua", "Lua"),
+        (".syntax", "YAML"),
+        (".adp", "Tcl"),
+        (".ads", "Ada"),
+        (".vcxproj", "XML"),
+        (".maxhelp", "Max"),
+        (".gv", "Graphviz (DOT)"),
+        (".java", "Java"),
+        (".tfstate.backup", "JSON"),
+        (".ecr", "HTML+ECR"),
+        (".dpatch", "Darcs Patch"),
+        (".aug", "Augeas"),
+        (".eliomi", "OCaml"),
+        (".lol", "LOLCODE"),
+        (".mdwn", "Markdown"),
+        (".dm", "DM"),
+        (".c++-objdump", "Cpp-ObjDump"),
+        (".weechatlog", "IRC log"),
+        (".desktop", "desktop"),
+        (".xsd", "XML"),
+        (".csv", "CSV"),
+        (".depproj", "XML"),
+        (".yaml.sed", "YAML"),
+        (".opa", "Opa"),
+        (".anim", "Unity3D Asset"),
+        (".vcl", "VCL"),
+        (".dotsettings", "XML"),
+        (".urdf", "XML"),
+        (".dof", "INI"),
+        (".sj", "Objective-J"),
+        (".x3d", "XML"),
+        (".pwn", "Pawn"),
+        (".lgt", "Logtalk"),
+        (".yaml", "YAML"),
+        (".ooc", "ooc"),
+        (".css", "CSS"),
+        (".dpr", "Pascal"),
+        (".webmanifest", "JSON"),
+        (".pde", "Processing"),
+        (".lidr", "Idris"),
+        (".pmod", "Pike"),
+        (".nimble", "Nim"),
+        (".vstemplate", "XML"),
+        (".adml", "XML"),
+        (".zeek", "Zeek"),
+        (".eb", "Easybuild"),
+        (".wlk", "Wollok"),
+        (".diff", "Diff"),
+        (".mkdown", "Markdown"),
+        (".axi.erb", "NetLinx+ERB"),
+        (".cmake.in", "CMake"),
+        (".hack", "Hack"),
+        (".ktm", "Kotlin"),
+        (".cljc", "Clojure"),
+        (".prw", "xBase"),
+        (".bibtex", "BibTeX"),
+        (".hs", "Haskell"),
+        (".oxh", "Ox"),
+        (".mq5", "MQL5"),
+        (".less", "Less"),
+        (".json", "JSON"),
+        (".erb", "HTML+ERB"),
+        (".conll", "CoNLL-U"),
+        (".mk", "Makefile"),
+        (".mediawiki", "MediaWiki"),
+        (".aw", "PHP"),
+        (".jq", "JSONiq"),
+        (".mq4", "MQL4"),
+        (".gtp", "Gerber Image"),
+        (".mspec", "Ruby"),
+        (".mkfile", "Makefile"),
+        (".vhd", "VHDL"),
+        (".ps1xml", "XML"),
+        (".cql", "SQL"),
+        (".rbmnu", "REALbasic"),
+        (".ne", "Nearley"),
+        (".raw", "Raw token data"),
+        (".xql", "XQuery"),
+        (".chpl", "Chapel"),
+        (".xaml", "XML"),
+        (".csd", "Csound Document"),
+        (".dtx", "TeX"),
+        (".zig", "Zig"),
+        (".php5", "PHP"),
+        (".vw", "PLSQL"),
+        (".ccxml", "XML"),
+        (".dart", "Dart"),
+        (".ring", "Ring"),
+        (".gawk", "Awk"),
+        (".fxml", "XML"),
+        (".vrx", "GLSL"),
+        (".a51", "Assembly"),
+        (".rg", "Rouge"),
+        (".oz", "Oz"),
+        (".db2", "SQLPL"),
+        (".meta", "Unity3D Asset"),
+        (".tl", "Type Language"),
+        ("._js", "JavaScript"),
+        (".flf", "FIGlet Font"),
+        (".blade.php", "Blade"),
+        (".axs", "NetLinx"),
+        (".epsi", "PostScript"),
+        (".unity", "Unity3D Asset"),
+        (".elm", "Elm"),
+        (".sv", "SystemVerilog"),
+        (".ipynb", "Jupyter Notebook"),
+        (".vbproj", "XML"),
+        (".sparql", "SPARQL"),
+        (".scm", "Scheme"),
+        (".smk", "Python"),
+        (".scd", "SuperCollider"),
+        (".awk", "Awk"),
+        (".escript", "Erlang"),
+        (".mao", "Mako"),
+        (".tex", "TeX"),
+        (".dyl", "Dylan"),
+        (".tpl", "Smarty"),
+        (".pcbdoc", "Altium Designer"),
+        (".natvis", "XML"),
+        (".vbhtml", "Visual Basic .NET"),
+        (".xhtml", "HTML"),
+        (".frm", "VBA"),
+        (".mlir", "MLIR"),
+        (".sage", "Sage"),
+        (".glslf", "GLSL"),
+        (".spc", "PLSQL"),
+        (".yrl", "Erlang"),
+        (".rnh", "RUNOFF"),
+        (".slim", "Slim"),
+        (".lasso9", "Lasso"),
+        (".ijs", "J"),
+        (".cr", "Crystal"),
+        (".lasso", "Lasso"),
+        (".gql", "GraphQL"),
+        (".rbw", "Ruby"),
+        (".hsc", "Haskell"),
+        (".jflex", "JFlex"),
+        (".php3", "PHP"),
+        (".gsx", "Gosu"),
+        (".scala", "Scala"),
+        (".emacs.desktop", "Emacs Lisp"),
+        (".auk", "Awk"),
+        (".tpb", "PLSQL"),
+        (".pir", "Parrot Internal Representation"),
+        (".fy", "Fancy"),
+        (".xmi", "XML"),
+        (".gbl", "Gerber Image"),
+        (".sp", "SourcePawn"),
+        (".gbo", "Gerber Image"),
+        (".xsp.metadata", "XPages"),
+        (".f08", "Fortran"),
+        (".tmlanguage", "XML Property List"),
+        (".i3", "Modula-3"),
+        (".gco", "G-code"),
+        (".hy", "Hy"),
+        (".befunge", "Befunge"),
+        (".pyw", "Python"),
+        (".mli", "OCaml"),
+        (".x68", "Motorola 68K Assembly"),
+        (".os", "1C Enterprise"),
+        (".sru", "PowerBuilder"),
+        (".fsproj", "XML"),
+        (".fst", "F*"),
+        (".ly", "LilyPond"),
+        (".fish", "fish"),
+        (".nproj", "XML"),
+        (".rq", "SPARQL"),
+        (".mat", "Unity3D Asset"),
+        (".prefs", "INI"),
+        (".ph", "Perl"),
+        (".props", "XML"),
+        (".hs-boot", "Haskell"),
+        (".srw", "PowerBuilder"),
+        (".thy", "Isabelle"),
+        (".vim", "Vim script"),
+        (".adb", "Ada"),
+        (".nse", "Lua"),
+        (".i7x", "Inform 7"),
+        (".schdoc", "Altium Designer"),
+        (".tps", "PLSQL"),
+        (".xqy", "XQuery"),
+        (".sass", "Sass"),
+        (".haml.deface", "Haml"),
+        (".gn", "GN"),
+        (".sig", "Standard ML"),
+        (".epj", "Ecere Projects"),
+        (".ins", "TeX"),
+        (".zimpl", "Zimpl"),
+        (".cc", "C++"),
+        (".bzl", "Starlark"),
+        (".handlebars", "Handlebars"),
+        (".asset", "Unity3D Asset"),
+        (".bmx", "BlitzMax"),
+        (".monkey2", "Monkey"),
+        (".forth", "Forth"),
+        (".xojo_window", "Xojo"),
+        (".sh", "Shell"),
+        (".apl", "APL"),
+        (".pt", "XML"),
+        (".mtml", "MTML"),
+        (".asn1", "ASN.1"),
+        (".rmd", "RMarkdown"),
+        (".filters", "XML"),
+        (".zil", "ZIL"),
+        (".sttheme", "XML Property List"),
+        (".dylan", "Dylan"),
+        ("._ls", "LiveScript"),
+        (".tmsnippet", "XML Property List"),
+        (".reg", "Windows Registry Entries"),
+        (".mumps", "M"),
+        (".bash", "Shell"),
+        (".tab", "SQL"),
+        (".cocci", "SmPL"),
+    ]),
+};
+
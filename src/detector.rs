@@ -0,0 +1,659 @@
+use crate::{
+    detect_path_content, detect_str_with_confidence,
+    detectors::{self, ClassifierScratch, HeuristicRules},
+    filters,
+    stats::ScanCounters,
+    walk, BreakdownConfig, Detection, PathContentDetection, PathDetection,
+};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+/// A configurable detector for when the defaults used by [`detect`](crate::detect) and
+/// [`get_language_breakdown`](crate::get_language_breakdown) aren't enough
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{Detection, Detector};
+/// use std::path::Path;
+///
+/// let detector = Detector::new().add_extension(".gleam", "Gleam");
+/// let detection = detector.detect(Path::new("src/app.gleam")).unwrap();
+/// assert_eq!(detection, Some(Detection::Extension("Gleam")));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Detector {
+    extensions: HashMap<String, &'static str>,
+    reject_prose_misclassification: bool,
+    min_classifier_confidence: f64,
+    classifier_disabled: bool,
+    dedent_snippets: bool,
+    restricted_languages: Vec<&'static str>,
+    case_sensitive_c_extensions: bool,
+    heuristics: Option<HeuristicRules>,
+}
+
+// The extensions that are C++ when they're uppercase, following GCC, and C when they're lowercase
+const UPPERCASE_CPP_EXTENSIONS: &[&str] = &[".C", ".H"];
+
+impl Detector {
+    pub fn new() -> Self {
+        Detector::default()
+    }
+
+    /// Registers a language for an extension at runtime
+    ///
+    /// Files with the extension are detected as the language using the extension strategy. This
+    /// takes precedence over every other strategy so it can be used to add languages that aren't
+    /// in Linguist's languages.yml yet or to override the languages of an existing extension. The
+    /// extension is case insensitive and can be given with or without the leading dot.
+    pub fn add_extension(mut self, extension: &str, language: &'static str) -> Self {
+        let extension = extension.trim_start_matches('.').to_ascii_lowercase();
+        self.extensions.insert(format!(".{}", extension), language);
+        self
+    }
+
+    /// Sets whether files that look like prose are left undetected when the classifier would be
+    /// the one to pick their language
+    ///
+    /// Prose in a file with an ambiguous extension, like notes saved as `.h`, has no real signal
+    /// for the classifier, so its guess is usually a phantom programming language. When enabled,
+    /// these files aren't detected. Files detected by any other strategy are unaffected. Defaults
+    /// to false.
+    pub fn reject_prose_misclassification(mut self, reject: bool) -> Self {
+        self.reject_prose_misclassification = reject;
+        self
+    }
+
+    /// Sets the confidence below which the classifier's pick is ignored in favor of the
+    /// extension
+    ///
+    /// When the classifier picks between the candidates of an ambiguous extension with a
+    /// confidence below `min_confidence`, the file is detected as the first of the remaining
+    /// candidates using the extension strategy with a confidence of 0, the same language used when
+    /// none of the candidates can be classified. This trades the classifier's guesses on short or
+    /// unusual files for predictability. Files without an extension are unaffected. Defaults to 0,
+    /// which always keeps the classifier's pick.
+    ///
+    /// # Examples
+    /// ```
+    /// use hyperpolyglot::{Detection, Detector};
+    /// # if cfg!(not(feature = "classifier")) { return; }
+    ///
+    /// let detector = Detector::new().trust_extension_on_low_confidence(0.9);
+    /// let detection = detector.detect_from_str(Some("main.rs"), "x");
+    /// assert_eq!(detection, Some(Detection::Extension("Rust")));
+    /// ```
+    pub fn trust_extension_on_low_confidence(mut self, min_confidence: f64) -> Self {
+        self.min_classifier_confidence = min_confidence;
+        self
+    }
+
+    /// Sets whether the classifier is used to pick between the candidates that are left after
+    /// every other strategy
+    ///
+    /// The classifier is a statistical model that changes whenever it's retrained, so disabling it
+    /// makes detection purely rule based using the filename, extension, shebang, and heuristics.
+    /// When those leave more than one candidate the file isn't detected, rather than guessing
+    /// one of the candidates. Defaults to true. Without the `classifier` feature the classifier is
+    /// never used, as if this were set to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use hyperpolyglot::Detector;
+    ///
+    /// let detector = Detector::new().use_classifier(false);
+    /// let detection = detector.detect_from_str(Some("square.m"), "y = x .^ 2;");
+    /// assert_eq!(detection, None);
+    /// ```
+    pub fn use_classifier(mut self, use_classifier: bool) -> Self {
+        self.classifier_disabled = !use_classifier;
+        self
+    }
+
+    /// Sets whether the indentation shared by every line of content is removed before detecting
+    /// its language
+    ///
+    /// Snippets copied out of a nested block keep the indentation of the block, which hides a
+    /// shebang or first line signature from the detectors that expect them at the start of the
+    /// line. This only applies to content passed in directly, like with
+    /// [`detect_from_str`](Detector::detect_from_str), since files on disk aren't indented this
+    /// way. Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use hyperpolyglot::{Detection, Detector};
+    ///
+    /// let detector = Detector::new().dedent_snippets(true);
+    /// let detection = detector.detect_from_str(None, "    #!/bin/sh\n    echo hello\n");
+    /// assert_eq!(detection, Some(Detection::Shebang("Shell")));
+    /// ```
+    pub fn dedent_snippets(mut self, dedent: bool) -> Self {
+        self.dedent_snippets = dedent;
+        self
+    }
+
+    /// Restricts the languages the classifier picks from for files that no other strategy could
+    /// narrow down, like files without an extension
+    ///
+    /// Without a restriction these files are only detected if their first line or the directory
+    /// they're in identifies the language, since classifying them against every language is mostly
+    /// guesswork. When the languages that can show up are known ahead of time, these files are
+    /// classified against just those languages instead. Files with candidates from their extension
+    /// or shebang are unaffected.
+    ///
+    /// # Examples
+    /// ```
+    /// use hyperpolyglot::{Detection, Detector};
+    /// # if cfg!(not(feature = "classifier")) { return; }
+    ///
+    /// let detector = Detector::new().restrict_languages(&["Rust", "Go", "Python"]);
+    /// let detection = detector.detect_from_str(Some("build"), "def main():\n    print('hi')\n");
+    /// assert_eq!(detection, Some(Detection::Classifier("Python")));
+    /// ```
+    pub fn restrict_languages(mut self, languages: &[&'static str]) -> Self {
+        self.restricted_languages = languages.to_vec();
+        self
+    }
+
+    /// Sets whether the uppercase `.C` and `.H` extensions are detected as C++
+    ///
+    /// Extensions are case insensitive, so `foo.C` is C like `foo.c` is. GCC and some other
+    /// toolchains treat the uppercase extensions as C++ though, which this follows when enabled.
+    /// Extensions registered with [`add_extension`](Detector::add_extension) take precedence.
+    /// Defaults to false.
+    ///
+    /// # Examples
+    /// ```
+    /// use hyperpolyglot::{Detection, Detector};
+    ///
+    /// let detector = Detector::new().case_sensitive_c_extensions(true);
+    /// let detection = detector.detect_from_str(Some("foo.C"), "int main() {}");
+    /// assert_eq!(detection, Some(Detection::Extension("C++")));
+    /// ```
+    pub fn case_sensitive_c_extensions(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive_c_extensions = case_sensitive;
+        self
+    }
+
+    /// Sets the disambiguation rules loaded from a heuristics file at runtime
+    ///
+    /// The rules for an extension in the file shadow every compiled rule for that extension.
+    /// Extensions that aren't in the file are disambiguated with the compiled rules. See
+    /// [`HeuristicRules`] for an example.
+    pub fn heuristics(mut self, rules: HeuristicRules) -> Self {
+        self.heuristics = Some(rules);
+        self
+    }
+
+    /// Detects the programming language of the file at the given path. See
+    /// [`detect`](crate::detect) for details.
+    pub fn detect(&self, path: &Path) -> Result<Option<Detection>, std::io::Error> {
+        Ok(self
+            .detect_with_confidence(path)?
+            .map(|(detection, _)| detection))
+    }
+
+    /// Detects the programming language of content that has already been read, using the
+    /// filename for the filename and extension strategies. See
+    /// [`detect_with_content`](crate::detect_with_content) for details.
+    ///
+    /// Without a filename only the shebang can be used to detect the language.
+    pub fn detect_from_str(&self, filename: Option<&str>, content: &str) -> Option<Detection> {
+        let mut scratch = ClassifierScratch::default();
+        self.detect_str_with_scratch(filename, content, &mut scratch)
+    }
+
+    /// Detects the programming languages of many pieces of content, returning the detections in
+    /// the same order as the items
+    ///
+    /// Each item is an optional filename and the content of the file. The results are the same as
+    /// calling [`detect_from_str`](Detector::detect_from_str) on every item, but the buffers used
+    /// for tokenizing and scoring the content are reused between items, which adds up when
+    /// detecting lots of short snippets.
+    ///
+    /// # Examples
+    /// ```
+    /// use hyperpolyglot::{Detection, Detector};
+    ///
+    /// let detector = Detector::new();
+    /// let detections = detector.detect_batch(vec![
+    ///     (Some("main.rs"), "fn main() {}"),
+    ///     (None, "#!/usr/bin/env python\nprint('hello')"),
+    /// ]);
+    /// assert_eq!(
+    ///     detections,
+    ///     vec![
+    ///         Some(Detection::Heuristics("Rust")),
+    ///         Some(Detection::Shebang("Python"))
+    ///     ]
+    /// );
+    /// ```
+    pub fn detect_batch<'a, I>(&self, items: I) -> Vec<Option<Detection>>
+    where
+        I: IntoIterator<Item = (Option<&'a str>, &'a str)>,
+    {
+        let mut scratch = ClassifierScratch::default();
+        items
+            .into_iter()
+            .map(|(filename, content)| {
+                self.detect_str_with_scratch(filename, content, &mut scratch)
+            })
+            .collect()
+    }
+
+    /// Walks the path provided and tallies the programming languages detected in the given path.
+    /// See [`get_language_breakdown`](crate::get_language_breakdown) for details.
+    pub fn get_language_breakdown<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> HashMap<&'static str, Vec<(Detection, PathBuf)>> {
+        let mut language_breakdown = HashMap::new();
+        for file in walk(
+            path,
+            &BreakdownConfig::default(),
+            self,
+            None,
+            &ScanCounters::default(),
+        ) {
+            let files = language_breakdown
+                .entry(file.key())
+                .or_insert_with(Vec::new);
+            files.push((file.detection, file.path));
+        }
+
+        language_breakdown
+    }
+
+    pub(crate) fn detect_with_confidence(
+        &self,
+        path: &Path,
+    ) -> Result<Option<(Detection, f64)>, std::io::Error> {
+        Ok(self.detect_path(path)?.0)
+    }
+
+    // Detects the language of the file along with the confidence of the detection and the number
+    // of bytes read from the file
+    pub(crate) fn detect_path(&self, path: &Path) -> Result<PathDetection, std::io::Error> {
+        let (detection, content) = self.detect_path_content(path)?;
+        Ok((
+            detection,
+            content.map_or(0, |content| content.bytes.len() as u64),
+        ))
+    }
+
+    // Detects the language of the file like detect_path, along with the content read from the
+    // file if it had to be read
+    pub(crate) fn detect_path_content(
+        &self,
+        path: &Path,
+    ) -> Result<PathContentDetection, std::io::Error> {
+        let filename = path.file_name().and_then(|filename| filename.to_str());
+        if let Some(language) = filename.and_then(|filename| self.configured_language(filename)) {
+            return Ok((Some((Detection::Extension(language), 1.0)), None));
+        }
+        detect_path_content(path, self)
+    }
+
+    // Returns true if the detection the classifier made for the content should be rejected
+    pub(crate) fn rejects_classification(&self, content: &str) -> bool {
+        self.reject_prose_misclassification && filters::is_prose(content)
+    }
+
+    // Returns true if the classifier can be used to pick between the candidates left
+    pub(crate) fn uses_classifier(&self) -> bool {
+        cfg!(feature = "classifier") && !self.classifier_disabled
+    }
+
+    // Returns the languages to classify files without any candidates against, if they're
+    // restricted
+    pub(crate) fn restricted_languages(&self) -> Option<&[&'static str]> {
+        if self.restricted_languages.is_empty() {
+            None
+        } else {
+            Some(&self.restricted_languages)
+        }
+    }
+
+    // Returns the languages the heuristics narrow the candidates down to, using the runtime rules
+    // for the extension over the compiled rules when there are any
+    pub(crate) fn heuristic_languages(
+        &self,
+        extension: &str,
+        candidates: &[&'static str],
+        content: &str,
+    ) -> Vec<&'static str> {
+        self.heuristics
+            .as_ref()
+            .and_then(|rules| rules.get_languages(extension, candidates, content))
+            .unwrap_or_else(|| {
+                detectors::get_languages_from_heuristics(extension, candidates, content)
+            })
+    }
+
+    // Returns true if a classification with the confidence should give way to the extension
+    pub(crate) fn trusts_extension_over(&self, confidence: f64) -> bool {
+        confidence < self.min_classifier_confidence
+    }
+
+    fn detect_str_with_scratch(
+        &self,
+        filename: Option<&str>,
+        content: &str,
+        scratch: &mut ClassifierScratch,
+    ) -> Option<Detection> {
+        if let Some(language) = filename.and_then(|filename| self.configured_language(filename)) {
+            return Some(Detection::Extension(language));
+        }
+        let content = if self.dedent_snippets {
+            dedent(content)
+        } else {
+            Cow::Borrowed(content)
+        };
+        detect_str_with_confidence(filename.map(Path::new), &content, scratch, self)
+            .map(|(detection, _)| detection)
+    }
+
+    // Returns the language the detector is configured to detect the filename as, which takes
+    // precedence over every other strategy
+    fn configured_language(&self, filename: &str) -> Option<&'static str> {
+        self.registered_language(filename).or_else(|| {
+            let uppercase_cpp = UPPERCASE_CPP_EXTENSIONS
+                .iter()
+                .any(|extension| filename.ends_with(extension) && filename.len() > extension.len());
+            if self.case_sensitive_c_extensions && uppercase_cpp {
+                Some("C++")
+            } else {
+                None
+            }
+        })
+    }
+
+    // Returns the language registered for the longest extension the filename ends with
+    fn registered_language(&self, filename: &str) -> Option<&'static str> {
+        if self.extensions.is_empty() {
+            return None;
+        }
+
+        let filename = filename.to_ascii_lowercase();
+        filename
+            .char_indices()
+            .filter(|(_, ch)| *ch == '.')
+            .find_map(|(pos, _)| self.extensions.get(&filename[pos..]).copied())
+    }
+}
+
+// Removes the leading whitespace every non-blank line of the content starts with
+fn dedent(content: &str) -> Cow<'_, str> {
+    let indentation = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .fold(None, |common: Option<&str>, indentation| match common {
+            Some(common) => {
+                let shared = common
+                    .bytes()
+                    .zip(indentation.bytes())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                Some(&common[..shared])
+            }
+            None => Some(indentation),
+        })
+        .unwrap_or_default();
+    if indentation.is_empty() {
+        return Cow::Borrowed(content);
+    }
+
+    let dedented = content
+        .lines()
+        .map(|line| {
+            line.strip_prefix(indentation)
+                .unwrap_or_else(|| line.trim_start())
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    Cow::Owned(dedented)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_extension() {
+        let detector = Detector::new()
+            .add_extension("roc", "Roc")
+            .add_extension(".H", "Objective-C")
+            .add_extension(".d.ts", "TypeScript Declaration");
+
+        let detect = |path: &str| detector.detect(Path::new(path)).unwrap();
+        assert_eq!(detect("main.roc"), Some(Detection::Extension("Roc")));
+        assert_eq!(detect("MAIN.ROC"), Some(Detection::Extension("Roc")));
+        assert_eq!(detect("foo.h"), Some(Detection::Extension("Objective-C")));
+        assert_eq!(
+            detect("index.d.ts"),
+            Some(Detection::Extension("TypeScript Declaration"))
+        );
+        assert_eq!(
+            detect("pizza.purs"),
+            Some(Detection::Extension("PureScript"))
+        );
+    }
+
+    #[test]
+    fn test_case_sensitive_c_extensions() {
+        let detector = Detector::new().case_sensitive_c_extensions(true);
+        let detect = |filename: &str| detector.detect_from_str(Some(filename), "int a;\n");
+        assert_eq!(detect("foo.C"), Some(Detection::Extension("C++")));
+        assert_eq!(detect("foo.c"), Some(Detection::Extension("C")));
+        assert_eq!(detect("include/foo.H"), Some(Detection::Extension("C++")));
+        assert_eq!(detect(".C"), None);
+
+        // Extensions are case insensitive by default
+        let detector = Detector::new();
+        assert_eq!(
+            detector.detect_from_str(Some("foo.C"), "int a;\n"),
+            Some(Detection::Extension("C"))
+        );
+    }
+
+    #[test]
+    fn test_detect_batch() {
+        let detector = Detector::new().add_extension("roc", "Roc");
+        let items = vec![
+            (Some("main.roc"), "app \"hello\" provides [main] to pf"),
+            (Some("Makefile"), "all:\n\techo hi"),
+            (Some("script"), "#!/usr/bin/env python\nprint('hello')"),
+            (
+                Some("foo.h"),
+                "#include <iostream>\nclass Foo {\n  public:\n    Foo();\n};",
+            ),
+            (
+                Some("foo.h"),
+                "#include <stdio.h>\nint main(void) { return 0; }",
+            ),
+            (Some("query.sql"), "SELECT * FROM users;"),
+            (None, "#!/bin/bash\necho hi"),
+            (None, "no filename or shebang"),
+            (Some("unknown.xyz"), "?"),
+        ];
+
+        let batch = detector.detect_batch(items.clone());
+        let individual: Vec<_> = items
+            .into_iter()
+            .map(|(filename, content)| detector.detect_from_str(filename, content))
+            .collect();
+        assert_eq!(batch, individual);
+        assert_eq!(batch[0], Some(Detection::Extension("Roc")));
+        assert_eq!(batch[2], Some(Detection::Shebang("Python")));
+        assert_eq!(batch[7], None);
+    }
+
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_trust_extension_on_low_confidence() {
+        let matlab = "function y = square(x)\n  y = x .^ 2;\nend\n";
+
+        let detector = Detector::new();
+        assert_eq!(
+            detector.detect_from_str(Some("main.rs"), "x"),
+            Some(Detection::Classifier("RenderScript"))
+        );
+
+        let detector = Detector::new().trust_extension_on_low_confidence(0.9);
+        assert_eq!(
+            detector.detect_from_str(Some("main.rs"), "x"),
+            Some(Detection::Extension("Rust"))
+        );
+        // Confident classifications and files without an extension are unaffected
+        assert_eq!(
+            detector.detect_from_str(Some("square.m"), matlab),
+            Some(Detection::Classifier("MATLAB"))
+        );
+        assert_eq!(detector.detect_from_str(None, "x"), None);
+    }
+
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_use_classifier() {
+        // .h files always end up with a single candidate after the heuristics
+        let matlab = "function y = square(x)\n  y = x .^ 2;\nend\n";
+        let detector = Detector::new();
+        assert_eq!(
+            detector.detect_from_str(Some("square.m"), matlab),
+            Some(Detection::Classifier("MATLAB"))
+        );
+
+        let detector = Detector::new().use_classifier(false);
+        assert_eq!(detector.detect_from_str(Some("square.m"), matlab), None);
+        // The deterministic strategies still detect files
+        assert_eq!(
+            detector.detect_from_str(Some("point.h"), "@interface Point\n@end\n"),
+            Some(Detection::Heuristics("Objective-C"))
+        );
+        assert_eq!(
+            detector.detect_from_str(Some("main.go"), "package main\n"),
+            Some(Detection::Extension("Go"))
+        );
+    }
+
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_reject_prose_misclassification() {
+        let readme = "This project is a small tool for tracking your reading list. It was \
+                      written over a weekend and is not very polished, but it works.\n\n\
+                      To use it, run the program and follow the prompts.\n";
+        let code = "#include <stdio.h>\nint main(void) { return 0; }\n";
+
+        let detector = Detector::new();
+        assert_eq!(detector.detect_from_str(Some("README"), readme), None);
+        assert!(detector
+            .detect_from_str(Some("notes.inc"), readme)
+            .is_some());
+
+        let detector = Detector::new().reject_prose_misclassification(true);
+        assert_eq!(detector.detect_from_str(Some("README"), readme), None);
+        assert_eq!(detector.detect_from_str(Some("notes.inc"), readme), None);
+        assert_eq!(
+            detector.detect_from_str(Some("notes.txt"), readme),
+            Some(Detection::Extension("Text"))
+        );
+        assert!(detector.detect_from_str(Some("main.h"), code).is_some());
+    }
+
+    #[test]
+    fn test_dedent_snippets() {
+        let snippet =
+            "        #!/usr/bin/env python\n        def greet(name):\n\n            print(name)\n";
+        assert_eq!(Detector::new().detect_from_str(None, snippet), None);
+        assert_eq!(
+            Detector::new()
+                .dedent_snippets(true)
+                .detect_from_str(None, snippet),
+            Some(Detection::Shebang("Python"))
+        );
+    }
+
+    #[test]
+    fn test_dedent() {
+        assert_eq!(
+            dedent("    if x:\n\n        y()\n    z()\n"),
+            "if x:\n\n    y()\nz()"
+        );
+        assert_eq!(dedent("\t\ta\n\tb"), "\ta\nb");
+        assert!(matches!(dedent("a\n    b"), Cow::Borrowed(_)));
+        assert!(matches!(dedent(""), Cow::Borrowed(_)));
+    }
+
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_heuristics_without_extension_candidates() {
+        // .zork isn't in the extension map so the heuristics seed the candidates
+        let rules = HeuristicRules::from_yaml(
+            r#"
+disambiguations:
+- extensions: ['.zork']
+  rules:
+  - language: Python
+    pattern: '^\s*def\s+\w+\('
+  - language: [Shell, Perl]
+    pattern: '^\s*echo\b'
+named_patterns: {}
+"#,
+        )
+        .unwrap();
+        let detector = Detector::new().heuristics(rules);
+        assert_eq!(detectors::languages_from_extension(".zork"), &[] as &[&str]);
+        assert_eq!(
+            detector.detect_from_str(Some("game.zork"), "def play():\n    pass\n"),
+            Some(Detection::Heuristics("Python"))
+        );
+        // A rule with more than one language leaves the classifier to pick between them
+        assert!(matches!(
+            detector.detect_from_str(Some("game.zork"), "echo hello\necho world\n"),
+            Some(Detection::Classifier("Shell")) | Some(Detection::Classifier("Perl"))
+        ));
+        assert_eq!(
+            detector.detect_from_str(Some("game.zork"), "go north\n"),
+            None
+        );
+        assert_eq!(
+            Detector::new().detect_from_str(Some("game.zork"), "def play():\n    pass\n"),
+            None
+        );
+    }
+
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_restrict_languages() {
+        let python = "def greet(name):\n    print('hello ' + name)\n\ngreet('world')\n";
+        assert_eq!(Detector::new().detect_from_str(Some("greet"), python), None);
+
+        let detector = Detector::new().restrict_languages(&["Rust", "Go", "Python"]);
+        assert_eq!(
+            detector.detect_from_str(Some("greet"), python),
+            Some(Detection::Classifier("Python"))
+        );
+        let rust = "fn main() {\n    let mut names = Vec::new();\n    names.push(1);\n}\n";
+        assert_eq!(
+            detector.detect_from_str(Some("main"), rust),
+            Some(Detection::Classifier("Rust"))
+        );
+        // Files with candidates still use them
+        assert_eq!(
+            detector.detect_from_str(Some("main.c"), python),
+            Some(Detection::Extension("C"))
+        );
+        assert_eq!(detector.detect_from_str(Some("empty"), "\n"), None);
+        assert_eq!(
+            detector
+                .clone()
+                .use_classifier(false)
+                .detect_from_str(Some("greet"), python),
+            None
+        );
+    }
+}
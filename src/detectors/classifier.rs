@@ -1,58 +1,394 @@
-// Include the map that contains the token log probabilities
-// static TOKEN_LOG_PROBABILITIES: phf::Map<&'static str, f64> = ...;
+use lazy_static::lazy_static;
+use log::trace;
+use polyglot_tokenizer::{Token, Tokenizer};
+use std::{collections::HashMap, ops::Range};
+
+// Include the maps that contain the token log probabilities and the log probability of a token
+// that wasn't in a language's samples
+// static TOKEN_LOG_PROBABILITIES: phf::Map<&'static str, phf::Map<&'static str, f64>> = ...;
+// static UNSEEN_TOKEN_LOG_PROBABILITIES: phf::Map<&'static str, f64> = ...;
+#[cfg(feature = "classifier")]
 include!("../codegen/token-log-probabilities.rs");
 
+// Without the classifier feature the maps are left empty to keep them out of the binary
+#[cfg(not(feature = "classifier"))]
+static TOKEN_LOG_PROBABILITIES: phf::Map<&'static str, phf::Map<&'static str, f64>> = phf::Map {
+    key: 0,
+    disps: phf::Slice::Static(&[]),
+    entries: phf::Slice::Static(&[]),
+};
+#[cfg(not(feature = "classifier"))]
+static UNSEEN_TOKEN_LOG_PROBABILITIES: phf::Map<&'static str, f64> = phf::Map {
+    key: 0,
+    disps: phf::Slice::Static(&[]),
+    entries: phf::Slice::Static(&[]),
+};
+
 // Include the array of all possible languages
 // static LANGUAGES: &[&'static str] = ...;
 include!("../codegen/languages.rs");
 
-const MAX_TOKEN_BYTES: usize = 32;
+/// The longest identifier or symbol in bytes that the classifier considers. Longer tokens are
+/// mostly generated names, hashes, and encoded data that say little about the language, and the
+/// token probabilities were built from the samples with the same limit.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{tokenize, MAX_TOKEN_BYTES};
+///
+/// let long = "x".repeat(MAX_TOKEN_BYTES + 1);
+/// assert_eq!(tokenize(&long).count(), 0);
+/// assert_eq!(tokenize(&long[1..]).count(), 1);
+/// ```
+pub const MAX_TOKEN_BYTES: usize = 32;
 const DEFAULT_LOG_PROB: f64 = -19f64;
 
+// Candidate lists at least this long are scored token by token rather than language by language,
+// which looks every unique token up once instead of once per candidate
+const TOKEN_MAJOR_MIN_CANDIDATES: usize = 32;
+
+lazy_static! {
+    // The position of every language in LANGUAGES
+    static ref LANGUAGE_INDICES: HashMap<&'static str, usize> = LANGUAGES
+        .iter()
+        .enumerate()
+        .map(|(index, language)| (*language, index))
+        .collect();
+    // The unseen token log probability of every language in LANGUAGES, or None for the languages
+    // that don't have token probabilities
+    static ref LANGUAGE_UNSEEN_LOG_PROBABILITIES: Vec<Option<f64>> = LANGUAGES
+        .iter()
+        .map(|language| {
+            TOKEN_LOG_PROBABILITIES.get(language).map(|_| {
+                UNSEEN_TOKEN_LOG_PROBABILITIES
+                    .get(language)
+                    .copied()
+                    .unwrap_or(DEFAULT_LOG_PROB)
+            })
+        })
+        .collect();
+    // The token probabilities inverted to be token-major. Every token has the positions in
+    // LANGUAGES of the languages that have it along with how much more likely it is for them than
+    // an unseen token
+    static ref TOKEN_LANGUAGE_LOG_PROBABILITIES: HashMap<&'static str, Vec<(usize, f64)>> = {
+        let mut token_languages: HashMap<&'static str, Vec<(usize, f64)>> = HashMap::new();
+        for (index, language) in LANGUAGES.iter().enumerate() {
+            let (token_map, unseen_log_prob) = match (
+                TOKEN_LOG_PROBABILITIES.get(language),
+                LANGUAGE_UNSEEN_LOG_PROBABILITIES[index],
+            ) {
+                (Some(token_map), Some(unseen_log_prob)) => (token_map, unseen_log_prob),
+                _ => continue,
+            };
+            for (token, log_prob) in token_map.entries() {
+                token_languages
+                    .entry(token)
+                    .or_default()
+                    .push((index, log_prob - unseen_log_prob));
+            }
+        }
+        token_languages
+    };
+}
+
+/// The order the classifier looks up the token probabilities in, which only changes how fast it
+/// is. Only public for the benchmarks
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ClassifierLayout {
+    /// Every token is looked up in the probabilities of each candidate
+    LanguageMajor,
+    /// Every unique token is looked up once for the probabilities of every language
+    TokenMajor,
+}
+
 #[derive(Debug)]
 pub struct LanguageScore {
     language: &'static str,
     score: f64,
 }
 
+// Buffers used while classifying that can be reused across calls to avoid allocating them for
+// every piece of content classified
+#[derive(Debug, Default)]
+pub(crate) struct ClassifierScratch {
+    tokens: Vec<Range<usize>>,
+    scores: Vec<LanguageScore>,
+    // The scores of every language in LANGUAGES, used by the token-major layout
+    language_scores: Vec<f64>,
+}
+
+/// Tokenizes the content into the tokens used by the classifier. Only identifiers and symbols
+/// that are at most [`MAX_TOKEN_BYTES`] long are considered when classifying.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{tokenize, Token};
+///
+/// let tokens: Vec<Token> = tokenize("let x = \"hello\"; // comment").collect();
+/// let expected = vec![
+///     Token::Ident("let"),
+///     Token::Ident("x"),
+///     Token::Symbol("="),
+///     Token::Symbol(";"),
+/// ];
+/// assert_eq!(tokens, expected);
+/// ```
+pub fn tokenize(content: &str) -> impl Iterator<Item = Token<'_>> {
+    Tokenizer::new(content)
+        .tokens()
+        .filter(|token| match token {
+            Token::Ident(token) | Token::Symbol(token) => token.len() <= MAX_TOKEN_BYTES,
+            _ => false,
+        })
+}
+
 pub fn classify(content: &str, candidates: &[&'static str]) -> &'static str {
+    classify_with_confidence(content, candidates).0
+}
+
+/// Classifies the content and returns the winning language along with its confidence. The
+/// confidence is the winner's share of the probability across all of the candidates scored, so
+/// it is between 0 and 1.
+///
+/// If none of the candidates were in the classifier's training data, there is no way to tell them
+/// apart so the first candidate is returned with a confidence of 0. Without any candidates every
+/// language is scored, and if none of them can be, like when the `classifier` feature is
+/// disabled, an empty language name is returned with a confidence of 0.
+pub fn classify_with_confidence(content: &str, candidates: &[&'static str]) -> (&'static str, f64) {
+    match try_classify(content, candidates) {
+        Some(classification) => classification,
+        None => (candidates.first().copied().unwrap_or_default(), 0.0),
+    }
+}
+
+/// Classifies the content and returns the winning language along with its confidence, or None if
+/// none of the candidates were in the classifier's training data.
+///
+/// When the `classifier` feature is disabled, there are no token probabilities so this always
+/// returns None.
+pub fn try_classify(content: &str, candidates: &[&'static str]) -> Option<(&'static str, f64)> {
+    classify_with_scratch(content, candidates, &mut ClassifierScratch::default())
+}
+
+/// Classifies the content and returns every candidate the classifier could score along with its
+/// confidence, ranked from the most to the least likely. The confidences are each candidate's
+/// share of the probability, so they add up to 1 and the first is the confidence
+/// [`try_classify`] returns. Candidates that weren't in the classifier's training data are left
+/// out, so this is empty if none of them were.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::detectors::classify_with_scores;
+/// # if cfg!(not(feature = "classifier")) { return; }
+///
+/// let scores = classify_with_scores("fn main() {\n    let x: u32 = 1;\n}\n", &["Rust", "C", "Go"]);
+/// assert_eq!(scores[0].0, "Rust");
+/// assert_eq!(scores.len(), 3);
+/// assert!((scores.iter().map(|(_, confidence)| confidence).sum::<f64>() - 1.0).abs() < 1e-9);
+/// ```
+pub fn classify_with_scores(
+    content: &str,
+    candidates: &[&'static str],
+) -> Vec<(&'static str, f64)> {
+    let mut scratch = ClassifierScratch::default();
+    if classify_with_scratch(content, candidates, &mut scratch).is_none() {
+        return vec![];
+    }
+
+    // The scores are left sorted from the classification
+    let top_score = scratch.scores[0].score;
+    let total: f64 = scratch
+        .scores
+        .iter()
+        .map(|candidate| (candidate.score - top_score).exp())
+        .sum();
+    scratch
+        .scores
+        .iter()
+        .filter(|candidate| candidate.score.is_finite())
+        .map(|candidate| {
+            (
+                candidate.language,
+                (candidate.score - top_score).exp() / total,
+            )
+        })
+        .collect()
+}
+
+// Classifies the content like try_classify using the scratch buffers
+pub(crate) fn classify_with_scratch(
+    content: &str,
+    candidates: &[&'static str],
+    scratch: &mut ClassifierScratch,
+) -> Option<(&'static str, f64)> {
     let candidates = match candidates.len() {
         0 => LANGUAGES,
         _ => candidates,
     };
+    let layout = if candidates.len() >= TOKEN_MAJOR_MIN_CANDIDATES {
+        ClassifierLayout::TokenMajor
+    } else {
+        ClassifierLayout::LanguageMajor
+    };
+    classify_with_layout(content, candidates, layout, scratch)
+}
 
-    let tokens: Vec<_> = polyglot_tokenizer::get_key_tokens(content)
-        .filter(|token| token.len() <= MAX_TOKEN_BYTES)
-        .collect();
+/// Classifies the content like [`try_classify`], scoring the candidates with the given layout.
+/// Only public for the benchmarks
+#[doc(hidden)]
+pub fn try_classify_with_layout(
+    content: &str,
+    candidates: &[&'static str],
+    layout: ClassifierLayout,
+) -> Option<(&'static str, f64)> {
+    let candidates = match candidates.len() {
+        0 => LANGUAGES,
+        _ => candidates,
+    };
+    let mut scratch = ClassifierScratch::default();
+    classify_with_layout(content, candidates, layout, &mut scratch)
+}
 
-    let mut scored_candidates: Vec<LanguageScore> = candidates
-        .iter()
-        .map(|language| {
-            let score = match TOKEN_LOG_PROBABILITIES.get(language) {
-                Some(token_map) => tokens
-                    .iter()
-                    .map(|token| token_map.get(*token).copied().unwrap_or(DEFAULT_LOG_PROB))
-                    .sum(),
-                None => std::f64::NEG_INFINITY,
-            };
-            LanguageScore { language, score }
-        })
-        .collect();
+fn classify_with_layout(
+    content: &str,
+    candidates: &[&'static str],
+    layout: ClassifierLayout,
+    scratch: &mut ClassifierScratch,
+) -> Option<(&'static str, f64)> {
+    // There are no token probabilities to score the candidates with
+    if cfg!(not(feature = "classifier")) {
+        return None;
+    }
+
+    // Tokens are stored as ranges of the content so the buffer doesn't borrow the content
+    let tokens = &mut scratch.tokens;
+    tokens.clear();
+    tokens.extend(tokenize(content).filter_map(|token| match token {
+        Token::Ident(token) | Token::Symbol(token) => {
+            let start = token.as_ptr() as usize - content.as_ptr() as usize;
+            Some(start..start + token.len())
+        }
+        _ => None,
+    }));
+
+    let scored_candidates = &mut scratch.scores;
+    scored_candidates.clear();
+    match layout {
+        ClassifierLayout::LanguageMajor => {
+            score_language_major(scored_candidates, content, tokens, candidates)
+        }
+        ClassifierLayout::TokenMajor => score_token_major(
+            scored_candidates,
+            &mut scratch.language_scores,
+            content,
+            tokens,
+            candidates,
+        ),
+    }
 
     scored_candidates.sort_by(|a, b| {
         b.score
             .partial_cmp(&a.score)
             .unwrap_or(std::cmp::Ordering::Equal)
     });
+    trace!(
+        "classifier scores for {} tokens {:?}",
+        tokens.len(),
+        scored_candidates
+    );
 
-    scored_candidates[0].language
+    // Every candidate scores negative infinity when none of them have token probabilities
+    let top_score = scored_candidates[0].score;
+    if !top_score.is_finite() {
+        return None;
+    }
+
+    let total: f64 = scored_candidates
+        .iter()
+        .map(|candidate| (candidate.score - top_score).exp())
+        .sum();
+    Some((scored_candidates[0].language, 1.0 / total))
+}
+
+// Scores every candidate by summing the log probabilities of the tokens for it
+fn score_language_major(
+    scored_candidates: &mut Vec<LanguageScore>,
+    content: &str,
+    tokens: &[Range<usize>],
+    candidates: &[&'static str],
+) {
+    scored_candidates.extend(candidates.iter().map(|language| {
+        let score = match TOKEN_LOG_PROBABILITIES.get(language) {
+            Some(token_map) => {
+                let unseen_log_prob = UNSEEN_TOKEN_LOG_PROBABILITIES
+                    .get(language)
+                    .copied()
+                    .unwrap_or(DEFAULT_LOG_PROB);
+                tokens
+                    .iter()
+                    .map(|token| {
+                        token_map
+                            .get(&content[token.clone()])
+                            .copied()
+                            .unwrap_or(unseen_log_prob)
+                    })
+                    .sum()
+            }
+            None => f64::NEG_INFINITY,
+        };
+        LanguageScore { language, score }
+    }));
+}
+
+// Scores every candidate like score_language_major, but goes through the unique tokens once and
+// adds how much more likely each is than an unseen token to the scores of every language that has
+// it. Every language starts out scoring every token as unseen.
+fn score_token_major(
+    scored_candidates: &mut Vec<LanguageScore>,
+    language_scores: &mut Vec<f64>,
+    content: &str,
+    tokens: &[Range<usize>],
+    candidates: &[&'static str],
+) {
+    let mut token_counts: HashMap<&str, usize> = HashMap::new();
+    for token in tokens {
+        *token_counts.entry(&content[token.clone()]).or_default() += 1;
+    }
+
+    language_scores.clear();
+    language_scores.extend(
+        LANGUAGE_UNSEEN_LOG_PROBABILITIES
+            .iter()
+            .map(|unseen_log_prob| match unseen_log_prob {
+                Some(unseen_log_prob) => tokens.len() as f64 * unseen_log_prob,
+                None => f64::NEG_INFINITY,
+            }),
+    );
+    for (token, count) in token_counts {
+        if let Some(languages) = TOKEN_LANGUAGE_LOG_PROBABILITIES.get(token) {
+            for (index, log_prob_over_unseen) in languages {
+                language_scores[*index] += count as f64 * log_prob_over_unseen;
+            }
+        }
+    }
+
+    scored_candidates.extend(candidates.iter().map(|language| {
+        let score = LANGUAGE_INDICES
+            .get(language)
+            .map(|index| language_scores[*index])
+            .unwrap_or(f64::NEG_INFINITY);
+        LanguageScore { language, score }
+    }));
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "classifier")]
     use std::fs;
 
+    #[cfg(feature = "classifier")]
     #[test]
     fn test_classify() {
         let content = fs::read_to_string("samples/Rust/main.rs").unwrap();
@@ -71,6 +407,7 @@ mod tests {
         assert_eq!(language, "TypeScript");
     }
 
+    #[cfg(feature = "classifier")]
     #[test]
     fn test_classify_non_sample_data() {
         let sample = r#"#[cfg(not(feature = "pcre2"))]
@@ -81,11 +418,42 @@ mod tests {
     }
 
     imp(args)"#;
-        let candidates = vec!["Rust", "RenderScript"];
-        let language = classify(sample, &candidates);
+        // RenderScript is listed first so picking the first candidate doesn't pass
+        let candidates = vec!["RenderScript", "Rust"];
+        let (language, confidence) = try_classify(sample, &candidates).unwrap();
+        assert_eq!(language, "Rust");
+        assert!(confidence > 0.5);
+    }
+
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_classify_candidates_not_in_model() {
+        let content = fs::read_to_string("samples/Rust/main.rs").unwrap();
+        let candidates = vec!["Boo", "Ada"];
+        assert_eq!(try_classify(content.as_str(), &candidates), None);
+        assert_eq!(
+            classify_with_confidence(content.as_str(), &candidates),
+            ("Boo", 0.0)
+        );
+
+        // Candidates that aren't in the model never beat candidates that are
+        let candidates = vec!["Ada", "Rust"];
+        let (language, _) = try_classify(content.as_str(), &candidates).unwrap();
         assert_eq!(language, "Rust");
     }
 
+    #[test]
+    fn test_unseen_token_log_probabilities() {
+        for (language, token_map) in TOKEN_LOG_PROBABILITIES.entries() {
+            let unseen_log_prob = UNSEEN_TOKEN_LOG_PROBABILITIES[language];
+            assert!(unseen_log_prob.is_finite());
+            assert!(token_map
+                .values()
+                .all(|log_prob| *log_prob > unseen_log_prob));
+        }
+    }
+
+    #[cfg(feature = "classifier")]
     #[test]
     fn test_classify_empty_candidates() {
         let content = fs::read_to_string("samples/Rust/main.rs").unwrap();
@@ -94,6 +462,50 @@ mod tests {
         assert_eq!(language, "Rust");
     }
 
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_classify_with_confidence() {
+        let content = fs::read_to_string("samples/Rust/main.rs").unwrap();
+        let (language, confidence) = classify_with_confidence(content.as_str(), &["C", "Rust"]);
+        assert_eq!(language, "Rust");
+        assert!(confidence > 0.5 && confidence <= 1.0);
+
+        let (_, confidence) = classify_with_confidence("x", &["C", "C"]);
+        assert!((confidence - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_classify_layouts_agree() {
+        for sample in &[
+            "samples/Rust/main.rs",
+            "samples/TypeScript/classes.ts",
+            "samples/Fstar/Hacl.HKDF.fst",
+        ] {
+            let content = fs::read_to_string(sample).unwrap();
+            for candidates in &[&[][..], &["C", "Rust", "Boo"][..]] {
+                let (language_major, language_major_confidence) =
+                    try_classify_with_layout(&content, candidates, ClassifierLayout::LanguageMajor)
+                        .unwrap();
+                let (token_major, token_major_confidence) =
+                    try_classify_with_layout(&content, candidates, ClassifierLayout::TokenMajor)
+                        .unwrap();
+                assert_eq!(language_major, token_major);
+                assert!((language_major_confidence - token_major_confidence).abs() < 1e-6);
+            }
+        }
+
+        assert_eq!(
+            try_classify_with_layout("", &["C", "C"], ClassifierLayout::TokenMajor),
+            Some(("C", 0.5))
+        );
+        assert_eq!(
+            try_classify_with_layout("x", &["Boo", "Ada"], ClassifierLayout::TokenMajor),
+            None
+        );
+    }
+
+    #[cfg(feature = "classifier")]
     #[test]
     fn test_classify_f_star() {
         let content = fs::read_to_string("samples/Fstar/Hacl.HKDF.fst").unwrap();
@@ -1,26 +1,74 @@
+use std::borrow::Cow;
+
 // Include the map from extensions to languages at compile time
 // static EXTENSIONS: phf::Map<&'static str, &[&str]> = ...;
 include!("../codegen/extension-language-map.rs");
 
+// Include the map from the extensions that only map to one language to that language
+// static UNAMBIGUOUS_EXTENSIONS: phf::Map<&'static str, &'static str> = ...;
+include!("../codegen/unambiguous-extension-map.rs");
+
 pub fn get_languages_from_extension(extension: &str) -> Vec<&'static str> {
-    let languages = EXTENSIONS
-        .get(extension)
-        .map(|languages| languages.to_vec());
+    languages_from_extension(extension).to_vec()
+}
 
-    match languages {
-        Some(languages) => languages,
-        None => vec![],
-    }
+/// Returns the languages the extension maps to like [`get_languages_from_extension`], borrowing
+/// them from the static map instead of allocating a Vec
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::detectors::languages_from_extension;
+///
+/// assert_eq!(languages_from_extension(".go"), &["Go"]);
+/// assert!(languages_from_extension(".notrealextension").is_empty());
+/// ```
+pub fn languages_from_extension(extension: &str) -> &'static [&'static str] {
+    EXTENSIONS.get(extension).copied().unwrap_or(&[])
 }
 
+/// Returns the language of an extension that only maps to a single language, which is enough to
+/// detect the language of a file without looking at its content
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::detectors::get_language_from_unambiguous_extension;
+///
+/// assert_eq!(get_language_from_unambiguous_extension(".go"), Some("Go"));
+/// assert_eq!(get_language_from_unambiguous_extension(".h"), None);
+/// ```
+pub fn get_language_from_unambiguous_extension(extension: &str) -> Option<&'static str> {
+    UNAMBIGUOUS_EXTENSIONS.get(extension).copied()
+}
+
+// The suffix of templates that are filled in to generate a file, like config.h.in for config.h
+const TEMPLATE_SUFFIX: &str = ".in";
+
+/// Returns the longest known extension at the end of the filename, ignoring case
+///
+/// The leading dots of hidden files aren't part of an extension, so `.cs` and `..json` don't have
+/// one, and neither does a filename that ends with a dot like `main.rs.`. A template like
+/// `config.h.in` without a known extension of its own has the extension of the file it generates.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::detectors::get_extension;
+///
+/// assert_eq!(get_extension("a..b.rs"), Some(".rs"));
+/// assert_eq!(get_extension(".eslintrc.json"), Some(".json"));
+/// assert_eq!(get_extension("main.rs."), None);
+/// ```
 pub fn get_extension(filename: &str) -> Option<&'static str> {
-    let filename = if filename.starts_with('.') {
-        &filename[1..]
+    let filename = filename.trim_start_matches('.');
+    if filename.ends_with('.') {
+        return None;
+    }
+
+    // Most filenames are already lowercase so only allocate when they aren't
+    let filename = if filename.bytes().any(|byte| byte.is_ascii_uppercase()) {
+        Cow::Owned(filename.to_ascii_lowercase())
     } else {
-        filename
+        Cow::Borrowed(filename)
     };
-
-    let filename = filename.to_ascii_lowercase();
     for (pos, ch) in filename.char_indices() {
         if ch == '.' {
             if let Some(extension) = EXTENSIONS.get_key(&filename[pos..]) {
@@ -28,7 +76,12 @@ pub fn get_extension(filename: &str) -> Option<&'static str> {
             };
         };
     }
-    None
+
+    // A template without an extension of its own, like config.h.in, has the extension of the
+    // file it generates
+    filename
+        .strip_suffix(TEMPLATE_SUFFIX)
+        .and_then(get_extension)
 }
 
 #[cfg(test)]
@@ -48,6 +101,65 @@ mod tests {
         assert_eq!(get_languages_from_extension(""), empty_vec);
     }
 
+    #[test]
+    fn test_languages_from_extension() {
+        assert_eq!(languages_from_extension(".djs"), &["Dogescript"]);
+        assert_eq!(
+            languages_from_extension(".h").to_vec(),
+            EXTENSIONS[".h"].to_vec()
+        );
+        assert!(languages_from_extension("").is_empty());
+    }
+
+    #[test]
+    fn test_get_language_from_unambiguous_extension() {
+        assert_eq!(get_language_from_unambiguous_extension(".go"), Some("Go"));
+        assert_eq!(
+            get_language_from_unambiguous_extension(".purs"),
+            Some("PureScript")
+        );
+        // .rs is also RenderScript
+        assert_eq!(get_language_from_unambiguous_extension(".rs"), None);
+        assert_eq!(get_language_from_unambiguous_extension(".h"), None);
+        assert_eq!(get_language_from_unambiguous_extension(""), None);
+    }
+
+    #[test]
+    fn test_extension_maps_agree() {
+        // An extension is only in the unambiguous map when it maps to that one language
+        for (extension, languages) in EXTENSIONS.entries() {
+            let expected = match languages {
+                [language] => Some(*language),
+                _ => None,
+            };
+            assert_eq!(
+                get_language_from_unambiguous_extension(extension),
+                expected,
+                "{}",
+                extension
+            );
+            for language in languages.iter() {
+                assert!(crate::LANGUAGE_INFO.contains_key(language), "{}", language);
+            }
+        }
+        for (extension, language) in UNAMBIGUOUS_EXTENSIONS.entries() {
+            assert_eq!(
+                languages_from_extension(extension),
+                &[*language],
+                "{}",
+                extension
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_languages_from_extension_graphql() {
+        assert_eq!(get_languages_from_extension(".graphql"), vec!["GraphQL"]);
+        assert_eq!(get_languages_from_extension(".gql"), vec!["GraphQL"]);
+        assert_eq!(get_languages_from_extension(".graphqls"), vec!["GraphQL"]);
+        assert_eq!(get_extension("schema.graphqls"), Some(".graphqls"));
+    }
+
     #[test]
     fn test_get_extension() {
         assert_eq!(get_extension("index.djs"), Some(".djs"));
@@ -57,5 +169,29 @@ mod tests {
         assert_eq!(get_extension(".eslintrc.json"), Some(".json"));
         assert_eq!(get_extension(".cs"), None);
         assert_eq!(get_extension("noextension"), None);
+        // Templates have the extension of the file they generate
+        assert_eq!(get_extension("config.h.in"), Some(".h"));
+        assert_eq!(get_extension("setup.py.in"), Some(".py"));
+        assert_eq!(get_extension("version.in"), None);
+        // The longest extension wins
+        assert_eq!(get_extension("build.gradle.kts"), Some(".gradle.kts"));
+        assert_eq!(get_extension("script.kts"), Some(".kts"));
+    }
+
+    #[test]
+    fn test_get_extension_dots() {
+        assert_eq!(get_extension("foo."), None);
+        assert_eq!(get_extension("main.rs."), None);
+        assert_eq!(get_extension("main.rs.."), None);
+        assert_eq!(get_extension("a..b.rs"), Some(".rs"));
+        assert_eq!(get_extension("a.rs..b.c"), Some(".c"));
+        // Every leading dot is part of the name of a hidden file
+        assert_eq!(get_extension("..gitignore"), None);
+        assert_eq!(get_extension("..json"), None);
+        assert_eq!(get_extension("..config.json"), Some(".json"));
+        assert_eq!(get_extension("."), None);
+        assert_eq!(get_extension(".."), None);
+        assert_eq!(get_extension(""), None);
+        assert_eq!(get_extension("config.h.in."), None);
     }
 }
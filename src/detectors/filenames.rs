@@ -1,9 +1,60 @@
+use super::get_extension;
+
 // Include the map from filenames to languages at compile time
 // static FILENAMES: phf::Map<&'static str, &'static str> = ...;
 include!("../codegen/filename-language-map.rs");
 
+// Well known filenames that show up with different casing depending on the project or filesystem.
+// Most filenames are matched exactly because their casing can be meaningful.
+const CASE_INSENSITIVE_FILENAMES: &[&str] = &[
+    "Berksfile",
+    "Brewfile",
+    "CMakeLists.txt",
+    "Capfile",
+    "Dockerfile",
+    "Gemfile",
+    "Guardfile",
+    "Jenkinsfile",
+    "Makefile",
+    "Podfile",
+    "Rakefile",
+    "Vagrantfile",
+];
+
+// Prefixes of filenames that are variants of a well known filename, ex/ Dockerfile.ci. The
+// prefixes are matched case insensitively and only when the rest of the filename isn't an
+// extension of its own, so Dockerfile.md is still Markdown.
+const FILENAME_PREFIXES: &[(&str, &str)] = &[("Dockerfile.", "Dockerfile")];
+
 pub fn get_language_from_filename(filename: &str) -> Option<&'static str> {
-    FILENAMES.get(filename).copied()
+    FILENAMES
+        .get(filename)
+        .copied()
+        .or_else(|| {
+            CASE_INSENSITIVE_FILENAMES
+                .iter()
+                .find(|known_filename| known_filename.eq_ignore_ascii_case(filename))
+                .and_then(|known_filename| FILENAMES.get(*known_filename).copied())
+        })
+        .or_else(|| get_language_from_filename_prefix(filename))
+        // Templates like Dockerfile.in are the language of the file they generate
+        .or_else(|| {
+            filename
+                .strip_suffix(".in")
+                .and_then(get_language_from_filename)
+        })
+}
+
+fn get_language_from_filename_prefix(filename: &str) -> Option<&'static str> {
+    FILENAME_PREFIXES
+        .iter()
+        .find(|(prefix, _)| {
+            filename.len() > prefix.len()
+                && filename.is_char_boundary(prefix.len())
+                && filename[..prefix.len()].eq_ignore_ascii_case(prefix)
+        })
+        .filter(|_| get_extension(filename).is_none())
+        .map(|(_, language)| *language)
 }
 
 #[cfg(test)]
@@ -21,4 +72,56 @@ mod tests {
             Some("JSON with Comments")
         );
     }
+
+    #[test]
+    fn test_get_language_from_filename_dotfiles() {
+        assert_eq!(
+            get_language_from_filename(".editorconfig"),
+            Some("EditorConfig")
+        );
+        assert_eq!(
+            get_language_from_filename(".gitignore"),
+            Some("Ignore List")
+        );
+        assert_eq!(
+            get_language_from_filename(".npmignore"),
+            Some("Ignore List")
+        );
+        assert_eq!(
+            get_language_from_filename(".gitattributes"),
+            Some("Git Attributes")
+        );
+    }
+
+    #[test]
+    fn test_get_language_from_filename_case_insensitive() {
+        assert_eq!(get_language_from_filename("makefile"), Some("Makefile"));
+        assert_eq!(get_language_from_filename("MAKEFILE"), Some("Makefile"));
+        assert_eq!(get_language_from_filename("dockerfile"), Some("Dockerfile"));
+        assert_eq!(get_language_from_filename("DOCKERFILE"), Some("Dockerfile"));
+        assert_eq!(get_language_from_filename("apkbuild"), None);
+    }
+
+    #[test]
+    fn test_get_language_from_filename_prefix() {
+        assert_eq!(
+            get_language_from_filename("Dockerfile.ci"),
+            Some("Dockerfile")
+        );
+        assert_eq!(
+            get_language_from_filename("dockerfile.prod"),
+            Some("Dockerfile")
+        );
+        assert_eq!(get_language_from_filename("Dockerfile.md"), None);
+        assert_eq!(get_language_from_filename("Dockerfile."), None);
+        assert_eq!(get_language_from_filename("Dockerfiles"), None);
+    }
+
+    #[test]
+    fn test_filename_map_languages() {
+        for (filename, language) in FILENAMES.entries() {
+            assert_eq!(get_language_from_filename(filename), Some(*language));
+            assert!(crate::LANGUAGE_INFO.contains_key(language), "{}", language);
+        }
+    }
 }
@@ -0,0 +1,212 @@
+use log::trace;
+
+// Signatures that the first non-empty line of a file starts with and the language they identify.
+// The signatures are lowercase and are matched case insensitively.
+const FIRST_LINE_SIGNATURES: &[(&str, &str)] = &[
+    ("<?php", "PHP"),
+    ("<?xml", "XML"),
+    ("<!doctype html", "HTML"),
+    ("<html", "HTML"),
+    ("<svg", "SVG"),
+    ("<!doctype svg", "SVG"),
+    ("%yaml", "YAML"),
+    ("#cloud-config", "YAML"),
+    ("%!ps", "PostScript"),
+    ("{\\rtf", "Rich Text Format"),
+    ("\\documentclass", "TeX"),
+    ("diff --git ", "Diff"),
+    ("@echo off", "Batchfile"),
+    // zsh completion functions, which are named like _git
+    ("#compdef", "Shell"),
+    ("#autoload", "Shell"),
+];
+
+// The line that starts a YAML document or the YAML frontmatter of a document
+const YAML_DOCUMENT_START: &str = "---";
+
+// Returns the language of a file that starts with a YAML document start line given the lines after
+// it. Static site generators put YAML frontmatter between two of these lines at the top of
+// Markdown files, so a document after the frontmatter that doesn't look like more YAML makes the
+// file Markdown. Otherwise the whole file is YAML.
+fn frontmatter_host_language<'a, I: Iterator<Item = &'a str>>(mut lines: I) -> &'static str {
+    let frontmatter_closed = lines
+        .by_ref()
+        .any(|line| line == YAML_DOCUMENT_START || line == "...");
+    match lines.find(|line| !line.is_empty()) {
+        Some(line) if frontmatter_closed && !is_yaml_line(line) => {
+            trace!("YAML frontmatter followed by a document");
+            "Markdown"
+        }
+        _ => {
+            trace!("YAML document start matched");
+            "YAML"
+        }
+    }
+}
+
+// The signature of the XML declaration that XML based formats like SVG start with
+const XML_DECLARATION: &str = "<?xml";
+
+// The most lines after an XML declaration that are looked at for the root element
+const MAX_XML_PROLOG_LINES: usize = 8;
+
+// Returns the language of a file that starts with an XML declaration given the lines after it.
+// SVG images usually start with a declaration, so they're told apart from other XML by the
+// doctype or root element that follows it.
+fn xml_host_language<'a, I: Iterator<Item = &'a str>>(lines: I) -> &'static str {
+    let root = lines
+        .take(MAX_XML_PROLOG_LINES)
+        .find(|line| !line.is_empty() && !line.starts_with("<!--"))
+        .map(|line| line.to_ascii_lowercase());
+    match root {
+        Some(root) if root.starts_with("<svg") || root.starts_with("<!doctype svg") => {
+            trace!("XML declaration followed by an SVG root");
+            "SVG"
+        }
+        _ => "XML",
+    }
+}
+
+// Returns true if the line looks like the start of a YAML document, ex/ `key: value` or `- item`
+fn is_yaml_line(line: &str) -> bool {
+    if line == YAML_DOCUMENT_START || line.starts_with("- ") {
+        return true;
+    }
+    match line.find(':') {
+        Some(colon) => {
+            let key = &line[..colon];
+            !key.is_empty() && !key.starts_with('#') && !key.contains(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+// The most characters of the first line that are looked at, which is enough for every signature
+const MAX_SIGNATURE_CHARS: usize = 16;
+
+/// Returns the language identified by a well known signature at the start of the first non-empty
+/// line of the content, ex/ `<?php` or `#cloud-config`
+///
+/// This is used for files without an extension or filename the language can be detected from,
+/// like configuration files and scripts without a shebang. A first line of `---` starts YAML, or
+/// Markdown when the YAML is frontmatter followed by a document. An XML declaration starts XML, or
+/// SVG when it's followed by an SVG doctype or root element.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::detectors::get_language_from_first_line;
+///
+/// assert_eq!(get_language_from_first_line("\n<?xml version=\"1.0\"?>\n<a/>"), Some("XML"));
+/// assert_eq!(get_language_from_first_line("hello"), None);
+/// ```
+pub fn get_language_from_first_line(content: &str) -> Option<&'static str> {
+    let mut lines = content
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty());
+    let first_line = lines.next()?;
+    if first_line == YAML_DOCUMENT_START {
+        return Some(frontmatter_host_language(lines));
+    }
+    let start = first_line
+        .chars()
+        .take(MAX_SIGNATURE_CHARS)
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let (signature, language) = FIRST_LINE_SIGNATURES
+        .iter()
+        .find(|(signature, _)| start.starts_with(signature))?;
+    trace!("first line signature {:?} matched", signature);
+    if *signature == XML_DECLARATION {
+        return Some(xml_host_language(lines));
+    }
+    Some(language)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_language_from_first_line() {
+        assert_eq!(
+            get_language_from_first_line("<?php\necho 'hello';\n"),
+            Some("PHP")
+        );
+        assert_eq!(
+            get_language_from_first_line("\n\n  <?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"),
+            Some("XML")
+        );
+        assert_eq!(
+            get_language_from_first_line("%YAML 1.1\n---\nkey: value\n"),
+            Some("YAML")
+        );
+        assert_eq!(
+            get_language_from_first_line("#cloud-config\npackages:\n  - git\n"),
+            Some("YAML")
+        );
+        assert_eq!(
+            get_language_from_first_line("<!DOCTYPE html>\n<html></html>\n"),
+            Some("HTML")
+        );
+        assert_eq!(
+            get_language_from_first_line("@ECHO OFF\r\necho hello\r\n"),
+            Some("Batchfile")
+        );
+    }
+
+    #[test]
+    fn test_get_language_from_first_line_frontmatter() {
+        assert_eq!(
+            get_language_from_first_line(
+                "---\ntitle: Hello World\nlayout: post\n---\n\n# Hello\n\nMy first post.\n"
+            ),
+            Some("Markdown")
+        );
+        assert_eq!(
+            get_language_from_first_line("---\nname: build\non: push\n"),
+            Some("YAML")
+        );
+        // A YAML file with multiple documents is still YAML
+        assert_eq!(
+            get_language_from_first_line("---\na: 1\n---\nb: 2\n"),
+            Some("YAML")
+        );
+        assert_eq!(
+            get_language_from_first_line("---\n- a\n---\n- b\n"),
+            Some("YAML")
+        );
+    }
+
+    #[test]
+    fn test_get_language_from_first_line_svg() {
+        assert_eq!(
+            get_language_from_first_line("<svg xmlns=\"http://www.w3.org/2000/svg\"/>"),
+            Some("SVG")
+        );
+        assert_eq!(
+            get_language_from_first_line(
+                "<?xml version=\"1.0\"?>\n<!-- Created with Inkscape -->\n\n<svg width=\"10\">\n</svg>\n"
+            ),
+            Some("SVG")
+        );
+        assert_eq!(
+            get_language_from_first_line(
+                "<?xml version=\"1.0\"?>\n<!DOCTYPE svg PUBLIC \"-//W3C//DTD SVG 1.1//EN\">\n<svg/>\n"
+            ),
+            Some("SVG")
+        );
+        assert_eq!(
+            get_language_from_first_line("<?xml version=\"1.0\"?>\n<project>\n</project>\n"),
+            Some("XML")
+        );
+    }
+
+    #[test]
+    fn test_get_language_from_first_line_no_signature() {
+        assert_eq!(get_language_from_first_line(""), None);
+        assert_eq!(get_language_from_first_line("\n\n"), None);
+        assert_eq!(get_language_from_first_line("key: value\n<?php\n"), None);
+        assert_eq!(get_language_from_first_line("# cloud-config\n"), None);
+    }
+}
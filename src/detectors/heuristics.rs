@@ -1,4 +1,7 @@
-use pcre2::bytes::RegexBuilder as PCRERegex;
+use log::trace;
+use pcre2::bytes::{Regex, RegexBuilder as PCRERegex};
+use serde::Deserialize;
+use std::{collections::HashMap, error, fmt, fs, io, path::Path};
 
 // Include the map from interpreters to languages at compile time
 // static DISAMBIGUATIONS: phf::Map<&'static str, &'static [Rule]> = ...;
@@ -18,7 +21,13 @@ struct Rule {
     pattern: Option<Pattern>,
 }
 
-impl Pattern {
+// A pattern the rules for an extension match content with, either one compiled into the crate or
+// one loaded at runtime
+trait Matcher: fmt::Debug {
+    fn matches(&self, content: &str) -> bool;
+}
+
+impl Matcher for Pattern {
     fn matches(&self, content: &str) -> bool {
         match self {
             Pattern::Positive(pattern) => {
@@ -49,28 +58,319 @@ pub fn get_languages_from_heuristics(
     content: &str,
 ) -> Vec<&'static str> {
     match DISAMBIGUATIONS.get(extension) {
-        Some(rules) => {
-            let rules = rules.iter().filter(|rule| {
-                rule.languages
-                    .iter()
-                    .all(|language| candidates.contains(language))
+        Some(rules) => get_languages_from_rules(
+            extension,
+            rules
+                .iter()
+                .map(|rule| (rule.languages, rule.pattern.as_ref())),
+            candidates,
+            content,
+        ),
+        None => vec![],
+    }
+}
+
+// Returns the languages of the first rule that matches the content, only considering the rules
+// whose languages are all candidates. Without any candidates every rule is considered, which
+// seeds the candidates of an extension the heuristics know about but the extensions don't
+fn get_languages_from_rules<'a, P: Matcher + 'a>(
+    extension: &str,
+    rules: impl Iterator<Item = (&'a [&'static str], Option<&'a P>)>,
+    candidates: &[&'static str],
+    content: &str,
+) -> Vec<&'static str> {
+    let rules = rules.filter(|(languages, _)| {
+        candidates.is_empty()
+            || languages
+                .iter()
+                .all(|language| candidates.contains(language))
+    });
+    for (languages, pattern) in rules {
+        if let Some(pattern) = pattern {
+            if pattern.matches(content) {
+                trace!("{} heuristic {:?} matched", extension, pattern);
+                return languages.to_vec();
+            };
+        } else {
+            // if there is no pattern then it is a match by default
+            trace!("{} fallback heuristic for {:?}", extension, languages);
+            return languages.to_vec();
+        };
+    }
+    vec![]
+}
+
+/// Disambiguation rules loaded from a heuristics file at runtime, for trying out new rules
+/// without regenerating the rules compiled into the crate
+///
+/// The file uses the same schema as Linguist's heuristics.yml that the compiled rules are
+/// generated from. The rules for an extension in the file shadow all of the compiled rules for
+/// that extension, while extensions that aren't in the file keep using the compiled rules. Like
+/// the compiled rules, `.h` falls back to C when none of its rules match. The patterns are
+/// compiled when the rules are loaded. Use [`Detector::heuristics`](crate::Detector::heuristics)
+/// to detect with the rules.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{Detection, Detector, HeuristicRules};
+///
+/// let rules = HeuristicRules::from_yaml(r#"
+/// disambiguations:
+/// - extensions: ['.pl']
+///   rules:
+///   - language: Raku
+///     pattern: '^\s*use\s+v6\b'
+/// named_patterns: {}
+/// "#).unwrap();
+/// let detector = Detector::new().heuristics(rules);
+/// let detection = detector.detect_from_str(Some("hello.pl"), "use v6;\nsay 'hi';\n");
+/// assert_eq!(detection, Some(Detection::Heuristics("Raku")));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HeuristicRules {
+    disambiguations: HashMap<String, Vec<LoadedRule>>,
+}
+
+// The loaded rules own their languages and patterns, unlike the compiled rules which borrow them
+// from the generated map
+#[derive(Debug, Clone)]
+struct LoadedRule {
+    languages: Vec<&'static str>,
+    pattern: Option<LoadedPattern>,
+}
+
+#[derive(Debug, Clone)]
+enum LoadedPattern {
+    And(Vec<LoadedPattern>),
+    Negative(Regex),
+    Or(Vec<LoadedPattern>),
+    Positive(Regex),
+}
+
+impl Matcher for LoadedPattern {
+    fn matches(&self, content: &str) -> bool {
+        match self {
+            LoadedPattern::Positive(regex) => regex.is_match(content.as_bytes()).unwrap_or(false),
+            LoadedPattern::Negative(regex) => !regex.is_match(content.as_bytes()).unwrap_or(true),
+            LoadedPattern::Or(patterns) => patterns.iter().any(|pattern| pattern.matches(content)),
+            LoadedPattern::And(patterns) => patterns.iter().all(|pattern| pattern.matches(content)),
+        }
+    }
+}
+
+impl HeuristicRules {
+    /// Loads the rules from a heuristics file
+    ///
+    /// # Errors
+    /// Errors if the file can't be read or if its rules are invalid, see
+    /// [`from_yaml`](HeuristicRules::from_yaml)
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<HeuristicRules, HeuristicsError> {
+        HeuristicRules::from_yaml(&fs::read_to_string(path)?)
+    }
+
+    /// Parses the rules from the YAML of a heuristics file
+    ///
+    /// # Errors
+    /// Errors if the YAML doesn't follow the heuristics.yml schema, a pattern isn't a valid regex,
+    /// or a rule refers to a named pattern that isn't defined
+    pub fn from_yaml(yaml: &str) -> Result<HeuristicRules, HeuristicsError> {
+        let heuristics: HeuristicsDTO = serde_yaml::from_str(yaml)?;
+
+        // The rules are kept in the order they appear in the file, since the first match wins
+        let mut disambiguations: HashMap<String, Vec<LoadedRule>> = HashMap::new();
+        for disambiguation in heuristics.disambiguations.iter() {
+            let mut rules = vec![];
+            for rule in disambiguation.rules.iter() {
+                if let Some(rule) = rule.to_rule(&heuristics.named_patterns)? {
+                    rules.push(rule);
+                }
+            }
+            for extension in disambiguation.extensions.iter() {
+                disambiguations
+                    .entry(extension.to_ascii_lowercase())
+                    .or_default()
+                    .extend(rules.iter().cloned());
+            }
+        }
+        // The compiled rules default .h to C when no other rule matches, see codegen
+        if let Some(rules) = disambiguations.get_mut(".h") {
+            rules.push(LoadedRule {
+                languages: vec!["C"],
+                pattern: None,
             });
-            for rule in rules {
-                if let Some(pattern) = &rule.pattern {
-                    if pattern.matches(content) {
-                        return rule.languages.to_vec();
-                    };
-                } else {
-                    // if there is no pattern then it is a match by default
-                    return rule.languages.to_vec();
-                };
+        }
+        Ok(HeuristicRules { disambiguations })
+    }
+
+    /// Returns the languages the rules for the extension narrow the candidates down to, or None
+    /// if the rules don't have the extension
+    pub(crate) fn get_languages(
+        &self,
+        extension: &str,
+        candidates: &[&'static str],
+        content: &str,
+    ) -> Option<Vec<&'static str>> {
+        let rules = self.disambiguations.get(extension)?;
+        Some(get_languages_from_rules(
+            extension,
+            rules
+                .iter()
+                .map(|rule| (&rule.languages[..], rule.pattern.as_ref())),
+            candidates,
+            content,
+        ))
+    }
+}
+
+/// An error loading the rules of a heuristics file
+#[derive(Debug)]
+pub enum HeuristicsError {
+    /// The file couldn't be read
+    Io(io::Error),
+    /// The file isn't valid YAML or doesn't follow the heuristics.yml schema
+    Yaml(serde_yaml::Error),
+    /// A pattern isn't a valid regex, along with the error compiling it
+    InvalidPattern(String, String),
+    /// A rule refers to a named pattern that isn't in the named patterns
+    UnknownNamedPattern(String),
+}
+
+impl fmt::Display for HeuristicsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HeuristicsError::Io(e) => write!(f, "couldn't read the heuristics file: {}", e),
+            HeuristicsError::Yaml(e) => write!(f, "invalid heuristics file: {}", e),
+            HeuristicsError::InvalidPattern(pattern, e) => {
+                write!(f, "invalid pattern {:?}: {}", pattern, e)
+            }
+            HeuristicsError::UnknownNamedPattern(name) => {
+                write!(f, "the named pattern {} isn't defined", name)
             }
-            vec![]
         }
-        None => vec![],
     }
 }
 
+impl error::Error for HeuristicsError {}
+
+impl From<io::Error> for HeuristicsError {
+    fn from(e: io::Error) -> Self {
+        HeuristicsError::Io(e)
+    }
+}
+
+impl From<serde_yaml::Error> for HeuristicsError {
+    fn from(e: serde_yaml::Error) -> Self {
+        HeuristicsError::Yaml(e)
+    }
+}
+
+// The schema of heuristics.yml, which is the same one the codegen reads
+type NamedPatterns = HashMap<String, MaybeMany<String>>;
+
+#[derive(Deserialize)]
+struct HeuristicsDTO {
+    disambiguations: Vec<DisambiguationDTO>,
+    #[serde(default)]
+    named_patterns: NamedPatterns,
+}
+
+#[derive(Deserialize)]
+struct DisambiguationDTO {
+    extensions: Vec<String>,
+    rules: Vec<RuleDTO>,
+}
+
+#[derive(Deserialize)]
+struct RuleDTO {
+    language: MaybeMany<String>,
+    #[serde(flatten)]
+    pattern: Option<PatternDTO>,
+}
+
+impl RuleDTO {
+    // Returns None for a rule with a language that isn't in languages.yml, since the rule could
+    // never be the one that detects the content
+    fn to_rule(
+        &self,
+        named_patterns: &NamedPatterns,
+    ) -> Result<Option<LoadedRule>, HeuristicsError> {
+        let pattern = match &self.pattern {
+            Some(pattern) => Some(pattern.to_pattern(named_patterns)?),
+            None => None,
+        };
+        let languages = match &self.language {
+            MaybeMany::Many(languages) => languages.clone(),
+            MaybeMany::One(language) => vec![language.clone()],
+        };
+        let languages: Option<Vec<&'static str>> = languages
+            .iter()
+            .map(|language| crate::LANGUAGE_INFO.get_key(&language[..]).copied())
+            .collect();
+        Ok(languages.map(|languages| LoadedRule { languages, pattern }))
+    }
+}
+
+#[derive(Clone, Deserialize)]
+enum PatternDTO {
+    #[serde(rename = "and")]
+    And(Vec<PatternDTO>),
+    #[serde(rename = "named_pattern")]
+    Named(String),
+    #[serde(rename = "negative_pattern")]
+    Negative(String),
+    #[serde(rename = "pattern")]
+    Positive(MaybeMany<String>),
+}
+
+impl PatternDTO {
+    fn to_pattern(&self, named_patterns: &NamedPatterns) -> Result<LoadedPattern, HeuristicsError> {
+        let pattern = match self {
+            PatternDTO::Positive(MaybeMany::One(pattern)) => {
+                LoadedPattern::Positive(build_regex(pattern)?)
+            }
+            PatternDTO::Negative(pattern) => LoadedPattern::Negative(build_regex(pattern)?),
+            PatternDTO::Positive(MaybeMany::Many(patterns)) => {
+                let patterns = patterns
+                    .iter()
+                    .map(|pattern| Ok(LoadedPattern::Positive(build_regex(pattern)?)))
+                    .collect::<Result<Vec<LoadedPattern>, HeuristicsError>>()?;
+                LoadedPattern::Or(patterns)
+            }
+            PatternDTO::And(patterns) => {
+                let patterns = patterns
+                    .iter()
+                    .map(|pattern| pattern.to_pattern(named_patterns))
+                    .collect::<Result<Vec<LoadedPattern>, HeuristicsError>>()?;
+                LoadedPattern::And(patterns)
+            }
+            PatternDTO::Named(name) => match named_patterns.get(name) {
+                // Named patterns are always positive
+                Some(pattern) => {
+                    PatternDTO::Positive(pattern.clone()).to_pattern(named_patterns)?
+                }
+                None => return Err(HeuristicsError::UnknownNamedPattern(name.clone())),
+            },
+        };
+        Ok(pattern)
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(untagged)]
+enum MaybeMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+// Compiles the pattern with the same options the compiled rules match with
+fn build_regex(pattern: &str) -> Result<Regex, HeuristicsError> {
+    PCRERegex::new()
+        .crlf(true)
+        .multi_line(true)
+        .build(pattern)
+        .map_err(|e| HeuristicsError::InvalidPattern(pattern.to_string(), e.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,6 +395,212 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_heuristics_get_languages_sql_dialects() {
+        let candidates = vec!["PLSQL", "PLpgSQL", "SQL", "SQLPL", "TSQL"];
+        assert_eq!(
+            get_languages_from_heuristics(
+                ".sql",
+                &candidates,
+                "DO $$\nBEGIN\n  RAISE NOTICE 'hello';\nEND\n$$;\n"
+            ),
+            vec!["PLpgSQL"]
+        );
+        assert_eq!(
+            get_languages_from_heuristics(
+                ".sql",
+                &candidates,
+                "CREATE FUNCTION add(a integer, b integer) RETURNS integer\n\
+                 LANGUAGE plpgsql\nAS 'select a + b';\n"
+            ),
+            vec!["PLpgSQL"]
+        );
+        assert_eq!(
+            get_languages_from_heuristics(
+                ".sql",
+                &candidates,
+                "CREATE TABLE users (name NVARCHAR(100) NOT NULL);\nGO\n"
+            ),
+            vec!["TSQL"]
+        );
+        assert_eq!(
+            get_languages_from_heuristics(
+                ".sql",
+                &candidates,
+                "UPDATE users SET active = 0 WHERE age > 99;\nSELECT @@ROWCOUNT;\n"
+            ),
+            vec!["TSQL"]
+        );
+        assert_eq!(
+            get_languages_from_heuristics(
+                ".sql",
+                &candidates,
+                "SELECT name FROM users ORDER BY name;\n"
+            ),
+            vec!["SQL"]
+        );
+    }
+
+    #[test]
+    fn test_heuristics_get_languages_csharp() {
+        let candidates = ["Smalltalk", "C#"];
+        for content in &[
+            "using System;\n\nclass Program { static void Main() {} }\n",
+            "using static System.Math;\n",
+            "namespace Acme.Tools;\n\nclass Tool {}\n",
+            "public sealed class Point\n{\n    public int X { get; }\n}\n",
+            "internal interface IShape {}\n",
+        ] {
+            assert_eq!(
+                get_languages_from_heuristics(".cs", &candidates, content),
+                vec!["C#"],
+                "{}",
+                content
+            );
+        }
+        assert_eq!(
+            get_languages_from_heuristics(
+                ".cs",
+                &candidates,
+                "!Point methodsFor: 'accessing'!\nx\n    ^x! !\n"
+            ),
+            vec!["Smalltalk"]
+        );
+    }
+
+    #[test]
+    fn test_heuristics_get_languages_first_rule_wins() {
+        // Both the Objective-C and C++ rules match but Objective-C comes first in heuristics.yml
+        assert_eq!(
+            get_languages_from_heuristics(
+                ".h",
+                &["C", "C++", "Objective-C"],
+                "@interface Foo : NSObject\n@end\nstd::string name;\n"
+            ),
+            vec!["Objective-C"]
+        );
+        assert_eq!(
+            get_languages_from_heuristics(
+                ".sql",
+                &["PLSQL", "PLpgSQL", "SQL", "SQLPL", "TSQL"],
+                "DO $$ BEGIN PERFORM 1; END $$;\nGO\n"
+            ),
+            vec!["PLpgSQL"]
+        );
+    }
+
+    #[test]
+    fn test_heuristics_rules_in_file_order() {
+        let heuristics = std::fs::read_to_string("heuristics.yml").unwrap();
+        let heuristics: serde_yaml::Value = serde_yaml::from_str(&heuristics).unwrap();
+        let rule_languages = |rule: &serde_yaml::Value| -> Vec<String> {
+            match &rule["language"] {
+                serde_yaml::Value::Sequence(languages) => languages
+                    .iter()
+                    .map(|language| language.as_str().unwrap().to_string())
+                    .collect(),
+                language => vec![language.as_str().unwrap().to_string()],
+            }
+        };
+
+        for disambiguation in heuristics["disambiguations"].as_sequence().unwrap() {
+            let expected: Vec<Vec<String>> = disambiguation["rules"]
+                .as_sequence()
+                .unwrap()
+                .iter()
+                .map(rule_languages)
+                .collect();
+            for extension in disambiguation["extensions"].as_sequence().unwrap() {
+                let extension = extension.as_str().unwrap().to_ascii_lowercase();
+                let actual: Vec<Vec<String>> = DISAMBIGUATIONS[extension.as_str()]
+                    .iter()
+                    .take(expected.len())
+                    .map(|rule| rule.languages.iter().map(|l| l.to_string()).collect())
+                    .collect();
+                assert_eq!(actual, expected, "{}", extension);
+            }
+        }
+    }
+
+    #[test]
+    fn test_heuristic_rules_from_yaml() {
+        let rules = HeuristicRules::from_yaml(
+            r#"
+disambiguations:
+- extensions: ['.h']
+  rules:
+  - language: Not A Language
+    pattern: '^\s*namespace\b'
+  - language: C++
+    named_pattern: cpp
+  - language: Objective-C
+    and:
+    - pattern: '^@interface'
+    - negative_pattern: 'TODO'
+named_patterns:
+  cpp:
+  - '^\s*namespace\b'
+  - '^\s*template\s*<'
+"#,
+        )
+        .unwrap();
+        let candidates = ["C", "C++", "Objective-C"];
+        let languages = |content| rules.get_languages(".h", &candidates, content);
+
+        assert_eq!(languages("namespace a {}\n"), Some(vec!["C++"]));
+        assert_eq!(
+            languages("@interface Foo\n@end\n"),
+            Some(vec!["Objective-C"])
+        );
+        // The rules from the file replace the compiled rules, with the same fallback to C
+        assert_eq!(languages("@interface Foo\n// TODO\n"), Some(vec!["C"]));
+        assert_eq!(languages("#include <vector>\n"), Some(vec!["C"]));
+        assert_eq!(rules.get_languages(".sql", &["SQL", "TSQL"], "GO"), None);
+        // A rule for a language that isn't known is skipped, even without any candidates
+        assert_eq!(
+            rules.get_languages(".h", &[], "namespace a {}\n"),
+            Some(vec!["C++"])
+        );
+    }
+
+    #[test]
+    fn test_heuristic_rules_from_yaml_errors() {
+        let yaml = |rule: &str| {
+            format!(
+                "disambiguations:\n- extensions: ['.pl']\n  rules:\n  - {}\nnamed_patterns: {{}}\n",
+                rule
+            )
+        };
+        assert!(matches!(
+            HeuristicRules::from_yaml(&yaml("language: Perl\n    pattern: '(unclosed'")),
+            Err(HeuristicsError::InvalidPattern(_, _))
+        ));
+        assert!(matches!(
+            HeuristicRules::from_yaml(&yaml("language: Perl\n    named_pattern: missing")),
+            Err(HeuristicsError::UnknownNamedPattern(_))
+        ));
+        assert!(matches!(
+            HeuristicRules::from_yaml("rules: []"),
+            Err(HeuristicsError::Yaml(_))
+        ));
+        assert!(matches!(
+            HeuristicRules::from_file("not-a-heuristics-file.yml"),
+            Err(HeuristicsError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_heuristic_rules_from_file_matches_compiled() {
+        let rules = HeuristicRules::from_file("heuristics.yml").unwrap();
+        let candidates = ["PLSQL", "PLpgSQL", "SQL", "SQLPL", "TSQL"];
+        for content in &["DO $$ BEGIN END $$;", "SELECT @@ROWCOUNT;", "SELECT 1;"] {
+            assert_eq!(
+                rules.get_languages(".sql", &candidates, content),
+                Some(get_languages_from_heuristics(".sql", &candidates, content))
+            );
+        }
+    }
+
     #[test]
     fn test_heuristics_get_languages_and_positives_pattern() {
         assert_eq!(
@@ -8,20 +8,45 @@ include!("../codegen/interpreter-language-map.rs");
 pub fn get_languages_from_shebang<R: std::io::BufRead>(
     reader: R,
 ) -> Result<Vec<&'static str>, std::io::Error> {
-    let mut lines = reader.lines();
-    let shebang_line = match lines.next() {
-        Some(line) => line,
-        None => return Ok(vec![]),
-    }?;
+    let languages = get_interpreter_from_shebang(reader)?
+        .and_then(|interpreter| get_languages_from_interpreter(&interpreter));
+
+    match languages {
+        Some(languages) => Ok(languages.to_vec()),
+        None => Ok(vec![]),
+    }
+}
+
+/// Returns the interpreter named by the shebang on the first line, without its path or version
+///
+/// The interpreter is the program `env` runs for `#!/usr/bin/env` shebangs, and the program a
+/// `sh` script `exec`s itself with for the shebang hack.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::detectors::get_interpreter_from_shebang;
+/// use std::io::Cursor;
+///
+/// let interpreter = get_interpreter_from_shebang(Cursor::new("#!/usr/bin/env ruby -w\n")).unwrap();
+/// assert_eq!(interpreter, Some(String::from("ruby")));
+/// ```
+pub fn get_interpreter_from_shebang<R: std::io::BufRead>(
+    mut reader: R,
+) -> Result<Option<String>, std::io::Error> {
+    let mut shebang_line = Vec::new();
+    reader.read_until(b'\n', &mut shebang_line)?;
+    let shebang_line = String::from_utf8_lossy(&shebang_line);
+    let shebang_line = shebang_line.trim_end_matches(&['\n', '\r'][..]);
+    let lines = reader.lines();
     let mut extra_content = String::new();
 
     if !shebang_line.starts_with("#!") {
-        return Ok(vec![]);
+        return Ok(None);
     }
 
-    let languages = shebang_line
+    let interpreter = shebang_line
         .split('/')
-        .last()
+        .next_back()
         .and_then(|interpreter_line| {
             let mut splits = interpreter_line.split_whitespace();
             match splits.next() {
@@ -48,19 +73,45 @@ pub fn get_languages_from_shebang<R: std::io::BufRead>(
                 None => None,
             }
         })
-        .and_then(|interpreter| {
+        .map(|interpreter| {
             // #!/usr/bin/python2.6.3 -> #!/usr/bin/python2
             lazy_static! {
                 static ref RE: Regex = Regex::new(r#"[0-9]\.[0-9]"#).unwrap();
             }
-            let interpreter = RE.split(interpreter).next().unwrap();
-
-            INTERPRETERS.get(interpreter)
+            RE.split(interpreter).next().unwrap().to_string()
         });
 
-    match languages {
-        Some(languages) => Ok(languages.to_vec()),
-        None => Ok(vec![]),
+    Ok(interpreter)
+}
+
+fn get_languages_from_interpreter(interpreter: &str) -> Option<&'static [&'static str]> {
+    if let Some(languages) = INTERPRETERS.get(interpreter) {
+        return Some(*languages);
+    }
+
+    // #!/usr/bin/perl.exe -> #!/usr/bin/perl
+    let len = interpreter.len();
+    let interpreter = if len > 4 && interpreter.is_char_boundary(len - 4) {
+        match interpreter.split_at(len - 4) {
+            (name, suffix) if suffix.eq_ignore_ascii_case(".exe") => name,
+            _ => interpreter,
+        }
+    } else {
+        interpreter
+    };
+    if let Some(languages) = INTERPRETERS.get(interpreter) {
+        return Some(*languages);
+    }
+
+    // #!/usr/bin/Python -> #!/usr/bin/python
+    // Only used when exactly one interpreter matches so that interpreters differing only by
+    // case are never confused with each other
+    let mut matches = INTERPRETERS
+        .entries()
+        .filter(|(name, _)| name.eq_ignore_ascii_case(interpreter));
+    match (matches.next(), matches.next()) {
+        (Some((_, languages)), None) => Some(*languages),
+        _ => None,
     }
 }
 
@@ -76,6 +127,33 @@ mod tests {
             vec!["Python"]
         );
     }
+    #[test]
+    fn test_shebang_case_insensitive() {
+        assert_eq!(
+            get_languages_from_shebang(Cursor::new("#!/usr/bin/Python")).unwrap(),
+            vec!["Python"]
+        );
+        assert_eq!(
+            get_languages_from_shebang(Cursor::new("#!/usr/bin/env RSCRIPT")).unwrap(),
+            vec!["R"]
+        );
+    }
+
+    #[test]
+    fn test_shebang_exe() {
+        assert_eq!(
+            get_languages_from_shebang(Cursor::new("#!/usr/bin/perl.exe")).unwrap(),
+            get_languages_from_shebang(Cursor::new("#!/usr/bin/perl")).unwrap()
+        );
+        assert_eq!(
+            get_languages_from_shebang(Cursor::new("#!/usr/bin/env Python.EXE")).unwrap(),
+            vec!["Python"]
+        );
+        assert!(get_languages_from_shebang(Cursor::new("#!/usr/bin/.exe"))
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn test_shebang_get_languages_env() {
         assert_eq!(
@@ -144,6 +222,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_shebang_invalid_utf8() {
+        assert_eq!(
+            get_languages_from_shebang(Cursor::new(b"#!/usr/bin/python \xff\n")).unwrap(),
+            vec!["Python"]
+        );
+    }
+
     #[test]
     fn test_shebang_hack() {
         let content = Cursor::new(
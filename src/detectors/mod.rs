@@ -1,11 +1,24 @@
 mod classifier;
 mod extensions;
 mod filenames;
+mod first_line;
 mod heuristics;
 mod interpreters;
+mod paths;
 
-pub use classifier::classify;
-pub use extensions::{get_extension, get_languages_from_extension};
+pub use classifier::{
+    classify, classify_with_confidence, classify_with_scores, tokenize, try_classify,
+    MAX_TOKEN_BYTES,
+};
+pub(crate) use classifier::{classify_with_scratch, ClassifierScratch};
+#[doc(hidden)]
+pub use classifier::{try_classify_with_layout, ClassifierLayout};
+pub use extensions::{
+    get_extension, get_language_from_unambiguous_extension, get_languages_from_extension,
+    languages_from_extension,
+};
 pub use filenames::get_language_from_filename;
-pub use heuristics::get_languages_from_heuristics;
-pub use interpreters::get_languages_from_shebang;
+pub use first_line::get_language_from_first_line;
+pub use heuristics::{get_languages_from_heuristics, HeuristicRules, HeuristicsError};
+pub use interpreters::{get_interpreter_from_shebang, get_languages_from_shebang};
+pub use paths::get_language_from_path_convention;
@@ -0,0 +1,77 @@
+use log::trace;
+use std::path::Path;
+
+// Directories that hold files of a single language by convention even though the files don't
+// have an extension, like the shell completion directories of bash and zsh. A file matches when
+// the directory it's in ends with one of these paths.
+const PATH_CONVENTIONS: &[(&str, &str)] = &[
+    ("bash-completion/completions", "Shell"),
+    ("bash_completion.d", "Shell"),
+    ("zsh/site-functions", "Shell"),
+    ("zsh/vendor-completions", "Shell"),
+    ("zsh/functions", "Shell"),
+];
+
+/// Returns the language the directory a file is in holds by convention, ex/ files in
+/// `zsh/site-functions` are shell completions
+///
+/// This is only used for files that no other strategy could detect since the files in these
+/// directories don't have an extension or a distinctive filename.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::detectors::get_language_from_path_convention;
+/// use std::path::Path;
+///
+/// let path = Path::new("/usr/share/zsh/site-functions/_cargo");
+/// assert_eq!(get_language_from_path_convention(path), Some("Shell"));
+/// ```
+pub fn get_language_from_path_convention(path: &Path) -> Option<&'static str> {
+    let directory = path.parent()?;
+    let (convention, language) = PATH_CONVENTIONS
+        .iter()
+        .find(|(convention, _)| directory.ends_with(convention))?;
+    trace!(
+        "{} matched the path convention {}",
+        path.display(),
+        convention
+    );
+    Some(language)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_language_from_path_convention() {
+        assert_eq!(
+            get_language_from_path_convention(Path::new(
+                "dotfiles/bash-completion/completions/hyply"
+            )),
+            Some("Shell")
+        );
+        assert_eq!(
+            get_language_from_path_convention(Path::new("/etc/bash_completion.d/git")),
+            Some("Shell")
+        );
+        assert_eq!(
+            get_language_from_path_convention(Path::new("zsh/site-functions/_git")),
+            Some("Shell")
+        );
+
+        // Only the directory the file is directly in is checked
+        assert_eq!(
+            get_language_from_path_convention(Path::new("zsh/site-functions/docs/README")),
+            None
+        );
+        assert_eq!(
+            get_language_from_path_convention(Path::new("bash_completion.d")),
+            None
+        );
+        assert_eq!(
+            get_language_from_path_convention(Path::new("my-zsh/site-functions/_git")),
+            None
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use crate::detectors;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{ops::Range, path::Path};
+
+// The extensions of single-file component formats along with the default language of their
+// script sections. Astro's scripts are TypeScript unless they say otherwise.
+const COMPONENT_EXTENSIONS: &[(&str, &str)] = &[
+    (".vue", "JavaScript"),
+    (".svelte", "JavaScript"),
+    (".astro", "TypeScript"),
+];
+
+// The values of the lang attribute of a section and the languages they're written in
+const LANG_ATTRIBUTES: &[(&str, &str)] = &[
+    ("coffee", "CoffeeScript"),
+    ("css", "CSS"),
+    ("html", "HTML"),
+    ("javascript", "JavaScript"),
+    ("js", "JavaScript"),
+    ("jsx", "JavaScript"),
+    ("less", "Less"),
+    ("postcss", "CSS"),
+    ("pug", "Pug"),
+    ("sass", "Sass"),
+    ("scss", "SCSS"),
+    ("styl", "Stylus"),
+    ("stylus", "Stylus"),
+    ("ts", "TypeScript"),
+    ("tsx", "TSX"),
+    ("typescript", "TypeScript"),
+];
+
+lazy_static! {
+    static ref SECTION_START: Regex =
+        Regex::new(r"(?i)<(script|style|template)\b([^>]*)>").unwrap();
+    static ref SECTION_TAG: Regex = Regex::new(r"(?i)<(/?)(script|style|template)\b").unwrap();
+    static ref LANG_ATTRIBUTE: Regex = Regex::new(r#"(?i)\blang\s*=\s*["']?([\w-]+)"#).unwrap();
+}
+
+/// A section of a single-file component that's written in its own language, like the
+/// `<script>` of a Vue component
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedSection {
+    /// The language the section is written in
+    pub language: &'static str,
+    /// The byte range of the section's content in the file, not including the tags around it
+    pub range: Range<usize>,
+}
+
+/// Detects the languages of the sections embedded in a Vue, Svelte, or Astro single-file
+/// component
+///
+/// The `<script>`, `<style>`, and `<template>` sections are reported with the language in their
+/// `lang` attribute, or JavaScript, CSS, and HTML when they don't have one. Astro's frontmatter
+/// and scripts are TypeScript by default. Sections in a language that isn't known and empty
+/// sections are left out. The component itself is still detected as Vue, Svelte, or Astro by
+/// [`detect`](crate::detect), this only reports what it's made of. Files that aren't single-file
+/// components don't have any sections.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::detect_embedded;
+/// use std::path::Path;
+///
+/// let component = "<template><p>{{ msg }}</p></template>\n<script lang=\"ts\">\nexport default {}\n</script>\n";
+/// let sections = detect_embedded(Path::new("Hello.vue"), component);
+/// let languages: Vec<&str> = sections.iter().map(|section| section.language).collect();
+/// assert_eq!(languages, vec!["HTML", "TypeScript"]);
+/// ```
+pub fn detect_embedded(path: &Path, content: &str) -> Vec<EmbeddedSection> {
+    let extension = path
+        .file_name()
+        .and_then(|filename| filename.to_str())
+        .and_then(detectors::get_extension);
+    let default_script_language = match COMPONENT_EXTENSIONS
+        .iter()
+        .find(|(component_extension, _)| Some(*component_extension) == extension)
+    {
+        Some((_, language)) => *language,
+        None => return vec![],
+    };
+
+    let mut sections = vec![];
+    let mut position = 0;
+    if extension == Some(".astro") {
+        if let Some(frontmatter) = astro_frontmatter(content) {
+            position = frontmatter.end;
+            sections.push(EmbeddedSection {
+                language: "TypeScript",
+                range: frontmatter,
+            });
+        }
+    }
+
+    while let Some(captures) = SECTION_START.captures(&content[position..]) {
+        let start_tag = captures.get(0).unwrap();
+        let tag = captures[1].to_ascii_lowercase();
+        let content_start = position + start_tag.end();
+        let content_end = match section_end(content, content_start, &tag) {
+            Some(end) => end,
+            None => break,
+        };
+        position = content_end;
+
+        let default_language = match &tag[..] {
+            "script" => default_script_language,
+            "style" => "CSS",
+            _ => "HTML",
+        };
+        let language = match LANG_ATTRIBUTE.captures(&captures[2]) {
+            Some(lang) => LANG_ATTRIBUTES
+                .iter()
+                .find(|(attribute, _)| lang[1].eq_ignore_ascii_case(attribute))
+                .map(|(_, language)| *language),
+            None => Some(default_language),
+        };
+        if let Some(language) = language {
+            if !content[content_start..content_end].trim().is_empty() {
+                sections.push(EmbeddedSection {
+                    language,
+                    range: content_start..content_end,
+                });
+            }
+        }
+    }
+    sections
+}
+
+// Returns the range of the content of the frontmatter fenced by --- at the top of an Astro
+// component
+fn astro_frontmatter(content: &str) -> Option<Range<usize>> {
+    let leading_whitespace = content.len() - content.trim_start().len();
+    let rest = content[leading_whitespace..].strip_prefix("---")?;
+    let start = content.len() - rest.len();
+    let end = start + rest.find("\n---")? + 1;
+    Some(start..end)
+}
+
+// Returns where the content of the section that starts at `start` ends, which is the start of its
+// closing tag. Sections of the same tag nested inside of it, like a <template v-if> in a Vue
+// template, are skipped over.
+fn section_end(content: &str, start: usize, tag: &str) -> Option<usize> {
+    let mut depth = 0;
+    for captures in SECTION_TAG.captures_iter(&content[start..]) {
+        if !captures[2].eq_ignore_ascii_case(tag) {
+            continue;
+        }
+        if captures[1].is_empty() {
+            depth += 1;
+        } else if depth == 0 {
+            return Some(start + captures.get(0).unwrap().start());
+        } else {
+            depth -= 1;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn languages(path: &str, content: &str) -> Vec<&'static str> {
+        detect_embedded(Path::new(path), content)
+            .iter()
+            .map(|section| section.language)
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_embedded_vue() {
+        let component = r#"<template>
+  <div>
+    <template v-if="ok"><h1>{{ title }}</h1></template>
+  </div>
+</template>
+
+<script lang="ts">
+import { defineComponent } from 'vue'
+export default defineComponent({ props: { title: String } })
+</script>
+
+<style lang="scss" scoped>
+$primary: #42b983;
+h1 { color: $primary; }
+</style>
+"#;
+        let sections = detect_embedded(Path::new("Title.vue"), component);
+        let languages: Vec<&str> = sections.iter().map(|section| section.language).collect();
+        assert_eq!(languages, vec!["HTML", "TypeScript", "SCSS"]);
+        assert!(component[sections[0].range.clone()].contains("v-if"));
+        assert!(component[sections[0].range.clone()]
+            .trim_end()
+            .ends_with("</div>"));
+        assert_eq!(
+            component[sections[2].range.clone()].trim(),
+            "$primary: #42b983;\nh1 { color: $primary; }"
+        );
+    }
+
+    #[test]
+    fn test_detect_embedded_defaults() {
+        assert_eq!(
+            languages(
+                "App.svelte",
+                "<script>\n  let name = 'world';\n</script>\n\n<h1>Hello {name}!</h1>\n\n<style>\n  h1 { color: red; }\n</style>\n"
+            ),
+            vec!["JavaScript", "CSS"]
+        );
+        assert_eq!(
+            languages(
+                "index.astro",
+                "---\nconst title = 'Home';\n---\n<h1>{title}</h1>\n<script>\n  console.log(1);\n</script>\n"
+            ),
+            vec!["TypeScript", "TypeScript"]
+        );
+    }
+
+    #[test]
+    fn test_detect_embedded_skipped_sections() {
+        // Unknown languages and empty sections are left out
+        assert_eq!(
+            languages(
+                "Page.vue",
+                "<template lang=\"haml\">\n%p hi\n</template>\n<script></script>\n<style lang=\"less\">\na { b: c; }\n</style>\n"
+            ),
+            vec!["Less"]
+        );
+        // Unclosed sections are ignored
+        assert_eq!(
+            languages("Broken.vue", "<script>\nlet a = 1;\n"),
+            Vec::<&str>::new()
+        );
+        // Only single-file components have sections
+        assert_eq!(
+            languages("index.html", "<script>\nlet a = 1;\n</script>\n"),
+            Vec::<&str>::new()
+        );
+    }
+}
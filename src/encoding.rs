@@ -0,0 +1,105 @@
+use std::{borrow::Cow, fmt, str};
+
+/// The text encoding the content of a file was decoded with before it was detected
+///
+/// Files that start with a UTF-16 byte order mark are decoded as UTF-16. Everything else is
+/// decoded as UTF-8, and files that aren't valid UTF-8 are decoded as Latin-1 so that files in
+/// legacy encodings that are mostly ascii can still be detected.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Encoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Latin1Lossy,
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Encoding::Utf8 => write!(f, "UTF-8"),
+            Encoding::Utf16Le => write!(f, "UTF-16LE"),
+            Encoding::Utf16Be => write!(f, "UTF-16BE"),
+            Encoding::Latin1Lossy => write!(f, "Latin-1"),
+        }
+    }
+}
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+const UTF16_LE_BOM: &[u8] = &[0xFF, 0xFE];
+const UTF16_BE_BOM: &[u8] = &[0xFE, 0xFF];
+
+// Decodes the bytes into text, stripping any byte order mark. The bytes may be a truncated prefix
+// of the file so a character cut off at the end is dropped rather than treated as invalid.
+pub(crate) fn decode(bytes: &[u8]) -> (Cow<'_, str>, Encoding) {
+    if bytes.starts_with(UTF16_LE_BOM) {
+        let content = decode_utf16(&bytes[UTF16_LE_BOM.len()..], u16::from_le_bytes);
+        return (Cow::Owned(content), Encoding::Utf16Le);
+    }
+    if bytes.starts_with(UTF16_BE_BOM) {
+        let content = decode_utf16(&bytes[UTF16_BE_BOM.len()..], u16::from_be_bytes);
+        return (Cow::Owned(content), Encoding::Utf16Be);
+    }
+
+    let bytes = if bytes.starts_with(UTF8_BOM) {
+        &bytes[UTF8_BOM.len()..]
+    } else {
+        bytes
+    };
+    match str::from_utf8(bytes) {
+        Ok(content) => (Cow::Borrowed(content), Encoding::Utf8),
+        Err(e) if e.error_len().is_none() => {
+            let content = str::from_utf8(&bytes[..e.valid_up_to()]).unwrap();
+            (Cow::Borrowed(content), Encoding::Utf8)
+        }
+        Err(_) => {
+            let content = bytes.iter().map(|byte| *byte as char).collect();
+            (Cow::Owned(content), Encoding::Latin1Lossy)
+        }
+    }
+}
+
+fn decode_utf16(bytes: &[u8], to_u16: fn([u8; 2]) -> u16) -> String {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| to_u16([pair[0], pair[1]]))
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            decode(b"fn main()"),
+            (Cow::from("fn main()"), Encoding::Utf8)
+        );
+        assert_eq!(
+            decode(b"\xEF\xBB\xBFfn main()"),
+            (Cow::from("fn main()"), Encoding::Utf8)
+        );
+        assert_eq!(
+            decode(b"\xFF\xFEf\x00n\x00"),
+            (Cow::from("fn"), Encoding::Utf16Le)
+        );
+        assert_eq!(
+            decode(b"\xFE\xFF\x00f\x00n"),
+            (Cow::from("fn"), Encoding::Utf16Be)
+        );
+        assert_eq!(
+            decode(b"caf\xe9 au lait"),
+            (Cow::from("caf\u{e9} au lait"), Encoding::Latin1Lossy)
+        );
+    }
+
+    #[test]
+    fn test_decode_truncated_utf8() {
+        // The first two bytes of a three byte character
+        assert_eq!(
+            decode(b"let x\xE4\xBA"),
+            (Cow::from("let x"), Encoding::Utf8)
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use std::{fs::File, io::Read, path::Path};
+
+// Extensions of files that are commonly minified
+const MINIFIABLE_EXTENSIONS: &[&str] = &["css", "js", "mjs"];
+
+// Files with an average line length over this are considered minified. This matches Linguist
+const MINIFIED_AVERAGE_LINE_LENGTH: usize = 110;
+
+// Only the start of the file is needed to tell if it has been minified
+const MAX_MINIFIED_CHECK_BYTES: u64 = 51200;
+
+/// Returns true if the file at the path is minified JavaScript or CSS. Minified files are
+/// generated and aren't representative of the code in a project.
+///
+/// Files that can't be read are not considered minified.
+pub fn is_minified<P: AsRef<Path>>(path: P) -> bool {
+    let path = path.as_ref();
+    let is_minifiable = match path.extension().and_then(|extension| extension.to_str()) {
+        Some(extension) => MINIFIABLE_EXTENSIONS
+            .iter()
+            .any(|minifiable| minifiable.eq_ignore_ascii_case(extension)),
+        None => false,
+    };
+    if !is_minifiable {
+        return false;
+    }
+
+    let mut content = Vec::new();
+    let read = File::open(path).and_then(|file| {
+        file.take(MAX_MINIFIED_CHECK_BYTES)
+            .read_to_end(&mut content)
+    });
+    match read {
+        Ok(_) => is_minified_content(&String::from_utf8_lossy(&content)),
+        Err(_) => false,
+    }
+}
+
+fn is_minified_content(content: &str) -> bool {
+    match content.lines().count() {
+        0 => false,
+        lines => content.len() / lines > MINIFIED_AVERAGE_LINE_LENGTH,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_minified_content() {
+        let minified = "var a=1;".repeat(100);
+        assert!(is_minified_content(&minified));
+
+        let unminified = "var a = 1;\n".repeat(100);
+        assert!(!is_minified_content(&unminified));
+        assert!(!is_minified_content(""));
+    }
+
+    #[test]
+    fn test_minified_file() {
+        fs::create_dir_all("temp-testing-dir-minified").unwrap();
+        let minified = "var a=1;".repeat(100);
+        fs::write("temp-testing-dir-minified/bundle.js", &minified).unwrap();
+        fs::write("temp-testing-dir-minified/bundle.txt", &minified).unwrap();
+
+        let is_js_minified = is_minified("temp-testing-dir-minified/bundle.js");
+        let is_txt_minified = is_minified("temp-testing-dir-minified/bundle.txt");
+        let is_missing_minified = is_minified("temp-testing-dir-minified/missing.js");
+
+        fs::remove_dir_all("temp-testing-dir-minified").unwrap();
+
+        assert!(is_js_minified);
+        assert!(!is_txt_minified);
+        assert!(!is_missing_minified);
+    }
+}
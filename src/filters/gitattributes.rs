@@ -0,0 +1,63 @@
+const DETECTABLE_ATTRIBUTE: &str = "linguist-detectable";
+
+// Returns the patterns in the .gitattributes content that set the linguist-detectable attribute,
+// in the order they appear, along with whether the paths they match are detectable. Patterns use
+// gitignore syntax like they do in .gitattributes. Attributes that unset the attribute, like
+// `!linguist-detectable`, are left out since they fall back to the default behavior.
+pub(crate) fn parse_linguist_detectable(content: &str) -> Vec<(String, bool)> {
+    let mut patterns = Vec::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.split_whitespace();
+        let pattern = match fields.next() {
+            // Negative patterns aren't allowed in .gitattributes
+            Some(pattern) if !pattern.starts_with('!') => pattern,
+            _ => continue,
+        };
+        // The last mention of an attribute on a line wins
+        let detectable = fields.rev().find_map(detectable_value);
+        if let Some(detectable) = detectable {
+            patterns.push((String::from(pattern), detectable));
+        }
+    }
+    patterns
+}
+
+fn detectable_value(attribute: &str) -> Option<bool> {
+    match attribute.strip_prefix(DETECTABLE_ATTRIBUTE) {
+        Some("") | Some("=true") => Some(true),
+        Some("=false") => Some(false),
+        _ if attribute.strip_prefix('-') == Some(DETECTABLE_ATTRIBUTE) => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_linguist_detectable() {
+        let content = "# Generated code\n\
+                       *.rs linguist-detectable=false\n\
+                       vendor/mine/** linguist-vendored linguist-detectable\n\
+                       docs/*.py -linguist-detectable text eol=lf\n\
+                       *.sh linguist-detectable=false linguist-detectable=true\n\
+                       *.md !linguist-detectable\n\
+                       !*.c linguist-detectable\n\
+                       *.txt text\n";
+        assert_eq!(
+            parse_linguist_detectable(content),
+            vec![
+                (String::from("*.rs"), false),
+                (String::from("vendor/mine/**"), true),
+                (String::from("docs/*.py"), false),
+                (String::from("*.sh"), true),
+            ]
+        );
+    }
+}
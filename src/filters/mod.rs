@@ -1,5 +1,94 @@
 mod documentation;
+mod generated;
+mod gitattributes;
+mod prose;
+mod test_code;
 mod vendor;
 
 pub use documentation::add_documentation_override;
+pub use generated::is_minified;
+pub(crate) use gitattributes::parse_linguist_detectable;
+pub use prose::is_prose;
+pub(crate) use test_code::test_override;
+pub use test_code::DEFAULT_TEST_GLOBS;
 pub use vendor::add_vendor_override;
+
+use ignore::overrides::{Override, OverrideBuilder};
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
+
+/// Returns an override builder for the path with every default filter
+/// [`get_language_breakdown`](crate::get_language_breakdown) uses to skip files, which are the
+/// documentation and vendor overrides
+///
+/// Hidden files, including the directories of version control systems, are skipped by the walk
+/// itself and minified files are detected from their content, so neither is part of the
+/// overrides.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::filters::default_overrides;
+///
+/// let overrides = default_overrides("repo").build().unwrap();
+/// assert!(overrides.matched("repo/node_modules/index.js", false).is_ignore());
+/// assert!(overrides.matched("repo/docs/index.js", false).is_ignore());
+/// ```
+pub fn default_overrides<P: AsRef<Path>>(path: P) -> OverrideBuilder {
+    let builder = OverrideBuilder::new(path);
+    let builder = add_documentation_override(builder);
+    add_vendor_override(builder)
+}
+
+// Returns true if the overrides ignore the relative path or any of the directories it's in. This
+// is needed when ignored directories can't be skipped before visiting the files inside of them.
+pub(crate) fn is_ignored(overrides: &Override, path: &Path) -> bool {
+    let path = normalize_separators(path);
+    path.ancestors()
+        .filter(|ancestor| ancestor.components().next().is_some())
+        .any(|ancestor| overrides.matched(ancestor, ancestor != path).is_ignore())
+}
+
+// Returns the path with backslashes replaced by forward slashes. The override globs only use
+// forward slashes, and while they match backslashes on Windows, paths with backslashes from
+// elsewhere, like the entries of archives made on Windows, would otherwise be a single component
+// that never matches on other platforms.
+fn normalize_separators(path: &Path) -> Cow<'_, Path> {
+    match path.to_str() {
+        Some(path_str) if path_str.contains('\\') => {
+            Cow::Owned(PathBuf::from(path_str.replace('\\', "/")))
+        }
+        _ => Cow::Borrowed(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_overrides() {
+        let overrides = default_overrides("repo").build().unwrap();
+        assert!(overrides.matched("repo/vendor/lib.rs", false).is_ignore());
+        assert!(overrides
+            .matched("repo/documentation/index.md", false)
+            .is_ignore());
+        assert!(!overrides.matched("repo/src/main.rs", false).is_ignore());
+    }
+
+    #[test]
+    fn test_is_ignored_windows_separators() {
+        let overrides = default_overrides("").build().unwrap();
+        assert!(is_ignored(
+            &overrides,
+            Path::new("web\\node_modules\\left-pad\\index.js")
+        ));
+        assert!(is_ignored(&overrides, Path::new("docs\\guide.md")));
+        assert!(is_ignored(
+            &overrides,
+            Path::new("third_party/lib\\vendor\\lib.rs")
+        ));
+        assert!(!is_ignored(&overrides, Path::new("src\\main.rs")));
+    }
+}
@@ -0,0 +1,73 @@
+// Content with fewer words than this doesn't have enough signal to be considered prose
+const MIN_PROSE_WORDS: usize = 8;
+
+// The fraction of whitespace separated tokens that need to be plain words for content to be prose
+const MIN_PROSE_WORD_RATIO: f64 = 0.8;
+
+// The fraction of non whitespace characters that can be symbols common in code but rare in prose
+const MAX_PROSE_SYMBOL_RATIO: f64 = 0.02;
+
+const CODE_SYMBOLS: &str = "{}[]<>=;$@#\\|&*^~`_/+%";
+
+// Punctuation that can surround a word in prose
+const WORD_PUNCTUATION: &[char] = &[
+    '.', ',', ';', ':', '!', '?', '"', '\'', '(', ')', '[', ']', '*', '_', '`',
+];
+
+/// Returns true if the content looks like natural language prose rather than code. Prose is made
+/// up of mostly plain words and has few of the symbols that are common in code.
+pub fn is_prose(content: &str) -> bool {
+    let mut tokens = 0;
+    let mut words = 0;
+    for token in content.split_whitespace() {
+        tokens += 1;
+        if is_word(token) {
+            words += 1;
+        }
+    }
+    if words < MIN_PROSE_WORDS || (words as f64) < (tokens as f64) * MIN_PROSE_WORD_RATIO {
+        return false;
+    }
+
+    let mut characters = 0;
+    let mut symbols = 0;
+    for ch in content.chars().filter(|ch| !ch.is_whitespace()) {
+        characters += 1;
+        if CODE_SYMBOLS.contains(ch) {
+            symbols += 1;
+        }
+    }
+    (symbols as f64) <= (characters as f64) * MAX_PROSE_SYMBOL_RATIO
+}
+
+fn is_word(token: &str) -> bool {
+    let word = token.trim_matches(WORD_PUNCTUATION);
+    !word.is_empty()
+        && word
+            .chars()
+            .all(|ch| ch.is_alphabetic() || ch == '\'' || ch == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_prose() {
+        assert!(is_prose(
+            "This project is a small tool for tracking your reading list.\n\
+             It was written over a weekend (and it shows), but it works!\n"
+        ));
+        assert!(is_prose(
+            "# Notes\n\nRemember to update the changelog before every release."
+        ));
+
+        assert!(!is_prose("Hello world"));
+        assert!(!is_prose(
+            "#include <stdio.h>\nint main(void) {\n    printf(\"hello\");\n    return 0;\n}\n"
+        ));
+        assert!(!is_prose(
+            "my $name = shift;\nprint \"Hello $name, how are you doing today?\\n\";\n"
+        ));
+    }
+}
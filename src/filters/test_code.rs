@@ -0,0 +1,25 @@
+use ignore::overrides::{Override, OverrideBuilder};
+
+/// Globs for the test directories and test file naming conventions of common languages, for use
+/// with [`BreakdownConfig::test_globs`](crate::BreakdownConfig::test_globs)
+pub const DEFAULT_TEST_GLOBS: &[&str] = &[
+    "**/test/**",
+    "**/tests/**",
+    "**/spec/**",
+    "**/__tests__/**",
+    "**/*_test.*",
+    "**/*_spec.*",
+    "**/*.test.*",
+    "**/*.spec.*",
+    "**/test_*.py",
+];
+
+// Returns the override that ignores the files matching any of the test globs. Invalid globs are
+// ignored like the include globs are
+pub(crate) fn test_override<S: AsRef<str>>(globs: &[S]) -> Override {
+    let mut builder = OverrideBuilder::new("");
+    for glob in globs {
+        let _ = builder.add(&format!("!{}", glob.as_ref()));
+    }
+    builder.build().unwrap()
+}
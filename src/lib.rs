@@ -1,25 +1,106 @@
 //! # Hyperpolyglot
 //! `hyperpolyglot` is a fast programming language detector.
 
-use ignore::{overrides::OverrideBuilder, WalkBuilder};
+use cache::FileStamp;
+use detectors::ClassifierScratch;
+use ignore::{
+    overrides::{Override, OverrideBuilder},
+    WalkBuilder, WalkParallel,
+};
+use log::{debug, trace};
+use stats::ScanCounters;
 use std::{
-    collections::HashMap,
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
     convert::TryFrom,
     env, fmt,
-    fs::File,
-    io::{BufReader, Read, Seek, SeekFrom},
+    fs::{self, File},
+    io::{self, BufRead, Cursor, Read},
     path::{Path, PathBuf},
-    sync::mpsc,
+    sync::{mpsc, Mutex},
 };
 
+#[cfg(feature = "archive")]
+pub mod archive;
+pub mod baseline;
+mod cache;
+mod detector;
 pub mod detectors;
+mod embedded;
+mod encoding;
 pub mod filters;
+mod stats;
+mod tags;
+
+pub use cache::DetectionCache;
+pub use detector::Detector;
+pub use detectors::{tokenize, HeuristicRules, HeuristicsError, MAX_TOKEN_BYTES};
+pub use embedded::{detect_embedded, EmbeddedSection};
+pub use encoding::Encoding;
+pub use polyglot_tokenizer::{Token, Tokenizer};
+pub use stats::ScanStats;
+pub use tags::{content_tags, ContentTag};
 
-// Include the map that stores language info
+// Include the maps that store language info and Linguist's id for every language
 // static LANGUAGE_INFO: phf::Map<&'static str, Language> = ...;
+// static LANGUAGE_IDS: phf::Map<&'static str, u32> = ...;
 include!("codegen/language-info-map.rs");
 
-const MAX_CONTENT_SIZE_BYTES: usize = 51200;
+/// The most bytes at the start of a file that are read to detect its language. The heuristics,
+/// signatures, and classifier rarely need more than the first few lines, so reading the rest of
+/// large files would only slow detection down. Longer content is truncated to this many bytes,
+/// rounded down to a character boundary.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{detect_from_bytes, Detection, MAX_CONTENT_SIZE_BYTES};
+///
+/// // The signature past the limit is never seen
+/// let mut content = vec![b'\n'; MAX_CONTENT_SIZE_BYTES];
+/// content.extend_from_slice(b"<?php echo 'hello';");
+/// assert_eq!(detect_from_bytes(Some("index"), &content), None);
+/// ```
+pub const MAX_CONTENT_SIZE_BYTES: usize = 51200;
+
+// How much of the content is checked for a NUL byte to tell if it's binary, the same amount git
+// and Linguist check
+const BINARY_CHECK_BYTES: usize = 8000;
+
+// Languages whose extension is commonly shared by the other languages in their group. When the
+// extension resolves to one of these, the shebang is checked for a more specific dialect.
+// ex/ a .sh file with a #!/usr/bin/fish shebang is fish
+const DIALECT_GROUPS: &[&str] = &["Shell"];
+
+// The most commonly used languages, based on Linguist's popular.yml
+const POPULAR_LANGUAGES: &[&str] = &[
+    "ActionScript",
+    "C",
+    "C#",
+    "C++",
+    "CSS",
+    "Clojure",
+    "CoffeeScript",
+    "Go",
+    "HTML",
+    "Haskell",
+    "Java",
+    "JavaScript",
+    "Lua",
+    "MATLAB",
+    "Objective-C",
+    "PHP",
+    "Perl",
+    "Python",
+    "R",
+    "Ruby",
+    "Rust",
+    "Scala",
+    "Shell",
+    "Swift",
+    "TeX",
+    "TypeScript",
+    "Vim script",
+];
 
 /// The language struct that contains the name and other interesting information about a
 /// language.
@@ -40,7 +121,8 @@ const MAX_CONTENT_SIZE_BYTES: usize = 51200;
 /// ```
 ///
 /// # Errors
-/// `try_from` will error if the langauge name is not one of the known languages
+/// `try_from` will error if the langauge name is not one of the known languages. The error
+/// suggests the known languages with the closest names, see [`LanguageNotFound`]
 ///
 /// If try_from is called with a language returned from [`detect`] or [`get_language_breakdown`]
 /// the value is guaranteed to be there and can be unwrapped
@@ -56,11 +138,143 @@ pub struct Language {
     pub group: Option<&'static str>,
 }
 
+impl Language {
+    /// Returns true if the language is one of the languages Linguist considers popular, which can
+    /// be used to break ties toward the more common language
+    ///
+    /// # Examples
+    /// ```
+    /// use hyperpolyglot::Language;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(Language::try_from("Rust").unwrap().is_popular());
+    /// assert!(!Language::try_from("Brainfuck").unwrap().is_popular());
+    /// ```
+    pub fn is_popular(&self) -> bool {
+        POPULAR_LANGUAGES.contains(&self.name)
+    }
+
+    /// Returns Linguist's stable numeric id for the language. This doesn't change when a language
+    /// is renamed so it can be used to join with other Linguist based data. A language that isn't
+    /// one of the known languages has an id of 0
+    ///
+    /// # Examples
+    /// ```
+    /// use hyperpolyglot::Language;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert_eq!(Language::try_from("Rust").unwrap().language_id(), 327);
+    /// ```
+    pub fn language_id(&self) -> u32 {
+        LANGUAGE_IDS.get(self.name).copied().unwrap_or_default()
+    }
+}
+
 impl TryFrom<&str> for Language {
-    type Error = &'static str;
+    type Error = LanguageNotFound;
     fn try_from(name: &str) -> Result<Self, Self::Error> {
-        LANGUAGE_INFO.get(name).copied().ok_or("Language not found")
+        LANGUAGE_INFO
+            .get(name)
+            .copied()
+            .ok_or_else(|| LanguageNotFound::new(name))
+    }
+}
+
+// The most suggestions a LanguageNotFound error has
+const MAX_LANGUAGE_SUGGESTIONS: usize = 3;
+
+/// The error for a language name that isn't one of the known languages, along with the known
+/// languages it was most likely meant to be
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::Language;
+/// use std::convert::TryFrom;
+///
+/// let error = Language::try_from("Rus").unwrap_err();
+/// assert_eq!(error.suggestions[0], "Rust");
+/// assert!(error.to_string().starts_with("Language not found: Rus. Did you mean Rust"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageNotFound {
+    /// The name that wasn't found
+    pub name: String,
+    /// Up to three known languages with the closest names by edit distance, ignoring case,
+    /// closest first. Empty if no language is close
+    pub suggestions: Vec<&'static str>,
+}
+
+impl LanguageNotFound {
+    fn new(name: &str) -> LanguageNotFound {
+        let lowercase_name = name.to_lowercase();
+        // Names further than this from every language are too different for a suggestion to help
+        let max_distance = (name.chars().count() / 3).clamp(1, 3);
+        let mut candidates: Vec<(usize, &'static str)> = LANGUAGE_INFO
+            .keys()
+            .map(|language| {
+                let distance = edit_distance(&lowercase_name, &language.to_lowercase());
+                (distance, *language)
+            })
+            .filter(|(distance, _)| *distance <= max_distance)
+            .collect();
+        candidates.sort();
+        LanguageNotFound {
+            name: name.to_string(),
+            suggestions: candidates
+                .into_iter()
+                .take(MAX_LANGUAGE_SUGGESTIONS)
+                .map(|(_, language)| language)
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for LanguageNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Language not found: {}", self.name)?;
+        if !self.suggestions.is_empty() {
+            write!(f, ". Did you mean {}?", self.suggestions.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for LanguageNotFound {}
+
+// Returns the Levenshtein distance between the strings, the number of characters that have to be
+// inserted, deleted, or substituted to turn one into the other
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + if a_char == *b_char { 0 } else { 1 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
     }
+    previous[b.len()]
+}
+
+/// Returns every known language of the given type, sorted by name
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{languages_of_type, LanguageType};
+///
+/// let mut data_languages = languages_of_type(LanguageType::Data);
+/// assert!(data_languages.any(|language| language.name == "JSON"));
+/// ```
+pub fn languages_of_type(language_type: LanguageType) -> impl Iterator<Item = Language> {
+    let mut languages: Vec<Language> = LANGUAGE_INFO
+        .values()
+        .filter(|language| language.language_type == language_type)
+        .copied()
+        .collect();
+    languages.sort_by(|a, b| a.name.cmp(b.name));
+    languages.into_iter()
 }
 
 /// The set of possible language types
@@ -106,8 +320,31 @@ impl Detection {
         }
     }
 
-    /// Returns the strategy used to detect the langauge
-    pub fn variant(&self) -> &str {
+    /// Returns the strategy used to detect the language
+    ///
+    /// # Examples
+    /// ```
+    /// use hyperpolyglot::{Detection, DetectionStrategy};
+    ///
+    /// let detection = Detection::Heuristics("Rust");
+    /// assert_eq!(detection.strategy(), DetectionStrategy::Heuristics);
+    /// ```
+    pub fn strategy(&self) -> DetectionStrategy {
+        match self {
+            Detection::Filename(_) => DetectionStrategy::Filename,
+            Detection::Extension(_) => DetectionStrategy::Extension,
+            Detection::Shebang(_) => DetectionStrategy::Shebang,
+            Detection::Heuristics(_) => DetectionStrategy::Heuristics,
+            Detection::Classifier(_) => DetectionStrategy::Classifier,
+        }
+    }
+
+    /// Returns the name of the strategy used to detect the langauge
+    ///
+    /// The names are `Filename`, `Extension`, `Shebang`, `Heuristics`, and `Classifier`, the same
+    /// as the [`DetectionStrategy`] they're displayed from. They're what the CLI groups the
+    /// strategy breakdown by, so they're stable and won't be renamed.
+    pub fn variant(&self) -> &'static str {
         match self {
             Detection::Filename(_) => "Filename",
             Detection::Extension(_) => "Extension",
@@ -118,6 +355,41 @@ impl Detection {
     }
 }
 
+/// The set of strategies that can be used to detect a language
+///
+/// The strategies are displayed as their names, which are stable. See [`Detection::variant`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum DetectionStrategy {
+    Filename,
+    Extension,
+    Shebang,
+    Heuristics,
+    Classifier,
+}
+
+impl DetectionStrategy {
+    /// Every strategy in the order they're tried in
+    pub const ALL: [DetectionStrategy; 5] = [
+        DetectionStrategy::Filename,
+        DetectionStrategy::Extension,
+        DetectionStrategy::Shebang,
+        DetectionStrategy::Heuristics,
+        DetectionStrategy::Classifier,
+    ];
+}
+
+impl fmt::Display for DetectionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DetectionStrategy::Filename => write!(f, "Filename"),
+            DetectionStrategy::Extension => write!(f, "Extension"),
+            DetectionStrategy::Shebang => write!(f, "Shebang"),
+            DetectionStrategy::Heuristics => write!(f, "Heuristics"),
+            DetectionStrategy::Classifier => write!(f, "Classifier"),
+        }
+    }
+}
+
 /// Detects the programming language of the file at a given path
 ///
 /// If the language cannot be determined, None will be returned.
@@ -134,231 +406,2809 @@ impl Detection {
 /// assert_eq!(Detection::Heuristics("Rust"), language);
 /// ```
 pub fn detect(path: &Path) -> Result<Option<Detection>, std::io::Error> {
+    Ok(detect_with_confidence(path)?.map(|(detection, _)| detection))
+}
+
+/// The filename, extension, or interpreter a detection matched to detect the language
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DetectionMatch {
+    /// The name of the file, ex/ `Makefile`
+    Filename(String),
+    /// The extension of the file, lowercase and with its leading dot, ex/ `.rs`
+    Extension(String),
+    /// The interpreter in the shebang, without its path or version, ex/ `python`
+    Interpreter(String),
+}
+
+/// Detects the programming language of the file at a given path like [`detect`], along with the
+/// filename, extension, or interpreter that matched
+///
+/// Detections by the filename, extension, and shebang have what they matched, and detections by
+/// the heuristics and classifier don't since they matched on the content. A file the classifier
+/// couldn't pick a candidate for falls back to its extension, so that extension is what matched.
+///
+/// # Errors
+/// Errors like [`detect`] does
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use hyperpolyglot::{detect_with_match, Detection, DetectionMatch};
+///
+/// let (detection, matched) = detect_with_match(Path::new("Cargo.toml")).unwrap().unwrap();
+/// assert_eq!(detection, Detection::Extension("TOML"));
+/// assert_eq!(matched, Some(DetectionMatch::Extension(String::from(".toml"))));
+/// ```
+pub fn detect_with_match(
+    path: &Path,
+) -> Result<Option<(Detection, Option<DetectionMatch>)>, std::io::Error> {
+    let (detection, content) = detect_path_content(path, &Detector::default())?;
+    let detection = match detection {
+        Some((detection, _)) => detection,
+        None => return Ok(None),
+    };
+    let filename = path.file_name().and_then(|filename| filename.to_str());
+    let matched = match detection {
+        Detection::Filename(_) => {
+            filename.map(|filename| DetectionMatch::Filename(filename.to_string()))
+        }
+        Detection::Extension(_) => filename
+            .and_then(detectors::get_extension)
+            .map(|extension| DetectionMatch::Extension(extension.to_string())),
+        Detection::Shebang(_) => {
+            // The shebang is only checked once the file has been read, so its content is reused
+            let bytes = content.map(|content| content.bytes).unwrap_or_default();
+            let (content, _) = encoding::decode(&bytes);
+            detectors::get_interpreter_from_shebang(Cursor::new(content.as_bytes()))?
+                .map(DetectionMatch::Interpreter)
+        }
+        Detection::Heuristics(_) | Detection::Classifier(_) => None,
+    };
+    Ok(Some((detection, matched)))
+}
+
+/// Detects the programming language of a file using its path and content that has already been
+/// read, without touching the filesystem
+///
+/// The path is used for the filename and extension strategies and the content is used for the
+/// shebang, heuristics, and classifier strategies. `content` should be the text of the file at
+/// `path`. If the language cannot be determined, None will be returned.
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use hyperpolyglot::{detect_with_content, Detection};
+///
+/// let path = Path::new("script");
+/// let detection = detect_with_content(path, "#!/usr/bin/env python\nprint('hello')");
+/// assert_eq!(detection, Some(Detection::Shebang("Python")));
+/// ```
+pub fn detect_with_content(path: &Path, content: &str) -> Option<Detection> {
+    path.file_name()?;
+    let mut scratch = ClassifierScratch::default();
+    detect_str_with_confidence(Some(path), content, &mut scratch, &Detector::default())
+        .map(|(detection, _)| detection)
+}
+
+/// Detects the programming language of raw file content that has already been read, along with
+/// the confidence of the detection
+///
+/// The filename is used for the filename and extension strategies, without it only the shebang
+/// and the content can be used. The bytes are decoded the same way files on disk are, so UTF-16
+/// and content with a byte order mark are supported. Detections that don't rely on the classifier
+/// have a confidence of 1.0. If the language cannot be determined, None will be returned.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{detect_from_bytes, Detection};
+///
+/// let detection = detect_from_bytes(Some("main.go"), b"package main");
+/// assert_eq!(detection, Some((Detection::Extension("Go"), 1.0)));
+/// ```
+pub fn detect_from_bytes(filename: Option<&str>, bytes: &[u8]) -> Option<(Detection, f64)> {
+    let bytes = &bytes[..bytes.len().min(MAX_CONTENT_SIZE_BYTES)];
+    let (content, _) = encoding::decode(bytes);
+    let mut scratch = ClassifierScratch::default();
+    detect_str_with_confidence(
+        filename.map(Path::new),
+        &content,
+        &mut scratch,
+        &Detector::default(),
+    )
+}
+
+/// Guesses the language of content using only the classifier, along with the confidence of the
+/// guess
+///
+/// Unlike [`detect`] and [`detect_with_content`], no filename, extension, shebang, or heuristics
+/// are consulted and the content is classified against every language the classifier knows. This
+/// suits pasted snippets where there's no filename to go on. The classifier is much less accurate
+/// across every language than between the few candidates of an extension, so the confidence is
+/// worth checking. Returns None if the content is empty or doesn't have any tokens to classify.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::classify_content;
+/// # if cfg!(not(feature = "classifier")) { return; }
+///
+/// let (language, confidence) = classify_content("#include <stdio.h>\nint main(void) {\n    printf(\"hello\\n\");\n    return 0;\n}\n").unwrap();
+/// assert!(confidence > 0.0 && confidence <= 1.0);
+/// assert_eq!(classify_content("  \n"), None);
+/// ```
+pub fn classify_content(content: &str) -> Option<(&'static str, f64)> {
+    // Without any tokens every language scores the same
+    detectors::tokenize(content).next()?;
+    detectors::try_classify(content, &[])
+}
+
+// Detects the language of a file from its path and content along with the confidence of the
+// detection, using the scratch buffers for classification
+fn detect_str_with_confidence(
+    path: Option<&Path>,
+    content: &str,
+    scratch: &mut ClassifierScratch,
+    detector: &Detector,
+) -> Option<(Detection, f64)> {
+    let filename = path
+        .and_then(|path| path.file_name())
+        .and_then(|filename| filename.to_str());
+    let extension = filename.and_then(detectors::get_extension);
+
+    let candidates = match detect_from_filename(filename, extension) {
+        Progress::Detected(detection) => return Some((detection, 1.0)),
+        Progress::Candidates(candidates) => candidates,
+    };
+
+    let candidates = match detect_from_shebang(candidates, Cursor::new(content.as_bytes())).ok()? {
+        Progress::Detected(detection) => return Some((detection, 1.0)),
+        Progress::Candidates(candidates) => candidates,
+    };
+
+    let content = truncate_to_char_boundary(content, MAX_CONTENT_SIZE_BYTES);
+    detect_from_content(path, extension, candidates, content, scratch, detector)
+}
+
+/// Detects the programming language of a file from its path and a reader over its content
+///
+/// The path is only used for its filename and extension, the filesystem is never touched. The
+/// reader is only read from if the filename and extension aren't enough to detect the language,
+/// and at most the first 50 KiB are read. This is useful for content that doesn't live on disk,
+/// like the entries of an archive.
+///
+/// If the language cannot be determined, None will be returned.
+/// `detect_reader` will error if the reader returns an error.
+///
+/// # Examples
+/// ```
+/// use std::{io::Cursor, path::Path};
+/// use hyperpolyglot::{detect_reader, Detection};
+///
+/// let content = Cursor::new("#!/usr/bin/env python\nprint('hello')");
+/// let detection = detect_reader(Path::new("script"), content).unwrap();
+/// assert_eq!(detection, Some(Detection::Shebang("Python")));
+/// ```
+pub fn detect_reader<R: Read>(path: &Path, reader: R) -> Result<Option<Detection>, std::io::Error> {
     let filename = match path.file_name() {
         Some(filename) => filename.to_str(),
         None => return Ok(None),
     };
+    let extension = filename.and_then(detectors::get_extension);
 
-    let candidate = filename.and_then(|filename| detectors::get_language_from_filename(filename));
-    if let Some(candidate) = candidate {
-        return Ok(Some(Detection::Filename(candidate)));
+    let candidates = match detect_from_filename(filename, extension) {
+        Progress::Detected(detection) => return Ok(Some(detection)),
+        Progress::Candidates(candidates) => candidates,
     };
 
-    let extension = filename.and_then(|filename| detectors::get_extension(filename));
+    let bytes = read_content(reader)?;
+    let (content, _) = encoding::decode(&bytes);
 
-    let candidates = extension
-        .map(|ext| detectors::get_languages_from_extension(ext))
-        .unwrap_or_else(Vec::new);
+    let candidates = match detect_from_shebang(candidates, Cursor::new(content.as_bytes()))? {
+        Progress::Detected(detection) => return Ok(Some(detection)),
+        Progress::Candidates(candidates) => candidates,
+    };
 
-    if candidates.len() == 1 {
-        return Ok(Some(Detection::Extension(candidates[0])));
+    let content = truncate_to_char_boundary(&content, MAX_CONTENT_SIZE_BYTES);
+    let mut scratch = ClassifierScratch::default();
+    let detector = Detector::default();
+    Ok(detect_from_content(
+        Some(path),
+        extension,
+        candidates,
+        content,
+        &mut scratch,
+        &detector,
+    )
+    .map(|(detection, _)| detection))
+}
+
+/// Detects the programming language of the file at a given path without blocking the async
+/// runtime
+///
+/// The file is read with `tokio::fs` and only if the filename and extension aren't enough to
+/// detect the language. The tokenization and classification still run on the calling task since
+/// they are CPU bound and only look at the first 50 KiB of the file. Requires the `async`
+/// feature.
+///
+/// If the language cannot be determined, None will be returned.
+/// `detect_async` will error on an io error.
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use hyperpolyglot::{detect_async, Detection};
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let detection = detect_async(Path::new("src/bin/main.rs")).await.unwrap();
+/// assert_eq!(detection, Some(Detection::Heuristics("Rust")));
+/// # });
+/// ```
+#[cfg(feature = "async")]
+pub async fn detect_async(path: &Path) -> Result<Option<Detection>, std::io::Error> {
+    use tokio::io::AsyncReadExt;
+
+    let filename = match path.file_name() {
+        Some(filename) => filename.to_str(),
+        None => return Ok(None),
     };
+    let extension = filename.and_then(detectors::get_extension);
+    if let Progress::Detected(detection) = detect_from_filename(filename, extension) {
+        return Ok(Some(detection));
+    }
 
-    let file = File::open(path)?;
-    let mut reader = BufReader::new(file);
+    let mut bytes = Vec::new();
+    tokio::fs::File::open(path)
+        .await?
+        .take(MAX_CONTENT_SIZE_BYTES as u64)
+        .read_to_end(&mut bytes)
+        .await?;
+    detect_reader(path, Cursor::new(bytes))
+}
 
-    let candidates = filter_candidates(
-        candidates,
-        detectors::get_languages_from_shebang(&mut reader)?,
-    );
-    if candidates.len() == 1 {
-        return Ok(Some(Detection::Shebang(candidates[0])));
+/// Detects the programming language of a file and reports the encoding its content was decoded
+/// with
+///
+/// Unlike [`detect`], the file is always read so that the encoding can be reported, even when the
+/// filename or extension is enough to detect the language. See [`Encoding`] for how the encoding
+/// is chosen.
+///
+/// # Errors
+/// `detect_with_encoding` will error if the file can't be read
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use hyperpolyglot::{detect_with_encoding, Detection, Encoding};
+///
+/// let detection = detect_with_encoding(Path::new("src/bin/main.rs")).unwrap();
+/// assert_eq!(detection, Some((Detection::Heuristics("Rust"), Encoding::Utf8)));
+/// ```
+pub fn detect_with_encoding(path: &Path) -> Result<Option<(Detection, Encoding)>, std::io::Error> {
+    let bytes = read_content(File::open(path)?)?;
+    let (content, encoding) = encoding::decode(&bytes);
+    Ok(detect_with_content(path, &content).map(|detection| (detection, encoding)))
+}
+
+/// Runs every detection strategy on the file and returns each strategy's conclusion instead of
+/// stopping at the first strategy that settles on a language
+///
+/// The detections are returned in the order the strategies run: the filename match, every
+/// language the extension maps to, every language the shebang maps to, the languages the
+/// heuristics narrow the candidates down to or the language the first line's signature maps to
+/// when there are no candidates, and the classifier's top pick among the candidates
+/// that are left. If no candidates are left, the classifier picks from every language it knows.
+/// Files that are detected as different languages by different strategies are ambiguous or
+/// polyglot files.
+///
+/// # Errors
+/// `detect_all` will error if the file can't be read
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use hyperpolyglot::{detect_all, Detection};
+///
+/// let detections = detect_all(Path::new("src/bin/main.rs")).unwrap();
+/// assert!(detections.contains(&Detection::Extension("Rust")));
+/// assert!(detections.contains(&Detection::Extension("RenderScript")));
+/// ```
+pub fn detect_all(path: &Path) -> Result<Vec<Detection>, std::io::Error> {
+    let filename = match path.file_name() {
+        Some(filename) => filename.to_str(),
+        None => return Ok(vec![]),
     };
-    reader.seek(SeekFrom::Start(0))?;
+    let extension = filename.and_then(detectors::get_extension);
+
+    let mut detections = Vec::new();
+    if let Some(language) = filename.and_then(detectors::get_language_from_filename) {
+        detections.push(Detection::Filename(language));
+    }
+
+    let extension_candidates = extension
+        .map(detectors::get_languages_from_extension)
+        .unwrap_or_default();
+    detections.extend(extension_candidates.iter().map(|l| Detection::Extension(l)));
 
-    let mut content = String::new();
-    reader.read_to_string(&mut content)?;
+    let bytes = read_content(File::open(path)?)?;
+    let (content, _) = encoding::decode(&bytes);
     let content = truncate_to_char_boundary(&content, MAX_CONTENT_SIZE_BYTES);
 
-    // using heuristics is only going to be useful if we have more than one candidate
-    // if the extension didn't result in candidate languages then the heuristics won't either
-    let candidates = if candidates.len() > 1 {
-        if let Some(extension) = extension {
+    let shebang_candidates = detectors::get_languages_from_shebang(content.as_bytes())?;
+    detections.extend(shebang_candidates.iter().map(|l| Detection::Shebang(l)));
+
+    let candidates = filter_candidates(extension_candidates, shebang_candidates);
+    let candidates = match extension {
+        Some(extension) if candidates.len() > 1 => {
             let languages =
-                detectors::get_languages_from_heuristics(&extension[..], &candidates, &content);
+                detectors::get_languages_from_heuristics(extension, &candidates, content);
+            detections.extend(languages.iter().map(|l| Detection::Heuristics(l)));
             filter_candidates(candidates, languages)
-        } else {
+        }
+        None if candidates.is_empty() => {
+            detections.extend(
+                detectors::get_language_from_first_line(content).map(Detection::Heuristics),
+            );
             candidates
         }
-    } else {
-        candidates
+        _ => candidates,
     };
 
-    match candidates.len() {
-        0 => Ok(None),
-        1 => Ok(Some(Detection::Heuristics(candidates[0]))),
-        _ => Ok(Some(Detection::Classifier(detectors::classify(
-            &content,
-            &candidates,
-        )))),
+    if let Some((language, _)) = detectors::try_classify(content, &candidates) {
+        detections.push(Detection::Classifier(language));
     }
+
+    Ok(detections)
 }
 
-// function stolen from from https://doc.rust-lang.org/nightly/src/core/str/mod.rs.html
-fn truncate_to_char_boundary(s: &str, mut max: usize) -> &str {
-    if max >= s.len() {
-        s
-    } else {
-        while !s.is_char_boundary(max) {
-            max -= 1;
+/// Detects the programming language of the file at a given path like [`detect`] and returns the
+/// top `n` languages it could be, ranked with their confidences
+///
+/// When the language is picked by the classifier, the candidates left after the heuristics are
+/// ranked by their confidence, see [`detectors::classify_with_scores`]. Otherwise the language was
+/// determined by a strategy that doesn't guess, so it's the only entry with a confidence of 1.0.
+/// Returns None if the language cannot be determined.
+///
+/// # Errors
+/// Errors if there's an error reading the file
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use hyperpolyglot::detect_top_n;
+///
+/// let languages = detect_top_n(Path::new("src/bin/main.rs"), 3).unwrap();
+/// assert_eq!(languages, Some(vec![("Rust", 1.0)]));
+/// ```
+pub fn detect_top_n(
+    path: &Path,
+    n: usize,
+) -> Result<Option<Vec<(&'static str, f64)>>, std::io::Error> {
+    let (detection, content) = detect_path_content(path, &Detector::default())?;
+    let mut languages = match detection {
+        Some((Detection::Classifier(_), _)) => {
+            // The classifier only runs on content, so the file was read to detect it
+            let bytes = content.map(|content| content.bytes).unwrap_or_default();
+            let (content, _) = encoding::decode(&bytes);
+            let content = truncate_to_char_boundary(&content, MAX_CONTENT_SIZE_BYTES);
+            let filename = path.file_name().and_then(|filename| filename.to_str());
+            let extension = filename.and_then(detectors::get_extension);
+            let extension_candidates = extension
+                .map(detectors::languages_from_extension)
+                .unwrap_or_default()
+                .to_vec();
+            let candidates = narrow_candidates(path, extension, extension_candidates, content)?;
+            detectors::classify_with_scores(content, &candidates.after_heuristics)
         }
-        &s[..max]
-    }
+        Some((detection, _)) => vec![(detection.language(), 1.0)],
+        None => return Ok(None),
+    };
+    languages.truncate(n);
+    Ok(Some(languages))
 }
 
-/// Walks the path provided and tallies the programming languages detected in the given path
+/// Runs only the heuristics stage of detection over the content
 ///
-/// Returns a map from the programming languages to a Vec of the files that were detected and the
-/// strategy used
+/// The heuristics from Linguist's heuristics.yml for the extension are used to narrow down the
+/// candidate languages. The extension can be given with or without the leading dot. Returns the
+/// candidates that are left after the heuristics are applied, which are the candidates that
+/// would be passed on to the classifier by [`detect`]. If no heuristic matches, the candidates
+/// are returned unchanged.
 ///
 /// # Examples
 /// ```
-/// use hyperpolyglot::get_language_breakdown;
-/// let breakdown = get_language_breakdown("src/");
-/// let total_detections = breakdown.iter().fold(0, |sum, (language, detections)| sum + detections.len());
-/// println!("Total files detected: {}", total_detections);
+/// use hyperpolyglot::apply_heuristics;
+///
+/// let languages = apply_heuristics(".h", &["C", "C++", "Objective-C"], "@interface Foo");
+/// assert_eq!(languages, vec!["Objective-C"]);
 /// ```
-pub fn get_language_breakdown<P: AsRef<Path>>(
-    path: P,
-) -> HashMap<&'static str, Vec<(Detection, PathBuf)>> {
-    let override_builder = OverrideBuilder::new(&path);
-    let override_builder = filters::add_documentation_override(override_builder);
-    let override_builder = filters::add_vendor_override(override_builder);
+pub fn apply_heuristics(
+    extension: &str,
+    candidates: &[&'static str],
+    content: &str,
+) -> Vec<&'static str> {
+    let extension = extension.to_ascii_lowercase();
+    let extension = if extension.starts_with('.') {
+        extension
+    } else {
+        format!(".{}", extension)
+    };
 
-    let num_threads = env::var_os("HYPLY_THREADS")
-        .and_then(|threads| threads.into_string().ok())
-        .and_then(|threads| threads.parse().ok())
-        .unwrap_or_else(num_cpus::get);
+    let languages = detectors::get_languages_from_heuristics(&extension, candidates, content);
+    filter_candidates(candidates.to_vec(), languages)
+}
 
-    let (tx, rx) = mpsc::channel::<(Detection, PathBuf)>();
-    let walker = WalkBuilder::new(path)
-        .threads(num_threads)
-        .overrides(override_builder.build().unwrap())
-        .build_parallel();
+/// Returns true if the filename or its extension maps to at least one language, without touching
+/// the filesystem
+///
+/// Filenames with an extension shared by several languages, like `.h`, return true even though
+/// their content is needed to pick between the languages. Files that return false can still be
+/// detected by their shebang or content.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::is_detectable_by_name;
+///
+/// assert!(is_detectable_by_name("main.rs"));
+/// assert!(is_detectable_by_name("Makefile"));
+/// assert!(!is_detectable_by_name("data.bin"));
+/// ```
+pub fn is_detectable_by_name(filename: &str) -> bool {
+    detectors::get_language_from_filename(filename).is_some()
+        || detectors::get_extension(filename).is_some()
+}
 
-    walker.run(|| {
-        let tx = tx.clone();
-        Box::new(move |result| {
-            use ignore::WalkState::*;
+// Detects the language of the file along with the confidence of the detection. Detections that
+// don't rely on the classifier have a confidence of 1.0
+fn detect_with_confidence(path: &Path) -> Result<Option<(Detection, f64)>, std::io::Error> {
+    Ok(detect_path(path, &Detector::default())?.0)
+}
 
-            if let Ok(path) = result {
-                let path = path.into_path();
-                if !path.is_dir() {
-                    if let Ok(Some(detection)) = detect(&path) {
-                        tx.send((detection, path)).unwrap();
-                    }
-                }
-            }
-            Continue
-        })
-    });
-    drop(tx);
+// A detection and its confidence along with the number of bytes read from the file to detect it
+type PathDetection = (Option<(Detection, f64)>, u64);
 
-    let mut language_breakdown = HashMap::new();
-    for (detection, file) in rx {
-        let files = language_breakdown
-            .entry(detection.language())
-            .or_insert_with(Vec::new);
-        files.push((detection, file));
-    }
+// A detection and its confidence along with the content read from the file to detect it, which is
+// None when the file didn't have to be read
+type PathContentDetection = (Option<(Detection, f64)>, Option<FileContent>);
 
-    language_breakdown
+// Detects the language of the file like detect_with_confidence, also returning the number of
+// bytes that were read from the file
+fn detect_path(path: &Path, detector: &Detector) -> Result<PathDetection, std::io::Error> {
+    let (detection, content) = detect_path_content(path, detector)?;
+    Ok((
+        detection,
+        content.map_or(0, |content| content.bytes.len() as u64),
+    ))
 }
 
-fn filter_candidates(
-    previous_candidates: Vec<&'static str>,
-    new_candidates: Vec<&'static str>,
-) -> Vec<&'static str> {
-    if previous_candidates.is_empty() {
-        return new_candidates;
-    }
+// Detects the language of the file like detect_path, returning the content that was read instead
+// of its length so the helpers that need the content too don't read the file again
+fn detect_path_content(
+    path: &Path,
+    detector: &Detector,
+) -> Result<PathContentDetection, std::io::Error> {
+    let filename = match path.file_name() {
+        Some(filename) => filename.to_str(),
+        None => return Ok((None, None)),
+    };
+    let extension = filename.and_then(detectors::get_extension);
 
-    if new_candidates.is_empty() {
+    let candidates = match detect_from_filename(filename, extension) {
+        Progress::Detected(detection) => return Ok((Some((detection, 1.0)), None)),
+        Progress::Candidates(candidates) => candidates,
+    };
+
+    let file_content = read_file_content(path)?;
+    let (content, _) = encoding::decode(&file_content.bytes);
+
+    let detection = match detect_from_shebang(candidates, Cursor::new(content.as_bytes()))? {
+        Progress::Detected(detection) => Some((detection, 1.0)),
+        Progress::Candidates(candidates) => {
+            let content = truncate_to_char_boundary(&content, MAX_CONTENT_SIZE_BYTES);
+            let mut scratch = ClassifierScratch::default();
+            detect_from_content(
+                Some(path),
+                extension,
+                candidates,
+                content,
+                &mut scratch,
+                detector,
+            )
+        }
+    };
+    Ok((detection, Some(file_content)))
+}
+
+/// The candidate languages of a file after each of the strategies that narrow them down, which
+/// explains why a file with an ambiguous extension was detected the way it was
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DetectionCandidates {
+    /// The languages of the file's name or extension. A known filename only has its language
+    pub extension: Vec<&'static str>,
+    /// The candidates left after the shebang was checked
+    pub after_shebang: Vec<&'static str>,
+    /// The candidates left after the heuristics, which the classifier would pick between when
+    /// there's more than one
+    pub after_heuristics: Vec<&'static str>,
+}
+
+/// Returns the candidate languages of the file after the extension, shebang, and heuristics
+/// strategies, without classifying the content to pick between the ones left
+///
+/// Like [`detect`], once a strategy leaves a single candidate the later strategies don't change
+/// it and the file is only read when there's more than one candidate. The heuristics for the
+/// extension are tried even without any candidates, and a file that still doesn't have any has
+/// the language of its first line or of the directory it's in by convention as its heuristics
+/// candidate, if there is one.
+///
+/// # Errors
+/// Errors if the file is read and there's an error reading it
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::detection_candidates;
+///
+/// let candidates = detection_candidates("src/lib.rs").unwrap();
+/// assert_eq!(candidates.extension, vec!["Rust", "RenderScript"]);
+/// assert_eq!(candidates.after_heuristics, vec!["Rust"]);
+/// ```
+pub fn detection_candidates<P: AsRef<Path>>(
+    path: P,
+) -> Result<DetectionCandidates, std::io::Error> {
+    path_candidates(path.as_ref(), None)
+}
+
+// Returns the candidates of the file like detection_candidates, narrowing them down with the bytes
+// already read from the file when there are some instead of reading it
+fn path_candidates(
+    path: &Path,
+    bytes: Option<&[u8]>,
+) -> Result<DetectionCandidates, std::io::Error> {
+    let filename = path.file_name().and_then(|filename| filename.to_str());
+    let extension = filename.and_then(detectors::get_extension);
+    let extension_candidates = match filename.and_then(detectors::get_language_from_filename) {
+        Some(language) => vec![language],
+        None => extension
+            .map(detectors::languages_from_extension)
+            .unwrap_or_default()
+            .to_vec(),
+    };
+    if extension_candidates.len() == 1 {
+        return Ok(DetectionCandidates {
+            after_shebang: extension_candidates.clone(),
+            after_heuristics: extension_candidates.clone(),
+            extension: extension_candidates,
+        });
+    }
+
+    let read_bytes;
+    let bytes = match bytes {
+        Some(bytes) => bytes,
+        None => {
+            read_bytes = read_content(File::open(path)?)?;
+            &read_bytes[..]
+        }
+    };
+    let (content, _) = encoding::decode(bytes);
+    let content = truncate_to_char_boundary(&content, MAX_CONTENT_SIZE_BYTES);
+    narrow_candidates(path, extension, extension_candidates, content)
+}
+
+// Narrows down the candidates from the filename or extension with the strategies that look at the
+// content like detection_candidates
+fn narrow_candidates(
+    path: &Path,
+    extension: Option<&str>,
+    extension_candidates: Vec<&'static str>,
+    content: &str,
+) -> Result<DetectionCandidates, std::io::Error> {
+    let shebang_candidates =
+        detectors::get_languages_from_shebang(Cursor::new(content.as_bytes()))?;
+    let after_shebang = filter_candidates(extension_candidates.clone(), shebang_candidates);
+
+    let after_heuristics = match heuristics_extension(Some(path), extension, &after_shebang) {
+        Some(extension) if after_shebang.len() != 1 => {
+            let languages =
+                detectors::get_languages_from_heuristics(&extension, &after_shebang, content);
+            filter_candidates(after_shebang.clone(), languages)
+        }
+        _ => after_shebang.clone(),
+    };
+    let after_heuristics = match after_heuristics.is_empty() {
+        true => detectors::get_language_from_first_line(content)
+            .or_else(|| detectors::get_language_from_path_convention(path))
+            .into_iter()
+            .collect(),
+        false => after_heuristics,
+    };
+
+    Ok(DetectionCandidates {
+        extension: extension_candidates,
+        after_shebang,
+        after_heuristics,
+    })
+}
+
+/// How a file was detected, with the candidate languages after each strategy to explain it
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetectionTrace {
+    /// The language detected along with the confidence of the detection, or None if the language
+    /// couldn't be determined
+    pub detection: Option<(Detection, f64)>,
+    /// The candidate languages after each strategy. See [`detection_candidates`]
+    pub candidates: DetectionCandidates,
+    /// Whether the content was read to detect the language but the file is larger than
+    /// [`MAX_CONTENT_SIZE_BYTES`], so the content strategies only saw the start of it. Files
+    /// detected by their filename or extension are never read so they aren't truncated
+    pub truncated: bool,
+}
+
+/// Detects the programming language of the file at a given path like [`detect`], along with the
+/// candidate languages after each strategy
+///
+/// # Errors
+/// Errors if there's an error reading the file
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{detect_verbose, Detection};
+///
+/// let trace = detect_verbose("src/lib.rs").unwrap();
+/// assert_eq!(trace.detection, Some((Detection::Heuristics("Rust"), 1.0)));
+/// assert_eq!(trace.candidates.extension, vec!["Rust", "RenderScript"]);
+/// ```
+pub fn detect_verbose<P: AsRef<Path>>(path: P) -> Result<DetectionTrace, std::io::Error> {
+    trace_path(path.as_ref(), &Detector::default())
+}
+
+// Detects the language of the file with the detector along with the candidates after each
+// strategy
+fn trace_path(path: &Path, detector: &Detector) -> Result<DetectionTrace, std::io::Error> {
+    let (detection, content) = detector.detect_path_content(path)?;
+    let candidates = path_candidates(path, content.as_ref().map(|content| &content.bytes[..]))?;
+    Ok(DetectionTrace {
+        detection,
+        candidates,
+        truncated: content.is_some_and(|content| content.truncated),
+    })
+}
+
+// Reads the part of the content that is used for detection
+fn read_content<R: Read>(reader: R) -> Result<Vec<u8>, std::io::Error> {
+    let mut bytes = Vec::new();
+    reader
+        .take(MAX_CONTENT_SIZE_BYTES as u64)
+        .read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+// The part of a file's content that was read to detect its language
+struct FileContent {
+    bytes: Vec<u8>,
+    // Whether the file has more content after the bytes that were read
+    truncated: bool,
+}
+
+// Reads the part of the file's content that is used for detection. Whether the content was
+// truncated comes from the length of the file that was opened rather than reading past it, so the
+// bytes that are kept are all the bytes that were read
+fn read_file_content(path: &Path) -> Result<FileContent, std::io::Error> {
+    let mut file = File::open(path)?;
+    let bytes = read_content(&mut file)?;
+    let truncated =
+        bytes.len() == MAX_CONTENT_SIZE_BYTES && file.metadata()?.len() > bytes.len() as u64;
+    Ok(FileContent { bytes, truncated })
+}
+
+// The state of the detection after running a strategy. Either the language has been detected or
+// there are candidate languages left for the remaining strategies to narrow down
+enum Progress {
+    Detected(Detection),
+    Candidates(Vec<&'static str>),
+}
+
+fn detect_from_filename(filename: Option<&str>, extension: Option<&'static str>) -> Progress {
+    let candidate = filename.and_then(detectors::get_language_from_filename);
+    if let Some(candidate) = candidate {
+        debug!(
+            "{} detected as {} by its filename",
+            filename.unwrap_or_default(),
+            candidate
+        );
+        return Progress::Detected(Detection::Filename(candidate));
+    };
+
+    // Most extensions map to a single language so those are looked up first, and the candidates
+    // are only copied into a Vec when the extension isn't enough to detect the language
+    let unambiguous = extension.and_then(detectors::get_language_from_unambiguous_extension);
+    if let Some(language) = unambiguous.filter(|language| !DIALECT_GROUPS.contains(language)) {
+        debug!("{:?} detected as {} by its extension", filename, language);
+        return Progress::Detected(Detection::Extension(language));
+    };
+
+    let candidates = extension
+        .map(detectors::languages_from_extension)
+        .unwrap_or_default();
+
+    trace!(
+        "{:?} has the extension candidates {:?}",
+        filename,
+        candidates
+    );
+    Progress::Candidates(candidates.to_vec())
+}
+
+fn detect_from_shebang<R: BufRead>(
+    candidates: Vec<&'static str>,
+    reader: R,
+) -> Result<Progress, std::io::Error> {
+    let shebang_candidates = detectors::get_languages_from_shebang(reader)?;
+    trace!("shebang candidates {:?}", shebang_candidates);
+    if candidates.len() == 1 {
+        let detection = match shebang_candidates
+            .iter()
+            .find(|language| is_dialect_of(language, candidates[0]))
+        {
+            Some(dialect) => Detection::Shebang(dialect),
+            None => Detection::Extension(candidates[0]),
+        };
+        debug!("detected as {:?} after checking the shebang", detection);
+        return Ok(Progress::Detected(detection));
+    }
+
+    let candidates = filter_candidates(candidates, shebang_candidates);
+    if candidates.len() == 1 {
+        debug!("detected as {} by the shebang", candidates[0]);
+        return Ok(Progress::Detected(Detection::Shebang(candidates[0])));
+    };
+
+    Ok(Progress::Candidates(candidates))
+}
+
+fn detect_from_content(
+    path: Option<&Path>,
+    extension: Option<&str>,
+    candidates: Vec<&'static str>,
+    content: &str,
+    scratch: &mut ClassifierScratch,
+    detector: &Detector,
+) -> Option<(Detection, f64)> {
+    // Binary files like compiled property lists aren't source code, so like Linguist they're
+    // skipped rather than matched against heuristics meant for text
+    if is_binary(content) {
+        debug!("skipping binary content");
+        return None;
+    }
+
+    // Using heuristics isn't useful when there's a single candidate. Heuristics can be keyed on
+    // an extension that isn't in the extension map, so they're still tried without any candidates
+    // and a matching rule seeds them
+    let candidates = match heuristics_extension(path, extension, &candidates) {
+        Some(extension) if candidates.len() != 1 => {
+            let languages = detector.heuristic_languages(&extension, &candidates, content);
+            filter_candidates(candidates, languages)
+        }
+        _ => candidates,
+    };
+    trace!("candidates after the heuristics {:?}", candidates);
+
+    let detection = match candidates.len() {
+        // Nothing about the filename or shebang hinted at the language so look for a signature
+        // on the first line, then at the directory the file is in. Both count as heuristics
+        0 => match detectors::get_language_from_first_line(content)
+            .or_else(|| path.and_then(detectors::get_language_from_path_convention))
+        {
+            Some(language) => Some((Detection::Heuristics(language), 1.0)),
+            // Only a detector restricted to a few languages classifies the content, since
+            // picking from every language is mostly guesswork
+            None => match detector.restricted_languages() {
+                Some(languages)
+                    if detector.uses_classifier()
+                        && !content.trim().is_empty()
+                        && !detector.rejects_classification(content) =>
+                {
+                    detectors::classify_with_scratch(content, languages, scratch)
+                        .map(|(language, confidence)| (Detection::Classifier(language), confidence))
+                }
+                _ => None,
+            },
+        },
+        1 => Some((Detection::Heuristics(candidates[0]), 1.0)),
+        _ if !detector.uses_classifier() || detector.rejects_classification(content) => None,
+        _ => match detectors::classify_with_scratch(content, &candidates, scratch) {
+            Some((language, confidence))
+                if extension.is_some()
+                    && language != candidates[0]
+                    && detector.trusts_extension_over(confidence) =>
+            {
+                Some((Detection::Extension(candidates[0]), 0.0))
+            }
+            Some((language, confidence)) => Some((Detection::Classifier(language), confidence)),
+            // None of the candidates can be classified so fall back to the first candidate
+            None => candidates
+                .first()
+                .map(|language| (Detection::Extension(language), 0.0)),
+        },
+    };
+    match detection {
+        Some((detection, confidence)) => debug!(
+            "detected as {:?} from the content with a confidence of {}",
+            detection, confidence
+        ),
+        None => debug!("no language detected from the content"),
+    }
+    detection
+}
+
+// Returns the extension to look up the heuristics by. Only extensions in the extension map are
+// detected, so a file without any candidates uses the last extension of its filename instead
+fn heuristics_extension<'a>(
+    path: Option<&Path>,
+    extension: Option<&'a str>,
+    candidates: &[&'static str],
+) -> Option<Cow<'a, str>> {
+    if let Some(extension) = extension {
+        return Some(Cow::Borrowed(extension));
+    }
+    if !candidates.is_empty() {
+        return None;
+    }
+    let filename = path?.file_name()?.to_str()?;
+    let position = filename.rfind('.').filter(|position| *position > 0)?;
+    Some(Cow::Owned(filename[position..].to_ascii_lowercase()))
+}
+
+// Whether the content looks binary, which like git and Linguist is a NUL byte near the start. The
+// content has already been decoded so UTF-16 text doesn't have any
+fn is_binary(content: &str) -> bool {
+    content
+        .bytes()
+        .take(BINARY_CHECK_BYTES)
+        .any(|byte| byte == 0)
+}
+
+fn is_dialect_of(language: &str, group: &str) -> bool {
+    LANGUAGE_INFO
+        .get(language)
+        .and_then(|language| language.group)
+        == Some(group)
+}
+
+// function stolen from from https://doc.rust-lang.org/nightly/src/core/str/mod.rs.html
+fn truncate_to_char_boundary(s: &str, mut max: usize) -> &str {
+    if max >= s.len() {
+        s
+    } else {
+        while !s.is_char_boundary(max) {
+            max -= 1;
+        }
+        &s[..max]
+    }
+}
+
+/// The ways files can be tallied when totaling up the languages in a path
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum Aggregation {
+    /// Every file detected adds 1 to its language's total
+    #[default]
+    Count,
+    /// Every file detected adds the confidence of its detection to its language's total. Files
+    /// detected by the filename, extension, shebang, or heuristics have a confidence of 1.0 and
+    /// files detected by the classifier have the classifier's normalized score.
+    Confidence,
+}
+
+/// How files that would normally be left out of a breakdown are handled
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum ExcludedFiles {
+    /// Documentation, vendored, and generated files are skipped
+    #[default]
+    Exclude,
+    /// Documentation, vendored, and generated files are still detected but are tallied under
+    /// [`DOCUMENTATION_BUCKET`], [`VENDORED_BUCKET`], and [`GENERATED_BUCKET`] instead of their
+    /// languages. A file that falls under more than one is tallied under the first of vendored,
+    /// documentation, and generated.
+    Bucket,
+}
+
+/// The key vendored files are tallied under with [`ExcludedFiles::Bucket`]
+pub const VENDORED_BUCKET: &str = "Vendored";
+/// The key documentation files are tallied under with [`ExcludedFiles::Bucket`]
+pub const DOCUMENTATION_BUCKET: &str = "Documentation";
+/// The key generated files, like minified JavaScript, are tallied under with
+/// [`ExcludedFiles::Bucket`]
+pub const GENERATED_BUCKET: &str = "Generated";
+/// The key test code is tallied under when there are [`BreakdownConfig::test_globs`]
+pub const TESTS_BUCKET: &str = "Tests";
+/// The key symbolic links to files are tallied under with [`Symlinks::Bucket`]
+pub const SYMLINKS_BUCKET: &str = "Symlinks";
+
+/// How symbolic links found while walking are handled
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum Symlinks {
+    /// Links to files are detected from the content of the file they link to and tallied under
+    /// the path of the link. Links to directories aren't walked
+    #[default]
+    Detect,
+    /// Links to files are detected like [`Symlinks::Detect`] and links to directories are walked
+    /// like the directories they link to. Links that form a cycle are skipped
+    Follow,
+    /// Links to files are still detected but are tallied under [`SYMLINKS_BUCKET`] instead of
+    /// their languages, and links to directories aren't walked. Links take precedence over the
+    /// other buckets since they aren't code of their own
+    Bucket,
+}
+
+/// Configuration for walking a path and tallying the languages detected
+#[derive(Debug, Clone, Default)]
+pub struct BreakdownConfig {
+    /// How each file contributes to its language's total. Defaults to [`Aggregation::Count`]
+    pub aggregation: Aggregation,
+    /// Whether hidden files and directories, like .editorconfig, are walked. Version control
+    /// directories like .git are skipped either way. Defaults to false
+    pub include_hidden: bool,
+    /// Globs for paths that are walked even if they would be skipped as documentation or vendored
+    /// code, ex/ `vendor/mine/**`. The globs use gitignore syntax relative to the path walked.
+    /// Invalid globs are ignored
+    pub include_globs: Vec<String>,
+    /// Whether files that look like prose are left out instead of being counted as the language the
+    /// classifier guesses. See [`Detector::reject_prose_misclassification`]. Defaults to false
+    pub reject_prose_misclassification: bool,
+    /// Whether files the filename, extension, shebang, and heuristics leave more than one
+    /// candidate for are left undetected instead of being classified. See
+    /// [`Detector::use_classifier`]. Defaults to false
+    pub disable_classifier: bool,
+    /// What happens to documentation, vendored, and generated files. Defaults to
+    /// [`ExcludedFiles::Exclude`]
+    pub excluded_files: ExcludedFiles,
+    /// Whether `.h` files in a directory with `.m` or `.mm` files are detected as Objective-C
+    /// when their content would otherwise leave them as C. Headers the heuristics detect as C++
+    /// or Objective-C are unaffected. MATLAB files also use `.m`, so this is best left off for
+    /// repos with MATLAB and C side by side. Defaults to false
+    pub objective_c_header_hints: bool,
+    /// Globs for test code, ex/ `**/tests/**`. Files matching them are tallied under
+    /// [`TESTS_BUCKET`] instead of their language, which separates production code from tests.
+    /// [`filters::DEFAULT_TEST_GLOBS`] has the common conventions. The globs use gitignore syntax
+    /// relative to the path walked. Vendored and documentation files that are bucketed stay in
+    /// their buckets. Invalid globs are ignored. Defaults to no globs, which doesn't bucket tests
+    pub test_globs: Vec<String>,
+    /// What happens to symbolic links. Links that point to a file that doesn't exist are skipped
+    /// either way. Defaults to [`Symlinks::Detect`]
+    pub symlinks: Symlinks,
+}
+
+impl BreakdownConfig {
+    // Returns the detector used to detect the files walked with the config
+    fn detector(&self) -> Detector {
+        Detector::new()
+            .reject_prose_misclassification(self.reject_prose_misclassification)
+            .use_classifier(!self.disable_classifier)
+    }
+}
+
+// A map from languages to the files detected as the language
+type LanguageBreakdown = HashMap<&'static str, Vec<(Detection, PathBuf)>>;
+
+/// Walks the path provided and tallies the programming languages detected in the given path
+///
+/// Returns a map from the programming languages to a Vec of the files that were detected and the
+/// strategy used
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::get_language_breakdown;
+/// let breakdown = get_language_breakdown("src/");
+/// let total_detections = breakdown.iter().fold(0, |sum, (language, detections)| sum + detections.len());
+/// println!("Total files detected: {}", total_detections);
+/// ```
+pub fn get_language_breakdown<P: AsRef<Path>>(
+    path: P,
+) -> HashMap<&'static str, Vec<(Detection, PathBuf)>> {
+    get_language_breakdown_with_config(path, &BreakdownConfig::default())
+}
+
+/// Walks the path provided and tallies the programming languages detected in the given path,
+/// using the config to decide which files are walked
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{get_language_breakdown_with_config, BreakdownConfig};
+/// let config = BreakdownConfig {
+///     include_hidden: true,
+///     ..BreakdownConfig::default()
+/// };
+/// let breakdown = get_language_breakdown_with_config("src/", &config);
+/// assert!(breakdown.contains_key("Rust"));
+/// ```
+pub fn get_language_breakdown_with_config<P: AsRef<Path>>(
+    path: P,
+    config: &BreakdownConfig,
+) -> HashMap<&'static str, Vec<(Detection, PathBuf)>> {
+    get_language_breakdown_with_stats(path, config).0
+}
+
+/// Walks the path provided and tallies the programming languages detected in the given path like
+/// [`get_language_breakdown_with_config`], also returning stats about the work done by the walk
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{get_language_breakdown_with_stats, BreakdownConfig};
+/// let (breakdown, stats) = get_language_breakdown_with_stats("src/", &BreakdownConfig::default());
+/// let total_detections: usize = breakdown.values().map(|files| files.len()).sum();
+/// assert_eq!(stats.files_detected, total_detections);
+/// ```
+pub fn get_language_breakdown_with_stats<P: AsRef<Path>>(
+    path: P,
+    config: &BreakdownConfig,
+) -> (LanguageBreakdown, ScanStats) {
+    get_language_breakdown_multi_with_stats(&[path], config)
+}
+
+/// Walks every path provided and tallies the programming languages detected across all of them
+/// into a single breakdown
+///
+/// Files that are under more than one of the paths, like when one path is inside of another, are
+/// only tallied once. They're matched by their canonical path.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::get_language_breakdown_multi;
+/// let breakdown = get_language_breakdown_multi(&["src/bin", "src/detectors"]);
+/// assert!(breakdown.contains_key("Rust"));
+/// ```
+pub fn get_language_breakdown_multi<P: AsRef<Path>>(
+    paths: &[P],
+) -> HashMap<&'static str, Vec<(Detection, PathBuf)>> {
+    get_language_breakdown_multi_with_stats(paths, &BreakdownConfig::default()).0
+}
+
+/// Walks every path provided like [`get_language_breakdown_multi`] using the config, also
+/// returning stats about the work done by the walks
+///
+/// Files under more than one of the paths are visited once for each path they're under, so they
+/// count towards the stats more than once even though they're only tallied once.
+pub fn get_language_breakdown_multi_with_stats<P: AsRef<Path>>(
+    paths: &[P],
+    config: &BreakdownConfig,
+) -> (LanguageBreakdown, ScanStats) {
+    let counters = ScanCounters::default();
+    let detector = config.detector();
+    let mut language_breakdown = HashMap::new();
+    let mut seen_paths = HashSet::new();
+    for path in paths {
+        for file in walk(path, config, &detector, None, &counters) {
+            // Canonicalizing every file is only needed when the paths could overlap
+            if paths.len() > 1 {
+                let canonical_path =
+                    fs::canonicalize(&file.path).unwrap_or_else(|_| file.path.clone());
+                if !seen_paths.insert(canonical_path) {
+                    continue;
+                }
+            }
+            let files = language_breakdown
+                .entry(file.key())
+                .or_insert_with(Vec::new);
+            files.push((file.detection, file.path));
+        }
+    }
+
+    (language_breakdown, counters.stats())
+}
+
+/// Detects the languages of exactly the files provided and tallies them like a breakdown, without
+/// walking any directories
+///
+/// This is for file lists that come from elsewhere, like `git ls-files`, which have already
+/// applied their own ignore rules, so the documentation and vendored code overrides, the include
+/// globs, and the buckets of the config aren't applied. Minified files are still skipped. Paths
+/// that aren't files, like ones that don't exist, and files that can't be read are skipped and
+/// counted in the stats. A file that's listed more than once is only tallied once.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{detect_paths, BreakdownConfig};
+///
+/// let (breakdown, stats) = detect_paths(&["src/lib.rs", "Cargo.toml"], &BreakdownConfig::default());
+/// assert_eq!(breakdown.get("Rust").unwrap().len(), 1);
+/// assert_eq!(breakdown.get("TOML").unwrap().len(), 1);
+/// assert_eq!(stats.files_detected, 2);
+/// ```
+pub fn detect_paths<P: AsRef<Path>>(
+    paths: &[P],
+    config: &BreakdownConfig,
+) -> (LanguageBreakdown, ScanStats) {
+    let counters = ScanCounters::default();
+    let detector = config.detector();
+    let mut language_breakdown = HashMap::new();
+    let mut seen_paths = HashSet::new();
+    for path in paths {
+        let path = path.as_ref();
+        if !seen_paths.insert(path) {
+            continue;
+        }
+        if !path.is_file() {
+            debug!("skipping {} which isn't a file", path.display());
+            counters.add_skipped();
+            continue;
+        }
+        if filters::is_minified(path) {
+            debug!("skipping minified file {}", path.display());
+            counters.add_skipped();
+            continue;
+        }
+        match detector.detect_path(path) {
+            Ok((Some((detection, _)), bytes_read)) => {
+                debug!("{} detected as {:?}", path.display(), detection);
+                counters.add_detected(bytes_read);
+                let files = language_breakdown
+                    .entry(detection.language())
+                    .or_insert_with(Vec::new);
+                files.push((detection, path.to_path_buf()));
+            }
+            Ok((None, bytes_read)) => {
+                debug!("no language detected for {}", path.display());
+                counters.add_undetected(bytes_read);
+            }
+            Err(e) => {
+                debug!("skipping {} which couldn't be read: {}", path.display(), e);
+                counters.add_skipped();
+            }
+        }
+    }
+
+    (language_breakdown, counters.stats())
+}
+
+/// Walks the path provided and tallies the programming languages detected in the given path like
+/// [`get_language_breakdown`], reporting the files that couldn't be read instead of skipping them
+///
+/// Files that are removed between being found by the walk and being read, which happens in a
+/// working tree that's being built or rewritten, aren't errors and are skipped.
+///
+/// # Errors
+/// Errors with every file that couldn't be read, ex/ because of its permissions, along with the
+/// error reading it
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::try_get_language_breakdown;
+/// let breakdown = try_get_language_breakdown("src/").unwrap();
+/// assert!(breakdown.contains_key("Rust"));
+/// ```
+pub fn try_get_language_breakdown<P: AsRef<Path>>(
+    path: P,
+) -> Result<LanguageBreakdown, Vec<(PathBuf, io::Error)>> {
+    let mut language_breakdown = HashMap::new();
+    let mut errors = vec![];
+    for result in walk_with_errors(
+        path,
+        &BreakdownConfig::default(),
+        &Detector::default(),
+        None,
+        &ScanCounters::default(),
+    ) {
+        match result {
+            Ok(file) => {
+                let files = language_breakdown
+                    .entry(file.key())
+                    .or_insert_with(Vec::new);
+                files.push((file.detection, file.path));
+            }
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(language_breakdown)
+    } else {
+        errors.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Err(errors)
+    }
+}
+
+/// Walks the path provided and tallies the programming languages detected in the given path,
+/// reusing the detections in the cache for files that haven't changed
+///
+/// Files whose modified time or size don't match the cache are detected again. After the walk,
+/// the cache only holds the files visited by this walk so files that were deleted are dropped.
+/// See [`DetectionCache`] for an example.
+pub fn get_language_breakdown_with_cache<P: AsRef<Path>>(
+    path: P,
+    cache: &mut DetectionCache,
+) -> HashMap<&'static str, Vec<(Detection, PathBuf)>> {
+    let mut language_breakdown = HashMap::new();
+    let mut updated_cache = DetectionCache::new();
+    for file in walk(
+        path,
+        &BreakdownConfig::default(),
+        &Detector::default(),
+        Some(cache),
+        &ScanCounters::default(),
+    ) {
+        if let Some(stamp) = file.stamp {
+            updated_cache.insert(file.path.clone(), stamp, file.detection, file.confidence);
+        }
+        let files = language_breakdown
+            .entry(file.key())
+            .or_insert_with(Vec::new);
+        files.push((file.detection, file.path));
+    }
+    *cache = updated_cache;
+
+    language_breakdown
+}
+
+/// Walks the path provided and totals up the programming languages detected in the given path
+///
+/// Returns a map from the programming languages to their total, where the amount each file adds
+/// to the total is determined by the [`Aggregation`] in the config
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{get_language_totals, Aggregation, BreakdownConfig};
+/// let config = BreakdownConfig {
+///     aggregation: Aggregation::Confidence,
+///     ..BreakdownConfig::default()
+/// };
+/// let totals = get_language_totals("src/", &config);
+/// assert!(totals["Rust"] > 0.0);
+/// ```
+pub fn get_language_totals<P: AsRef<Path>>(
+    path: P,
+    config: &BreakdownConfig,
+) -> HashMap<&'static str, f64> {
+    let mut language_totals = HashMap::new();
+    for file in walk(
+        path,
+        config,
+        &config.detector(),
+        None,
+        &ScanCounters::default(),
+    ) {
+        let total = language_totals.entry(file.key()).or_insert(0.0);
+        *total += match config.aggregation {
+            Aggregation::Count => 1.0,
+            Aggregation::Confidence => file.confidence,
+        };
+    }
+
+    language_totals
+}
+
+/// Lists the files a breakdown of the path with the config would visit, without reading them
+///
+/// The same ignore rules, documentation and vendored code overrides, include globs, and
+/// `.gitattributes` overrides are applied as for
+/// [`get_language_breakdown_with_config`]. Minified files are listed since checking whether a file
+/// is minified means reading it. The files are sorted. Use [`is_detectable_by_name`] to check
+/// which of the files can be detected without reading their content.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{list_files, BreakdownConfig};
+/// use std::path::PathBuf;
+///
+/// let files = list_files("src/bin", &BreakdownConfig::default());
+/// assert_eq!(files, vec![PathBuf::from("src/bin/codegen.rs"), PathBuf::from("src/bin/main.rs")]);
+/// ```
+pub fn list_files<P: AsRef<Path>>(path: P, config: &BreakdownConfig) -> Vec<PathBuf> {
+    let walkers = Walkers::new(&path, config);
+    let (tx, rx) = mpsc::channel::<PathBuf>();
+    let included = walkers.included.map(|(walker, _)| walker);
+    for walker in std::iter::once(walkers.main).chain(included) {
+        walker.run(|| {
+            let tx = tx.clone();
+            Box::new(move |result| {
+                if let Ok(entry) = result {
+                    if entry
+                        .file_type()
+                        .is_some_and(|file_type| !file_type.is_dir())
+                    {
+                        tx.send(entry.into_path()).unwrap();
+                    }
+                }
+                ignore::WalkState::Continue
+            })
+        });
+    }
+    drop(tx);
+
+    let mut files: Vec<PathBuf> = rx.into_iter().collect();
+    files.sort();
+    files
+}
+
+// The characters that make a path component a glob
+const GLOB_CHARACTERS: &[char] = &['*', '?', '[', '{'];
+
+/// Returns whether the path is a glob to expand with [`expand_glob`] rather than a path to walk,
+/// which is when it has glob characters like `*` and isn't the name of an existing file
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::is_glob;
+///
+/// assert!(is_glob("src/**/*.rs"));
+/// assert!(!is_glob("src/"));
+/// ```
+pub fn is_glob(path: &str) -> bool {
+    path.contains(GLOB_CHARACTERS) && !Path::new(path).exists()
+}
+
+/// Lists the files matching a glob like `src/**/*.rs`, sorted
+///
+/// The directory before the first component with a glob character is walked, `src` in the
+/// example, and the rest of the glob is matched against the paths relative to it using gitignore
+/// syntax, so `**` matches any number of directories. Ignore files and hidden files are handled
+/// as configured, but the documentation and vendored code overrides aren't applied since the glob
+/// picks the files. Passing each of the files to [`get_language_breakdown_multi_with_stats`]
+/// tallies the languages of just those files.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{expand_glob, BreakdownConfig};
+/// use std::path::PathBuf;
+///
+/// let files = expand_glob("src/bin/*.rs", &BreakdownConfig::default());
+/// assert_eq!(files, vec![PathBuf::from("src/bin/codegen.rs"), PathBuf::from("src/bin/main.rs")]);
+/// ```
+pub fn expand_glob(pattern: &str, config: &BreakdownConfig) -> Vec<PathBuf> {
+    let mut base = PathBuf::new();
+    let mut glob_components = vec![];
+    for component in Path::new(pattern).components() {
+        let component = component.as_os_str().to_string_lossy();
+        if glob_components.is_empty() && !component.contains(GLOB_CHARACTERS) {
+            base.push(&*component);
+        } else {
+            glob_components.push(component);
+        }
+    }
+    if glob_components.is_empty() {
+        return if base.is_file() { vec![base] } else { vec![] };
+    }
+    if base.as_os_str().is_empty() {
+        base.push(".");
+    }
+
+    // The leading slash anchors the glob to the base like it is in a shell
+    let mut override_builder = OverrideBuilder::new(&base);
+    if override_builder
+        .add(&format!("/{}", glob_components.join("/")))
+        .is_err()
+    {
+        debug!("skipping the invalid glob {}", pattern);
+        return vec![];
+    }
+    let walker = walk_builder(&base, config)
+        .overrides(override_builder.build().unwrap())
+        .build();
+    let mut files: Vec<PathBuf> = walker
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry
+                .file_type()
+                .is_some_and(|file_type| file_type.is_file())
+        })
+        .map(|entry| {
+            let path = entry.into_path();
+            // Walking the current directory prefixes every path with ./ which the glob didn't have
+            match path.strip_prefix(".") {
+                Ok(relative) if !pattern.starts_with('.') => relative.to_path_buf(),
+                _ => path,
+            }
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Walks the path provided like [`get_language_breakdown_with_config`] and traces how every file
+/// visited was detected, see [`detect_verbose`]
+///
+/// This does more work for each file than a breakdown, so it's meant for drilling down into how a
+/// breakdown came out. The files that no language was detected for are traced too, and the
+/// traces are sorted by path. Files are traced with their detected languages rather than the
+/// buckets of the config, and minified files and files that can't be read are skipped like they
+/// are for a breakdown.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{scan_verbose, BreakdownConfig};
+///
+/// let traces = scan_verbose("src/bin", &BreakdownConfig::default());
+/// let (path, trace) = &traces[0];
+/// assert_eq!(path.to_str(), Some("src/bin/codegen.rs"));
+/// assert_eq!(trace.candidates.after_heuristics, vec!["Rust"]);
+/// ```
+pub fn scan_verbose<P: AsRef<Path>>(
+    path: P,
+    config: &BreakdownConfig,
+) -> Vec<(PathBuf, DetectionTrace)> {
+    let walkers = Walkers::new(&path, config);
+    let detector = config.detector();
+    let (tx, rx) = mpsc::channel::<(PathBuf, DetectionTrace)>();
+    let included = walkers.included.map(|(walker, _)| walker);
+    for walker in std::iter::once(walkers.main).chain(included) {
+        walker.run(|| {
+            let tx = tx.clone();
+            let detector = &detector;
+            Box::new(move |result| {
+                let path = match result {
+                    Ok(entry)
+                        if entry
+                            .file_type()
+                            .is_some_and(|file_type| !file_type.is_dir()) =>
+                    {
+                        entry.into_path()
+                    }
+                    _ => return ignore::WalkState::Continue,
+                };
+                if filters::is_minified(&path) {
+                    debug!("skipping minified file {}", path.display());
+                    return ignore::WalkState::Continue;
+                }
+                match trace_path(&path, detector) {
+                    Ok(trace) => tx.send((path, trace)).unwrap(),
+                    Err(e) => debug!("skipping {} which couldn't be read: {}", path.display(), e),
+                }
+                ignore::WalkState::Continue
+            })
+        });
+    }
+    drop(tx);
+
+    let mut traces: Vec<(PathBuf, DetectionTrace)> = rx.into_iter().collect();
+    traces.sort_by(|(a, _), (b, _)| a.cmp(b));
+    traces
+}
+
+/// Counts the files in a breakdown detected by each strategy
+///
+/// Every strategy is in the counts, including the ones that didn't detect any files. The counts
+/// show how much of the breakdown relied on the classifier rather than the filename, extension,
+/// shebang, or heuristics.
+///
+/// The counts are keyed by [`DetectionStrategy`] instead of the strategy's name so they're
+/// ordered the way the strategies are tried in, which is the order the CLI's `--strategy-summary`
+/// prints them in. The names are the strategies' `Display` output, so a map keyed by name is
+/// `counts.iter().map(|(strategy, count)| (strategy.to_string(), *count)).collect()`.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{get_language_breakdown, strategy_counts, DetectionStrategy};
+///
+/// let breakdown = get_language_breakdown("src/bin");
+/// let counts = strategy_counts(&breakdown);
+/// assert_eq!(counts.values().sum::<usize>(), 2);
+/// assert_eq!(counts[&DetectionStrategy::Shebang], 0);
+/// ```
+pub fn strategy_counts(
+    breakdown: &HashMap<&'static str, Vec<(Detection, PathBuf)>>,
+) -> BTreeMap<DetectionStrategy, usize> {
+    let mut counts: BTreeMap<DetectionStrategy, usize> = DetectionStrategy::ALL
+        .iter()
+        .map(|strategy| (*strategy, 0))
+        .collect();
+    for (detection, _) in breakdown.values().flatten() {
+        *counts.entry(detection.strategy()).or_insert(0) += 1;
+    }
+    counts
+}
+
+// A file visited by a walk along with its detection
+struct WalkedFile {
+    detection: Detection,
+    confidence: f64,
+    path: PathBuf,
+    // The stamp of the file, only set when walking with a cache
+    stamp: Option<FileStamp>,
+    // The bucket the file is tallied under instead of its language
+    bucket: Option<&'static str>,
+}
+
+impl WalkedFile {
+    // Returns the key the file is tallied under in a breakdown
+    fn key(&self) -> &'static str {
+        self.bucket.unwrap_or_else(|| self.detection.language())
+    }
+}
+
+// The directories that have been checked for Objective-C implementation files, used to detect the
+// ambiguous headers next to them as Objective-C
+#[derive(Debug, Default)]
+struct ObjectiveCDirectories(Mutex<HashMap<PathBuf, bool>>);
+
+impl ObjectiveCDirectories {
+    // Returns the detection for the file, which is Objective-C rather than C for a header in a
+    // directory with .m or .mm files
+    fn hint(&self, path: &Path, detection: Detection) -> Detection {
+        let is_header = path
+            .file_name()
+            .and_then(|filename| filename.to_str())
+            .and_then(detectors::get_extension)
+            == Some(".h");
+        if !is_header || detection != Detection::Heuristics("C") {
+            return detection;
+        }
+        let directory = match path.parent() {
+            Some(directory) => directory,
+            None => return detection,
+        };
+
+        let mut directories = self.0.lock().unwrap();
+        let has_objective_c = *directories
+            .entry(directory.to_path_buf())
+            .or_insert_with(|| has_objective_c_files(directory));
+        if has_objective_c {
+            debug!(
+                "{} detected as Objective-C from the files next to it",
+                path.display()
+            );
+            Detection::Heuristics("Objective-C")
+        } else {
+            detection
+        }
+    }
+}
+
+// Returns true if the directory has any .m or .mm files in it
+fn has_objective_c_files(directory: &Path) -> bool {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(_) => return false,
+    };
+    entries.filter_map(Result::ok).any(|entry| {
+        let filename = entry.file_name();
+        let extension = filename.to_str().and_then(detectors::get_extension);
+        extension == Some(".m") || extension == Some(".mm")
+    })
+}
+
+// The filters used to sort files into buckets, for excluded files when they're bucketed rather
+// than skipped and for test code when there are test globs
+#[derive(Clone)]
+struct Buckets {
+    root: PathBuf,
+    // The vendor and documentation filters, only set when excluded files are bucketed
+    excluded: Option<(Override, Override)>,
+    tests: Option<Override>,
+    // Whether symbolic links are bucketed
+    symlinks: bool,
+}
+
+impl Buckets {
+    // Returns the buckets for the config, or None if no files are bucketed
+    fn new(root: &Path, config: &BreakdownConfig) -> Option<Buckets> {
+        let excluded = match config.excluded_files {
+            ExcludedFiles::Exclude => None,
+            ExcludedFiles::Bucket => {
+                let vendor = filters::add_vendor_override(OverrideBuilder::new(""));
+                let documentation = filters::add_documentation_override(OverrideBuilder::new(""));
+                Some((vendor.build().unwrap(), documentation.build().unwrap()))
+            }
+        };
+        let tests = match config.test_globs.is_empty() {
+            true => None,
+            false => Some(filters::test_override(&config.test_globs)),
+        };
+        let symlinks = config.symlinks == Symlinks::Bucket;
+        if excluded.is_none() && tests.is_none() && !symlinks {
+            return None;
+        }
+        Some(Buckets {
+            root: root.to_path_buf(),
+            excluded,
+            tests,
+            symlinks,
+        })
+    }
+
+    // Returns the buckets for the files matching the include globs, which are never excluded
+    fn without_excluded(&self) -> Option<Buckets> {
+        if self.tests.is_none() && !self.symlinks {
+            return None;
+        }
+        Some(Buckets {
+            excluded: None,
+            ..self.clone()
+        })
+    }
+
+    // Returns true if minified files are bucketed rather than skipped
+    fn buckets_generated(&self) -> bool {
+        self.excluded.is_some()
+    }
+
+    fn bucket(&self, path: &Path) -> Option<&'static str> {
+        if self.symlinks
+            && fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_symlink())
+        {
+            return Some(SYMLINKS_BUCKET);
+        }
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        if let Some((vendor, documentation)) = &self.excluded {
+            if filters::is_ignored(vendor, relative) {
+                return Some(VENDORED_BUCKET);
+            } else if filters::is_ignored(documentation, relative) {
+                return Some(DOCUMENTATION_BUCKET);
+            }
+        }
+        match &self.tests {
+            Some(tests) if filters::is_ignored(tests, relative) => Some(TESTS_BUCKET),
+            _ if self.buckets_generated() && filters::is_minified(path) => Some(GENERATED_BUCKET),
+            _ => None,
+        }
+    }
+}
+
+// A file detected by a walk, or a file that couldn't be read along with the error reading it
+type WalkResult = Result<WalkedFile, (PathBuf, io::Error)>;
+
+// Walks the path in parallel, detecting the language of every file that isn't ignored. Files that
+// couldn't be read are skipped, see walk_with_errors.
+fn walk<P: AsRef<Path>>(
+    path: P,
+    config: &BreakdownConfig,
+    detector: &Detector,
+    cache: Option<&DetectionCache>,
+    counters: &ScanCounters,
+) -> impl Iterator<Item = WalkedFile> {
+    walk_with_errors(path, config, detector, cache, counters)
+        .into_iter()
+        .filter_map(Result::ok)
+}
+
+// Walks the path in parallel, detecting the language of every file that isn't ignored and sending
+// the errors for the files that couldn't be read. Files that no longer exist by the time they're
+// read aren't errors. When a cache is given, files that haven't changed use the cached detection
+// and the stamp of every file is sent along with its detection. The work done by the walk is
+// tallied in the counters.
+fn walk_with_errors<P: AsRef<Path>>(
+    path: P,
+    config: &BreakdownConfig,
+    detector: &Detector,
+    cache: Option<&DetectionCache>,
+    counters: &ScanCounters,
+) -> mpsc::Receiver<WalkResult> {
+    let walkers = Walkers::new(&path, config);
+    let objective_c_directories = match config.objective_c_header_hints {
+        true => Some(ObjectiveCDirectories::default()),
+        false => None,
+    };
+
+    let (tx, rx) = mpsc::channel::<WalkResult>();
+    run_walker(
+        walkers.main,
+        &tx,
+        detector,
+        cache,
+        walkers.buckets.as_ref(),
+        objective_c_directories.as_ref(),
+        counters,
+    );
+    if let Some((walker, buckets)) = walkers.included {
+        run_walker(
+            walker,
+            &tx,
+            detector,
+            cache,
+            buckets.as_ref(),
+            objective_c_directories.as_ref(),
+            counters,
+        );
+    }
+    drop(tx);
+
+    rx
+}
+
+// The walkers for the files of a breakdown. The main walker skips the files matching the include
+// globs, which are picked up by a separate walker, since any allow glob in an override ignores
+// every file that doesn't match it.
+struct Walkers {
+    main: WalkParallel,
+    // The buckets the files of the main walk are sorted into
+    buckets: Option<Buckets>,
+    // The walker for the files matching the include globs and the buckets they're sorted into
+    included: Option<(WalkParallel, Option<Buckets>)>,
+}
+
+impl Walkers {
+    fn new<P: AsRef<Path>>(path: P, config: &BreakdownConfig) -> Walkers {
+        let mut override_builder = match config.excluded_files {
+            ExcludedFiles::Exclude => filters::default_overrides(&path),
+            ExcludedFiles::Bucket => OverrideBuilder::new(&path),
+        };
+        let buckets = Buckets::new(path.as_ref(), config);
+        if config.include_hidden {
+            for vcs_directory in &["!.git/", "!.hg/", "!.svn/"] {
+                override_builder.add(vcs_directory).unwrap();
+            }
+        }
+
+        // Paths marked linguist-detectable in the .gitattributes at the root are walked even if
+        // they would be skipped like the include globs are, and paths marked not detectable are
+        // skipped
+        let detectable_patterns = fs::read_to_string(path.as_ref().join(".gitattributes"))
+            .map(|content| filters::parse_linguist_detectable(&content))
+            .unwrap_or_default();
+        let mut include_globs = config.include_globs.clone();
+        include_globs.extend(
+            detectable_patterns
+                .iter()
+                .filter(|(_, detectable)| *detectable)
+                .map(|(pattern, _)| pattern.clone()),
+        );
+
+        for include_glob in include_globs.iter() {
+            let _ = override_builder.add(&format!("!{}", include_glob));
+        }
+        for (pattern, _) in detectable_patterns.iter() {
+            let _ = override_builder.add(&format!("!{}", pattern));
+        }
+        let main = walk_builder(&path, config)
+            .overrides(override_builder.build().unwrap())
+            .build_parallel();
+
+        let included = if include_globs.is_empty() {
+            None
+        } else {
+            let mut include_builder = OverrideBuilder::new(&path);
+            for include_glob in config.include_globs.iter() {
+                let _ = include_builder.add(include_glob);
+            }
+            // Later patterns in .gitattributes take precedence like they do in git
+            for (pattern, detectable) in detectable_patterns.iter() {
+                let glob = match detectable {
+                    true => pattern.clone(),
+                    false => format!("!{}", pattern),
+                };
+                let _ = include_builder.add(&glob);
+            }
+            let walker = walk_builder(&path, config)
+                .overrides(include_builder.build().unwrap())
+                .build_parallel();
+            Some((walker, buckets.as_ref().and_then(Buckets::without_excluded)))
+        };
+
+        Walkers {
+            main,
+            buckets,
+            included,
+        }
+    }
+}
+
+fn walk_builder<P: AsRef<Path>>(path: P, config: &BreakdownConfig) -> WalkBuilder {
+    let num_threads = env::var_os("HYPLY_THREADS")
+        .and_then(|threads| threads.into_string().ok())
+        .and_then(|threads| threads.parse().ok())
+        .unwrap_or_else(num_cpus::get);
+
+    let mut builder = WalkBuilder::new(path);
+    builder
+        .threads(num_threads)
+        .hidden(!config.include_hidden)
+        .follow_links(config.symlinks == Symlinks::Follow);
+    builder
+}
+
+// Runs the walker, sending every file detected. Unless the buckets include generated files,
+// minified files are skipped.
+fn run_walker(
+    walker: WalkParallel,
+    tx: &mpsc::Sender<WalkResult>,
+    detector: &Detector,
+    cache: Option<&DetectionCache>,
+    buckets: Option<&Buckets>,
+    objective_c_directories: Option<&ObjectiveCDirectories>,
+    counters: &ScanCounters,
+) {
+    walker.run(|| {
+        let tx = tx.clone();
+        Box::new(move |result| {
+            use ignore::WalkState::*;
+
+            let path = match result {
+                Ok(path) => path.into_path(),
+                Err(e) => {
+                    debug!("skipping a path that couldn't be walked: {}", e);
+                    counters.add_skipped();
+                    return Continue;
+                }
+            };
+            if path.is_dir() {
+                return Continue;
+            }
+            let buckets_generated = buckets.is_some_and(Buckets::buckets_generated);
+            if !buckets_generated && filters::is_minified(&path) {
+                debug!("skipping minified file {}", path.display());
+                counters.add_skipped();
+                return Continue;
+            }
+            let bucket = buckets.and_then(|buckets| buckets.bucket(&path));
+
+            let stamp = cache.and_then(|_| FileStamp::from_path(&path));
+            let cached = match (cache, stamp) {
+                (Some(cache), Some(stamp)) => cache.get(&path, stamp),
+                _ => None,
+            };
+            let detection = match cached {
+                Some(detection) => Ok((Some(detection), 0)),
+                None => detector.detect_path(&path),
+            };
+            match detection {
+                Ok((Some((detection, confidence)), bytes_read)) => {
+                    let detection = match objective_c_directories {
+                        Some(directories) => directories.hint(&path, detection),
+                        None => detection,
+                    };
+                    debug!("{} detected as {:?}", path.display(), detection);
+                    counters.add_detected(bytes_read);
+                    let file = WalkedFile {
+                        detection,
+                        confidence,
+                        path,
+                        stamp,
+                        bucket,
+                    };
+                    tx.send(Ok(file)).unwrap();
+                }
+                Ok((None, bytes_read)) => {
+                    debug!("no language detected for {}", path.display());
+                    counters.add_undetected(bytes_read);
+                }
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                    // The file was removed after the walk found it
+                    debug!("skipping {} which no longer exists", path.display());
+                    counters.add_skipped();
+                }
+                Err(e) => {
+                    debug!("skipping {} which couldn't be read: {}", path.display(), e);
+                    counters.add_skipped();
+                    tx.send(Err((path, e))).unwrap();
+                }
+            }
+            Continue
+        })
+    });
+}
+
+fn filter_candidates(
+    previous_candidates: Vec<&'static str>,
+    new_candidates: Vec<&'static str>,
+) -> Vec<&'static str> {
+    if previous_candidates.is_empty() {
+        return new_candidates;
+    }
+
+    if new_candidates.is_empty() {
         return previous_candidates;
     }
 
-    let filtered_candidates: Vec<&'static str> = previous_candidates
-        .iter()
-        .filter(|l| new_candidates.contains(l))
-        .copied()
-        .collect();
+    let filtered_candidates: Vec<&'static str> = previous_candidates
+        .iter()
+        .filter(|l| new_candidates.contains(l))
+        .copied()
+        .collect();
+
+    match filtered_candidates.len() {
+        0 => previous_candidates,
+        _ => filtered_candidates,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::io::prelude::*;
+    use std::iter;
+
+    #[test]
+    fn test_strategy_names_are_stable() {
+        // The strategy names are parsed from the CLI's output, renaming them is a breaking change
+        let detections = [
+            Detection::Filename("Makefile"),
+            Detection::Extension("Rust"),
+            Detection::Shebang("Python"),
+            Detection::Heuristics("C"),
+            Detection::Classifier("C++"),
+        ];
+        let names: Vec<&str> = detections.iter().map(|d| d.variant()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "Filename",
+                "Extension",
+                "Shebang",
+                "Heuristics",
+                "Classifier"
+            ]
+        );
+
+        let strategies: Vec<String> = DetectionStrategy::ALL
+            .iter()
+            .map(|strategy| strategy.to_string())
+            .collect();
+        assert_eq!(strategies, names);
+        for detection in detections.iter() {
+            assert_eq!(detection.strategy().to_string(), detection.variant());
+        }
+    }
+
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_classify_content() {
+        let python = "import os\n\ndef main():\n    for name in os.listdir('.'):\n        print(name)\n\nif __name__ == '__main__':\n    main()\n";
+        let (language, confidence) = classify_content(python).unwrap();
+        assert_eq!(language, "Python");
+        assert!(confidence > 0.9 && confidence <= 1.0);
+
+        assert_eq!(classify_content(""), None);
+        assert_eq!(classify_content("\n\t \n"), None);
+        assert_eq!(classify_content("\"only a string\""), None);
+    }
+
+    #[test]
+    fn test_language_not_found_suggestions() {
+        let error = Language::try_from("Rus").unwrap_err();
+        assert_eq!(error.name, "Rus");
+        assert_eq!(error.suggestions[0], "Rust");
+        assert!(error.suggestions.len() <= 3);
+
+        // Names are compared ignoring case
+        assert_eq!(
+            Language::try_from("javascript").unwrap_err().suggestions[0],
+            "JavaScript"
+        );
+
+        let error = Language::try_from("definitely not a language").unwrap_err();
+        assert!(error.suggestions.is_empty());
+        assert_eq!(
+            error.to_string(),
+            "Language not found: definitely not a language"
+        );
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("rust", "rust"), 0);
+        assert_eq!(edit_distance("rus", "rust"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "go"), 2);
+    }
+
+    #[test]
+    fn test_language_is_popular() {
+        assert!(Language::try_from("Rust").unwrap().is_popular());
+        assert!(Language::try_from("JavaScript").unwrap().is_popular());
+        assert!(!Language::try_from("Brainfuck").unwrap().is_popular());
+        assert!(!Language::try_from("JSON").unwrap().is_popular());
+        // Every popular language is a known language
+        assert!(POPULAR_LANGUAGES
+            .iter()
+            .all(|name| Language::try_from(*name).is_ok()));
+    }
+
+    #[test]
+    fn test_languages_of_type() {
+        let programming: Vec<Language> = languages_of_type(LanguageType::Programming).collect();
+        assert!(programming.iter().any(|language| language.name == "Rust"));
+        assert!(programming
+            .iter()
+            .all(|language| language.language_type == LanguageType::Programming));
+        assert!(programming
+            .windows(2)
+            .all(|pair| pair[0].name < pair[1].name));
+
+        let data: Vec<Language> = languages_of_type(LanguageType::Data).collect();
+        assert!(data.iter().any(|language| language.name == "JSON"));
+        assert!(!data.iter().any(|language| language.name == "Rust"));
+    }
+
+    #[test]
+    fn test_detect_filename() {
+        let path = Path::new("APKBUILD");
+        let detected_language = detect(path).unwrap().unwrap();
+
+        assert_eq!(detected_language, Detection::Filename("Alpine Abuild"));
+    }
+
+    #[test]
+    fn test_detect_dockerfile() {
+        let content = "FROM rust:1.44\nRUN cargo build --release\n";
+        assert_eq!(
+            detect_with_content(Path::new("prod.dockerfile"), content),
+            Some(Detection::Extension("Dockerfile"))
+        );
+        assert_eq!(
+            detect_with_content(Path::new("Dockerfile.ci"), content),
+            Some(Detection::Filename("Dockerfile"))
+        );
+    }
+
+    #[test]
+    fn test_detect_mod() {
+        assert_eq!(
+            detect_with_content(Path::new("go.mod"), "module example.com/hello\n\ngo 1.14\n"),
+            Some(Detection::Filename("Go Module"))
+        );
+        assert_eq!(
+            detect_with_content(Path::new("go.sum"), ""),
+            Some(Detection::Filename("Go Checksums"))
+        );
+        assert_eq!(
+            detect_with_content(Path::new("md5.mod"), "crypto/md5.ko\ncrypto/md5.o\n"),
+            Some(Detection::Heuristics("Linux Kernel Module"))
+        );
+        assert_eq!(
+            detect_with_content(
+                Path::new("diet.mod"),
+                "set FOOD;\nparam cost {FOOD} > 0;\nvar Buy {FOOD} >= 0;\nminimize Total_Cost: sum {j in FOOD} cost[j] * Buy[j];\n"
+            ),
+            Some(Detection::Heuristics("AMPL"))
+        );
+        assert_eq!(
+            detect_with_content(Path::new("Hello.mod"), "MODULE Hello;\nEND Hello.\n"),
+            Some(Detection::Heuristics("Modula-2"))
+        );
+    }
+
+    #[test]
+    fn test_strategy_counts() {
+        let mut breakdown = HashMap::new();
+        breakdown.insert(
+            "Rust",
+            vec![
+                (Detection::Extension("Rust"), PathBuf::from("src/main.rs")),
+                (Detection::Classifier("Rust"), PathBuf::from("src/lib.rs")),
+            ],
+        );
+        breakdown.insert(
+            "Python",
+            vec![(Detection::Shebang("Python"), PathBuf::from("script"))],
+        );
+        breakdown.insert(
+            "Go",
+            vec![(Detection::Extension("Go"), PathBuf::from("main.go"))],
+        );
+
+        let counts: Vec<(DetectionStrategy, usize)> =
+            strategy_counts(&breakdown).into_iter().collect();
+        assert_eq!(
+            counts,
+            vec![
+                (DetectionStrategy::Filename, 0),
+                (DetectionStrategy::Extension, 2),
+                (DetectionStrategy::Shebang, 1),
+                (DetectionStrategy::Heuristics, 0),
+                (DetectionStrategy::Classifier, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_detectable_by_name() {
+        assert!(is_detectable_by_name("main.rs"));
+        assert!(is_detectable_by_name("MAIN.RS"));
+        assert!(is_detectable_by_name("Makefile"));
+        assert!(is_detectable_by_name("Dockerfile.ci"));
+        assert!(!is_detectable_by_name("data.bin"));
+        assert!(!is_detectable_by_name("script"));
+        assert!(!is_detectable_by_name(""));
+    }
+
+    #[test]
+    fn test_detect_extension() {
+        let path = Path::new("pizza.purs");
+        let detected_language = detect(path).unwrap().unwrap();
+
+        assert_eq!(detected_language, Detection::Extension("PureScript"));
+    }
+
+    #[test]
+    fn test_detect_shebang() {
+        let path = Path::new("a");
+        let mut file = File::create(path).unwrap();
+        file.write(b"#!/usr/bin/python").unwrap();
+        file.flush().unwrap();
+
+        let detected_language = detect(path).unwrap().unwrap();
+
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(detected_language, Detection::Shebang("Python"));
+    }
+
+    #[test]
+    fn test_detect_shell_dialects() {
+        fs::create_dir_all("temp-testing-dir-shell").unwrap();
+        fs::write("temp-testing-dir-shell/a.sh", "#!/usr/bin/fish\necho hi").unwrap();
+        fs::write("temp-testing-dir-shell/b.sh", "#!/bin/bash\n[[ -n $x ]]").unwrap();
+        fs::write("temp-testing-dir-shell/c.sh", "echo hi").unwrap();
+        fs::write("temp-testing-dir-shell/d", "#!/usr/bin/env fish\necho hi").unwrap();
+        fs::write("temp-testing-dir-shell/e", "#!/bin/bash\n[[ -n $x ]]").unwrap();
+
+        let detect_file = |name: &str| {
+            detect(&Path::new("temp-testing-dir-shell").join(name))
+                .unwrap()
+                .unwrap()
+        };
+        let fish_sh = detect_file("a.sh");
+        let bash_sh = detect_file("b.sh");
+        let plain_sh = detect_file("c.sh");
+        let fish = detect_file("d");
+        let bash = detect_file("e");
+
+        fs::remove_dir_all("temp-testing-dir-shell").unwrap();
+
+        assert_eq!(fish_sh, Detection::Shebang("fish"));
+        assert_eq!(bash_sh, Detection::Extension("Shell"));
+        assert_eq!(plain_sh, Detection::Extension("Shell"));
+        assert_eq!(fish, Detection::Shebang("fish"));
+        assert_eq!(bash, Detection::Shebang("Shell"));
+    }
+
+    #[test]
+    fn test_apply_heuristics() {
+        let candidates = ["JavaScript", "Erlang"];
+        assert_eq!(
+            apply_heuristics("es", &candidates, "'use strict'"),
+            vec!["JavaScript"]
+        );
+        assert_eq!(
+            apply_heuristics(".ES", &candidates, "'use strict'"),
+            vec!["JavaScript"]
+        );
+        assert_eq!(
+            apply_heuristics(".es", &candidates, "no match"),
+            vec!["JavaScript", "Erlang"]
+        );
+        assert_eq!(
+            apply_heuristics(".unknown", &candidates, ""),
+            vec!["JavaScript", "Erlang"]
+        );
+    }
+
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_detect_all() {
+        let path = Path::new("temp-detect-all.h");
+        fs::write(path, "#!/usr/bin/env python\n@interface Foo\n@end\n").unwrap();
+
+        let detections = detect_all(path).unwrap();
+
+        fs::remove_file(path).unwrap();
+
+        let mut extension_candidates: Vec<&str> = detections
+            .iter()
+            .filter(|detection| detection.strategy() == DetectionStrategy::Extension)
+            .map(|detection| detection.language())
+            .collect();
+        extension_candidates.sort();
+        assert_eq!(extension_candidates, vec!["C", "C++", "Objective-C"]);
+        assert!(detections.contains(&Detection::Shebang("Python")));
+        assert!(detections.contains(&Detection::Heuristics("Objective-C")));
+        assert!(detections
+            .iter()
+            .any(|detection| detection.strategy() == DetectionStrategy::Classifier));
+    }
+
+    #[test]
+    fn test_detect_with_encoding() {
+        let path = Path::new("temp-utf16.py");
+        let content: Vec<u8> = "\u{feff}#!/usr/bin/env python\nprint('hello')\n"
+            .encode_utf16()
+            .flat_map(|unit| unit.to_le_bytes().to_vec())
+            .collect();
+        fs::write(path, content).unwrap();
 
-    match filtered_candidates.len() {
-        0 => previous_candidates,
-        _ => filtered_candidates,
+        let utf16 = detect_with_encoding(path).unwrap();
+
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(
+            utf16,
+            Some((Detection::Extension("Python"), Encoding::Utf16Le))
+        );
+
+        let path = Path::new("temp-utf16-script");
+        let content: Vec<u8> = "\u{feff}#!/usr/bin/env python\n"
+            .encode_utf16()
+            .flat_map(|unit| unit.to_be_bytes().to_vec())
+            .collect();
+        fs::write(path, content).unwrap();
+
+        let shebang = detect(path).unwrap();
+
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(shebang, Some(Detection::Shebang("Python")));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
-    use std::io::prelude::*;
-    use std::iter;
+    #[test]
+    fn test_detect_interface_definition_languages() {
+        // Short schemas have too little content for the classifier, but their extensions only
+        // map to one language so the classifier is never needed
+        let schemas = [
+            (
+                "temp-idl.proto",
+                "syntax = \"proto3\";\n",
+                "Protocol Buffer",
+            ),
+            (
+                "temp-idl.thrift",
+                "struct User {\n  1: string name\n}\n",
+                "Thrift",
+            ),
+            (
+                "temp-idl.capnp",
+                "@0xdbb9ad1f14bf0b36;\nstruct User {}\n",
+                "Cap'n Proto",
+            ),
+        ];
+        for (filename, content, language) in schemas.iter() {
+            let path = Path::new(filename);
+            fs::write(path, content).unwrap();
+
+            let detection = detect(path).unwrap();
+
+            fs::remove_file(path).unwrap();
+            assert_eq!(detection, Some(Detection::Extension(language)));
+        }
+    }
 
     #[test]
-    fn test_detect_filename() {
-        let path = Path::new("APKBUILD");
-        let detected_language = detect(path).unwrap().unwrap();
+    fn test_detect_terraform() {
+        // .tf and .tfvars only map to HCL so even a single line is detected
+        let detect = |filename: &str, content: &str| {
+            detect_with_content(Path::new(filename), content).unwrap()
+        };
+        assert_eq!(
+            detect("main.tf", "provider \"aws\" {}\n"),
+            Detection::Extension("HCL")
+        );
+        assert_eq!(
+            detect("prod.tfvars", "region = \"us-east-1\"\n"),
+            Detection::Extension("HCL")
+        );
 
-        assert_eq!(detected_language, Detection::Filename("Alpine Abuild"));
+        // .workflow is shared with XML so the content is used to tell them apart
+        assert_eq!(
+            detect(
+                "deploy.workflow",
+                "resource \"aws_s3_bucket\" \"logs\" {\n  bucket = \"logs\"\n}\n"
+            ),
+            Detection::Heuristics("HCL")
+        );
+        assert_eq!(
+            detect(
+                "main.workflow",
+                "workflow \"Build\" {\n  on = \"push\"\n  resolves = [\"Test\"]\n}\n"
+            ),
+            Detection::Heuristics("HCL")
+        );
+        assert_eq!(
+            detect(
+                "build.workflow",
+                "<?xml version=\"1.0\"?>\n<workflow name=\"build\"/>\n"
+            ),
+            Detection::Heuristics("XML")
+        );
+
+        assert_eq!(
+            crate::detect(Path::new("samples/HCL/main.tf")).unwrap(),
+            Some(Detection::Extension("HCL"))
+        );
+        // OpenTofu's .tofu isn't in the extension map, so the Terraform blocks pin it to HCL
+        for sample in &["samples/HCL/main.tf", "samples/HCL/example.tf"] {
+            let content = fs::read_to_string(sample).unwrap();
+            assert_eq!(
+                detect("main.tofu", &content),
+                Detection::Heuristics("HCL"),
+                "{}",
+                sample
+            );
+            assert_eq!(
+                detect("main.workflow", &content),
+                Detection::Heuristics("HCL"),
+                "{}",
+                sample
+            );
+        }
+        assert_eq!(
+            detect(
+                "versions.tofu",
+                "terraform {\n  required_version = \">= 1.6\"\n}\n"
+            ),
+            Detection::Heuristics("HCL")
+        );
+        assert_eq!(
+            detect_with_content(Path::new("notes.tofu"), "remember the milk\n"),
+            None
+        );
     }
 
     #[test]
-    fn test_detect_extension() {
-        let path = Path::new("pizza.purs");
-        let detected_language = detect(path).unwrap().unwrap();
+    fn test_detect_react() {
+        let detect = |filename: &str, content: &str| {
+            detect_with_content(Path::new(filename), content).unwrap()
+        };
+        // .jsx only maps to JSX so it never reaches the classifier
+        assert_eq!(
+            detect("util.jsx", "export const add = (a, b) => a + b;\n"),
+            Detection::Extension("JSX")
+        );
 
-        assert_eq!(detected_language, Detection::Extension("PureScript"));
+        // .tsx is shared with XML so anything that isn't XML is TSX
+        assert_eq!(
+            detect(
+                "util.tsx",
+                "export const add = (a: number, b: number) => a + b;\n"
+            ),
+            Detection::Heuristics("TSX")
+        );
+        assert_eq!(
+            detect(
+                "App.tsx",
+                "import React from 'react';\nexport const App = () => <div />;\n"
+            ),
+            Detection::Heuristics("TSX")
+        );
+        assert_eq!(
+            detect(
+                "tiles.tsx",
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<tileset name=\"tiles\"/>\n"
+            ),
+            Detection::Heuristics("XML")
+        );
     }
 
     #[test]
-    fn test_detect_shebang() {
-        let path = Path::new("a");
+    fn test_detect_heuristics() {
+        let path = Path::new("a.es");
         let mut file = File::create(path).unwrap();
-        file.write(b"#!/usr/bin/python").unwrap();
+        file.write(b"'use strict'").unwrap();
         file.flush().unwrap();
 
         let detected_language = detect(path).unwrap().unwrap();
 
         fs::remove_file(path).unwrap();
 
-        assert_eq!(detected_language, Detection::Shebang("Python"));
+        assert_eq!(detected_language, Detection::Heuristics("JavaScript"));
     }
 
     #[test]
-    fn test_detect_heuristics() {
-        let path = Path::new("a.es");
+    fn test_detect_r_heuristics() {
+        let detect = |filename: &str, content: &str| {
+            detect_with_content(Path::new(filename), content).unwrap()
+        };
+
+        assert_eq!(
+            detect("analysis.R", "library(ggplot2)\nx <- c(1, 2, 3)\nplot(x)\n"),
+            Detection::Heuristics("R")
+        );
+        assert_eq!(
+            detect("square.r", "square <- function(x) x^2\n"),
+            Detection::Heuristics("R")
+        );
+        assert_eq!(
+            detect(
+                "helpers.r",
+                "summarize = function(df) {\n  summary(df)\n}\n"
+            ),
+            Detection::Heuristics("R")
+        );
+        assert_eq!(
+            detect(
+                "hello.r",
+                "REBOL [\n    Title: \"Hello\"\n]\nprint \"Hello\"\n"
+            ),
+            Detection::Heuristics("Rebol")
+        );
+        // A comment mentioning Rebol shouldn't be mistaken for a Rebol header
+        assert_eq!(
+            detect("port.R", "# ported from rebol\ny <- 2\n"),
+            Detection::Heuristics("R")
+        );
+    }
+
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_detect_classify() {
+        let path = Path::new("peep.rs");
         let mut file = File::create(path).unwrap();
-        file.write(b"'use strict'").unwrap();
+        file.write(
+            b"
+            match optional {
+                Some(pattern) => println!(\"Hello World\"),
+                None => println!(\"u missed\")
+            }
+            ",
+        )
+        .unwrap();
         file.flush().unwrap();
 
         let detected_language = detect(path).unwrap().unwrap();
 
         fs::remove_file(path).unwrap();
+        assert_eq!(detected_language, Detection::Classifier("Rust"));
+    }
 
-        assert_eq!(detected_language, Detection::Heuristics("JavaScript"));
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_detect_invalid_utf8() {
+        let path = Path::new("invalid-utf8.rs");
+        let mut file = File::create(path).unwrap();
+        file.write_all(
+            b"
+            // caf\xe9
+            match optional {
+                Some(pattern) => println!(\"Hello World\"),
+                None => println!(\"u missed\")
+            }
+            ",
+        )
+        .unwrap();
+        file.flush().unwrap();
+
+        let detected_language = detect(path).unwrap().unwrap();
+
+        fs::remove_file(path).unwrap();
+        assert_eq!(detected_language, Detection::Classifier("Rust"));
+    }
+
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_detect_with_content() {
+        let rust = "
+            match optional {
+                Some(pattern) => println!(\"Hello World\"),
+                None => println!(\"u missed\")
+            }
+            ";
+        assert_eq!(
+            detect_with_content(Path::new("does-not-exist.rs"), rust),
+            Some(Detection::Classifier("Rust"))
+        );
+        assert_eq!(
+            detect_with_content(Path::new("does-not-exist/APKBUILD"), ""),
+            Some(Detection::Filename("Alpine Abuild"))
+        );
+        assert_eq!(
+            detect_with_content(Path::new("a.es"), "'use strict'"),
+            Some(Detection::Heuristics("JavaScript"))
+        );
+        assert_eq!(
+            detect_with_content(Path::new("a.sh"), "#!/usr/bin/fish"),
+            Some(Detection::Shebang("fish"))
+        );
+        assert_eq!(detect_with_content(Path::new("y"), rust), None);
+    }
+
+    #[test]
+    fn test_detect_first_line() {
+        assert_eq!(
+            detect_with_content(Path::new("index"), "<?php\necho 'hello';\n"),
+            Some(Detection::Heuristics("PHP"))
+        );
+        assert_eq!(
+            detect_with_content(
+                Path::new("user-data"),
+                "#cloud-config\npackages:\n  - git\n"
+            ),
+            Some(Detection::Heuristics("YAML"))
+        );
+        // The shebang takes precedence over the first line
+        assert_eq!(
+            detect_with_content(Path::new("script"), "#!/usr/bin/env php\n<?php\n"),
+            Some(Detection::Shebang("PHP"))
+        );
+        assert_eq!(
+            detect_with_content(
+                Path::new("2020-06-01-hello-world"),
+                "---\ntitle: Hello World\n---\n\nThis is my first post.\n"
+            ),
+            Some(Detection::Heuristics("Markdown"))
+        );
+        // An unknown extension doesn't stop the first line from being used
+        assert_eq!(
+            detect_with_content(Path::new("config.unknown"), "<?xml version=\"1.0\"?>\n"),
+            Some(Detection::Heuristics("XML"))
+        );
+    }
+
+    #[test]
+    fn test_detect_components() {
+        // The sections inside of a component are reported by detect_embedded, the component itself
+        // is still detected as the framework's language
+        let component = "<script lang=\"ts\">\nlet a: number = 1;\n</script>\n<style lang=\"scss\">\n$a: red;\n</style>\n";
+        assert_eq!(
+            detect_with_content(Path::new("App.vue"), component),
+            Some(Detection::Extension("Vue"))
+        );
+        assert_eq!(
+            detect_with_content(Path::new("index.astro"), "---\n---\n<h1>Hi</h1>\n"),
+            Some(Detection::Extension("Astro"))
+        );
+        let languages: Vec<&str> = detect_embedded(Path::new("App.vue"), component)
+            .iter()
+            .map(|section| section.language)
+            .collect();
+        assert_eq!(languages, vec!["TypeScript", "SCSS"]);
+    }
+
+    #[test]
+    fn test_detect_shell_completions() {
+        assert_eq!(
+            detect_with_content(Path::new("_cargo"), "#compdef cargo\n\n_cargo() {\n}\n"),
+            Some(Detection::Heuristics("Shell"))
+        );
+        assert_eq!(
+            detect_with_content(
+                Path::new("share/bash-completion/completions/hyply"),
+                "complete -F _hyply hyply\n"
+            ),
+            Some(Detection::Heuristics("Shell"))
+        );
+        assert_eq!(
+            detect_with_content(
+                Path::new("/usr/share/zsh/site-functions/_git"),
+                "local curcontext=$curcontext\n"
+            ),
+            Some(Detection::Heuristics("Shell"))
+        );
+        // The path convention is only used when nothing else matched
+        assert_eq!(
+            detect_with_content(Path::new("bash_completion.d/hyply.py"), "print(1)\n"),
+            Some(Detection::Extension("Python"))
+        );
+    }
+
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_detect_reader() {
+        let rust = "
+            match optional {
+                Some(pattern) => println!(\"Hello World\"),
+                None => println!(\"u missed\")
+            }
+            ";
+        assert_eq!(
+            detect_reader(Path::new("does-not-exist.rs"), Cursor::new(rust)).unwrap(),
+            Some(Detection::Classifier("Rust"))
+        );
+        assert_eq!(
+            detect_reader(Path::new("pizza.purs"), Cursor::new("")).unwrap(),
+            Some(Detection::Extension("PureScript"))
+        );
+        assert_eq!(
+            detect_reader(Path::new("y"), Cursor::new(rust)).unwrap(),
+            None
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_detect_async() {
+        assert_eq!(
+            detect_async(Path::new("src/bin/main.rs")).await.unwrap(),
+            Some(Detection::Heuristics("Rust"))
+        );
+        // The file isn't opened when the extension is enough
+        assert_eq!(
+            detect_async(Path::new("does-not-exist.purs"))
+                .await
+                .unwrap(),
+            Some(Detection::Extension("PureScript"))
+        );
+        assert!(detect_async(Path::new("does-not-exist.h")).await.is_err());
+    }
+
+    #[test]
+    fn test_detect_autotools() {
+        let detector = Detector::new();
+        assert_eq!(
+            detector.detect_from_str(Some("configure.ac"), "AC_INIT([hyply], [1.0])\n"),
+            Some(Detection::Filename("M4Sugar"))
+        );
+        assert_eq!(
+            detector.detect_from_str(Some("configure.in"), "AC_INIT(hyply, 1.0)\n"),
+            Some(Detection::Filename("M4Sugar"))
+        );
+        assert_eq!(
+            detector.detect_from_str(Some("Makefile.am"), "bin_PROGRAMS = hyply\n"),
+            Some(Detection::Filename("Makefile"))
+        );
+        assert_eq!(
+            detector.detect_from_str(Some("include.am"), "hyply_SOURCES += src/main.c\n"),
+            Some(Detection::Extension("Makefile"))
+        );
+        assert_eq!(
+            detector.detect_from_str(
+                Some("ax_pthread.m4"),
+                "AC_DEFUN([AX_PTHREAD], [\nAC_REQUIRE([AC_CANONICAL_HOST])\n])\n"
+            ),
+            Some(Detection::Heuristics("M4Sugar"))
+        );
+        assert_eq!(
+            detector.detect_from_str(Some("macros.m4"), "define(`greeting', `hello')dnl\n"),
+            Some(Detection::Heuristics("M4"))
+        );
+
+        // Templates are detected as the file they generate
+        assert_eq!(
+            detector.detect_from_str(Some("config.h.in"), "#undef HAVE_UNISTD_H\n"),
+            Some(Detection::Heuristics("C"))
+        );
+        assert_eq!(
+            detector.detect_from_str(Some("setup.py.in"), "from setuptools import setup\n"),
+            Some(Detection::Extension("Python"))
+        );
+        assert_eq!(
+            detector.detect_from_str(Some("Dockerfile.in"), "FROM @BASE_IMAGE@\n"),
+            Some(Detection::Filename("Dockerfile"))
+        );
+    }
+
+    #[test]
+    fn test_detect_kotlin_and_gradle() {
+        let detect = |filename: &str, content: &str| {
+            detect_from_bytes(Some(filename), content.as_bytes()).map(|(detection, _)| detection)
+        };
+        let kotlin_dsl = "plugins {\n    kotlin(\"jvm\") version \"1.9.0\"\n}\n";
+        assert_eq!(
+            detect("build.gradle.kts", kotlin_dsl),
+            Some(Detection::Extension("Gradle"))
+        );
+        assert_eq!(
+            detect("settings.gradle.kts", "rootProject.name = \"app\"\n"),
+            Some(Detection::Extension("Gradle"))
+        );
+        assert_eq!(
+            detect("build.gradle", "apply plugin: 'java'\n"),
+            Some(Detection::Extension("Gradle"))
+        );
+        // Kotlin scripts that aren't Gradle builds are still Kotlin
+        assert_eq!(
+            detect("deploy.main.kts", "println(\"Deploying\")\n"),
+            Some(Detection::Extension("Kotlin"))
+        );
+        assert_eq!(
+            detect("Main.kt", "fun main() = println(\"Hello\")\n"),
+            Some(Detection::Extension("Kotlin"))
+        );
+    }
+
+    #[test]
+    fn test_detect_ml_family() {
+        let detect = |filename: &str, content: &str| {
+            detect_from_bytes(Some(filename), content.as_bytes()).map(|(detection, _)| detection)
+        };
+        assert_eq!(
+            detect(
+                "greet.ml",
+                "let greet name =\n  print_endline (\"Hello \" ^ name)\n"
+            ),
+            Some(Detection::Heuristics("OCaml"))
+        );
+        assert_eq!(
+            detect("tree.ml", "module Tree = struct\n  type t = Leaf\nend\n"),
+            Some(Detection::Heuristics("OCaml"))
+        );
+        assert_eq!(
+            detect(
+                "greet.ml",
+                "fun greet name =\n  print (\"Hello \" ^ name)\nval x = fn y => y\n"
+            ),
+            Some(Detection::Heuristics("Standard ML"))
+        );
+
+        assert_eq!(
+            detect(
+                "App.re",
+                "let component = ReasonReact.statelessComponent(\"App\");\n"
+            ),
+            Some(Detection::Heuristics("Reason"))
+        );
+        assert_eq!(
+            detect("Button.re", "[@react.component]\nlet make = (~label) => <button> {React.string(label)} </button>;\n"),
+            Some(Detection::Heuristics("Reason"))
+        );
+        assert_eq!(
+            detect(
+                "lexer.re",
+                "#include <stdio.h>\n/*!re2c\n  [0-9]+ { return 1; }\n*/\n"
+            ),
+            Some(Detection::Heuristics("C++"))
+        );
+        // ReScript isn't a known language so .res isn't detected
+        assert_eq!(detect("App.res", "let x = 1\n"), None);
+    }
+
+    #[test]
+    fn test_detect_svg() {
+        let svg = br#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16">
+  <circle cx="8" cy="8" r="8"/>
+</svg>
+"#;
+        assert_eq!(
+            detect_from_bytes(Some("icon.svg"), svg),
+            Some((Detection::Extension("SVG"), 1.0))
+        );
+        assert_eq!(
+            detect_from_bytes(Some("icon"), svg),
+            Some((Detection::Heuristics("SVG"), 1.0))
+        );
+        assert_eq!(
+            detect_from_bytes(Some("fragment"), b"<svg viewBox=\"0 0 1 1\"></svg>"),
+            Some((Detection::Heuristics("SVG"), 1.0))
+        );
+        // SVG is counted as data rather than markup
+        assert_eq!(
+            Language::try_from("SVG").unwrap().language_type,
+            LanguageType::Data
+        );
+    }
+
+    #[test]
+    fn test_detect_plist() {
+        let xml = br#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>CFBundleName</key>
+    <string>Hyperpolyglot</string>
+</dict>
+</plist>
+"#;
+        assert_eq!(
+            detect_from_bytes(Some("Info.plist"), xml),
+            Some((Detection::Heuristics("XML Property List"), 1.0))
+        );
+
+        // Binary property lists aren't text so they're skipped like Linguist skips them
+        let mut binary = b"bplist00\xd1\x01\x02\x5cCFBundleName".to_vec();
+        binary.extend_from_slice(&[0x00, 0x08, 0x0b, 0xff, 0xfe, 0x80, 0x01]);
+        assert_eq!(detect_from_bytes(Some("Settings.plist"), &binary), None);
+        assert_eq!(detect_from_bytes(Some("Settings"), &binary), None);
+
+        assert_eq!(
+            detect_from_bytes(Some("defaults.plist"), b"{\n    Name = \"bplist00\";\n}\n"),
+            Some((Detection::Heuristics("OpenStep Property List"), 1.0))
+        );
     }
 
+    #[cfg(feature = "classifier")]
     #[test]
-    fn test_detect_classify() {
-        let path = Path::new("peep.rs");
-        let mut file = File::create(path).unwrap();
-        file.write(
-            b"
-            match optional {
-                Some(pattern) => println!(\"Hello World\"),
-                None => println!(\"u missed\")
-            }
-            ",
+    fn test_detect_from_bytes() {
+        assert_eq!(
+            detect_from_bytes(Some("pizza.purs"), b""),
+            Some((Detection::Extension("PureScript"), 1.0))
+        );
+        assert_eq!(
+            detect_from_bytes(None, b"#!/usr/bin/env python\nprint('hello')"),
+            Some((Detection::Shebang("Python"), 1.0))
+        );
+        // UTF-16 content is decoded before detection
+        let utf16: Vec<u8> = std::iter::once(0xFEFF)
+            .chain("#!/bin/sh\necho hello\n".encode_utf16())
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        assert_eq!(
+            detect_from_bytes(None, &utf16),
+            Some((Detection::Shebang("Shell"), 1.0))
+        );
+
+        let (detection, confidence) = detect_from_bytes(
+            Some("square.m"),
+            b"function y = square(x)\n  y = x .^ 2;\nend\n",
         )
         .unwrap();
-        file.flush().unwrap();
-
-        let detected_language = detect(path).unwrap().unwrap();
-
-        fs::remove_file(path).unwrap();
-        assert_eq!(detected_language, Detection::Classifier("Rust"));
+        assert_eq!(detection, Detection::Classifier("MATLAB"));
+        assert!(confidence > 0.0 && confidence < 1.0);
+        assert_eq!(detect_from_bytes(None, b"hello"), None);
     }
 
     #[test]
@@ -471,6 +3321,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_language_totals_confidence() {
+        fs::create_dir_all("temp-testing-dir-totals").unwrap();
+        fs::write("temp-testing-dir-totals/main.go", "package main").unwrap();
+        fs::write("temp-testing-dir-totals/other.go", "package other").unwrap();
+        fs::write(
+            "temp-testing-dir-totals/peep.rs",
+            "fn main() { println!(\"Hello World\"); }",
+        )
+        .unwrap();
+
+        let counts = get_language_totals("temp-testing-dir-totals", &BreakdownConfig::default());
+        let config = BreakdownConfig {
+            aggregation: Aggregation::Confidence,
+            ..BreakdownConfig::default()
+        };
+        let weighted = get_language_totals("temp-testing-dir-totals", &config);
+
+        fs::remove_dir_all("temp-testing-dir-totals").unwrap();
+
+        assert_eq!(counts.get("Go"), Some(&2.0));
+        assert_eq!(weighted.get("Go"), Some(&2.0));
+        assert_eq!(counts.get("Rust"), Some(&1.0));
+        let rust = weighted.get("Rust").copied().unwrap();
+        assert!(rust > 0.0 && rust <= 1.0);
+    }
+
     #[test]
     fn test_get_language_breakdown_ignores_overrides_documentation() {
         fs::create_dir_all("temp-testing-dir").unwrap();
@@ -480,6 +3357,770 @@ mod tests {
         fs::remove_dir_all("temp-testing-dir").unwrap();
     }
 
+    #[test]
+    fn test_get_language_breakdown_ignores_minified() {
+        fs::create_dir_all("temp-testing-dir-minified-breakdown").unwrap();
+        fs::write(
+            "temp-testing-dir-minified-breakdown/bundle.js",
+            "function a(b){return b+1}var c=a(1);".repeat(50),
+        )
+        .unwrap();
+        fs::write(
+            "temp-testing-dir-minified-breakdown/main.rs",
+            "fn main() {\n    println!(\"Hello World\");\n}\n",
+        )
+        .unwrap();
+
+        let breakdown = get_language_breakdown("temp-testing-dir-minified-breakdown");
+
+        fs::remove_dir_all("temp-testing-dir-minified-breakdown").unwrap();
+
+        assert!(!breakdown.contains_key("JavaScript"));
+        assert!(breakdown.contains_key("Rust"));
+    }
+
+    #[test]
+    fn test_get_language_breakdown_bucket_excluded_files() {
+        let dir = "temp-testing-dir-bucket-breakdown";
+        fs::create_dir_all(format!("{}/node_modules/dep", dir)).unwrap();
+        fs::create_dir_all(format!("{}/docs", dir)).unwrap();
+        fs::write(format!("{}/main.go", dir), "package main\n").unwrap();
+        fs::write(format!("{}/node_modules/dep/index.go", dir), "").unwrap();
+        fs::write(format!("{}/docs/example.py", dir), "print('hi')\n").unwrap();
+        fs::write(
+            format!("{}/bundle.js", dir),
+            "function a(b){return b+1}var c=a(1);".repeat(50),
+        )
+        .unwrap();
+
+        let excluded = get_language_breakdown(dir);
+        let config = BreakdownConfig {
+            excluded_files: ExcludedFiles::Bucket,
+            ..BreakdownConfig::default()
+        };
+        let bucketed = get_language_breakdown_with_config(dir, &config);
+        let totals = get_language_totals(dir, &config);
+
+        fs::remove_dir_all(dir).unwrap();
+
+        let mut excluded_keys: Vec<_> = excluded.keys().copied().collect();
+        excluded_keys.sort();
+        assert_eq!(excluded_keys, vec!["Go"]);
+
+        let mut bucketed_keys: Vec<_> = bucketed.keys().copied().collect();
+        bucketed_keys.sort();
+        assert_eq!(
+            bucketed_keys,
+            vec![
+                DOCUMENTATION_BUCKET,
+                GENERATED_BUCKET,
+                "Go",
+                VENDORED_BUCKET
+            ]
+        );
+        // The files keep the detection of their actual language
+        assert_eq!(bucketed[VENDORED_BUCKET][0].0, Detection::Extension("Go"));
+        assert_eq!(totals[DOCUMENTATION_BUCKET], 1.0);
+    }
+
+    #[test]
+    fn test_get_language_breakdown_tests_bucket() {
+        let dir = "temp-testing-dir-tests-breakdown";
+        fs::create_dir_all(format!("{}/src", dir)).unwrap();
+        fs::create_dir_all(format!("{}/tests", dir)).unwrap();
+        fs::create_dir_all(format!("{}/node_modules/dep/test", dir)).unwrap();
+        fs::write(format!("{}/src/main.go", dir), "package main\n").unwrap();
+        fs::write(format!("{}/src/main_test.go", dir), "package main\n").unwrap();
+        fs::write(format!("{}/src/app.spec.ts", dir), "it('works')\n").unwrap();
+        fs::write(format!("{}/tests/cli.py", dir), "import os\n").unwrap();
+        fs::write(format!("{}/node_modules/dep/test/a.go", dir), "").unwrap();
+
+        let config = BreakdownConfig {
+            test_globs: filters::DEFAULT_TEST_GLOBS
+                .iter()
+                .map(|glob| glob.to_string())
+                .collect(),
+            ..BreakdownConfig::default()
+        };
+        let breakdown = get_language_breakdown_with_config(dir, &config);
+        let bucketed = get_language_breakdown_with_config(
+            dir,
+            &BreakdownConfig {
+                excluded_files: ExcludedFiles::Bucket,
+                ..config.clone()
+            },
+        );
+        let unbucketed = get_language_breakdown(dir);
+
+        fs::remove_dir_all(dir).unwrap();
+
+        let mut keys: Vec<_> = breakdown.keys().copied().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["Go", TESTS_BUCKET]);
+        assert_eq!(breakdown[TESTS_BUCKET].len(), 3);
+        assert_eq!(breakdown["Go"][0].1, Path::new(dir).join("src/main.go"));
+
+        // Vendored tests stay vendored
+        assert_eq!(bucketed[TESTS_BUCKET].len(), 3);
+        assert_eq!(bucketed[VENDORED_BUCKET].len(), 1);
+
+        assert!(!unbucketed.contains_key(TESTS_BUCKET));
+        assert_eq!(unbucketed["Go"].len(), 2);
+    }
+
+    #[test]
+    fn test_get_language_breakdown_with_stats() {
+        let rust = "fn main() {\n    println!(\"Hello World\");\n}\n";
+        fs::create_dir_all("temp-testing-dir-stats-breakdown").unwrap();
+        fs::write(
+            "temp-testing-dir-stats-breakdown/bundle.js",
+            "function a(b){return b+1}var c=a(1);".repeat(50),
+        )
+        .unwrap();
+        fs::write("temp-testing-dir-stats-breakdown/main.rs", rust).unwrap();
+        fs::write("temp-testing-dir-stats-breakdown/Makefile", "all:\n").unwrap();
+        fs::write("temp-testing-dir-stats-breakdown/notes.unknown", "hello").unwrap();
+
+        let (breakdown, stats) = get_language_breakdown_with_stats(
+            "temp-testing-dir-stats-breakdown",
+            &BreakdownConfig::default(),
+        );
+
+        fs::remove_dir_all("temp-testing-dir-stats-breakdown").unwrap();
+
+        assert_eq!(breakdown.len(), 2);
+        assert_eq!(
+            stats,
+            ScanStats {
+                files_visited: 4,
+                files_detected: 2,
+                files_skipped: 1,
+                files_undetected: 1,
+                // The Makefile is detected by name so only main.rs and notes.unknown are read
+                bytes_read: (rust.len() + "hello".len()) as u64,
+            }
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_try_get_language_breakdown_missing_file() {
+        let dir = Path::new("temp-testing-dir-try-breakdown");
+        fs::create_dir_all(dir).unwrap();
+        fs::write(dir.join("main.go"), "package main\n").unwrap();
+        // A symlink to a file that doesn't exist is found by the walk but can't be opened, like a
+        // file that's removed between being walked and being read
+        std::os::unix::fs::symlink("removed", dir.join("script")).unwrap();
+
+        let breakdown = try_get_language_breakdown(dir);
+
+        fs::remove_dir_all(dir).unwrap();
+
+        let breakdown = breakdown.unwrap();
+        assert_eq!(breakdown.len(), 1);
+        assert_eq!(breakdown["Go"].len(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlinks() {
+        let dir = Path::new("temp-testing-dir-symlinks");
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/main.rs"), "fn main() {}\n").unwrap();
+        std::os::unix::fs::symlink("src/main.rs", dir.join("link.rs")).unwrap();
+        std::os::unix::fs::symlink("src", dir.join("linked")).unwrap();
+        // A link back up the tree is a cycle when links are followed
+        std::os::unix::fs::symlink("..", dir.join("src/parent")).unwrap();
+
+        let breakdown = |symlinks| {
+            let config = BreakdownConfig {
+                symlinks,
+                ..BreakdownConfig::default()
+            };
+            let mut breakdown = get_language_breakdown_with_config(dir, &config);
+            for files in breakdown.values_mut() {
+                files.sort_by(|(_, a), (_, b)| a.cmp(b));
+            }
+            breakdown
+        };
+        let detected = breakdown(Symlinks::Detect);
+        let followed = breakdown(Symlinks::Follow);
+        let bucketed = breakdown(Symlinks::Bucket);
+
+        fs::remove_dir_all(dir).unwrap();
+
+        let paths = |files: &Vec<(Detection, PathBuf)>| {
+            files
+                .iter()
+                .map(|(_, path)| path.clone())
+                .collect::<Vec<PathBuf>>()
+        };
+        assert_eq!(detected.len(), 1);
+        assert_eq!(
+            paths(&detected["Rust"]),
+            vec![dir.join("link.rs"), dir.join("src/main.rs")]
+        );
+
+        assert_eq!(followed.len(), 1);
+        assert_eq!(
+            paths(&followed["Rust"]),
+            vec![
+                dir.join("link.rs"),
+                dir.join("linked/main.rs"),
+                dir.join("src/main.rs")
+            ]
+        );
+
+        assert_eq!(bucketed.len(), 2);
+        assert_eq!(paths(&bucketed["Rust"]), vec![dir.join("src/main.rs")]);
+        assert_eq!(
+            bucketed[SYMLINKS_BUCKET],
+            vec![(Detection::Heuristics("Rust"), dir.join("link.rs"))]
+        );
+    }
+
+    #[test]
+    fn test_objective_c_header_hints() {
+        let dir = Path::new("temp-testing-dir-objective-c-headers");
+        fs::create_dir_all(dir.join("App")).unwrap();
+        fs::create_dir_all(dir.join("lib")).unwrap();
+        fs::write(dir.join("App/Greeter.h"), "void greet(const char *name);\n").unwrap();
+        fs::write(
+            dir.join("App/Greeter.m"),
+            "#import \"Greeter.h\"\n\n@implementation Greeter\n@end\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("App/Vector.h"),
+            "#include <vector>\nstd::vector<int> values();\n",
+        )
+        .unwrap();
+        fs::write(dir.join("lib/util.h"), "int add(int a, int b);\n").unwrap();
+
+        let config = BreakdownConfig {
+            objective_c_header_hints: true,
+            ..BreakdownConfig::default()
+        };
+        let hinted = get_language_breakdown_with_config(dir, &config);
+        let unhinted = get_language_breakdown(dir);
+
+        fs::remove_dir_all(dir).unwrap();
+
+        let mut objective_c: Vec<&PathBuf> =
+            hinted["Objective-C"].iter().map(|(_, path)| path).collect();
+        objective_c.sort();
+        assert_eq!(
+            objective_c,
+            vec![&dir.join("App/Greeter.h"), &dir.join("App/Greeter.m")]
+        );
+        // Headers the heuristics detect as C++ and headers without .m files next to them are
+        // unaffected
+        assert_eq!(
+            hinted["C++"],
+            vec![(Detection::Heuristics("C++"), dir.join("App/Vector.h"))]
+        );
+        assert_eq!(
+            hinted["C"],
+            vec![(Detection::Heuristics("C"), dir.join("lib/util.h"))]
+        );
+
+        assert_eq!(unhinted["C"].len(), 2);
+        assert_eq!(unhinted["Objective-C"].len(), 1);
+    }
+
+    #[test]
+    fn test_list_files() {
+        let dir = Path::new("temp-testing-dir-list-files");
+        fs::create_dir_all(dir.join("node_modules/dep")).unwrap();
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(
+            dir.join("node_modules/dep/index.js"),
+            "module.exports = 1;\n",
+        )
+        .unwrap();
+        fs::write(dir.join("src/main.go"), "package main\n").unwrap();
+        fs::write(dir.join("build"), "#!/bin/sh\n").unwrap();
+
+        let files = list_files(dir, &BreakdownConfig::default());
+        let breakdown = get_language_breakdown(dir);
+
+        fs::remove_dir_all(dir).unwrap();
+
+        // Vendored code is skipped the same way it is for a breakdown
+        assert_eq!(files, vec![dir.join("build"), dir.join("src/main.go")]);
+        let mut detected: Vec<PathBuf> = breakdown
+            .into_values()
+            .flat_map(|files| files.into_iter().map(|(_, path)| path))
+            .collect();
+        detected.sort();
+        assert_eq!(files, detected);
+    }
+
+    #[cfg(feature = "classifier")]
+    #[test]
+    fn test_detect_top_n() {
+        let dir = Path::new("temp-testing-dir-detect-top-n");
+        fs::create_dir_all(dir).unwrap();
+        // Nothing but the classifier picks between the languages that use .m for this file
+        let square = dir.join("square.m");
+        fs::write(&square, "function y = square(x)\n  y = x .^ 2;\nend\n").unwrap();
+        let main = dir.join("main.go");
+        fs::write(&main, "package main\n").unwrap();
+        let notes = dir.join("notes");
+        fs::write(&notes, "remember the milk\n").unwrap();
+
+        let top_three = detect_top_n(&square, 3).unwrap().unwrap();
+        let detection = detect(&square).unwrap();
+        let top_go = detect_top_n(&main, 3).unwrap();
+        let top_notes = detect_top_n(&notes, 3).unwrap();
+        let top_none = detect_top_n(&square, 0).unwrap();
+
+        fs::remove_dir_all(dir).unwrap();
+
+        assert_eq!(top_three.len(), 3);
+        assert_eq!(detection, Some(Detection::Classifier(top_three[0].0)));
+        assert!(top_three
+            .windows(2)
+            .all(|pair| pair[0].1 >= pair[1].1 && pair[1].1 > 0.0));
+        assert!(
+            top_three
+                .iter()
+                .map(|(_, confidence)| confidence)
+                .sum::<f64>()
+                <= 1.0
+        );
+
+        assert_eq!(top_go, Some(vec![("Go", 1.0)]));
+        // Like detect, a file detected by its extension isn't read
+        assert_eq!(
+            detect_top_n(Path::new("does-not-exist.go"), 3).unwrap(),
+            Some(vec![("Go", 1.0)])
+        );
+        assert_eq!(top_notes, None);
+        assert_eq!(top_none, Some(vec![]));
+    }
+
+    #[test]
+    fn test_detect_with_match() {
+        let dir = Path::new("temp-testing-dir-detect-with-match");
+        fs::create_dir_all(dir).unwrap();
+        fs::write(dir.join("Makefile"), "all:\n\techo hi\n").unwrap();
+        fs::write(dir.join("main.GO"), "package main\n").unwrap();
+        fs::write(
+            dir.join("script"),
+            "#!/usr/bin/env python3.8\nprint('hi')\n",
+        )
+        .unwrap();
+        fs::write(dir.join("lib.rs"), "fn main() {}\n").unwrap();
+
+        let filename = detect_with_match(&dir.join("Makefile"));
+        let extension = detect_with_match(&dir.join("main.GO"));
+        let shebang = detect_with_match(&dir.join("script"));
+        let heuristics = detect_with_match(&dir.join("lib.rs"));
+
+        fs::remove_dir_all(dir).unwrap();
+
+        assert_eq!(
+            filename.unwrap(),
+            Some((
+                Detection::Filename("Makefile"),
+                Some(DetectionMatch::Filename(String::from("Makefile")))
+            ))
+        );
+        assert_eq!(
+            extension.unwrap(),
+            Some((
+                Detection::Extension("Go"),
+                Some(DetectionMatch::Extension(String::from(".go")))
+            ))
+        );
+        assert_eq!(
+            shebang.unwrap(),
+            Some((
+                Detection::Shebang("Python"),
+                Some(DetectionMatch::Interpreter(String::from("python")))
+            ))
+        );
+        assert_eq!(
+            heuristics.unwrap(),
+            Some((Detection::Heuristics("Rust"), None))
+        );
+    }
+
+    #[test]
+    fn test_detection_candidates_header() {
+        let dir = Path::new("temp-testing-dir-detection-candidates");
+        fs::create_dir_all(dir).unwrap();
+        fs::write(
+            dir.join("Widget.h"),
+            "#import <Foundation/Foundation.h>\n\n@interface Widget : NSObject\n@end\n",
+        )
+        .unwrap();
+        fs::write(dir.join("math.h"), "int add(int a, int b);\n").unwrap();
+
+        let objective_c = detection_candidates(dir.join("Widget.h"));
+        let c = detection_candidates(dir.join("math.h"));
+        let detected = detect(&dir.join("math.h"));
+
+        fs::remove_dir_all(dir).unwrap();
+
+        let objective_c = objective_c.unwrap();
+        assert_eq!(objective_c.extension, vec!["Objective-C", "C", "C++"]);
+        assert_eq!(objective_c.after_shebang, objective_c.extension);
+        assert_eq!(objective_c.after_heuristics, vec!["Objective-C"]);
+
+        let c = c.unwrap();
+        assert_eq!(c.after_heuristics, vec!["C"]);
+        assert_eq!(
+            detected.unwrap().map(|detection| detection.language()),
+            Some("C")
+        );
+
+        // Files with a single candidate aren't read
+        let candidates = detection_candidates("does-not-exist.go").unwrap();
+        assert_eq!(candidates.after_heuristics, vec!["Go"]);
+        assert!(detection_candidates("does-not-exist.h").is_err());
+    }
+
+    #[test]
+    fn test_scan_verbose() {
+        let dir = Path::new("temp-testing-dir-scan-verbose");
+        fs::create_dir_all(dir.join("node_modules/dep")).unwrap();
+        fs::write(dir.join("main.go"), "package main\n").unwrap();
+        fs::write(dir.join("point.h"), "@interface Point\n@end\n").unwrap();
+        fs::write(dir.join("notes"), "remember the milk\n").unwrap();
+        fs::write(
+            dir.join("node_modules/dep/index.js"),
+            "module.exports = 1;\n",
+        )
+        .unwrap();
+
+        let traces = scan_verbose(dir, &BreakdownConfig::default());
+        let single = detect_verbose(dir.join("point.h"));
+
+        fs::remove_dir_all(dir).unwrap();
+
+        // Vendored code is skipped and undetected files are traced
+        let paths: Vec<&PathBuf> = traces.iter().map(|(path, _)| path).collect();
+        assert_eq!(
+            paths,
+            vec![
+                &dir.join("main.go"),
+                &dir.join("notes"),
+                &dir.join("point.h")
+            ]
+        );
+
+        let (_, go) = &traces[0];
+        assert_eq!(go.detection, Some((Detection::Extension("Go"), 1.0)));
+        assert_eq!(go.candidates.after_heuristics, vec!["Go"]);
+
+        let (_, notes) = &traces[1];
+        assert_eq!(notes.detection, None);
+        assert!(notes.candidates.extension.is_empty());
+
+        let (_, header) = &traces[2];
+        assert_eq!(
+            header.detection,
+            Some((Detection::Heuristics("Objective-C"), 1.0))
+        );
+        assert_eq!(header.candidates.extension.len(), 3);
+        assert_eq!(header.candidates.after_heuristics, vec!["Objective-C"]);
+        assert_eq!(&single.unwrap(), header);
+    }
+
+    #[test]
+    fn test_detect_verbose_truncated() {
+        let dir = Path::new("temp-testing-dir-detect-verbose-truncated");
+        fs::create_dir_all(dir).unwrap();
+        let padding = "// padding\n".repeat(MAX_CONTENT_SIZE_BYTES / 10);
+        fs::write(dir.join("small.h"), "@interface Foo\n@end\n").unwrap();
+        fs::write(
+            dir.join("large.h"),
+            format!("@interface Foo\n@end\n{}", padding),
+        )
+        .unwrap();
+        let exact = format!("@interface Foo\n@end\n{}", padding);
+        fs::write(dir.join("exact.h"), &exact[..MAX_CONTENT_SIZE_BYTES]).unwrap();
+        // Only the extension is used so the file isn't read
+        fs::write(dir.join("large.go"), format!("package main\n{}", padding)).unwrap();
+
+        let small = detect_verbose(dir.join("small.h"));
+        let large = detect_verbose(dir.join("large.h"));
+        let exact = detect_verbose(dir.join("exact.h"));
+        let large_go = detect_verbose(dir.join("large.go"));
+        let (_, large_bytes_read) =
+            detect_path(&dir.join("large.h"), &Detector::default()).unwrap();
+        let (_, go_bytes_read) = detect_path(&dir.join("large.go"), &Detector::default()).unwrap();
+
+        fs::remove_dir_all(dir).unwrap();
+
+        assert!(!small.unwrap().truncated);
+        let large = large.unwrap();
+        assert!(large.truncated);
+        assert_eq!(
+            large.detection,
+            Some((Detection::Heuristics("Objective-C"), 1.0))
+        );
+        assert_eq!(large.candidates.after_heuristics, vec!["Objective-C"]);
+        // A file that fits in the content that's read exactly isn't truncated
+        assert!(!exact.unwrap().truncated);
+        assert!(!large_go.unwrap().truncated);
+        // The bytes counted are only the ones read for detection
+        assert_eq!(large_bytes_read, MAX_CONTENT_SIZE_BYTES as u64);
+        assert_eq!(go_bytes_read, 0);
+    }
+
+    #[test]
+    fn test_detect_paths() {
+        let dir = Path::new("temp-testing-dir-detect-paths");
+        fs::create_dir_all(dir.join("node_modules/dep")).unwrap();
+        fs::write(dir.join("main.go"), "package main\n").unwrap();
+        fs::write(
+            dir.join("node_modules/dep/index.js"),
+            "module.exports = 1;\n",
+        )
+        .unwrap();
+        fs::write(dir.join("notes"), "\n").unwrap();
+
+        let (breakdown, stats) = detect_paths(
+            &[
+                dir.join("main.go"),
+                dir.join("node_modules/dep/index.js"),
+                dir.join("main.go"),
+                dir.join("notes"),
+                dir.join("missing.go"),
+            ],
+            &BreakdownConfig::default(),
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+
+        // The listed files are detected even if a walk would skip them as vendored code
+        assert_eq!(breakdown.get("Go").unwrap().len(), 1);
+        assert_eq!(breakdown.get("JavaScript").unwrap().len(), 1);
+        assert_eq!(stats.files_visited, 4);
+        assert_eq!(stats.files_skipped, 1);
+        assert_eq!(stats.files_detected, 2);
+        assert_eq!(stats.files_undetected, 1);
+    }
+
+    #[test]
+    fn test_expand_glob() {
+        let dir = Path::new("temp-testing-dir-expand-glob");
+        fs::create_dir_all(dir.join("src/nested")).unwrap();
+        fs::create_dir_all(dir.join("lib")).unwrap();
+        fs::write(dir.join("src/main.go"), "package main\n").unwrap();
+        fs::write(dir.join("src/nested/util.go"), "package nested\n").unwrap();
+        fs::write(dir.join("src/nested/util.py"), "print('hi')\n").unwrap();
+        fs::write(dir.join("lib/lib.go"), "package lib\n").unwrap();
+
+        let config = BreakdownConfig::default();
+        let recursive = expand_glob("temp-testing-dir-expand-glob/src/**/*.go", &config);
+        let top_level = expand_glob("temp-testing-dir-expand-glob/*/*.go", &config);
+        let anchored = expand_glob("temp-testing-dir-expand-glob/src/*.go", &config);
+        let breakdown = get_language_breakdown_multi(&recursive);
+
+        fs::remove_dir_all(dir).unwrap();
+
+        assert!(is_glob("temp-testing-dir-expand-glob/src/**/*.go"));
+        assert!(!is_glob("src/lib.rs"));
+        assert_eq!(
+            recursive,
+            vec![dir.join("src/main.go"), dir.join("src/nested/util.go")]
+        );
+        assert_eq!(
+            top_level,
+            vec![dir.join("lib/lib.go"), dir.join("src/main.go")]
+        );
+        // A glob without ** only matches the files directly in the directory
+        assert_eq!(anchored, vec![dir.join("src/main.go")]);
+        assert_eq!(breakdown.len(), 1);
+        assert_eq!(breakdown.get("Go").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_get_language_breakdown_multi() {
+        let dir = Path::new("temp-testing-dir-multi-breakdown");
+        fs::create_dir_all(dir.join("src/nested")).unwrap();
+        fs::create_dir_all(dir.join("lib")).unwrap();
+        fs::write(dir.join("src/main.go"), "package main\n").unwrap();
+        fs::write(dir.join("src/nested/util.go"), "package nested\n").unwrap();
+        fs::write(dir.join("lib/lib.py"), "print('hi')\n").unwrap();
+
+        let breakdown = get_language_breakdown_multi(&[
+            dir.join("src"),
+            dir.join("lib"),
+            dir.join("src/nested"),
+        ]);
+
+        fs::remove_dir_all(dir).unwrap();
+
+        let mut go_files: Vec<&PathBuf> = breakdown["Go"].iter().map(|(_, path)| path).collect();
+        go_files.sort();
+        assert_eq!(
+            go_files,
+            vec![&dir.join("src/main.go"), &dir.join("src/nested/util.go")]
+        );
+        assert_eq!(breakdown["Python"].len(), 1);
+    }
+
+    #[test]
+    fn test_get_language_breakdown_with_cache() {
+        let dir = Path::new("temp-testing-dir-cache");
+        fs::create_dir_all(dir).unwrap();
+        let script = dir.join("script");
+        fs::write(&script, "#!/usr/bin/env python\n").unwrap();
+
+        let mut cache = DetectionCache::new();
+        let breakdown = get_language_breakdown_with_cache(dir, &mut cache);
+        assert_eq!(
+            breakdown.get("Python"),
+            Some(&vec![(Detection::Shebang("Python"), script.clone())])
+        );
+        assert_eq!(cache.len(), 1);
+
+        // Unchanged files use the cached detection
+        let stamp = FileStamp::from_path(&script).unwrap();
+        cache.insert(script.clone(), stamp, Detection::Extension("Go"), 1.0);
+        let breakdown = get_language_breakdown_with_cache(dir, &mut cache);
+        assert!(breakdown.contains_key("Go"));
+
+        // Changed files are detected again
+        fs::write(&script, "#!/usr/bin/env ruby\nputs 'hello'\n").unwrap();
+        let breakdown = get_language_breakdown_with_cache(dir, &mut cache);
+        assert!(breakdown.contains_key("Ruby"));
+
+        // Files that no longer exist are dropped from the cache
+        fs::remove_file(&script).unwrap();
+        let breakdown = get_language_breakdown_with_cache(dir, &mut cache);
+
+        fs::remove_dir_all(dir).unwrap();
+
+        assert!(breakdown.is_empty());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_get_language_breakdown_newer_languages() {
+        let dir = Path::new("temp-testing-dir-newer-languages");
+        fs::create_dir_all(dir).unwrap();
+        for file in &[
+            "default.nix",
+            "part.scad",
+            "main.zig",
+            "app.gleam",
+            "main.roc",
+        ] {
+            fs::File::create(dir.join(file)).unwrap();
+        }
+
+        let breakdown = get_language_breakdown(dir);
+        let detector = Detector::new()
+            .add_extension(".gleam", "Gleam")
+            .add_extension(".roc", "Roc");
+        let registered_breakdown = detector.get_language_breakdown(dir);
+
+        fs::remove_dir_all(dir).unwrap();
+
+        let mut languages: Vec<&str> = breakdown.keys().copied().collect();
+        languages.sort();
+        assert_eq!(languages, vec!["Nix", "OpenSCAD", "Zig"]);
+
+        let mut languages: Vec<&str> = registered_breakdown.keys().copied().collect();
+        languages.sort();
+        assert_eq!(languages, vec!["Gleam", "Nix", "OpenSCAD", "Roc", "Zig"]);
+    }
+
+    #[test]
+    fn test_get_language_breakdown_hidden_files() {
+        let dir = Path::new("temp-testing-dir-hidden");
+        fs::create_dir_all(dir.join(".git")).unwrap();
+        fs::write(dir.join(".editorconfig"), "root = true\n").unwrap();
+        fs::write(dir.join(".npmignore"), "target/\n").unwrap();
+        // Git's own config files are vendored like they are in Linguist
+        fs::write(dir.join(".gitignore"), "target/\n").unwrap();
+        fs::write(dir.join(".git/config.rb"), "puts 'hello'\n").unwrap();
+
+        let default_breakdown = get_language_breakdown(dir);
+        let config = BreakdownConfig {
+            include_hidden: true,
+            ..BreakdownConfig::default()
+        };
+        let hidden_breakdown = get_language_breakdown_with_config(dir, &config);
+
+        fs::remove_dir_all(dir).unwrap();
+
+        assert!(default_breakdown.is_empty());
+        let mut languages: Vec<&str> = hidden_breakdown.keys().copied().collect();
+        languages.sort();
+        assert_eq!(languages, vec!["EditorConfig", "Ignore List"]);
+    }
+
+    #[test]
+    fn test_get_language_breakdown_include_globs() {
+        let dir = Path::new("temp-testing-dir-include-globs");
+        fs::create_dir_all(dir.join("vendor/mine")).unwrap();
+        fs::create_dir_all(dir.join("vendor/theirs")).unwrap();
+        fs::write(dir.join("vendor/mine/main.go"), "package main\n").unwrap();
+        fs::write(dir.join("vendor/theirs/lib.go"), "package lib\n").unwrap();
+        fs::write(dir.join("app.purs"), "").unwrap();
+
+        let default_breakdown = get_language_breakdown(dir);
+        let config = BreakdownConfig {
+            include_globs: vec![String::from("vendor/mine/**")],
+            ..BreakdownConfig::default()
+        };
+        let included_breakdown = get_language_breakdown_with_config(dir, &config);
+
+        fs::remove_dir_all(dir).unwrap();
+
+        assert!(!default_breakdown.contains_key("Go"));
+        assert_eq!(
+            included_breakdown.get("Go"),
+            Some(&vec![(
+                Detection::Extension("Go"),
+                dir.join("vendor/mine/main.go")
+            )])
+        );
+        assert_eq!(
+            included_breakdown.get("PureScript"),
+            Some(&vec![(
+                Detection::Extension("PureScript"),
+                dir.join("app.purs")
+            )])
+        );
+    }
+
+    #[test]
+    fn test_get_language_breakdown_linguist_detectable() {
+        let dir = Path::new("temp-testing-dir-linguist-detectable");
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::create_dir_all(dir.join("vendor/mine")).unwrap();
+        fs::write(
+            dir.join(".gitattributes"),
+            "*.rs linguist-detectable=false\nvendor/mine/** linguist-detectable=true\n",
+        )
+        .unwrap();
+        fs::write(dir.join("src/main.rs"), "fn main() {}\n").unwrap();
+        fs::write(dir.join("src/lib.rs"), "pub fn lib() {}\n").unwrap();
+        fs::write(dir.join("main.go"), "package main\n").unwrap();
+        fs::write(dir.join("vendor/mine/lib.go"), "package lib\n").unwrap();
+        fs::write(dir.join("vendor/theirs.go"), "package theirs\n").unwrap();
+
+        let breakdown = get_language_breakdown(dir);
+
+        fs::remove_dir_all(dir).unwrap();
+
+        assert!(!breakdown.contains_key("Rust"));
+        let mut go_files: Vec<&PathBuf> = breakdown["Go"].iter().map(|(_, path)| path).collect();
+        go_files.sort();
+        assert_eq!(
+            go_files,
+            vec![&dir.join("main.go"), &dir.join("vendor/mine/lib.go")]
+        );
+    }
+
     #[test]
     fn test_get_language_breakdown_ignores_overrides_vendor() {
         fs::create_dir_all("temp-testing-dir2/node_modules").unwrap();
@@ -0,0 +1,73 @@
+use std::{
+    fmt,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+};
+
+/// Counts of the work done while walking a path for a breakdown
+///
+/// Only files that make it past the ignore rules are visited, so files excluded by .gitignore or
+/// skipped as documentation or vendored code aren't counted. Every file visited is either
+/// detected, skipped, or undetected.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct ScanStats {
+    /// The number of files visited by the walk
+    pub files_visited: usize,
+    /// The number of files a language was detected for
+    pub files_detected: usize,
+    /// The number of files that were skipped because they were minified or couldn't be read
+    pub files_skipped: usize,
+    /// The number of files that were read but no language was detected for
+    pub files_undetected: usize,
+    /// The total number of bytes read from the files visited. At most the first 50 KiB of a file
+    /// are read and files detected by their name or a cached detection aren't read at all
+    pub bytes_read: u64,
+}
+
+impl fmt::Display for ScanStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Files visited: {}", self.files_visited)?;
+        writeln!(f, "Files detected: {}", self.files_detected)?;
+        writeln!(f, "Files skipped: {}", self.files_skipped)?;
+        writeln!(f, "Files undetected: {}", self.files_undetected)?;
+        write!(f, "Bytes read: {}", self.bytes_read)
+    }
+}
+
+// The counters behind ScanStats, shared between the threads of a parallel walk
+#[derive(Debug, Default)]
+pub(crate) struct ScanCounters {
+    files_visited: AtomicUsize,
+    files_detected: AtomicUsize,
+    files_skipped: AtomicUsize,
+    files_undetected: AtomicUsize,
+    bytes_read: AtomicU64,
+}
+
+impl ScanCounters {
+    pub(crate) fn add_detected(&self, bytes_read: u64) {
+        self.files_visited.fetch_add(1, Ordering::Relaxed);
+        self.files_detected.fetch_add(1, Ordering::Relaxed);
+        self.bytes_read.fetch_add(bytes_read, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_skipped(&self) {
+        self.files_visited.fetch_add(1, Ordering::Relaxed);
+        self.files_skipped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_undetected(&self, bytes_read: u64) {
+        self.files_visited.fetch_add(1, Ordering::Relaxed);
+        self.files_undetected.fetch_add(1, Ordering::Relaxed);
+        self.bytes_read.fetch_add(bytes_read, Ordering::Relaxed);
+    }
+
+    pub(crate) fn stats(&self) -> ScanStats {
+        ScanStats {
+            files_visited: self.files_visited.load(Ordering::Relaxed),
+            files_detected: self.files_detected.load(Ordering::Relaxed),
+            files_skipped: self.files_skipped.load(Ordering::Relaxed),
+            files_undetected: self.files_undetected.load(Ordering::Relaxed),
+            bytes_read: self.bytes_read.load(Ordering::Relaxed),
+        }
+    }
+}
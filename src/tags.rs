@@ -0,0 +1,104 @@
+use crate::detectors;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{fmt, path::Path};
+
+lazy_static! {
+    static ref PROTO_SERVICE: Regex =
+        Regex::new(r"(?m)^\s*(service\s+\w+\s*\{|rpc\s+\w+\s*\()").unwrap();
+    static ref PROTO_MESSAGE: Regex = Regex::new(r"(?m)^\s*(message|enum)\s+\w+\s*\{").unwrap();
+}
+
+/// A finer grained description of what a file holds than its language, ex/ whether a Protocol
+/// Buffer file defines gRPC services
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ContentTag {
+    /// A Protocol Buffer file that defines at least one gRPC `service` or `rpc`
+    ProtobufService,
+    /// A Protocol Buffer file that defines at least one `message` or `enum`
+    ProtobufMessage,
+}
+
+impl fmt::Display for ContentTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContentTag::ProtobufService => write!(f, "Protobuf Service"),
+            ContentTag::ProtobufMessage => write!(f, "Protobuf Message"),
+        }
+    }
+}
+
+/// Tags the content of a file with what it holds beyond its language
+///
+/// This is a sub-classification for inventories like finding the API definitions in a repo and
+/// doesn't change what the file is detected as. Only `.proto` files are tagged for now, with
+/// [`ContentTag::ProtobufService`] when they define gRPC services and
+/// [`ContentTag::ProtobufMessage`] when they define messages or enums. A schema-only file only has
+/// the message tag. Files of other types don't have any tags.
+///
+/// # Examples
+/// ```
+/// use hyperpolyglot::{content_tags, ContentTag};
+/// use std::path::Path;
+///
+/// let schema = "syntax = \"proto3\";\nmessage Point {\n  int32 x = 1;\n}\n";
+/// assert_eq!(content_tags(Path::new("point.proto"), schema), vec![ContentTag::ProtobufMessage]);
+/// ```
+pub fn content_tags(path: &Path, content: &str) -> Vec<ContentTag> {
+    let extension = path
+        .file_name()
+        .and_then(|filename| filename.to_str())
+        .and_then(detectors::get_extension);
+    let mut tags = vec![];
+    if extension == Some(".proto") {
+        if PROTO_SERVICE.is_match(content) {
+            tags.push(ContentTag::ProtobufService);
+        }
+        if PROTO_MESSAGE.is_match(content) {
+            tags.push(ContentTag::ProtobufMessage);
+        }
+    }
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_tags_grpc_service() {
+        let service = r#"syntax = "proto3";
+
+package helloworld;
+
+service Greeter {
+  rpc SayHello (HelloRequest) returns (HelloReply) {}
+}
+
+message HelloRequest {
+  string name = 1;
+}
+
+message HelloReply {
+  string message = 1;
+}
+"#;
+        assert_eq!(
+            content_tags(Path::new("protos/helloworld.proto"), service),
+            vec![ContentTag::ProtobufService, ContentTag::ProtobufMessage]
+        );
+    }
+
+    #[test]
+    fn test_content_tags_messages_only() {
+        let schema = "syntax = \"proto3\";\n\n// service Greeter is defined elsewhere\nenum Color {\n  RED = 0;\n}\n";
+        assert_eq!(
+            content_tags(Path::new("color.proto"), schema),
+            vec![ContentTag::ProtobufMessage]
+        );
+        assert_eq!(
+            content_tags(Path::new("notes.txt"), "service Greeter {\n}\n"),
+            vec![]
+        );
+    }
+}
@@ -0,0 +1,29 @@
+// Builds and runs with the classifier left out, `cargo test --no-default-features`
+#![cfg(not(feature = "classifier"))]
+
+use hyperpolyglot::{classify_content, detect_from_bytes, detectors, Detection};
+
+#[test]
+fn test_detect_without_classifier() {
+    let matlab = b"function y = square(x)\n  y = x .^ 2;\nend\n";
+    // Ambiguous files aren't detected, like they aren't when the classifier is disabled at runtime
+    assert_eq!(detect_from_bytes(Some("square.m"), matlab), None);
+    assert_eq!(detectors::try_classify("let x = 1;", &["Rust"]), None);
+    assert_eq!(classify_content("fn main() {}\n"), None);
+    // Without any candidates there's no first candidate to fall back to
+    assert_eq!(
+        detectors::classify_with_confidence("let x = 1;", &[]),
+        ("", 0.0)
+    );
+    assert_eq!(detectors::classify("let x = 1;", &[]), "");
+
+    // The other strategies don't need the classifier
+    assert_eq!(
+        detect_from_bytes(Some("main.go"), b"package main\n"),
+        Some((Detection::Extension("Go"), 1.0))
+    );
+    assert_eq!(
+        detect_from_bytes(Some("point.h"), b"@interface Point\n@end\n"),
+        Some((Detection::Heuristics("Objective-C"), 1.0))
+    );
+}
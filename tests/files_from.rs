@@ -0,0 +1,54 @@
+use std::{
+    fs,
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+#[test]
+fn test_files_from_stdin() {
+    let dir = Path::new("temp-testing-dir-files-from");
+    fs::create_dir_all(dir.join("vendor")).unwrap();
+    fs::write(dir.join("main.go"), "package main\n").unwrap();
+    fs::write(dir.join("util.go"), "package main\n").unwrap();
+    fs::write(dir.join("vendor/lib.py"), "print('hi')\n").unwrap();
+    fs::write(dir.join("unlisted.py"), "print('hi')\n").unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_hyply"))
+        .args(["--files-from", "-", "--no-color", "--breakdown"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let file_list = format!(
+        "{}\n\n{}\n{}\n{}\n",
+        dir.join("main.go").display(),
+        dir.join("util.go").display(),
+        dir.join("vendor/lib.py").display(),
+        dir.join("missing.go").display()
+    );
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(file_list.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    fs::remove_dir_all(dir).unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    // Only the listed files are detected, including the vendored one a walk would skip
+    assert!(stdout.contains("66.67% Go"), "{}", stdout);
+    assert!(stdout.contains("33.33% Python"), "{}", stdout);
+    assert!(!stdout.contains("unlisted.py"), "{}", stdout);
+    assert!(stdout.contains("vendor/lib.py"), "{}", stdout);
+    assert!(
+        stderr.contains("Skipping temp-testing-dir-files-from/missing.go which doesn't exist"),
+        "{}",
+        stderr
+    );
+}
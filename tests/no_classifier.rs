@@ -0,0 +1,53 @@
+// Compares against a run with the classifier, which needs the model
+#![cfg(feature = "classifier")]
+
+use std::{fs, path::Path, process::Command};
+
+#[test]
+fn test_no_classifier() {
+    let dir = Path::new("temp-testing-dir-no-classifier");
+    fs::create_dir_all(dir).unwrap();
+    // .m is MATLAB, Objective-C, and a few others, and nothing but the classifier picks between
+    // them for this file
+    fs::write(
+        dir.join("square.m"),
+        "function y = square(x)\n  y = x .^ 2;\nend\n",
+    )
+    .unwrap();
+    // The heuristics always pick a language for a .h file
+    fs::write(dir.join("point.h"), "@interface Point\n@end\n").unwrap();
+    fs::write(dir.join("main.go"), "package main\n").unwrap();
+
+    let run = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_hyply"))
+            .args(args)
+            .arg("--no-color")
+            .arg("--breakdown")
+            .arg(dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+    let with_classifier = run(&[]);
+    let without_classifier = run(&["--no-classifier"]);
+
+    fs::remove_dir_all(dir).unwrap();
+
+    assert!(with_classifier.contains("square.m"), "{}", with_classifier);
+    assert!(
+        !without_classifier.contains("square.m"),
+        "{}",
+        without_classifier
+    );
+    assert!(
+        without_classifier.contains("50.00% Go"),
+        "{}",
+        without_classifier
+    );
+    assert!(
+        without_classifier.contains("50.00% Objective-C"),
+        "{}",
+        without_classifier
+    );
+}